@@ -1,3 +1,5 @@
+use crate::bus::EventBus;
+use crate::limiter::FrameLimiter;
 pub use crate::timer::AppTimer;
 use crate::{Backend, WindowBackend};
 
@@ -32,6 +34,12 @@ pub struct App {
     /// App timer
     pub timer: AppTimer,
 
+    /// Frame rate limiter
+    pub limiter: FrameLimiter,
+
+    /// User-defined events shared between the callbacks, plugins and loaders
+    pub bus: EventBus,
+
     #[cfg(feature = "audio")]
     /// Audio manager
     pub audio: Audio,
@@ -53,6 +61,8 @@ impl App {
             touch,
             system_timer: AppTimer::default(),
             timer: AppTimer::default(),
+            limiter: FrameLimiter::default(),
+            bus: EventBus::default(),
             closed: false,
         }
     }
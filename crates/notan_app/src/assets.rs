@@ -1,13 +1,37 @@
 mod asset;
+mod bundle;
+mod cache;
+mod decoder;
+mod dependencies;
+mod fallback;
 mod list;
 mod loader;
 mod manager;
+mod preprocess;
+mod progress;
+mod queue;
+mod retry;
+#[cfg(feature = "serde_loaders")]
+mod serde_loader;
+mod sniff;
+mod source;
 mod storage;
 mod utils;
 mod waker;
+#[cfg(all(feature = "hot_reload", not(target_arch = "wasm32")))]
+mod watcher;
 
 pub use asset::*;
+pub use bundle::*;
+pub use dependencies::Dependencies;
 pub use list::*;
 pub use loader::*;
 pub use manager::*;
+pub use preprocess::Preprocessor;
+pub use progress::*;
+pub use queue::{LoadBudget, Priority};
+pub use retry::RetryPolicy;
+#[cfg(feature = "serde_loaders")]
+pub use serde_loader::SerdeLoader;
+pub use source::*;
 pub use storage::*;
@@ -1,6 +1,7 @@
+use super::progress::LoadProgress;
 use super::utils::DoneSignal;
 use parking_lot::{MappedRwLockReadGuard, RwLock, RwLockReadGuard};
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 
 /// Read-Only representation of an asset loaded from a file
 #[derive(Debug)]
@@ -10,6 +11,7 @@ where
 {
     pub(crate) id: String,
     pub(crate) loaded: DoneSignal,
+    pub(crate) progress: LoadProgress,
     pub(crate) inner: Arc<RwLock<Option<A>>>,
 }
 
@@ -34,7 +36,8 @@ where
         }))
     }
 
-    /// Consume the asset and returns the inner asset if it's already loaded and exists just one reference to it
+    /// Consume the asset and returns the inner asset if it's already loaded and this is its only handle.
+    /// The assets cache keeps a reference too while it has a budget, see [Assets::set_cache_budget](super::Assets::set_cache_budget)
     pub fn try_unwrap(self) -> Result<A, String> {
         if !self.is_loaded() {
             return Err(format!(
@@ -59,6 +62,38 @@ where
         self.loaded.is_done()
     }
 
+    /// Returns the bytes of the file read, the asset can be still parsing when all of them are read
+    pub fn bytes_loaded(&self) -> u64 {
+        self.progress.loaded()
+    }
+
+    /// Returns the error if the asset failed to load
+    pub fn error(&self) -> Option<String> {
+        self.progress.error()
+    }
+
+    /// Returns true if the asset failed to load and the value is the fallback of its type,
+    /// set with [Assets::set_fallback](super::Assets::set_fallback)
+    pub fn is_fallback(&self) -> bool {
+        self.progress.is_fallback()
+    }
+
+    /// Returns the size of the file if the source knows it
+    pub fn bytes_total(&self) -> Option<u64> {
+        self.progress.total()
+    }
+
+    /// Creates a handle that doesn't keep the asset alive, so it can be
+    /// unloaded once the rest of handles are dropped
+    pub fn downgrade(&self) -> WeakAsset<A> {
+        WeakAsset {
+            id: self.id.clone(),
+            loaded: self.loaded.clone(),
+            progress: self.progress.clone(),
+            inner: Arc::downgrade(&self.inner),
+        }
+    }
+
     /// Create a new asset from custom data
     pub fn from_data(id: &str, data: A) -> Asset<A> {
         Self::from_option(id, Some(data))
@@ -69,6 +104,7 @@ where
         Asset {
             id: id.to_string(),
             loaded: DoneSignal::from_bool(true),
+            progress: LoadProgress::default(),
             inner: Arc::new(RwLock::new(data)),
         }
     }
@@ -93,7 +129,70 @@ where
         Asset {
             id: self.id.clone(),
             loaded: self.loaded.clone(),
+            progress: self.progress.clone(),
             inner: self.inner.clone(),
         }
     }
 }
+
+/// Handle to an asset that doesn't keep it alive, created with [Asset::downgrade]
+#[derive(Debug)]
+pub struct WeakAsset<A>
+where
+    A: Send + Sync,
+{
+    id: String,
+    loaded: DoneSignal,
+    progress: LoadProgress,
+    inner: Weak<RwLock<Option<A>>>,
+}
+
+impl<A> WeakAsset<A>
+where
+    A: Send + Sync,
+{
+    /// Returns the id of the asset
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the asset if it was not dropped yet
+    pub fn upgrade(&self) -> Option<Asset<A>> {
+        Some(Asset {
+            id: self.id.clone(),
+            loaded: self.loaded.clone(),
+            progress: self.progress.clone(),
+            inner: self.inner.upgrade()?,
+        })
+    }
+}
+
+impl<A> Clone for WeakAsset<A>
+where
+    A: Send + Sync,
+{
+    fn clone(&self) -> Self {
+        WeakAsset {
+            id: self.id.clone(),
+            loaded: self.loaded.clone(),
+            progress: self.progress.clone(),
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn upgrade_while_alive() {
+        let asset = Asset::from_data("score.txt", 10);
+        let weak = asset.downgrade();
+        assert_eq!(weak.upgrade().as_ref(), Some(&asset));
+        assert_eq!(*weak.upgrade().unwrap().lock().unwrap(), 10);
+
+        drop(asset);
+        assert!(weak.upgrade().is_none());
+    }
+}
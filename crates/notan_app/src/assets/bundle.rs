@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+/// File of a [Bundle], the type is the extension used to select its loader
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "bundles", derive(serde::Deserialize), serde(untagged))]
+pub enum BundleAsset {
+    Path(String),
+    Typed {
+        path: String,
+        #[cfg_attr(feature = "bundles", serde(rename = "type"))]
+        ext: String,
+    },
+}
+
+impl BundleAsset {
+    pub fn path(&self) -> &str {
+        match self {
+            BundleAsset::Path(path) => path,
+            BundleAsset::Typed { path, .. } => path,
+        }
+    }
+
+    pub fn ext(&self) -> Option<&str> {
+        match self {
+            BundleAsset::Path(_) => None,
+            BundleAsset::Typed { ext, .. } => Some(ext),
+        }
+    }
+}
+
+/// Group of assets loaded and unloaded together, like the assets of a level
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "bundles", derive(serde::Deserialize))]
+pub struct Bundle {
+    pub(crate) assets: Vec<BundleAsset>,
+    /// Starts loading the bundle once it's added to the [Assets](super::Assets)
+    #[cfg_attr(feature = "bundles", serde(default))]
+    pub(crate) preload: bool,
+}
+
+impl Bundle {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds a file loaded by its extension
+    pub fn with_asset(mut self, path: &str) -> Self {
+        self.assets.push(BundleAsset::Path(path.to_string()));
+        self
+    }
+
+    /// Adds a file loaded by the loader of the extension given
+    pub fn with_typed_asset(mut self, path: &str, ext: &str) -> Self {
+        self.assets.push(BundleAsset::Typed {
+            path: path.to_string(),
+            ext: ext.to_string(),
+        });
+        self
+    }
+
+    /// Starts loading the bundle once it's added
+    pub fn with_preload(mut self, preload: bool) -> Self {
+        self.preload = preload;
+        self
+    }
+
+    pub fn assets(&self) -> &[BundleAsset] {
+        &self.assets
+    }
+}
+
+/// Bundles by name. With the feature `bundles` it can be read from JSON or RON files like:
+///
+/// ```json
+/// {
+///     "level1": {
+///         "preload": true,
+///         "assets": ["assets/tiles.png", { "path": "assets/theme", "type": "ogg" }]
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "bundles", derive(serde::Deserialize), serde(transparent))]
+pub struct BundleManifest {
+    pub(crate) bundles: HashMap<String, Bundle>,
+}
+
+impl BundleManifest {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with_bundle(mut self, name: &str, bundle: Bundle) -> Self {
+        self.bundles.insert(name.to_string(), bundle);
+        self
+    }
+
+    /// Reads a manifest from a JSON file
+    #[cfg(feature = "bundles")]
+    pub fn from_json(data: &[u8]) -> Result<Self, String> {
+        serde_json::from_slice(data).map_err(|e| e.to_string())
+    }
+
+    /// Reads a manifest from a RON file
+    #[cfg(feature = "bundles")]
+    pub fn from_ron(data: &[u8]) -> Result<Self, String> {
+        ron::de::from_bytes(data).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(all(test, feature = "bundles"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn read_json_and_ron_manifests() {
+        let expected = BundleManifest::new().with_bundle(
+            "level1",
+            Bundle::new()
+                .with_asset("tiles.png")
+                .with_typed_asset("theme", "ogg")
+                .with_preload(true),
+        );
+
+        let json = br#"{ "level1": { "preload": true, "assets": ["tiles.png", { "path": "theme", "type": "ogg" }] } }"#;
+        assert_eq!(BundleManifest::from_json(json).unwrap(), expected);
+
+        let ron = br#"{ "level1": (preload: true, assets: ["tiles.png", (path: "theme", type: "ogg")]) }"#;
+        assert_eq!(BundleManifest::from_ron(ron).unwrap(), expected);
+    }
+}
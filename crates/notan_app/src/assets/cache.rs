@@ -0,0 +1,216 @@
+use hashbrown::HashMap;
+use std::any::{Any, TypeId};
+use std::sync::{Arc, Weak};
+
+struct CachedAsset {
+    type_id: TypeId,
+    asset: Weak<dyn Any + Send + Sync>,
+    // strong reference kept only with a budget, so the unused assets stay loaded until evicted
+    retained: Option<Arc<dyn Any + Send + Sync>>,
+    bytes: usize,
+    last_used: u64,
+}
+
+impl CachedAsset {
+    /// Number of handles to the asset, without the cache
+    fn ref_count(&self) -> usize {
+        self.asset.strong_count() - self.retained.is_some() as usize
+    }
+
+    /// Nobody else is using the asset, without a budget it's already dropped
+    fn is_unused(&self) -> bool {
+        self.ref_count() == 0
+    }
+
+    fn is_dropped(&self) -> bool {
+        self.asset.strong_count() == 0
+    }
+}
+
+/// Keeps the loaded assets to give the same data when they're loaded again. Without a budget
+/// only weak references are kept, so the assets are dropped with their last handle. With a budget
+/// the assets without handles stay loaded until they're removed manually or the budget is exceeded
+#[derive(Default)]
+pub(crate) struct AssetCache {
+    assets: HashMap<String, CachedAsset>,
+    budget: Option<usize>,
+    bytes: usize,
+    // incremented each time an asset is used to know which one is the least recently used
+    clock: u64,
+}
+
+impl AssetCache {
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+
+    pub fn insert(
+        &mut self,
+        id: &str,
+        type_id: TypeId,
+        asset: Arc<dyn Any + Send + Sync>,
+        bytes: usize,
+    ) {
+        let last_used = self.tick();
+        self.bytes += bytes;
+        let old = self.assets.insert(
+            id.to_string(),
+            CachedAsset {
+                type_id,
+                asset: Arc::downgrade(&asset),
+                retained: self.budget.is_some().then_some(asset),
+                bytes,
+                last_used,
+            },
+        );
+
+        if let Some(old) = old {
+            self.bytes -= old.bytes;
+        }
+    }
+
+    /// Returns the asset marking it as used
+    pub fn get(&mut self, id: &str) -> Option<(TypeId, Arc<dyn Any + Send + Sync>)> {
+        let last_used = self.tick();
+        let cached = self.assets.get_mut(id)?;
+        let Some(asset) = cached.asset.upgrade() else {
+            // the asset was dropped with its last handle, it must be loaded again
+            self.remove(id);
+            return None;
+        };
+
+        cached.last_used = last_used;
+        Some((cached.type_id, asset))
+    }
+
+    /// Returns the references to the asset, without counting the cache
+    pub fn ref_count(&self, id: &str) -> usize {
+        self.assets.get(id).map_or(0, |cached| cached.ref_count())
+    }
+
+    pub fn remove(&mut self, id: &str) -> bool {
+        match self.assets.remove(id) {
+            Some(cached) => {
+                self.bytes -= cached.bytes;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes the assets without handles, returns how many were removed
+    pub fn remove_unused(&mut self) -> usize {
+        let len = self.assets.len();
+        self.assets.retain(|_, cached| !cached.is_unused());
+        self.bytes = self.assets.values().map(|cached| cached.bytes).sum();
+        len - self.assets.len()
+    }
+
+    pub fn set_budget(&mut self, budget: Option<usize>) {
+        self.budget = budget;
+        let retain = budget.is_some();
+        self.assets.values_mut().for_each(|cached| {
+            cached.retained = if retain { cached.asset.upgrade() } else { None };
+        });
+        self.evict();
+    }
+
+    pub fn budget(&self) -> Option<usize> {
+        self.budget
+    }
+
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Removes the assets already dropped, and the least recently used
+    /// assets without handles until the cache fits in the budget
+    pub fn evict(&mut self) {
+        let len = self.assets.len();
+        self.assets.retain(|_, cached| !cached.is_dropped());
+        if len != self.assets.len() {
+            self.bytes = self.assets.values().map(|cached| cached.bytes).sum();
+        }
+
+        let Some(budget) = self.budget else {
+            return;
+        };
+
+        while self.bytes > budget {
+            let lru = self
+                .assets
+                .iter()
+                .filter(|(_, cached)| cached.is_unused())
+                .min_by_key(|(_, cached)| cached.last_used)
+                .map(|(id, _)| id.clone());
+
+            match lru {
+                Some(id) => {
+                    log::debug!("Evicting asset '{}' from the cache", id);
+                    self.remove(&id);
+                }
+                // the rest of the assets are in use
+                None => break,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assets::asset::Asset;
+    use crate::assets::utils::DoneSignal;
+    use parking_lot::RwLock;
+
+    fn asset() -> Arc<dyn Any + Send + Sync> {
+        Arc::new(0u8)
+    }
+
+    #[test]
+    fn evict_least_recently_used() {
+        let mut cache = AssetCache::default();
+        cache.set_budget(Some(30));
+        let in_use = asset();
+        cache.insert("a", TypeId::of::<u8>(), asset(), 10);
+        cache.insert("b", TypeId::of::<u8>(), in_use.clone(), 10);
+        cache.insert("c", TypeId::of::<u8>(), asset(), 10);
+        cache.get("a");
+
+        cache.set_budget(Some(20));
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("b").is_some());
+        assert!(cache.get("c").is_none());
+        assert_eq!(cache.bytes(), 20);
+        assert_eq!(cache.ref_count("b"), 1);
+
+        drop(in_use);
+        assert_eq!(cache.remove_unused(), 2);
+        assert_eq!(cache.bytes(), 0);
+    }
+
+    #[test]
+    fn drop_assets_without_budget() {
+        let mut cache = AssetCache::default();
+        let handle = Asset {
+            id: "a".to_string(),
+            loaded: DoneSignal::from_bool(true),
+            progress: Default::default(),
+            inner: Arc::new(RwLock::new(Some(1u8))),
+        };
+        cache.insert("a", TypeId::of::<u8>(), handle.inner.clone(), 10);
+        assert_eq!(cache.ref_count("a"), 1);
+
+        // the cache doesn't keep the asset alive
+        let (_, cached) = cache.get("a").unwrap();
+        drop(cached);
+        assert_eq!(handle.try_unwrap(), Ok(1));
+        assert!(cache.get("a").is_none());
+        assert_eq!(cache.bytes(), 0);
+
+        cache.insert("b", TypeId::of::<u8>(), asset(), 10);
+        cache.evict();
+        assert_eq!(cache.bytes(), 0);
+    }
+}
@@ -0,0 +1,95 @@
+use std::any::Any;
+use std::sync::Arc;
+
+#[cfg(not(target_arch = "wasm32"))]
+use parking_lot::Mutex;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+pub(crate) type DecodeFn =
+    Arc<dyn Fn(&str, Vec<u8>) -> Result<Box<dyn Any + Send>, String> + Send + Sync>;
+
+type Decoded = (String, Result<Box<dyn Any + Send>, String>);
+
+#[cfg(not(target_arch = "wasm32"))]
+type Job = (String, Vec<u8>, DecodeFn);
+
+/// Runs the async parsers on worker threads, the threads are created the first time they're needed.
+/// Wasm doesn't have threads, so the parsers run on the main thread
+#[derive(Default)]
+pub(crate) struct DecodePool {
+    #[cfg(not(target_arch = "wasm32"))]
+    workers: Option<(Sender<Job>, Receiver<Decoded>)>,
+    #[cfg(target_arch = "wasm32")]
+    done: Vec<Decoded>,
+}
+
+impl DecodePool {
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn decode(&mut self, id: String, bytes: Vec<u8>, decoder: DecodeFn) {
+        let (jobs, _) = self.workers.get_or_insert_with(spawn_workers);
+        if let Err(e) = jobs.send((id, bytes, decoder)) {
+            log::error!("Asset decoder stopped: {}", e);
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn decode(&mut self, id: String, bytes: Vec<u8>, decoder: DecodeFn) {
+        let res = decoder(&id, bytes);
+        self.done.push((id, res));
+    }
+
+    /// Returns the assets decoded since the last call
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn finished(&mut self) -> Vec<Decoded> {
+        match &self.workers {
+            Some((_, results)) => results.try_iter().collect(),
+            None => vec![],
+        }
+    }
+
+    /// Returns the assets decoded since the last call
+    #[cfg(target_arch = "wasm32")]
+    pub fn finished(&mut self) -> Vec<Decoded> {
+        std::mem::take(&mut self.done)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn spawn_workers() -> (Sender<Job>, Receiver<Decoded>) {
+    let (job_tx, job_rx) = channel::<Job>();
+    let (res_tx, res_rx) = channel();
+    let job_rx = Arc::new(Mutex::new(job_rx));
+
+    let count = std::thread::available_parallelism().map_or(2, |n| n.get().clamp(1, 4));
+    for i in 0..count {
+        let jobs = job_rx.clone();
+        let results = res_tx.clone();
+        let spawned = std::thread::Builder::new()
+            .name(format!("notan-asset-decoder-{i}"))
+            .spawn(move || {
+                loop {
+                    // the workers stop when the pool is dropped and the channel is closed
+                    let job = jobs.lock().recv();
+                    let Ok((id, bytes, decoder)) = job else {
+                        break;
+                    };
+
+                    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                        decoder(&id, bytes)
+                    }))
+                    .unwrap_or_else(|_| Err(format!("The parser of '{id}' panicked")));
+
+                    if results.send((id, res)).is_err() {
+                        break;
+                    }
+                }
+            });
+
+        if let Err(e) = spawned {
+            log::error!("Cannot spawn the asset decoder thread: {}", e);
+        }
+    }
+
+    (job_tx, res_rx)
+}
@@ -0,0 +1,131 @@
+use super::asset::Asset;
+use super::loader::LoaderCallback;
+use super::utils::DoneSignal;
+use hashbrown::{HashMap, HashSet};
+use parking_lot::RwLock;
+use std::any::{Any, TypeId};
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Returns the ids of the assets needed to parse the file
+pub(crate) type DependenciesFn = Rc<dyn Fn(&str, &[u8]) -> Result<Vec<String>, String>>;
+
+/// Assets needed by another asset, they are loaded before parsing it
+#[derive(Default, Clone)]
+pub struct Dependencies {
+    assets: HashMap<String, (TypeId, Arc<dyn Any + Send + Sync>)>,
+}
+
+impl Dependencies {
+    pub(crate) fn insert(&mut self, id: &str, type_id: TypeId, asset: Arc<dyn Any + Send + Sync>) {
+        self.assets.insert(id.to_string(), (type_id, asset));
+    }
+
+    /// Returns the ids of the dependencies
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.assets.keys().map(|id| id.as_str())
+    }
+
+    /// Returns true if the asset is a dependency
+    pub fn contains(&self, id: &str) -> bool {
+        self.assets.contains_key(id)
+    }
+
+    /// Returns the dependency already loaded
+    pub fn get<A>(&self, id: &str) -> Result<Asset<A>, String>
+    where
+        A: Send + Sync + 'static,
+    {
+        let (type_id, asset) = self
+            .assets
+            .get(id)
+            .ok_or_else(|| format!("'{id}' is not a dependency"))?;
+
+        if *type_id != TypeId::of::<A>() {
+            return Err(format!("Invalid asset type for the dependency '{id}'"));
+        }
+
+        Ok(Asset {
+            id: id.to_string(),
+            loaded: DoneSignal::from_bool(true),
+            progress: Default::default(),
+            inner: asset.clone().downcast::<RwLock<Option<A>>>().unwrap(),
+        })
+    }
+}
+
+/// Returns the ids forming a cycle if the asset `id` needs `dep`, like `a -> b -> a`,
+/// following the dependencies that the files already read are waiting for
+pub(crate) fn dependency_cycle<'a, F>(id: &str, dep: &str, waiting: F) -> Option<Vec<String>>
+where
+    F: Fn(&str) -> Option<&'a [String]>,
+{
+    let mut path = vec![id.to_string(), dep.to_string()];
+    let mut visited = HashSet::new();
+    reaches(id, dep, &waiting, &mut visited, &mut path).then_some(path)
+}
+
+fn reaches<'a, F>(
+    target: &str,
+    current: &str,
+    waiting: &F,
+    visited: &mut HashSet<String>,
+    path: &mut Vec<String>,
+) -> bool
+where
+    F: Fn(&str) -> Option<&'a [String]>,
+{
+    if current == target {
+        return true;
+    }
+
+    if !visited.insert(current.to_string()) {
+        return false;
+    }
+
+    for next in waiting(current).unwrap_or_default() {
+        path.push(next.clone());
+        if reaches(target, next, waiting, visited, path) {
+            return true;
+        }
+        path.pop();
+    }
+
+    false
+}
+
+/// File read waiting for its dependencies to be parsed
+pub(crate) struct PendingParse {
+    pub id: String,
+    pub loader: LoaderCallback,
+    pub data: Vec<u8>,
+    pub waiting: Vec<String>,
+    pub dependencies: Dependencies,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn detect_dependency_cycles() {
+        let waiting: HashMap<String, Vec<String>> = [
+            ("a".to_string(), vec!["b".to_string()]),
+            ("b".to_string(), vec!["c".to_string(), "d".to_string()]),
+        ]
+        .into_iter()
+        .collect();
+        let waiting = |id: &str| waiting.get(id).map(|ids| ids.as_slice());
+
+        assert_eq!(
+            dependency_cycle("a", "a", waiting),
+            Some(vec!["a".into(), "a".into()])
+        );
+        assert_eq!(
+            dependency_cycle("d", "a", waiting),
+            Some(vec!["d".into(), "a".into(), "b".into(), "d".into()])
+        );
+        assert_eq!(dependency_cycle("c", "d", waiting), None);
+        assert_eq!(dependency_cycle("e", "a", waiting), None);
+    }
+}
@@ -0,0 +1,70 @@
+use super::utils::{AssetLoadTracker, DoneSignal};
+use hashbrown::HashMap;
+use std::any::TypeId;
+
+/// Writes the fallback into the asset tracked with the id, returns false if it cannot
+type FallbackFn = Box<dyn Fn(&AssetLoadTracker, &str, DoneSignal) -> bool>;
+
+/// Values used by type for the assets that fail to load
+#[derive(Default)]
+pub(crate) struct Fallbacks {
+    fallbacks: HashMap<TypeId, FallbackFn>,
+}
+
+impl Fallbacks {
+    pub fn insert<A>(&mut self, asset: A)
+    where
+        A: Clone + Send + Sync + 'static,
+    {
+        let fallback = move |tracker: &AssetLoadTracker, id: &str, loaded| {
+            let Ok(inner) = tracker.get_asset::<A>(id, loaded) else {
+                return false;
+            };
+
+            *inner.write() = Some(asset.clone());
+            true
+        };
+
+        self.fallbacks.insert(TypeId::of::<A>(), Box::new(fallback));
+    }
+
+    pub fn remove(&mut self, type_id: TypeId) -> bool {
+        self.fallbacks.remove(&type_id).is_some()
+    }
+
+    pub fn contains(&self, type_id: TypeId) -> bool {
+        self.fallbacks.contains_key(&type_id)
+    }
+
+    /// Gives the fallback of the type to the handles of the asset
+    pub fn apply(
+        &self,
+        type_id: TypeId,
+        tracker: &AssetLoadTracker,
+        id: &str,
+        loaded: DoneSignal,
+    ) -> bool {
+        self.fallbacks
+            .get(&type_id)
+            .is_some_and(|fallback| fallback(tracker, id, loaded))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn apply_fallback_by_type() {
+        let mut fallbacks = Fallbacks::default();
+        fallbacks.insert("missing".to_string());
+
+        let tracker = AssetLoadTracker::default();
+        let loaded = DoneSignal::new();
+        assert!(!fallbacks.apply(TypeId::of::<u32>(), &tracker, "a.txt", loaded.clone()));
+        assert!(fallbacks.apply(TypeId::of::<String>(), &tracker, "a.txt", loaded.clone()));
+
+        let inner = tracker.get_asset::<String>("a.txt", loaded).unwrap();
+        assert_eq!(inner.read().as_deref(), Some("missing"));
+    }
+}
@@ -1,4 +1,5 @@
 use super::asset::Asset;
+use super::progress::LoadProgress;
 use super::utils::{AssetLoadTracker, DoneSignal};
 use hashbrown::{HashMap, HashSet};
 use parking_lot::RwLock;
@@ -8,29 +9,52 @@ use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct AssetList {
+    id: u64,
     count: usize,
     load_tracker: HashMap<String, DoneSignal>,
+    progress: HashMap<String, LoadProgress>,
     assets: HashMap<TypeId, HashMap<String, Arc<dyn Any + Send + Sync>>>,
     claimed: HashSet<String>,
     tracker: AssetLoadTracker,
 }
 
 impl AssetList {
-    pub(crate) fn new(tracker: AssetLoadTracker) -> Self {
+    pub(crate) fn new(id: u64, tracker: AssetLoadTracker) -> Self {
         Self {
+            id,
             count: 0,
             assets: Default::default(),
             load_tracker: Default::default(),
+            progress: Default::default(),
             tracker,
             claimed: Default::default(),
         }
     }
 
-    pub(crate) fn insert(&mut self, id: &str, loader: DoneSignal) {
+    pub(crate) fn insert(&mut self, id: &str, loader: DoneSignal, progress: LoadProgress) {
         self.load_tracker.insert(id.to_string(), loader);
+        self.progress.insert(id.to_string(), progress);
         self.count += 1;
     }
 
+    /// Adds an asset already loaded
+    pub(crate) fn insert_loaded(
+        &mut self,
+        id: &str,
+        type_id: TypeId,
+        asset: Arc<dyn Any + Send + Sync>,
+    ) {
+        self.insert(id, DoneSignal::from_bool(true), LoadProgress::default());
+        let list = self.assets.entry(type_id).or_default();
+        list.insert(id.to_string(), asset);
+        self.claimed.insert(id.to_string());
+    }
+
+    /// Returns the id of the list, used by the event `AssetListCompleted`
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
     /// Returns true if all the assets were loaded
     pub fn is_loaded(&self) -> bool {
         let still_loading = self.load_tracker.values().any(|loaded| !loaded.is_done());
@@ -68,6 +92,50 @@ impl AssetList {
         loaded as f32 / self.count as f32
     }
 
+    /// Returns the bytes read of all the files
+    pub fn bytes_loaded(&self) -> u64 {
+        self.progress.values().map(|p| p.loaded()).sum()
+    }
+
+    /// Returns the size of all the files, or `None` if the size of any of them is not known yet
+    pub fn bytes_total(&self) -> Option<u64> {
+        self.progress.values().map(|p| p.total()).sum()
+    }
+
+    /// Cancels the assets still loading, and removes them from the list
+    pub fn cancel(&mut self) {
+        let loading = self
+            .load_tracker
+            .iter()
+            .filter(|(_, loaded)| !loaded.is_done())
+            .map(|(id, _)| id.clone())
+            .collect::<Vec<_>>();
+
+        loading.iter().for_each(|id| {
+            self.tracker.request_cancel(id);
+            self.load_tracker.remove(id);
+            self.progress.remove(id);
+            self.claimed.remove(id);
+            self.assets.values_mut().for_each(|list| {
+                list.remove(id);
+            });
+        });
+        self.count -= loading.len();
+    }
+
+    /// Returns the ids and the errors of the assets that failed to load
+    pub fn errors(&self) -> Vec<(String, String)> {
+        self.progress
+            .iter()
+            .filter_map(|(id, progress)| Some((id.clone(), progress.error()?)))
+            .collect()
+    }
+
+    /// Returns the ids of the assets
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.load_tracker.keys().map(|id| id.as_str())
+    }
+
     /// Returns if the list contains the asset
     pub fn contains(&self, id: &str) -> bool {
         self.load_tracker.contains_key(id)
@@ -89,6 +157,7 @@ impl AssetList {
             let asset = self.tracker.claim_asset::<A>(id, loaded.clone())?;
             let list = self.assets.entry(type_id).or_default();
             list.insert(id.to_string(), asset);
+            self.claimed.insert(id.to_string());
         }
 
         let list = match self.assets.get(&type_id) {
@@ -96,11 +165,13 @@ impl AssetList {
             _ => return Err("Invalid asset type".to_string()),
         };
 
+        let progress = self.progress.get(id).cloned().unwrap_or_default();
         list.get(id)
             .ok_or_else(|| "Invalid asset id".to_string())
             .map(|asset| Asset {
                 id: id.to_string(),
                 loaded,
+                progress,
                 inner: asset.clone().downcast::<RwLock<Option<A>>>().unwrap(),
             })
     }
@@ -113,6 +184,7 @@ impl AssetList {
         let asset = self.get_clone::<A>(id)?;
         self.count -= 1;
         self.load_tracker.remove(id);
+        self.progress.remove(id);
         self.claimed.remove(id);
         self.tracker.clean();
         if let Some(map) = self.assets.get_mut(&TypeId::of::<A>()) {
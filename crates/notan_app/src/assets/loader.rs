@@ -6,8 +6,14 @@ use crate::app::App;
 use crate::graphics::Graphics;
 use crate::plugins::Plugins;
 
-use std::any::TypeId;
+use super::decoder::DecodeFn;
+use super::dependencies::{Dependencies, DependenciesFn};
+use std::any::{Any, TypeId};
 use std::rc::Rc;
+use std::sync::Arc;
+
+/// Returns true if the bytes are a file that the loader can parse
+pub(crate) type Sniffer = Rc<dyn Fn(&[u8]) -> bool>;
 
 pub(crate) type LoaderParams<'a, S> = (&'a mut App, &'a mut Graphics, &'a mut Plugins, &'a mut S);
 
@@ -15,8 +21,30 @@ pub(crate) type LoaderParams<'a, S> = (&'a mut App, &'a mut Graphics, &'a mut Pl
 #[derive(Default, Clone)]
 pub struct AssetLoader {
     extensions: Vec<String>,
+    sniffers: Vec<Sniffer>,
+    dependencies: Option<DependenciesFn>,
     parser: Option<LoaderCallback>,
     type_id: Option<TypeId>,
+    type_name: &'static str,
+}
+
+/// Part of a file given to the parsers set with [AssetLoader::use_stream_parser]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StreamChunk<'a> {
+    /// Bytes read, in the order of the file
+    Data(&'a [u8]),
+    /// The file was read completely
+    End,
+}
+
+/// Extension claimed by a loader that was already registered by another one
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LoaderConflict {
+    pub extension: String,
+    /// Output type of the loader replaced
+    pub replaced: &'static str,
+    /// Output type of the loader that parses the extension now
+    pub winner: &'static str,
 }
 
 impl AssetLoader {
@@ -39,6 +67,27 @@ impl AssetLoader {
         self
     }
 
+    /// Set a function to detect the files of the loader by their content, used when
+    /// the id of the file doesn't have an extension with a loader associated
+    pub fn sniffer<F>(mut self, sniffer: F) -> Self
+    where
+        F: Fn(&[u8]) -> bool + 'static,
+    {
+        self.sniffers.push(Rc::new(sniffer));
+        self
+    }
+
+    /// Set a function that returns the ids of the assets needed to parse the file, like the
+    /// images of an atlas. They are loaded before the parser runs, and the parser can use them
+    /// taking a `&Dependencies` param after the bytes
+    pub fn use_dependencies<F>(mut self, dependencies: F) -> Self
+    where
+        F: Fn(&str, &[u8]) -> Result<Vec<String>, String> + 'static,
+    {
+        self.dependencies = Some(Rc::new(dependencies));
+        self
+    }
+
     /// Set the parser function
     pub fn use_parser<H, A, Params>(mut self, handler: H) -> Self
     where
@@ -46,15 +95,65 @@ impl AssetLoader {
         A: Send + Sync + 'static,
     {
         self.type_id = Some(TypeId::of::<A>());
+        self.type_name = std::any::type_name::<A>();
         self.parser = Some(handler.callback());
         self
     }
 
-    pub(crate) fn apply(self, manager: &mut Assets) -> Result<(), String> {
+    /// Set a parser that runs on a worker thread, so big files don't stall the frame.
+    /// It cannot access the app or the graphics, and on wasm it runs on the main thread
+    pub fn use_async_parser<F, A>(mut self, parser: F) -> Self
+    where
+        F: Fn(&str, Vec<u8>) -> Result<A, String> + Send + Sync + 'static,
+        A: Send + Sync + 'static,
+    {
+        self.type_id = Some(TypeId::of::<A>());
+        self.type_name = std::any::type_name::<A>();
+        self.parser = Some(LoaderCallback::Async(
+            None,
+            Arc::new(move |id, bytes| {
+                parser(id, bytes).map(|asset| Box::new(asset) as Box<dyn Any + Send>)
+            }),
+            Rc::new(|storage, id, decoded| {
+                let asset = decoded
+                    .downcast::<A>()
+                    .map_err(|_| format!("Invalid asset type decoded for '{id}'"))?;
+                storage.parse::<A>(id, *asset)
+            }),
+        ));
+        self
+    }
+
+    /// Set a parser that receives the file in chunks as they are read, for big files like
+    /// videos or music that don't need to be in memory at once. The parser creates the asset
+    /// setting the option, and it can be used before the file is read, but it's not loaded
+    /// until the stream ends. Only the sources that support it stream the files, the rest
+    /// give the whole file as one chunk
+    pub fn use_stream_parser<F, A>(mut self, parser: F) -> Self
+    where
+        F: Fn(&str, StreamChunk, &mut Option<A>) -> Result<(), String> + 'static,
+        A: Send + Sync + 'static,
+    {
+        self.type_id = Some(TypeId::of::<A>());
+        self.type_name = std::any::type_name::<A>();
+        self.parser = Some(LoaderCallback::Stream(
+            None,
+            Rc::new(move |storage, id, chunk, restart| {
+                storage.parse_chunk::<A, _>(id, chunk, restart, |asset| parser(id, chunk, asset))
+            }),
+        ));
+        self
+    }
+
+    /// Registers the loader, the extensions already registered are replaced
+    pub(crate) fn apply(self, manager: &mut Assets) -> Result<Vec<LoaderConflict>, String> {
         let AssetLoader {
             extensions,
+            sniffers,
+            dependencies,
             parser,
             type_id,
+            type_name,
         } = self;
 
         if extensions.is_empty() {
@@ -65,12 +164,35 @@ impl AssetLoader {
             type_id.ok_or_else(|| "Loader without output type associated.".to_string())?;
         let mut parser = parser.ok_or_else(|| "Loader without parser associated.".to_string())?;
         parser.set_type_id(type_id);
+        if let Some(dependencies) = dependencies {
+            if parser.is_stream() {
+                return Err("Stream parsers cannot have dependencies.".to_string());
+            }
+            parser = LoaderCallback::WithDependencies(dependencies, Box::new(parser));
+        }
 
-        extensions.iter().for_each(|ext| {
-            manager.loaders.insert(ext.to_string(), parser.clone());
+        let mut conflicts = vec![];
+        let mut registered = vec![];
+        for ext in extensions {
+            if registered.contains(&ext) {
+                continue;
+            }
+
+            if let Some(replaced) = manager.register_loader(&ext, type_name, parser.clone()) {
+                conflicts.push(LoaderConflict {
+                    extension: ext.clone(),
+                    replaced,
+                    winner: type_name,
+                });
+            }
+            registered.push(ext);
+        }
+
+        sniffers.into_iter().for_each(|sniffer| {
+            manager.sniffers.push((sniffer, parser.clone()));
         });
 
-        Ok(())
+        Ok(conflicts)
     }
 }
 
@@ -104,6 +226,19 @@ pub enum LoaderCallback {
         Option<TypeId>,
         Rc<dyn Fn(&mut AssetStorage, &str, Vec<u8>, &mut Plugins) -> Result<(), String>>,
     ),
+    /// Decodes the bytes on a worker thread and stores the result on the main thread
+    Async(
+        Option<TypeId>,
+        DecodeFn,
+        Rc<dyn Fn(&mut AssetStorage, &str, Box<dyn Any + Send>) -> Result<(), String>>,
+    ),
+    /// Parses the file in chunks while it's read, the bool is true if the asset must start again
+    Stream(
+        Option<TypeId>,
+        Rc<dyn Fn(&mut AssetStorage, &str, StreamChunk, bool) -> Result<(), String>>,
+    ),
+    /// Loads the assets needed by the file before parsing it with the inner loader
+    WithDependencies(DependenciesFn, Box<LoaderCallback>),
 }
 
 pub trait LoaderHandler<A, Params>
@@ -132,12 +267,38 @@ macro_rules! loader_handler {
     }
 }
 
+macro_rules! loader_handler_with_dependencies {
+    ($variant:expr, $($param:ident),*) => {
+        #[allow(unused_parens)]
+        impl<A, F> LoaderHandler<A, (&str, Vec<u8>, &Dependencies, $(&mut $param),*)> for F
+        where
+            F: Fn(&str, Vec<u8>, &Dependencies, $(&mut $param),*) -> Result<A, String> + 'static,
+            A: Send + Sync + 'static
+
+        {
+            fn callback(self) -> LoaderCallback {
+                $variant(None, Rc::new(move |storage, id, bytes, $($param),*| {
+                    let dependencies = storage.take_dependencies(id);
+                    let asset = self(id, bytes, &dependencies, $($param),*)?;
+                    storage.parse::<A>(id, asset)
+                }))
+            }
+        }
+    }
+}
+
 loader_handler!(LoaderCallback::Basic,);
 loader_handler!(LoaderCallback::A, App);
 loader_handler!(LoaderCallback::G, Graphics);
 loader_handler!(LoaderCallback::GP, Graphics, Plugins);
 loader_handler!(LoaderCallback::P, Plugins);
 
+loader_handler_with_dependencies!(LoaderCallback::Basic,);
+loader_handler_with_dependencies!(LoaderCallback::A, App);
+loader_handler_with_dependencies!(LoaderCallback::G, Graphics);
+loader_handler_with_dependencies!(LoaderCallback::GP, Graphics, Plugins);
+loader_handler_with_dependencies!(LoaderCallback::P, Plugins);
+
 impl LoaderCallback {
     pub(crate) fn exec<S>(
         &self,
@@ -156,6 +317,52 @@ impl LoaderCallback {
             G(_, cb) => cb(storage, id, data, graphics),
             GP(_, cb) => cb(storage, id, data, graphics, plugins),
             P(_, cb) => cb(storage, id, data, plugins),
+            Async(_, decode, cb) => cb(storage, id, decode(id, data)?),
+            Stream(_, cb) => {
+                cb(storage, id, StreamChunk::Data(&data), true)?;
+                cb(storage, id, StreamChunk::End, false)
+            }
+            WithDependencies(_, inner) => inner.exec(id, data, storage, params),
+        }
+    }
+
+    /// Gives a chunk of the file to a stream parser
+    pub(crate) fn exec_chunk(
+        &self,
+        id: &str,
+        chunk: StreamChunk,
+        storage: &mut AssetStorage,
+    ) -> Result<(), String> {
+        match self {
+            LoaderCallback::Stream(_, cb) => cb(storage, id, chunk, false),
+            _ => Err(format!("The loader of '{id}' cannot parse streams")),
+        }
+    }
+
+    pub(crate) fn is_stream(&self) -> bool {
+        matches!(self, LoaderCallback::Stream(..))
+    }
+
+    /// Returns the function to run on a worker thread if the loader is async
+    pub(crate) fn decoder(&self) -> Option<DecodeFn> {
+        match self {
+            LoaderCallback::Async(_, decode, _) => Some(decode.clone()),
+            LoaderCallback::WithDependencies(_, inner) => inner.decoder(),
+            _ => None,
+        }
+    }
+
+    /// Stores the asset decoded by an async loader
+    pub(crate) fn apply_decoded(
+        &self,
+        id: &str,
+        decoded: Box<dyn Any + Send>,
+        storage: &mut AssetStorage,
+    ) -> Result<(), String> {
+        match self {
+            LoaderCallback::Async(_, _, cb) => cb(storage, id, decoded),
+            LoaderCallback::WithDependencies(_, inner) => inner.apply_decoded(id, decoded, storage),
+            _ => Err(format!("The loader of '{id}' is not async")),
         }
     }
 
@@ -167,6 +374,9 @@ impl LoaderCallback {
             G(ref mut ty, _) => ty,
             GP(ref mut ty, _) => ty,
             P(ref mut ty, _) => ty,
+            Async(ref mut ty, _, _) => ty,
+            Stream(ref mut ty, _) => ty,
+            WithDependencies(_, inner) => return inner.set_type_id(type_id),
         };
 
         *ty = Some(type_id);
@@ -180,6 +390,17 @@ impl LoaderCallback {
             G(ty, _) => *ty,
             GP(ty, _) => *ty,
             P(ty, _) => *ty,
+            Async(ty, _, _) => *ty,
+            Stream(ty, _) => *ty,
+            WithDependencies(_, inner) => LoaderCallback::type_id(inner),
+        }
+    }
+
+    /// Returns the function that lists the dependencies if the loader has them
+    pub(crate) fn dependencies(&self) -> Option<DependenciesFn> {
+        match self {
+            LoaderCallback::WithDependencies(dependencies, _) => Some(dependencies.clone()),
+            _ => None,
         }
     }
 }
@@ -1,10 +1,26 @@
 use super::asset::Asset;
+use super::bundle::{Bundle, BundleManifest};
+use super::cache::AssetCache;
+use super::decoder::DecodePool;
+use super::dependencies::{dependency_cycle, Dependencies, PendingParse};
+use super::fallback::Fallbacks;
 use super::list::AssetList;
 use super::loader::*;
+use super::preprocess::{Preprocessor, Preprocessors};
+use super::progress::LoadProgress;
+use super::queue::{FrameBudget, LoadBudget, ParseQueue, Priority};
+use super::retry::RetryPolicy;
+use super::sniff::sniff_extension;
+use super::source::{AssetSource, AssetSourceFuture, AssetSources};
 use super::storage::AssetStorage;
 use super::utils::DoneSignal;
 
+use futures::future;
+use futures::task::Poll;
 use hashbrown::HashMap;
+use notan_core::events::Event;
+use notan_utils::Instant;
+use parking_lot::RwLock;
 use std::any::TypeId;
 use std::path::Path;
 use std::rc::Rc;
@@ -12,10 +28,41 @@ use std::rc::Rc;
 #[cfg(feature = "drop_files")]
 use crate::DroppedFile;
 
+#[cfg(all(feature = "hot_reload", not(target_arch = "wasm32")))]
+use super::watcher::AssetWatcher;
+#[cfg(all(feature = "hot_reload", not(target_arch = "wasm32")))]
+use hashbrown::HashSet;
+
 pub struct Assets {
     storage: AssetStorage,
-    pub(crate) loaders: HashMap<String, LoaderCallback>,
+    loaders: HashMap<String, (&'static str, LoaderCallback)>,
+    type_names: HashMap<TypeId, &'static str>,
+    pub(crate) sniffers: Vec<(Sniffer, LoaderCallback)>,
+    // extensions given for the assets loaded from memory
+    ext_hints: HashMap<String, String>,
+    // files waiting for their dependencies to be loaded
+    pending: Vec<PendingParse>,
+    // async loaders parsing a file
+    decoding: HashMap<String, LoaderCallback>,
     byte_loader: LoaderCallback,
+    decoder: DecodePool,
+    sources: AssetSources,
+    preprocessors: Preprocessors,
+    cache: AssetCache,
+    fallbacks: Fallbacks,
+    retry_policy: RetryPolicy,
+    queue: ParseQueue,
+    load_budget: LoadBudget,
+    bundles: HashMap<String, Bundle>,
+    bundle_lists: HashMap<String, AssetList>,
+    // lists loading to know when they're completed
+    lists: Vec<(u64, Vec<String>)>,
+    list_count: u64,
+    events: Vec<Event>,
+    #[cfg(all(feature = "hot_reload", not(target_arch = "wasm32")))]
+    watcher: Option<AssetWatcher>,
+    #[cfg(all(feature = "hot_reload", not(target_arch = "wasm32")))]
+    reloading: HashSet<String>,
 }
 
 impl Assets {
@@ -28,53 +75,361 @@ impl Assets {
 
         Self {
             loaders: HashMap::new(),
+            type_names: [(bytes_id, std::any::type_name::<Vec<u8>>())]
+                .into_iter()
+                .collect(),
+            sniffers: vec![],
+            ext_hints: HashMap::new(),
+            pending: vec![],
+            decoding: HashMap::new(),
             storage: AssetStorage::default(),
             byte_loader,
+            decoder: DecodePool::default(),
+            sources: AssetSources::default(),
+            preprocessors: Preprocessors::default(),
+            cache: AssetCache::default(),
+            fallbacks: Fallbacks::default(),
+            retry_policy: RetryPolicy::default(),
+            queue: ParseQueue::default(),
+            load_budget: LoadBudget::default(),
+            bundles: HashMap::new(),
+            bundle_lists: HashMap::new(),
+            lists: vec![],
+            list_count: 0,
+            events: vec![],
+            #[cfg(all(feature = "hot_reload", not(target_arch = "wasm32")))]
+            watcher: AssetWatcher::new()
+                .map_err(|e| log::error!("Assets will not be reloaded: {}", e))
+                .ok(),
+            #[cfg(all(feature = "hot_reload", not(target_arch = "wasm32")))]
+            reloading: HashSet::new(),
         }
     }
 
     pub(crate) fn tick<S>(&mut self, mut params: LoaderParams<S>) -> Result<(), String> {
-        if let Some(mut to_update) = self.storage.try_load() {
-            while let Some((id, data)) = to_update.pop() {
-                let ext = Path::new(&id)
-                    .extension()
-                    .map(|ext| ext.to_str().unwrap())
-                    .unwrap_or("");
-
-                let loader = match self.loaders.get(ext) {
-                    Some(loader) => loader,
-                    None => {
-                        log::warn!(
-                            "Not found a loader for '{}', loading as bytes (Vec<u8>)",
-                            id
-                        );
-                        &self.byte_loader
-                    }
+        #[cfg(all(feature = "hot_reload", not(target_arch = "wasm32")))]
+        self.reload_changed_files();
+
+        for id in self.storage.tracker.take_cancelled() {
+            self.cancel(&id);
+        }
+
+        self.retry_due_reads();
+
+        if let Some(read) = self.storage.try_load() {
+            for (id, res) in read {
+                match res {
+                    Ok(data) => match self.preprocessors.apply(&id, data) {
+                        Ok(data) => {
+                            let priority = self.storage.priority(&id);
+                            self.queue.push(id, data, priority);
+                        }
+                        Err(e) => {
+                            log::error!("File: {} -> {}", id, e);
+                            self.stop(&id, Some(&e));
+                        }
+                    },
+                    Err(e) => self.read_failed(&id, &e),
+                }
+            }
+
+            self.parse_chunks()?;
+
+            // the queue is taken to parse the files while the budget allows it
+            let mut queue = std::mem::take(&mut self.queue);
+            let budget = self.load_budget;
+            let res = queue
+                .take(&budget)
+                .try_for_each(|(id, data)| self.parse_file(id, data, &mut params));
+            self.queue = queue;
+            res?;
+
+            for (id, decoded) in self.decoder.finished() {
+                let Some(loader) = self.decoding.remove(&id) else {
+                    continue;
                 };
 
-                loader.exec(&id, data, &mut self.storage, &mut params)?;
-                self.storage.clean_asset(&id)?;
+                let res = decoded.and_then(|d| loader.apply_decoded(&id, d, &mut self.storage));
+                self.finish_load(&id, &loader, res)?;
+            }
+
+            for pending in self.resolve_dependencies() {
+                let PendingParse {
+                    id,
+                    loader,
+                    data,
+                    dependencies,
+                    ..
+                } = pending;
+                self.storage.set_dependencies(&id, dependencies);
+                self.parse(id, data, loader, &mut params)?;
             }
 
             self.storage.clean_ready_assets();
+            self.cache.evict();
         }
 
+        self.check_completed_lists();
+
         Ok(())
     }
 
-    pub fn add_loader(&mut self, loader: AssetLoader) {
-        if let Err(e) = loader.apply(self) {
-            log::error!("{}", e);
+    fn check_completed_lists(&mut self) {
+        let storage = &self.storage;
+        let events = &mut self.events;
+        self.lists.retain(|(id, ids)| {
+            let completed = ids.iter().all(|id| !storage.is_loading(id));
+            if completed {
+                events.push(Event::AssetListCompleted { id: *id });
+            }
+            !completed
+        });
+    }
+
+    /// Returns the events of the assets loaded or failed since the last call
+    pub(crate) fn take_events(&mut self) -> Vec<Event> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Reads again the files that failed or fails them if the retry policy doesn't allow it
+    fn read_failed(&mut self, id: &str, error: &str) {
+        let status = self.storage.progress(id).and_then(|p| p.status());
+        let retries = self.storage.retries(id);
+        match self.retry_policy.delay(retries, status) {
+            Some(delay) => {
+                log::warn!("File: {} -> {}, retrying in {:?}", id, error, delay);
+                self.storage.retry_at(id, Instant::now() + delay);
+            }
+            None => {
+                log::error!("File: {} -> {}", id, error);
+                self.stop(id, Some(error));
+            }
+        }
+    }
+
+    fn retry_due_reads(&mut self) {
+        for id in self.storage.due_retries(Instant::now()) {
+            let Some(progress) = self.storage.progress(&id) else {
+                continue;
+            };
+
+            if self.storage.is_stream(&id) {
+                let stream = self.sources.read_stream(&id, &progress);
+                self.storage.restart(&id, stream);
+            } else {
+                let fut = self.sources.read(&id, &progress);
+                self.storage.restart(&id, fut);
+            }
+        }
+    }
+
+    /// Gives the chunks read of the streamed files to their parsers while the budget allows it
+    fn parse_chunks(&mut self) -> Result<(), String> {
+        let mut budget = FrameBudget::new(&self.load_budget);
+        for id in self.storage.streams() {
+            let Some(loader) = self.loader_by_ext(&id).cloned() else {
+                continue;
+            };
+
+            while budget.allows() {
+                let Poll::Ready(next) = self.storage.poll_chunk(&id) else {
+                    break;
+                };
+
+                match next {
+                    Some(Ok(chunk)) => {
+                        budget.spend(chunk.len());
+                        let res =
+                            loader.exec_chunk(&id, StreamChunk::Data(&chunk), &mut self.storage);
+                        // the failure is handled, the rest of the streams keep loading
+                        if let Err(e) = res {
+                            log::error!("File: {} -> {}", id, e);
+                            self.stop(&id, Some(&e));
+                            break;
+                        }
+                    }
+                    // the parser has part of the file, so it cannot be read again
+                    Some(Err(e)) if self.storage.is_streaming(&id) => {
+                        log::error!("File: {} -> {}", id, e);
+                        self.stop(&id, Some(&e));
+                        break;
+                    }
+                    Some(Err(e)) => {
+                        self.read_failed(&id, &e);
+                        break;
+                    }
+                    None => {
+                        let res = loader.exec_chunk(&id, StreamChunk::End, &mut self.storage);
+                        self.finish_load(&id, &loader, res)?;
+                        break;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sets when to read again the files that failed to load
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry_policy = policy;
+    }
+
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Selects the loader of the file and parses it, or loads its dependencies first
+    fn parse_file<S>(
+        &mut self,
+        id: String,
+        data: Vec<u8>,
+        params: &mut LoaderParams<S>,
+    ) -> Result<(), String> {
+        if !self.storage.is_loading(&id) {
+            return Ok(());
+        }
+
+        let loader = self.loader(&id, &data).clone();
+
+        // the file is parsed once the assets it needs are loaded
+        if let Some(dependencies) = loader.dependencies() {
+            return match dependencies(&id, &data) {
+                Ok(ids) => self.load_dependencies(id, data, loader, ids),
+                Err(e) => self.finish_load(&id, &loader, Err(e)),
+            };
+        }
+
+        self.parse(id, data, loader, params)
+    }
+
+    /// Sets the max time or bytes used to parse files each frame, the chunks
+    /// of the streamed files have their own budget
+    pub fn set_load_budget(&mut self, budget: LoadBudget) {
+        self.load_budget = budget;
+    }
+
+    pub fn load_budget(&self) -> LoadBudget {
+        self.load_budget
+    }
+
+    /// Parses the bytes with the loader, async loaders parse them on a worker thread
+    fn parse<S>(
+        &mut self,
+        id: String,
+        data: Vec<u8>,
+        loader: LoaderCallback,
+        params: &mut LoaderParams<S>,
+    ) -> Result<(), String> {
+        if let Some(decoder) = loader.decoder() {
+            self.decoding.insert(id.clone(), loader);
+            self.decoder.decode(id, data, decoder);
+            return Ok(());
+        }
+
+        let res = loader.exec(&id, data, &mut self.storage, params);
+        self.finish_load(&id, &loader, res)
+    }
+
+    /// Loads the dependencies of a file that are not loaded yet
+    fn load_dependencies(
+        &mut self,
+        id: String,
+        data: Vec<u8>,
+        loader: LoaderCallback,
+        ids: Vec<String>,
+    ) -> Result<(), String> {
+        // the files of a cycle would wait for each other forever
+        let cycle = ids.iter().find_map(|dep| {
+            dependency_cycle(&id, dep, |pending_id| {
+                self.pending
+                    .iter()
+                    .find(|pending| pending.id == pending_id)
+                    .map(|pending| pending.waiting.as_slice())
+            })
+        });
+
+        if let Some(cycle) = cycle {
+            let error = format!("Dependency cycle: {}", cycle.join(" -> "));
+            log::error!("File: {} -> {}", id, error);
+            self.stop(&id, Some(&error));
+            return Ok(());
+        }
+
+        let priority = self.storage.priority(&id);
+        let mut waiting = vec![];
+        let mut dependencies = Dependencies::default();
+        for dep in ids {
+            if let Some((type_id, asset)) = self.cache.get(&dep) {
+                dependencies.insert(&dep, type_id, asset);
+                continue;
+            }
+
+            if !self.storage.is_loading(&dep) {
+                if let Err(e) = self.load(&dep, None, priority) {
+                    let error = format!("The dependency '{dep}' failed: {e}");
+                    log::error!("File: {} -> {}", id, error);
+                    self.stop(&id, Some(&error));
+                    return Ok(());
+                }
+            }
+            waiting.push(dep);
         }
+
+        self.pending.push(PendingParse {
+            id,
+            loader,
+            data,
+            waiting,
+            dependencies,
+        });
+        Ok(())
     }
 
-    fn load(&mut self, id: &str) -> Result<DoneSignal, String> {
-        let ext = Path::new(id)
-            .extension()
-            .map(|ext| ext.to_str().unwrap())
-            .unwrap_or("");
+    /// Returns the files whose dependencies are already loaded
+    fn resolve_dependencies(&mut self) -> Vec<PendingParse> {
+        for pending in &mut self.pending {
+            pending.waiting.retain(|dep| match self.cache.get(dep) {
+                Some((type_id, asset)) => {
+                    // the dependency keeps a reference, so the tracker can release it
+                    self.storage.tracker.claim(dep);
+                    pending.dependencies.insert(dep, type_id, asset);
+                    false
+                }
+                None => true,
+            });
+        }
+
+        let (ready, pending) = std::mem::take(&mut self.pending)
+            .into_iter()
+            .partition(|pending| pending.waiting.is_empty());
+        self.pending = pending;
+        ready
+    }
 
-        let loader = match self.loaders.get(ext) {
+    /// Returns the loader for the file's extension
+    fn loader_by_ext(&self, id: &str) -> Option<&LoaderCallback> {
+        match self.ext_hints.get(id) {
+            Some(ext) => self.extension_loader(ext),
+            None => extensions(self.preprocessors.loader_id(id))
+                .find_map(|ext| self.extension_loader(ext)),
+        }
+    }
+
+    fn extension_loader(&self, ext: &str) -> Option<&LoaderCallback> {
+        self.loaders.get(ext).map(|(_, loader)| loader)
+    }
+
+    /// Returns the loader for the file's extension, or the one detected by the content of the file
+    fn loader(&self, id: &str, bytes: &[u8]) -> &LoaderCallback {
+        let loader = self.loader_by_ext(id).or_else(|| {
+            let sniffed = self.sniffers.iter().find(|(sniffer, _)| sniffer(bytes));
+            sniffed
+                .map(|(_, loader)| loader)
+                .or_else(|| self.extension_loader(sniff_extension(bytes)?))
+        });
+
+        match loader {
             Some(loader) => loader,
             None => {
                 log::warn!(
@@ -83,46 +438,381 @@ impl Assets {
                 );
                 &self.byte_loader
             }
+        }
+    }
+
+    /// Stops tracking the asset once the loader stored it
+    fn finish_load(
+        &mut self,
+        id: &str,
+        loader: &LoaderCallback,
+        res: Result<(), String>,
+    ) -> Result<(), String> {
+        self.ext_hints.remove(id);
+
+        #[cfg(all(feature = "hot_reload", not(target_arch = "wasm32")))]
+        if self.reloading.remove(id) {
+            // a file saved with errors should not close the app
+            match res.and(self.storage.clean_asset(id)) {
+                Ok(_) => log::info!("Asset '{}' reloaded", id),
+                Err(e) => log::error!("Asset '{}' cannot be reloaded: {}", id, e),
+            }
+            return Ok(());
+        }
+
+        // the failures of a file don't stop the rest of the loads
+        if let Err(e) = res {
+            log::error!("File: {} -> {}", id, e);
+            self.stop(id, Some(&e));
+            return Ok(());
+        }
+
+        if let Some(type_id) = loader.type_id() {
+            let type_name = self.type_names.get(&type_id).copied().unwrap_or("unknown");
+            self.events.push(Event::AssetLoaded {
+                id: id.to_string(),
+                type_name: type_name.to_string(),
+            });
+        }
+
+        if let (Some(type_id), Some(asset)) = (loader.type_id(), self.storage.tracker.asset(id)) {
+            #[cfg(all(feature = "hot_reload", not(target_arch = "wasm32")))]
+            if let Some(watcher) = &mut self.watcher {
+                watcher.watch(id, type_id, &asset);
+            }
+
+            let bytes = self.storage.bytes(id);
+            self.cache.insert(id, type_id, asset, bytes);
+        }
+
+        self.storage.clean_asset(id)
+    }
+
+    /// Loads again the assets whose files changed, the loader
+    /// updates the data of the [Asset] handles already given
+    #[cfg(all(feature = "hot_reload", not(target_arch = "wasm32")))]
+    fn reload_changed_files(&mut self) {
+        let Some(watcher) = &mut self.watcher else {
+            return;
         };
 
-        Ok(match loader.type_id() {
-            Some(type_id) => self.storage.register(id, type_id),
-            None => return Err("Loader without output type id".to_string()),
-        })
+        for (id, type_id, asset) in watcher.changed() {
+            if self.storage.is_loading(&id) {
+                continue;
+            }
+
+            log::info!("Reloading asset '{}'", id);
+            let progress = LoadProgress::default();
+            let fut = self.sources.read(&id, &progress);
+            self.storage.reload(&id, fut, type_id, progress, asset);
+            self.reloading.insert(id);
+        }
     }
 
-    #[cfg(all(target_arch = "wasm32", feature = "drop_files"))]
-    fn load_wasm_dropped_file(&mut self, file: &DroppedFile) -> Result<DoneSignal, String> {
-        let id = file.name.clone();
-        let ext = Path::new(&id)
-            .extension()
-            .map(|ext| ext.to_str().unwrap())
-            .unwrap_or("");
+    /// Adds a loader for its extensions, returns the extensions that were
+    /// already registered by other loaders, which are replaced by the new one
+    pub fn add_loader(&mut self, loader: AssetLoader) -> Vec<LoaderConflict> {
+        match loader.apply(self) {
+            Ok(conflicts) => {
+                conflicts.iter().for_each(|c| {
+                    log::warn!(
+                        "The loader of '{}' for '.{}' files replaces the loader of '{}'",
+                        c.winner,
+                        c.extension,
+                        c.replaced
+                    );
+                });
+                conflicts
+            }
+            Err(e) => {
+                log::error!("{}", e);
+                vec![]
+            }
+        }
+    }
 
-        let loader = match self.loaders.get(ext) {
-            Some(loader) => loader,
-            None => {
-                log::warn!(
-                    "Not found a loader for '{}', loading as bytes (Vec<u8>)",
-                    id
-                );
-                &self.byte_loader
+    /// Adds a loader that deserializes the files with the extension into `T`,
+    /// the format is the last part of the extension, like `json` for `cfg.json`
+    #[cfg(feature = "serde_loaders")]
+    pub fn add_serde_loader<T>(&mut self, ext: &str) -> Vec<LoaderConflict>
+    where
+        T: serde::de::DeserializeOwned + Send + Sync + 'static,
+    {
+        self.add_loader(super::SerdeLoader::<T>::new(ext).into())
+    }
+
+    /// Sets the loader of the extension, returns the output type of the loader replaced
+    pub(crate) fn register_loader(
+        &mut self,
+        ext: &str,
+        type_name: &'static str,
+        loader: LoaderCallback,
+    ) -> Option<&'static str> {
+        if let Some(type_id) = loader.type_id() {
+            self.type_names.insert(type_id, type_name);
+        }
+
+        self.loaders
+            .insert(ext.to_string(), (type_name, loader))
+            .map(|(replaced, _)| replaced)
+    }
+
+    /// Returns the output type of the loader registered for the extension
+    pub fn loader_type(&self, ext: &str) -> Option<&'static str> {
+        self.loaders.get(ext).map(|(type_name, _)| *type_name)
+    }
+
+    /// Adds a function to transform the bytes of the files before their loader parses them,
+    /// like decompressing or decrypting them
+    pub fn add_preprocessor(&mut self, preprocessor: Preprocessor) {
+        if let Err(e) = self.preprocessors.add(preprocessor) {
+            log::error!("{}", e);
+        }
+    }
+
+    /// Reads the assets with an id that starts with the prefix from the source given,
+    /// without the prefix. By default the assets are read from the filesystem, or
+    /// from the server on the browsers
+    pub fn add_source<S: AssetSource + 'static>(&mut self, prefix: &str, source: S) {
+        self.sources.add(prefix, Box::new(source));
+    }
+
+    /// Returns true if the source of the asset has it
+    pub fn exists(&self, id: &str) -> bool {
+        self.sources.exists(id)
+    }
+
+    /// Returns the ids of the files inside of the directory
+    pub fn list(&self, dir: &str) -> Vec<String> {
+        self.sources.list(dir)
+    }
+
+    /// Stops loading the asset, the file is not parsed and the handles given will not be loaded.
+    /// The assets waiting for it as a dependency are cancelled too. Returns false if it was not loading
+    pub fn cancel(&mut self, id: &str) -> bool {
+        let cancelled = self.stop(id, None);
+        if cancelled {
+            log::info!("Asset '{}' cancelled", id);
+        }
+        cancelled
+    }
+
+    /// Stops loading the asset and the ones waiting for it as a dependency,
+    /// if it failed the error is kept for the handles
+    fn stop(&mut self, id: &str, error: Option<&str>) -> bool {
+        if let (Some(error), Some(progress)) = (error, self.storage.progress(id)) {
+            progress.set_error(error);
+
+            // the assets reloading keep their data
+            #[cfg(all(feature = "hot_reload", not(target_arch = "wasm32")))]
+            let reloading = self.reloading.contains(id);
+            #[cfg(not(all(feature = "hot_reload", not(target_arch = "wasm32"))))]
+            let reloading = false;
+
+            if !reloading && self.storage.set_fallback(id, &self.fallbacks) {
+                log::warn!("Asset '{}' uses the fallback of its type", id);
             }
+        }
+
+        if !self.storage.cancel(id) {
+            return false;
+        }
+
+        if let Some(error) = error {
+            self.events.push(Event::AssetFailed {
+                id: id.to_string(),
+                error: error.to_string(),
+            });
+        }
+
+        self.ext_hints.remove(id);
+        // the worker cannot be stopped, but the result will be ignored
+        self.decoding.remove(id);
+        self.queue.remove(id);
+        #[cfg(all(feature = "hot_reload", not(target_arch = "wasm32")))]
+        self.reloading.remove(id);
+
+        let (parents, pending) = std::mem::take(&mut self.pending)
+            .into_iter()
+            .partition::<Vec<_>, _>(|pending| {
+                pending.id == id || pending.waiting.iter().any(|dep| dep == id)
+            });
+        self.pending = pending;
+        let error = error.map(|e| format!("The dependency '{id}' failed: {e}"));
+        parents
+            .iter()
+            .filter(|parent| parent.id != id)
+            .for_each(|parent| {
+                self.stop(&parent.id, error.as_deref());
+            });
+
+        true
+    }
+
+    /// Sets the value given to the assets of its type that fail to load, like a checkerboard
+    /// texture, so they can be used as loaded. Use [Asset::is_fallback] to know if an asset has it.
+    /// The fallback is not used if the type is unknown before reading the file, like the
+    /// assets of a list selected by the content of the file
+    pub fn set_fallback<A>(&mut self, asset: A)
+    where
+        A: Clone + Send + Sync + 'static,
+    {
+        self.fallbacks.insert(asset);
+    }
+
+    /// Removes the fallback of the type, returns false if it has not one
+    pub fn remove_fallback<A>(&mut self) -> bool
+    where
+        A: Send + Sync + 'static,
+    {
+        self.fallbacks.remove(TypeId::of::<A>())
+    }
+
+    /// Returns true if the type has a fallback
+    pub fn has_fallback<A>(&self) -> bool
+    where
+        A: Send + Sync + 'static,
+    {
+        self.fallbacks.contains(TypeId::of::<A>())
+    }
+
+    /// Removes the asset from the cache, so it will be loaded again from its file next time.
+    /// The data is dropped once all the handles to it are dropped
+    pub fn unload(&mut self, id: &str) -> bool {
+        self.cache.remove(id)
+    }
+
+    /// Removes from the cache the assets without handles, returns how many were removed
+    pub fn unload_unused(&mut self) -> usize {
+        self.cache.remove_unused()
+    }
+
+    /// Returns the number of handles to a loaded asset, without the weak ones
+    pub fn ref_count(&self, id: &str) -> usize {
+        self.cache.ref_count(id)
+    }
+
+    /// Sets the max size in bytes of the files of the loaded assets. With a budget the cache keeps
+    /// the assets without handles loaded, and when it's exceeded the least recently used ones are
+    /// unloaded. Without it (the default) the assets are dropped with their last handle
+    pub fn set_cache_budget(&mut self, bytes: Option<usize>) {
+        self.cache.set_budget(bytes);
+    }
+
+    /// Returns the max size in bytes of the files of the loaded assets
+    pub fn cache_budget(&self) -> Option<usize> {
+        self.cache.budget()
+    }
+
+    /// Returns the size in bytes of the files of the loaded assets
+    pub fn cache_bytes(&self) -> usize {
+        self.cache.bytes()
+    }
+
+    /// Returns the asset if it's already loaded
+    fn cached<A>(&mut self, id: &str) -> Option<Result<Asset<A>, String>>
+    where
+        A: Send + Sync + 'static,
+    {
+        let (type_id, asset) = self.cache.get(id)?;
+        if type_id != TypeId::of::<A>() {
+            return Some(Err("Invalid asset type".to_string()));
+        }
+
+        let inner = asset.downcast::<RwLock<Option<A>>>().unwrap();
+        Some(Ok(Asset {
+            id: id.to_string(),
+            loaded: DoneSignal::from_bool(true),
+            progress: Default::default(),
+            inner,
+        }))
+    }
+
+    /// Returns the type of the asset if the loader is known before reading the file
+    fn output_type(&self, id: &str, expected: Option<TypeId>) -> Result<Option<TypeId>, String> {
+        match self.loader_by_ext(id) {
+            Some(loader) => loader
+                .type_id()
+                .map(Some)
+                .ok_or_else(|| "Loader without output type id".to_string()),
+            // the loader will be selected by the content of the file
+            None => Ok(expected),
+        }
+    }
+
+    fn load(
+        &mut self,
+        id: &str,
+        expected: Option<TypeId>,
+        priority: Priority,
+    ) -> Result<(DoneSignal, LoadProgress), String> {
+        let type_id = self.output_type(id, expected)?;
+        let progress = LoadProgress::default();
+        // the preprocessors need the whole file
+        let stream = self
+            .loader_by_ext(id)
+            .is_some_and(|loader| loader.is_stream())
+            && !self.preprocessors.matches(id);
+        let loaded = if stream {
+            let stream = self.sources.read_stream(id, &progress);
+            self.storage.register(id, stream, type_id, progress.clone())
+        } else {
+            let fut = self.sources.read(id, &progress);
+            self.storage.register(id, fut, type_id, progress.clone())
         };
+        self.storage.set_priority(id, priority);
+        Ok((loaded, progress))
+    }
 
-        Ok(match loader.type_id() {
-            Some(type_id) => self
-                .storage
-                .register_wasm_dropped_file(&id, file, type_id)?,
-            None => return Err("Loader without output type id".to_string()),
-        })
+    #[cfg(all(target_arch = "wasm32", feature = "drop_files"))]
+    fn load_wasm_dropped_file(&mut self, file: &DroppedFile) -> Result<DoneSignal, String> {
+        let id = file.name.clone();
+        let type_id = self.output_type(&id, None)?;
+        self.storage.register_wasm_dropped_file(&id, file, type_id)
     }
 
     pub fn load_asset<A>(&mut self, id: &str) -> Result<Asset<A>, String>
     where
         A: Send + Sync + 'static,
     {
-        let _ = self.load(id)?;
+        self.load_asset_with(id, Priority::Normal)
+    }
+
+    /// Loads the asset with a priority, the files with higher priority are parsed first
+    pub fn load_asset_with<A>(&mut self, id: &str, priority: Priority) -> Result<Asset<A>, String>
+    where
+        A: Send + Sync + 'static,
+    {
+        if let Some(asset) = self.cached(id) {
+            return asset;
+        }
+
+        let _ = self.load(id, Some(TypeId::of::<A>()), priority)?;
+        self.storage.get(id, true)
+    }
+
+    /// Loads an asset from bytes in memory, like generated data or data received from the network.
+    /// The loader is selected by the extension given, or by the content of the bytes if it's `None`,
+    /// and it runs on the next frame
+    pub fn load_from_bytes<A>(
+        &mut self,
+        id: &str,
+        bytes: Vec<u8>,
+        ext_hint: Option<&str>,
+    ) -> Result<Asset<A>, String>
+    where
+        A: Send + Sync + 'static,
+    {
+        match ext_hint {
+            Some(ext) => self.ext_hints.insert(id.to_string(), ext.to_string()),
+            None => self.ext_hints.remove(id),
+        };
+
+        let type_id = self.output_type(id, Some(TypeId::of::<A>()))?;
+        let progress = LoadProgress::default();
+        progress.set_total(bytes.len() as _);
+        let fut: AssetSourceFuture = Box::pin(future::ready(Ok(bytes)));
+        self.storage.register(id, fut, type_id, progress);
         self.storage.get(id, true)
     }
 
@@ -136,14 +826,95 @@ impl Assets {
     }
 
     pub fn load_list(&mut self, paths: &[&str]) -> Result<AssetList, String> {
-        let mut list = AssetList::new(self.storage.tracker.clone());
+        self.load_list_with(paths, Priority::Normal)
+    }
+
+    /// Loads the list with a priority, the files with higher priority are parsed first
+    pub fn load_list_with(
+        &mut self,
+        paths: &[&str],
+        priority: Priority,
+    ) -> Result<AssetList, String> {
+        self.list_count += 1;
+        let mut list = AssetList::new(self.list_count, self.storage.tracker.clone());
         for id in paths {
-            let loaded = self.load(id)?;
-            list.insert(id, loaded);
+            if let Some((type_id, asset)) = self.cache.get(id) {
+                list.insert_loaded(id, type_id, asset);
+                continue;
+            }
+
+            let (loaded, progress) = self.load(id, None, priority)?;
+            list.insert(id, loaded, progress);
         }
+        let ids = list.ids().map(|id| id.to_string()).collect();
+        self.lists.push((list.id(), ids));
         Ok(list)
     }
 
+    /// Adds the bundles of the manifest, replacing the ones with the same name.
+    /// The bundles marked to preload start loading
+    pub fn add_bundles(&mut self, manifest: BundleManifest) -> Result<(), String> {
+        let preload = manifest
+            .bundles
+            .iter()
+            .filter(|(_, bundle)| bundle.preload)
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+
+        self.bundles.extend(manifest.bundles);
+        preload
+            .iter()
+            .try_for_each(|name| self.load_bundle(name).map(|_| ()))
+    }
+
+    /// Starts loading the assets of the bundle, the list is kept until the bundle is unloaded
+    pub fn load_bundle(&mut self, name: &str) -> Result<&mut AssetList, String> {
+        if !self.bundle_lists.contains_key(name) {
+            let bundle = self
+                .bundles
+                .get(name)
+                .ok_or_else(|| format!("Bundle '{name}' not found"))?;
+
+            let paths = bundle
+                .assets
+                .iter()
+                .map(|asset| asset.path().to_string())
+                .collect::<Vec<_>>();
+            bundle
+                .assets
+                .iter()
+                .filter_map(|asset| Some((asset.path(), asset.ext()?)))
+                .for_each(|(path, ext)| {
+                    self.ext_hints.insert(path.to_string(), ext.to_string());
+                });
+
+            let paths = paths.iter().map(|path| path.as_str()).collect::<Vec<_>>();
+            let list = self.load_list(&paths)?;
+            self.bundle_lists.insert(name.to_string(), list);
+        }
+
+        Ok(self.bundle_lists.get_mut(name).unwrap())
+    }
+
+    /// Returns the list of the bundle if it's loading or loaded
+    pub fn bundle(&mut self, name: &str) -> Option<&mut AssetList> {
+        self.bundle_lists.get_mut(name)
+    }
+
+    /// Removes the list of the bundle and unloads its assets, the ones still loading are cancelled.
+    /// Returns false if it was not loaded
+    pub fn unload_bundle(&mut self, name: &str) -> bool {
+        let Some(list) = self.bundle_lists.remove(name) else {
+            return false;
+        };
+
+        list.ids().for_each(|id| {
+            self.cancel(id);
+            self.cache.remove(id);
+        });
+        true
+    }
+
     #[cfg(feature = "drop_files")]
     pub fn load_dropped_file<A>(&mut self, file: &DroppedFile) -> Result<Asset<A>, String>
     where
@@ -164,3 +935,76 @@ impl Assets {
         Err(format!("Can't load the dropped file {}", file.name))
     }
 }
+
+/// Returns the extensions of the id from the longest one, like `cfg.json` and `json`,
+/// ignoring the query and the fragment of the urls
+fn extensions(id: &str) -> impl Iterator<Item = &str> {
+    let path = id.split(['?', '#']).next().unwrap_or(id);
+    let name = Path::new(path)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("");
+
+    // the dot of the hidden files is not an extension
+    let name = name.strip_prefix('.').unwrap_or(name);
+    name.match_indices('.').map(move |(i, _)| &name[i + 1..])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn fail(_: &str, _: Vec<u8>) -> Result<u8, String> {
+        Err("Invalid data".to_string())
+    }
+
+    /// Finishes the load of the file as if its loader failed
+    fn finish_with_error(assets: &mut Assets, id: &str) {
+        let loader = assets.loader_by_ext(id).cloned().unwrap();
+        let res = Err("Invalid data".to_string());
+        assert!(assets.finish_load(id, &loader, res).is_ok());
+    }
+
+    #[test]
+    fn use_fallback_when_the_loader_fails() {
+        let mut assets = Assets::new();
+        assets.add_loader(AssetLoader::new().use_parser(fail).extension("bad"));
+        assets.set_fallback(7u8);
+
+        let asset = assets.load_from_bytes::<u8>("a.bad", vec![], None).unwrap();
+        let mut list = assets.load_list(&["b.bad"]).unwrap();
+        finish_with_error(&mut assets, "a.bad");
+        finish_with_error(&mut assets, "b.bad");
+
+        assert!(asset.is_fallback());
+        assert_eq!(asset.error().as_deref(), Some("Invalid data"));
+        assert!(list.is_loaded());
+        assert_eq!(
+            list.take::<u8>("b.bad").unwrap().lock().as_deref(),
+            Some(&7)
+        );
+        assert!(!assets.storage.is_loading("a.bad"));
+    }
+
+    #[test]
+    fn send_one_event_when_the_loader_fails() {
+        let mut assets = Assets::new();
+        assets.add_loader(AssetLoader::new().use_parser(fail).extension("bad"));
+
+        let _asset = assets.load_from_bytes::<u8>("a.bad", vec![], None).unwrap();
+        finish_with_error(&mut assets, "a.bad");
+
+        let failed = assets
+            .take_events()
+            .into_iter()
+            .filter_map(|evt| match evt {
+                Event::AssetFailed { id, error } => Some((id, error)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            failed,
+            vec![("a.bad".to_string(), "Invalid data".to_string())]
+        );
+    }
+}
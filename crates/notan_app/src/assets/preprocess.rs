@@ -0,0 +1,164 @@
+use std::rc::Rc;
+
+type PreprocessFn = Rc<dyn Fn(&str, Vec<u8>) -> Result<Vec<u8>, String>>;
+
+/// Transforms the bytes of the files before the loaders parse them, like decompressing
+/// or decrypting them. It runs on the files with its extensions or its prefixes
+#[derive(Clone)]
+pub struct Preprocessor {
+    extensions: Vec<String>,
+    prefixes: Vec<String>,
+    transform: PreprocessFn,
+}
+
+impl Preprocessor {
+    /// Creates a preprocessor with a function that receives the id and the bytes of the file
+    pub fn new<F>(transform: F) -> Self
+    where
+        F: Fn(&str, Vec<u8>) -> Result<Vec<u8>, String> + 'static,
+    {
+        Self {
+            extensions: vec![],
+            prefixes: vec![],
+            transform: Rc::new(transform),
+        }
+    }
+
+    /// Runs on the files with the extension, which is ignored to select their loader,
+    /// so `tiles.png.gz` is parsed by the loader of `png`
+    pub fn extension(mut self, ext: &str) -> Self {
+        self.extensions.push(ext.to_string());
+        self
+    }
+
+    pub fn extensions(mut self, exts: &[&str]) -> Self {
+        for ext in exts {
+            self.extensions.push(ext.to_string());
+        }
+        self
+    }
+
+    /// Runs on the files with an id starting with the prefix, like the files of an encrypted pack
+    pub fn prefix(mut self, prefix: &str) -> Self {
+        self.prefixes.push(prefix.to_string());
+        self
+    }
+
+    /// Decompresses the `.gz` files
+    #[cfg(feature = "gzip")]
+    pub fn gzip() -> Self {
+        Self::new(|id, data| {
+            use std::io::Read;
+
+            let mut bytes = vec![];
+            flate2::read::GzDecoder::new(data.as_slice())
+                .read_to_end(&mut bytes)
+                .map_err(|e| format!("Asset '{id}' cannot be decompressed: {e}"))?;
+            Ok(bytes)
+        })
+        .extension("gz")
+    }
+
+    /// Decompresses the `.zst` files
+    #[cfg(feature = "zstd")]
+    pub fn zstd() -> Self {
+        Self::new(|id, data| {
+            use std::io::Read;
+
+            let mut bytes = vec![];
+            ruzstd::StreamingDecoder::new(data.as_slice())
+                .map_err(|e| e.to_string())
+                .and_then(|mut decoder| decoder.read_to_end(&mut bytes).map_err(|e| e.to_string()))
+                .map_err(|e| format!("Asset '{id}' cannot be decompressed: {e}"))?;
+            Ok(bytes)
+        })
+        .extension("zst")
+    }
+
+    fn has_extension(&self, id: &str) -> bool {
+        last_extension(id).is_some_and(|ext| self.extensions.iter().any(|e| e == ext))
+    }
+
+    fn matches(&self, id: &str) -> bool {
+        self.has_extension(id) || self.prefixes.iter().any(|p| id.starts_with(p.as_str()))
+    }
+}
+
+/// Preprocessors added to the assets, they run in the order they were added
+#[derive(Default)]
+pub(crate) struct Preprocessors {
+    list: Vec<Preprocessor>,
+}
+
+impl Preprocessors {
+    pub fn add(&mut self, preprocessor: Preprocessor) -> Result<(), String> {
+        if preprocessor.extensions.is_empty() && preprocessor.prefixes.is_empty() {
+            return Err("Preprocessor without extensions or prefixes associated.".to_string());
+        }
+
+        self.list.push(preprocessor);
+        Ok(())
+    }
+
+    /// Returns true if any preprocessor runs on the file
+    pub fn matches(&self, id: &str) -> bool {
+        self.list.iter().any(|p| p.matches(id))
+    }
+
+    pub fn apply(&self, id: &str, data: Vec<u8>) -> Result<Vec<u8>, String> {
+        self.list
+            .iter()
+            .filter(|p| p.matches(id))
+            .try_fold(data, |data, p| (p.transform)(id, data))
+    }
+
+    /// Returns the id without the extension of the preprocessor, used to select the loader
+    pub fn loader_id<'a>(&self, id: &'a str) -> &'a str {
+        let path = id.split(['?', '#']).next().unwrap_or(id);
+        match last_extension(path) {
+            Some(ext) if self.list.iter().any(|p| p.has_extension(path)) => {
+                &path[..path.len() - ext.len() - 1]
+            }
+            _ => id,
+        }
+    }
+}
+
+/// Returns the last extension of the id, ignoring the query and the fragment of the urls
+fn last_extension(id: &str) -> Option<&str> {
+    let path = id.split(['?', '#']).next().unwrap_or(id);
+    std::path::Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn preprocess_by_extension_and_prefix() {
+        let mut preprocessors = Preprocessors::default();
+        preprocessors
+            .add(
+                Preprocessor::new(|_, data| Ok(data.iter().map(|b| b ^ 0xff).collect()))
+                    .prefix("secret/"),
+            )
+            .unwrap();
+        preprocessors
+            .add(Preprocessor::new(|_, data| Ok(data.repeat(2))).extension("twice"))
+            .unwrap();
+        assert!(preprocessors
+            .add(Preprocessor::new(|_, data| Ok(data)))
+            .is_err());
+
+        assert_eq!(preprocessors.loader_id("tiles.png.twice"), "tiles.png");
+        assert_eq!(preprocessors.loader_id("tiles.png"), "tiles.png");
+        assert!(!preprocessors.matches("tiles.png"));
+
+        let data = preprocessors
+            .apply("secret/a.txt.twice", vec![0xf0])
+            .unwrap();
+        assert_eq!(data, vec![0x0f, 0x0f]);
+    }
+}
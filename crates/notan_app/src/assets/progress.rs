@@ -0,0 +1,99 @@
+use parking_lot::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU16, AtomicU64, Ordering};
+use std::sync::Arc;
+
+// the total is unknown until it's set
+const UNKNOWN: u64 = u64::MAX;
+
+/// Bytes read of an asset and the errors reading it, shared between
+/// the [AssetSource](super::AssetSource) reading it and the handles
+#[derive(Clone, Debug)]
+pub struct LoadProgress {
+    loaded: Arc<AtomicU64>,
+    total: Arc<AtomicU64>,
+    // 0 if the source doesn't report it
+    status: Arc<AtomicU16>,
+    error: Arc<Mutex<Option<String>>>,
+    // the asset failed and has the fallback of its type
+    fallback: Arc<AtomicBool>,
+}
+
+impl Default for LoadProgress {
+    fn default() -> Self {
+        Self {
+            loaded: Arc::new(AtomicU64::new(0)),
+            total: Arc::new(AtomicU64::new(UNKNOWN)),
+            status: Default::default(),
+            error: Default::default(),
+            fallback: Default::default(),
+        }
+    }
+}
+
+impl LoadProgress {
+    /// Sets the size of the file, usually known from the metadata or the response headers
+    pub fn set_total(&self, total: u64) {
+        self.total.store(total, Ordering::Relaxed);
+    }
+
+    /// Adds the bytes read to the loaded ones
+    pub fn add_loaded(&self, bytes: u64) {
+        self.loaded.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    /// Bytes read
+    pub fn loaded(&self) -> u64 {
+        self.loaded.load(Ordering::Relaxed)
+    }
+
+    /// Size of the file if it's known
+    pub fn total(&self) -> Option<u64> {
+        match self.total.load(Ordering::Relaxed) {
+            UNKNOWN => None,
+            total => Some(total),
+        }
+    }
+
+    /// Sets the HTTP status of the response, used to know if the read can be retried
+    pub fn set_status(&self, status: u16) {
+        self.status.store(status, Ordering::Relaxed);
+    }
+
+    /// HTTP status of the response if the source reports it
+    pub fn status(&self) -> Option<u16> {
+        match self.status.load(Ordering::Relaxed) {
+            0 => None,
+            status => Some(status),
+        }
+    }
+
+    /// Error of the last try to read the file, if it failed
+    pub fn error(&self) -> Option<String> {
+        self.error.lock().clone()
+    }
+
+    /// Returns true if the asset failed to load and it has the fallback of its type
+    pub fn is_fallback(&self) -> bool {
+        self.fallback.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn set_fallback(&self) {
+        self.fallback.store(true, Ordering::Relaxed);
+    }
+
+    pub(crate) fn set_error(&self, error: &str) {
+        *self.error.lock() = Some(error.to_string());
+    }
+
+    /// Starts again before retrying the read
+    pub(crate) fn reset(&self) {
+        self.loaded.store(0, Ordering::Relaxed);
+        self.status.store(0, Ordering::Relaxed);
+    }
+
+    /// Sets the progress of the file read completely
+    pub(crate) fn finish(&self, len: usize) {
+        self.loaded.store(len as _, Ordering::Relaxed);
+        self.total.store(len as _, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,164 @@
+use notan_utils::{Duration, Instant};
+
+/// Order to parse the files read in the same frame, the higher first
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+/// Max time or bytes parsed each frame, the rest of files wait for the next frames.
+/// At least one file is parsed each frame
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LoadBudget {
+    time: Option<Duration>,
+    bytes: Option<usize>,
+}
+
+impl LoadBudget {
+    /// Without limits, all the files read are parsed in the same frame
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    pub fn with_time(mut self, time: Duration) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    pub fn with_bytes(mut self, bytes: usize) -> Self {
+        self.bytes = Some(bytes);
+        self
+    }
+
+    pub fn time(&self) -> Option<Duration> {
+        self.time
+    }
+
+    pub fn bytes(&self) -> Option<usize> {
+        self.bytes
+    }
+}
+
+struct QueuedFile {
+    id: String,
+    data: Vec<u8>,
+    priority: Priority,
+    // keeps the order of the files with the same priority
+    order: u64,
+}
+
+/// Files read waiting to be parsed
+#[derive(Default)]
+pub(crate) struct ParseQueue {
+    files: Vec<QueuedFile>,
+    count: u64,
+}
+
+impl ParseQueue {
+    pub fn push(&mut self, id: String, data: Vec<u8>, priority: Priority) {
+        self.count += 1;
+        self.files.push(QueuedFile {
+            id,
+            data,
+            priority,
+            order: self.count,
+        });
+    }
+
+    pub fn remove(&mut self, id: &str) {
+        self.files.retain(|file| file.id != id);
+    }
+
+    /// Returns the files to parse in this frame by priority
+    pub fn take(&mut self, budget: &LoadBudget) -> FrameFiles<'_> {
+        // sorted from the lowest priority so the next one is popped from the end
+        self.files
+            .sort_by_key(|file| (file.priority, std::cmp::Reverse(file.order)));
+
+        FrameFiles {
+            queue: self,
+            budget: FrameBudget::new(budget),
+        }
+    }
+}
+
+/// Time and bytes spent in a frame, the first bytes are always allowed
+pub(crate) struct FrameBudget {
+    budget: LoadBudget,
+    start: Instant,
+    bytes: usize,
+    first: bool,
+}
+
+impl FrameBudget {
+    pub fn new(budget: &LoadBudget) -> Self {
+        Self {
+            budget: *budget,
+            start: Instant::now(),
+            bytes: 0,
+            first: true,
+        }
+    }
+
+    pub fn allows(&self) -> bool {
+        if self.first {
+            return true;
+        }
+
+        let out_of_time = self
+            .budget
+            .time
+            .is_some_and(|time| self.start.elapsed() >= time);
+        let out_of_bytes = self.budget.bytes.is_some_and(|bytes| self.bytes >= bytes);
+        !(out_of_time || out_of_bytes)
+    }
+
+    pub fn spend(&mut self, bytes: usize) {
+        self.first = false;
+        self.bytes += bytes;
+    }
+}
+
+/// Iterator over the files to parse until the budget is exhausted
+pub(crate) struct FrameFiles<'a> {
+    queue: &'a mut ParseQueue,
+    budget: FrameBudget,
+}
+
+impl Iterator for FrameFiles<'_> {
+    type Item = (String, Vec<u8>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.budget.allows() {
+            return None;
+        }
+
+        let file = self.queue.files.pop()?;
+        self.budget.spend(file.data.len());
+        Some((file.id, file.data))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_by_priority_within_budget() {
+        let mut queue = ParseQueue::default();
+        queue.push("music".to_string(), vec![0; 10], Priority::Low);
+        queue.push("atlas".to_string(), vec![0; 10], Priority::High);
+        queue.push("font".to_string(), vec![0; 10], Priority::Normal);
+        queue.push("ui".to_string(), vec![0; 10], Priority::High);
+
+        let budget = LoadBudget::default().with_bytes(20);
+        let ids = queue.take(&budget).map(|(id, _)| id).collect::<Vec<_>>();
+        assert_eq!(ids, vec!["atlas", "ui"]);
+
+        let ids = queue.take(&budget).map(|(id, _)| id).collect::<Vec<_>>();
+        assert_eq!(ids, vec!["font", "music"]);
+    }
+}
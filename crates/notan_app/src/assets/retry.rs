@@ -0,0 +1,93 @@
+use notan_utils::Duration;
+
+/// When to read again the files that failed to load.
+/// By default only the browsers retry, because reading a local file again usually fails again
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    backoff: Duration,
+    max_backoff: Duration,
+    statuses: Vec<u16>,
+    retry_without_status: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: if cfg!(target_arch = "wasm32") { 3 } else { 0 },
+            backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(8),
+            statuses: vec![408, 429, 500, 502, 503, 504],
+            retry_without_status: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Never retries
+    pub fn none() -> Self {
+        Self::default().with_max_retries(0)
+    }
+
+    /// Times to read again a file before failing
+    pub fn with_max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// Delay before the first retry, it doubles on each retry up to the max given
+    pub fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// HTTP statuses of the responses that can be retried
+    pub fn with_statuses(mut self, statuses: &[u16]) -> Self {
+        self.statuses = statuses.to_vec();
+        self
+    }
+
+    /// Retry the errors without a response, like network errors or
+    /// the sources that don't report the status
+    pub fn with_retry_without_status(mut self, retry: bool) -> Self {
+        self.retry_without_status = retry;
+        self
+    }
+
+    /// Returns the delay before the retry, or `None` if the file should not be read again
+    pub(crate) fn delay(&self, retries: u32, status: Option<u16>) -> Option<Duration> {
+        if retries >= self.max_retries {
+            return None;
+        }
+
+        let retryable = match status {
+            Some(status) => self.statuses.contains(&status),
+            None => self.retry_without_status,
+        };
+        if !retryable {
+            return None;
+        }
+
+        let backoff = self.backoff.saturating_mul(2u32.saturating_pow(retries));
+        Some(backoff.min(self.max_backoff))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn backoff_until_max_retries() {
+        let policy = RetryPolicy::default()
+            .with_max_retries(5)
+            .with_backoff(Duration::from_secs(1), Duration::from_secs(3));
+
+        assert_eq!(policy.delay(0, Some(503)), Some(Duration::from_secs(1)));
+        assert_eq!(policy.delay(1, None), Some(Duration::from_secs(2)));
+        assert_eq!(policy.delay(2, None), Some(Duration::from_secs(3)));
+        assert_eq!(policy.delay(5, None), None);
+        assert_eq!(policy.delay(0, Some(404)), None);
+    }
+}
@@ -0,0 +1,96 @@
+use super::loader::AssetLoader;
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+use std::path::Path;
+
+/// Loader that deserializes JSON, RON or TOML files into `T`.
+/// The format is the last part of the extension, so `cfg.json` files are read as JSON
+pub struct SerdeLoader<T> {
+    extensions: Vec<String>,
+    _type: PhantomData<T>,
+}
+
+impl<T> SerdeLoader<T>
+where
+    T: DeserializeOwned + Send + Sync + 'static,
+{
+    pub fn new(ext: &str) -> Self {
+        Self {
+            extensions: vec![ext.to_string()],
+            _type: PhantomData,
+        }
+    }
+
+    /// Adds another extension parsed as `T`
+    pub fn extension(mut self, ext: &str) -> Self {
+        self.extensions.push(ext.to_string());
+        self
+    }
+}
+
+impl<T> From<SerdeLoader<T>> for AssetLoader
+where
+    T: DeserializeOwned + Send + Sync + 'static,
+{
+    fn from(loader: SerdeLoader<T>) -> Self {
+        let exts = loader
+            .extensions
+            .iter()
+            .map(|ext| ext.as_str())
+            .collect::<Vec<_>>();
+
+        AssetLoader::new()
+            .use_async_parser(|id, data| deserialize::<T>(id, &data))
+            .extensions(&exts)
+    }
+}
+
+fn deserialize<T: DeserializeOwned>(id: &str, data: &[u8]) -> Result<T, String> {
+    let path = id.split(['?', '#']).next().unwrap_or(id);
+    let format = Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or("");
+
+    match format {
+        "json" => serde_json::from_slice(data).map_err(|e| e.to_string()),
+        "ron" => ron::de::from_bytes(data).map_err(|e| e.to_string()),
+        "toml" => {
+            let text = std::str::from_utf8(data).map_err(|e| e.to_string())?;
+            toml::from_str(text).map_err(|e| e.to_string())
+        }
+        _ => Err(format!(
+            "Asset '{id}' cannot be deserialized, the extension must be json, ron or toml"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Config {
+        name: String,
+        lives: u32,
+    }
+
+    #[test]
+    fn deserialize_by_format() {
+        let expected = Config {
+            name: "notan".to_string(),
+            lives: 3,
+        };
+
+        let json = br#"{ "name": "notan", "lives": 3 }"#;
+        assert_eq!(deserialize::<Config>("game.cfg.json", json), Ok(expected));
+
+        let ron = br#"(name: "notan", lives: 3)"#;
+        assert!(deserialize::<Config>("game.ron", ron).is_ok());
+
+        let toml = b"name = \"notan\"\nlives = 3";
+        assert!(deserialize::<Config>("game.toml?v=2", toml).is_ok());
+
+        assert!(deserialize::<Config>("game.txt", json).is_err());
+    }
+}
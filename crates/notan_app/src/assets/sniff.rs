@@ -0,0 +1,47 @@
+/// Returns the usual extension of the file format detected by its first bytes
+pub(crate) fn sniff_extension(bytes: &[u8]) -> Option<&'static str> {
+    let riff = |format: &[u8]| bytes.starts_with(b"RIFF") && bytes.get(8..12) == Some(format);
+
+    let ext = if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        "png"
+    } else if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        "jpg"
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        "gif"
+    } else if bytes.starts_with(b"BM") {
+        "bmp"
+    } else if riff(b"WEBP") {
+        "webp"
+    } else if riff(b"WAVE") {
+        "wav"
+    } else if bytes.starts_with(b"OggS") {
+        "ogg"
+    } else if bytes.starts_with(b"fLaC") {
+        "flac"
+    } else if bytes.starts_with(b"ID3") || bytes.starts_with(&[0xFF, 0xFB]) {
+        "mp3"
+    } else if bytes.starts_with(&[0x00, 0x01, 0x00, 0x00]) {
+        "ttf"
+    } else if bytes.starts_with(b"OTTO") {
+        "otf"
+    } else {
+        return None;
+    };
+
+    Some(ext)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sniff_common_formats() {
+        assert_eq!(sniff_extension(b"\x89PNG\r\n\x1a\n...."), Some("png"));
+        assert_eq!(sniff_extension(b"RIFF\0\0\0\0WAVEfmt "), Some("wav"));
+        assert_eq!(sniff_extension(b"RIFF\0\0\0\0WEBPVP8 "), Some("webp"));
+        assert_eq!(sniff_extension(b"OggS\0\x02"), Some("ogg"));
+        assert_eq!(sniff_extension(b"RIFF"), None);
+        assert_eq!(sniff_extension(b"hello"), None);
+    }
+}
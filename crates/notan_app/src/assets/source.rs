@@ -0,0 +1,317 @@
+#[cfg(all(feature = "http_source", not(target_arch = "wasm32")))]
+mod http;
+#[cfg(all(feature = "http_source", target_arch = "wasm32"))]
+mod http_web;
+mod pak;
+#[cfg(feature = "zip_source")]
+mod zip;
+
+#[cfg(all(feature = "http_source", not(target_arch = "wasm32")))]
+pub use http::*;
+#[cfg(all(feature = "http_source", target_arch = "wasm32"))]
+pub use http_web::*;
+pub use pak::*;
+#[cfg(feature = "zip_source")]
+pub use zip::*;
+
+use super::progress::LoadProgress;
+use futures::future::LocalBoxFuture;
+use futures::prelude::*;
+use futures::stream::LocalBoxStream;
+use hashbrown::HashMap;
+use std::borrow::Cow;
+use std::path::PathBuf;
+
+/// Future returned by [AssetSource::read]
+pub type AssetSourceFuture = LocalBoxFuture<'static, Result<Vec<u8>, String>>;
+
+/// Chunks of the file returned by [AssetSource::read_stream]
+pub type AssetSourceStream = LocalBoxStream<'static, Result<Vec<u8>, String>>;
+
+/// Size of the chunks read by the sources that stream the files
+pub(crate) const STREAM_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Where the bytes of the assets come from, like the filesystem, a packed file or a server.
+/// The paths are relative to the prefix used to add the source to the [Assets](super::Assets)
+pub trait AssetSource {
+    /// Reads the bytes of the file
+    fn read(&self, path: &str) -> AssetSourceFuture;
+
+    /// Reads the bytes of the file reporting the bytes read while it's loading.
+    /// Once the bytes are returned the progress is set to their length, so the sources
+    /// that read the files at once don't need to implement it
+    fn read_with_progress(&self, path: &str, _progress: &LoadProgress) -> AssetSourceFuture {
+        self.read(path)
+    }
+
+    /// Reads the file in chunks as they arrive, used by the loaders that parse the files
+    /// while they are loading. By default the whole file is returned as one chunk
+    fn read_stream(&self, path: &str, progress: &LoadProgress) -> AssetSourceStream {
+        Box::pin(self.read_with_progress(path, progress).into_stream())
+    }
+
+    /// Returns true if the file exists, sources that cannot know it without reading the file return true
+    fn exists(&self, path: &str) -> bool;
+
+    /// Returns the paths of the files inside of the directory
+    fn list(&self, dir: &str) -> Vec<String>;
+}
+
+/// Reads the files from the filesystem, or from the server on the browsers
+#[derive(Default, Clone, Debug)]
+pub struct FileSource {
+    root: PathBuf,
+}
+
+impl FileSource {
+    /// Reads the paths relatives to the directory given
+    pub fn new(root: &str) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl AssetSource for FileSource {
+    fn read(&self, path: &str) -> AssetSourceFuture {
+        let path = self.root.join(path);
+        Box::pin(platter2::load_file(path).map_err(|e| e.to_string()))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn read_with_progress(&self, path: &str, progress: &LoadProgress) -> AssetSourceFuture {
+        if let Ok(meta) = std::fs::metadata(self.root.join(path)) {
+            progress.set_total(meta.len());
+        }
+        self.read(path)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn read_stream(&self, path: &str, progress: &LoadProgress) -> AssetSourceStream {
+        use std::io::Read;
+
+        let path = self.root.join(path);
+        let file = std::fs::File::open(&path).map_err(|e| format!("{}: {}", path.display(), e));
+        if let Ok(Ok(meta)) = file.as_ref().map(|file| file.metadata()) {
+            progress.set_total(meta.len());
+        }
+
+        let progress = progress.clone();
+        let chunks = stream::unfold(Some(file), move |file| {
+            let progress = progress.clone();
+            async move {
+                let mut file = match file? {
+                    Ok(file) => file,
+                    Err(e) => return Some((Err(e), None)),
+                };
+
+                let mut chunk = vec![0; STREAM_CHUNK_SIZE];
+                loop {
+                    match file.read(&mut chunk) {
+                        Ok(0) => return None,
+                        Ok(read) => {
+                            chunk.truncate(read);
+                            progress.add_loaded(read as _);
+                            return Some((Ok(chunk), Some(Ok(file))));
+                        }
+                        Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                        Err(e) => return Some((Err(e.to_string()), None)),
+                    }
+                }
+            }
+        });
+
+        Box::pin(chunks)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn exists(&self, path: &str) -> bool {
+        self.root.join(path).is_file()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn exists(&self, _path: &str) -> bool {
+        true
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn list(&self, dir: &str) -> Vec<String> {
+        let Ok(entries) = std::fs::read_dir(self.root.join(dir)) else {
+            return vec![];
+        };
+
+        entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_file())
+            .filter_map(|entry| {
+                let name = entry.file_name();
+                Some(join_path(dir, name.to_str()?))
+            })
+            .collect()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn list(&self, _dir: &str) -> Vec<String> {
+        vec![]
+    }
+}
+
+/// Files embedded in the binary, usually with `include_bytes!`
+#[derive(Default, Clone, Debug)]
+pub struct EmbeddedSource {
+    files: HashMap<String, Cow<'static, [u8]>>,
+}
+
+impl EmbeddedSource {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds a file
+    pub fn with_file(mut self, path: &str, bytes: &'static [u8]) -> Self {
+        self.files.insert(path.to_string(), Cow::Borrowed(bytes));
+        self
+    }
+
+    /// Adds a file created at runtime
+    pub fn with_owned_file(mut self, path: &str, bytes: Vec<u8>) -> Self {
+        self.files.insert(path.to_string(), Cow::Owned(bytes));
+        self
+    }
+}
+
+impl AssetSource for EmbeddedSource {
+    fn read(&self, path: &str) -> AssetSourceFuture {
+        let res = self
+            .files
+            .get(path)
+            .map(|bytes| bytes.to_vec())
+            .ok_or_else(|| format!("File '{path}' not found in the embedded files"));
+        Box::pin(future::ready(res))
+    }
+
+    fn exists(&self, path: &str) -> bool {
+        self.files.contains_key(path)
+    }
+
+    fn list(&self, dir: &str) -> Vec<String> {
+        list_keys(self.files.keys(), dir)
+    }
+}
+
+/// The sources added to the assets by path prefix
+pub(crate) struct AssetSources {
+    // sorted from the longest prefix to the shortest one
+    sources: Vec<(String, Box<dyn AssetSource>)>,
+}
+
+impl Default for AssetSources {
+    fn default() -> Self {
+        Self {
+            sources: vec![(String::new(), Box::new(FileSource::default()))],
+        }
+    }
+}
+
+impl AssetSources {
+    pub fn add(&mut self, prefix: &str, source: Box<dyn AssetSource>) {
+        self.sources.retain(|(p, _)| p != prefix);
+        self.sources.push((prefix.to_string(), source));
+        self.sources
+            .sort_by_key(|(prefix, _)| std::cmp::Reverse(prefix.len()));
+    }
+
+    /// Returns the source that contains the id and the path inside of it
+    fn resolve<'a>(&self, id: &'a str) -> Option<(&dyn AssetSource, &'a str)> {
+        self.sources.iter().find_map(|(prefix, source)| {
+            id.strip_prefix(prefix.as_str())
+                .map(|path| (source.as_ref(), path))
+        })
+    }
+
+    pub fn read(&self, id: &str, progress: &LoadProgress) -> AssetSourceFuture {
+        match self.resolve(id) {
+            Some((source, path)) => source.read_with_progress(path, progress),
+            None => {
+                let err = format!("There is no source for the asset '{id}'");
+                Box::pin(future::ready(Err(err)))
+            }
+        }
+    }
+
+    pub fn read_stream(&self, id: &str, progress: &LoadProgress) -> AssetSourceStream {
+        match self.resolve(id) {
+            Some((source, path)) => source.read_stream(path, progress),
+            None => {
+                let err = format!("There is no source for the asset '{id}'");
+                Box::pin(stream::once(future::ready(Err(err))))
+            }
+        }
+    }
+
+    pub fn exists(&self, id: &str) -> bool {
+        self.resolve(id)
+            .is_some_and(|(source, path)| source.exists(path))
+    }
+
+    pub fn list(&self, dir: &str) -> Vec<String> {
+        let Some((prefix, source)) = self
+            .sources
+            .iter()
+            .find(|(prefix, _)| dir.starts_with(prefix.as_str()))
+        else {
+            return vec![];
+        };
+
+        source
+            .list(&dir[prefix.len()..])
+            .into_iter()
+            .map(|path| format!("{prefix}{path}"))
+            .collect()
+    }
+}
+
+/// Paths of the keys that are directly inside of the directory
+fn list_keys<'a>(keys: impl Iterator<Item = &'a String>, dir: &str) -> Vec<String> {
+    let dir = dir.trim_end_matches('/');
+    keys.filter(|key| {
+        let parent = key.rsplit_once('/').map_or("", |(parent, _)| parent);
+        parent == dir
+    })
+    .cloned()
+    .collect()
+}
+
+fn join_path(dir: &str, name: &str) -> String {
+    if dir.is_empty() || dir.ends_with('/') {
+        format!("{dir}{name}")
+    } else {
+        format!("{dir}/{name}")
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn stream_file_in_chunks() {
+        let dir = std::env::temp_dir();
+        let name = "notan_stream_file_in_chunks.bin";
+        let data = (0..STREAM_CHUNK_SIZE * 2 + 10)
+            .map(|i| i as u8)
+            .collect::<Vec<_>>();
+        std::fs::write(dir.join(name), &data).unwrap();
+
+        let source = FileSource::new(dir.to_str().unwrap());
+        let progress = LoadProgress::default();
+        let chunks = futures::executor::block_on(
+            source.read_stream(name, &progress).try_collect::<Vec<_>>(),
+        )
+        .unwrap();
+        let _ = std::fs::remove_file(dir.join(name));
+
+        let sizes = chunks.iter().map(|c| c.len()).collect::<Vec<_>>();
+        assert_eq!(sizes, vec![STREAM_CHUNK_SIZE, STREAM_CHUNK_SIZE, 10]);
+        assert_eq!(chunks.concat(), data);
+        assert_eq!(progress.loaded(), progress.total().unwrap());
+    }
+}
@@ -0,0 +1,105 @@
+use super::{AssetSource, AssetSourceFuture, AssetSourceStream, LoadProgress, STREAM_CHUNK_SIZE};
+use futures::channel::{mpsc, oneshot};
+use futures::prelude::*;
+use std::io::Read;
+
+/// Downloads the files from a server, the requests are done on their own threads
+#[derive(Clone, Debug)]
+pub struct HttpSource {
+    base_url: String,
+}
+
+impl HttpSource {
+    /// The paths are appended to the url given
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+}
+
+impl AssetSource for HttpSource {
+    fn read(&self, path: &str) -> AssetSourceFuture {
+        self.read_with_progress(path, &LoadProgress::default())
+    }
+
+    fn read_with_progress(&self, path: &str, progress: &LoadProgress) -> AssetSourceFuture {
+        let url = format!("{}/{}", self.base_url, path);
+        let progress = progress.clone();
+        let (tx, rx) = oneshot::channel();
+        std::thread::spawn(move || {
+            let mut bytes = vec![];
+            let res = download(&url, &progress, |chunk| {
+                bytes.extend_from_slice(chunk);
+                true
+            });
+            let _ = tx.send(res.map(|_| bytes));
+        });
+
+        Box::pin(rx.map(|res| res.unwrap_or_else(|e| Err(e.to_string()))))
+    }
+
+    fn read_stream(&self, path: &str, progress: &LoadProgress) -> AssetSourceStream {
+        let url = format!("{}/{}", self.base_url, path);
+        let progress = progress.clone();
+        let (tx, rx) = mpsc::unbounded();
+        std::thread::spawn(move || {
+            // the download stops once the stream is dropped
+            let res = download(&url, &progress, |chunk| {
+                tx.unbounded_send(Ok(chunk.to_vec())).is_ok()
+            });
+            if let Err(e) = res {
+                let _ = tx.unbounded_send(Err(e));
+            }
+        });
+
+        Box::pin(rx)
+    }
+
+    // it cannot be known without a request
+    fn exists(&self, _path: &str) -> bool {
+        true
+    }
+
+    fn list(&self, _dir: &str) -> Vec<String> {
+        vec![]
+    }
+}
+
+/// Downloads the file giving the chunks to the function until it returns false
+fn download<F>(url: &str, progress: &LoadProgress, mut on_chunk: F) -> Result<(), String>
+where
+    F: FnMut(&[u8]) -> bool,
+{
+    let response = ureq::get(url).call().map_err(|e| {
+        if let ureq::Error::Status(status, _) = &e {
+            progress.set_status(*status);
+        }
+        format!("{url}: {e}")
+    })?;
+    progress.set_status(response.status());
+    let total = response
+        .header("Content-Length")
+        .and_then(|len| len.parse::<u64>().ok());
+    if let Some(total) = total {
+        progress.set_total(total);
+    }
+
+    let mut reader = response.into_reader();
+    let mut chunk = vec![0; STREAM_CHUNK_SIZE];
+    loop {
+        let read = match reader.read(&mut chunk) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        progress.add_loaded(read as _);
+        if !on_chunk(&chunk[..read]) {
+            break;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use super::{AssetSource, AssetSourceFuture};
+use futures::prelude::*;
+
+/// Downloads the files from a server
+#[derive(Clone, Debug)]
+pub struct HttpSource {
+    base_url: String,
+}
+
+impl HttpSource {
+    /// The paths are appended to the url given
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+}
+
+impl AssetSource for HttpSource {
+    fn read(&self, path: &str) -> AssetSourceFuture {
+        let url = format!("{}/{}", self.base_url, path);
+        Box::pin(platter2::load_file(url).map_err(|e| e.to_string()))
+    }
+
+    // it cannot be known without a request
+    fn exists(&self, _path: &str) -> bool {
+        true
+    }
+
+    fn list(&self, _dir: &str) -> Vec<String> {
+        vec![]
+    }
+}
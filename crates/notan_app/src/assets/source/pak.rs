@@ -0,0 +1,148 @@
+use super::{list_keys, AssetSource, AssetSourceFuture};
+use futures::future;
+use hashbrown::HashMap;
+use std::ops::Range;
+
+const MAGIC: &[u8; 4] = b"NPAK";
+const VERSION: u32 = 1;
+
+/// Files packed in one blob, created with [PakSource::pack].
+///
+/// The layout is the magic `NPAK`, the version and the number of files as `u32`,
+/// and for each file the length of its path as `u32`, the path, and its offset
+/// and length as `u64`, followed by the data. The numbers are little endian
+#[derive(Clone, Debug)]
+pub struct PakSource {
+    data: Vec<u8>,
+    files: HashMap<String, Range<usize>>,
+}
+
+impl PakSource {
+    /// Reads the table of files of the pak
+    pub fn from_bytes(data: Vec<u8>) -> Result<Self, String> {
+        let mut reader = Reader {
+            data: &data,
+            pos: 0,
+        };
+        if reader.bytes(4)? != MAGIC {
+            return Err("Invalid pak file".to_string());
+        }
+
+        let version = reader.u32()?;
+        if version != VERSION {
+            return Err(format!("Unsupported pak version {version}"));
+        }
+
+        let count = reader.u32()? as usize;
+        let mut files = HashMap::with_capacity(count);
+        for _ in 0..count {
+            let len = reader.u32()? as usize;
+            let path = std::str::from_utf8(reader.bytes(len)?).map_err(|e| e.to_string())?;
+            let offset = reader.u64()? as usize;
+            let len = reader.u64()? as usize;
+            let end = offset.saturating_add(len);
+            if end > data.len() {
+                return Err(format!("The file '{path}' is outside of the pak"));
+            }
+
+            files.insert(path.to_string(), offset..end);
+        }
+
+        Ok(Self { data, files })
+    }
+
+    /// Reads a pak from the filesystem
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn open(path: &str) -> Result<Self, String> {
+        let data = std::fs::read(path).map_err(|e| e.to_string())?;
+        Self::from_bytes(data)
+    }
+
+    /// Packs the files to be read by a [PakSource]
+    pub fn pack(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let header_len = 12 + files.iter().map(|(p, _)| 20 + p.len()).sum::<usize>();
+        let data_len = files.iter().map(|(_, b)| b.len()).sum::<usize>();
+
+        let mut pak = Vec::with_capacity(header_len + data_len);
+        pak.extend_from_slice(MAGIC);
+        pak.extend_from_slice(&VERSION.to_le_bytes());
+        pak.extend_from_slice(&(files.len() as u32).to_le_bytes());
+
+        let mut offset = header_len;
+        for (path, bytes) in files {
+            pak.extend_from_slice(&(path.len() as u32).to_le_bytes());
+            pak.extend_from_slice(path.as_bytes());
+            pak.extend_from_slice(&(offset as u64).to_le_bytes());
+            pak.extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+            offset += bytes.len();
+        }
+
+        files
+            .iter()
+            .for_each(|(_, bytes)| pak.extend_from_slice(bytes));
+        pak
+    }
+}
+
+impl AssetSource for PakSource {
+    fn read(&self, path: &str) -> AssetSourceFuture {
+        let res = self
+            .files
+            .get(path)
+            .map(|range| self.data[range.clone()].to_vec())
+            .ok_or_else(|| format!("File '{path}' not found in the pak"));
+        Box::pin(future::ready(res))
+    }
+
+    fn exists(&self, path: &str) -> bool {
+        self.files.contains_key(path)
+    }
+
+    fn list(&self, dir: &str) -> Vec<String> {
+        list_keys(self.files.keys(), dir)
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let bytes = self
+            .data
+            .get(self.pos..self.pos + len)
+            .ok_or_else(|| "Unexpected end of the pak file".to_string())?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        let bytes = self.bytes(4)?;
+        Ok(u32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, String> {
+        let bytes = self.bytes(8)?;
+        Ok(u64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn read_packed_files() {
+        let data = PakSource::pack(&[("a.txt", b"hello"), ("dir/b.txt", b"world")]);
+        let pak = PakSource::from_bytes(data).unwrap();
+
+        assert!(pak.exists("dir/b.txt"));
+        assert!(!pak.exists("b.txt"));
+        assert_eq!(pak.list("dir"), vec!["dir/b.txt".to_string()]);
+
+        let bytes = futures::executor::block_on(pak.read("a.txt")).unwrap();
+        assert_eq!(bytes, b"hello");
+    }
+}
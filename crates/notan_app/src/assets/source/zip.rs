@@ -0,0 +1,57 @@
+use super::{list_keys, AssetSource, AssetSourceFuture};
+use futures::future;
+use std::cell::RefCell;
+use std::io::{Cursor, Read};
+use zip::ZipArchive;
+
+/// Reads the files from a zip archive
+pub struct ZipSource {
+    // reading a file needs mutable access to the archive
+    archive: RefCell<ZipArchive<Cursor<Vec<u8>>>>,
+    files: Vec<String>,
+}
+
+impl ZipSource {
+    pub fn from_bytes(data: Vec<u8>) -> Result<Self, String> {
+        let archive = ZipArchive::new(Cursor::new(data)).map_err(|e| e.to_string())?;
+        let files = archive
+            .file_names()
+            .filter(|name| !name.ends_with('/'))
+            .map(|name| name.to_string())
+            .collect();
+
+        Ok(Self {
+            archive: RefCell::new(archive),
+            files,
+        })
+    }
+
+    /// Reads a zip archive from the filesystem
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn open(path: &str) -> Result<Self, String> {
+        let data = std::fs::read(path).map_err(|e| e.to_string())?;
+        Self::from_bytes(data)
+    }
+
+    fn read_file(&self, path: &str) -> Result<Vec<u8>, String> {
+        let mut archive = self.archive.borrow_mut();
+        let mut file = archive.by_name(path).map_err(|e| format!("{path}: {e}"))?;
+        let mut bytes = Vec::with_capacity(file.size() as usize);
+        file.read_to_end(&mut bytes).map_err(|e| e.to_string())?;
+        Ok(bytes)
+    }
+}
+
+impl AssetSource for ZipSource {
+    fn read(&self, path: &str) -> AssetSourceFuture {
+        Box::pin(future::ready(self.read_file(path)))
+    }
+
+    fn exists(&self, path: &str) -> bool {
+        self.files.iter().any(|name| name == path)
+    }
+
+    fn list(&self, dir: &str) -> Vec<String> {
+        list_keys(self.files.iter(), dir)
+    }
+}
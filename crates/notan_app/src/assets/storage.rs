@@ -1,36 +1,77 @@
 use super::asset::Asset;
-use super::utils::{AssetLoadTracker, DoneSignal, LoadWrapper};
-use futures::prelude::*;
+use super::dependencies::Dependencies;
+use super::fallback::Fallbacks;
+use super::loader::StreamChunk;
+use super::progress::LoadProgress;
+use super::queue::Priority;
+use super::utils::{AssetLoadTracker, DoneSignal, FileReader, LoadWrapper};
+use futures::task::Poll;
 use hashbrown::HashMap;
+use notan_utils::Instant;
 use std::any::TypeId;
 
+#[cfg(all(feature = "hot_reload", not(target_arch = "wasm32")))]
+use {
+    super::source::AssetSourceFuture,
+    std::{any::Any, sync::Arc},
+};
+
 #[cfg(all(target_arch = "wasm32", feature = "drop_files"))]
 use crate::DroppedFile;
+#[cfg(all(target_arch = "wasm32", feature = "drop_files"))]
+use futures::prelude::*;
+
+/// Id and bytes of a file read
+type FileRead = (String, Result<Vec<u8>, String>);
 
 /// Store the assets while they are loading
 #[derive(Default)]
 pub struct AssetStorage {
     to_load: HashMap<String, LoadWrapper>,
+    dependencies: HashMap<String, Dependencies>,
     pub(crate) tracker: AssetLoadTracker,
 }
 
 impl AssetStorage {
-    pub(crate) fn register(&mut self, id: &str, type_id: TypeId) -> DoneSignal {
-        #[allow(clippy::unnecessary_to_owned)]
-        let fut = Box::pin(platter2::load_file(id.to_string()).map_err(|e| e.to_string()));
-        let state = LoadWrapper::new(id, fut, type_id);
+    pub(crate) fn register(
+        &mut self,
+        id: &str,
+        reader: impl Into<FileReader>,
+        type_id: Option<TypeId>,
+        progress: LoadProgress,
+    ) -> DoneSignal {
+        let state = LoadWrapper::new(reader.into(), type_id, progress);
         let loaded = state.loaded.clone();
         log::info!("to load -> {} {:?}", id, state.type_id);
         self.to_load.insert(id.to_string(), state);
         loaded
     }
 
+    pub(crate) fn is_loading(&self, id: &str) -> bool {
+        self.to_load.contains_key(id)
+    }
+
+    /// Loads again the file of an asset to parse it into the same asset
+    #[cfg(all(feature = "hot_reload", not(target_arch = "wasm32")))]
+    pub(crate) fn reload(
+        &mut self,
+        id: &str,
+        fut: AssetSourceFuture,
+        type_id: TypeId,
+        progress: LoadProgress,
+        asset: Arc<dyn Any + Send + Sync>,
+    ) -> DoneSignal {
+        let loaded = self.register(id, fut, Some(type_id), progress);
+        self.tracker.insert_existing(id, loaded.clone(), asset);
+        loaded
+    }
+
     #[cfg(all(target_arch = "wasm32", feature = "drop_files"))]
     pub(crate) fn register_wasm_dropped_file(
         &mut self,
         id: &str,
         file: &DroppedFile,
-        type_id: TypeId,
+        type_id: Option<TypeId>,
     ) -> Result<DoneSignal, String> {
         let f = file
             .file
@@ -43,13 +84,22 @@ impl AssetStorage {
             }),
         );
 
-        let state = LoadWrapper::new(id, fut, type_id);
+        let state = LoadWrapper::new(FileReader::Future(fut), type_id, LoadProgress::default());
         let loaded = state.loaded.clone();
         log::info!("to load -> {} {:?}", id, state.type_id);
         self.to_load.insert(id.to_string(), state);
         Ok(loaded)
     }
 
+    pub(crate) fn set_dependencies(&mut self, id: &str, dependencies: Dependencies) {
+        self.dependencies.insert(id.to_string(), dependencies);
+    }
+
+    /// Returns the dependencies loaded for the asset
+    pub(crate) fn take_dependencies(&mut self, id: &str) -> Dependencies {
+        self.dependencies.remove(id).unwrap_or_default()
+    }
+
     /// Parse an asset with the loaded one
     pub fn parse<A>(&mut self, id: &str, asset: A) -> Result<(), String>
     where
@@ -66,13 +116,45 @@ impl AssetStorage {
             })
     }
 
+    /// Parses a chunk of a streamed asset, it can be used once the parser creates it,
+    /// but it's not loaded until the end of the stream
+    pub(crate) fn parse_chunk<A, F>(
+        &mut self,
+        id: &str,
+        chunk: StreamChunk,
+        restart: bool,
+        parse: F,
+    ) -> Result<(), String>
+    where
+        A: Send + Sync + 'static,
+        F: FnOnce(&mut Option<A>) -> Result<(), String>,
+    {
+        let mut stored_asset = self.get::<A>(id, false)?;
+        let mut inner = stored_asset.inner.write();
+        if restart {
+            *inner = None;
+        }
+
+        parse(&mut inner)?;
+        if chunk == StreamChunk::End {
+            if inner.is_none() {
+                return Err(format!("The stream of '{id}' ended without an asset"));
+            }
+
+            drop(inner);
+            stored_asset.loaded.done();
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn get<A>(&self, id: &str, claim: bool) -> Result<Asset<A>, String>
     where
         A: Send + Sync + 'static,
     {
         match self.to_load.get(id) {
             Some(state) => {
-                if TypeId::of::<A>() == state.type_id {
+                if state.type_id.is_none_or(|ty| ty == TypeId::of::<A>()) {
                     let loaded = state.loaded.clone();
                     let asset = if claim {
                         self.tracker.claim_asset(id, loaded.clone())
@@ -83,6 +165,7 @@ impl AssetStorage {
                     asset.map(|inner| Asset {
                         id: id.to_string(),
                         loaded,
+                        progress: state.progress.clone(),
                         inner,
                     })
                 } else {
@@ -93,8 +176,125 @@ impl AssetStorage {
         }
     }
 
+    /// Stops loading the asset, the handles given will not be loaded unless they have a fallback
+    pub(crate) fn cancel(&mut self, id: &str) -> bool {
+        let Some(state) = self.to_load.remove(id) else {
+            return false;
+        };
+
+        // the lists take the fallback from the tracker
+        if !state.is_loaded() {
+            self.tracker.remove(id);
+        }
+        self.dependencies.remove(id);
+        true
+    }
+
+    /// Gives the fallback of its type to the asset that failed, returns false if the type has not one
+    pub(crate) fn set_fallback(&mut self, id: &str, fallbacks: &Fallbacks) -> bool {
+        let Some(state) = self.to_load.get_mut(id) else {
+            return false;
+        };
+
+        // the type is unknown if the loader is selected by the content of the file
+        let Some(type_id) = state.type_id else {
+            return false;
+        };
+
+        if !fallbacks.apply(type_id, &self.tracker, id, state.loaded.clone()) {
+            return false;
+        }
+
+        state.progress.set_fallback();
+        state.loaded.done();
+        true
+    }
+
+    pub(crate) fn progress(&self, id: &str) -> Option<LoadProgress> {
+        self.to_load.get(id).map(|state| state.progress.clone())
+    }
+
+    pub(crate) fn set_priority(&mut self, id: &str, priority: Priority) {
+        if let Some(state) = self.to_load.get_mut(id) {
+            state.priority = priority;
+        }
+    }
+
+    pub(crate) fn priority(&self, id: &str) -> Priority {
+        self.to_load
+            .get(id)
+            .map_or(Priority::default(), |state| state.priority)
+    }
+
+    /// Returns how many times the file was read again
+    pub(crate) fn retries(&self, id: &str) -> u32 {
+        self.to_load.get(id).map_or(0, |state| state.retries)
+    }
+
+    /// Reads the file again once the time given is reached
+    pub(crate) fn retry_at(&mut self, id: &str, at: Instant) {
+        if let Some(state) = self.to_load.get_mut(id) {
+            state.retries += 1;
+            state.retry_at = Some(at);
+        }
+    }
+
+    /// Returns the files that must be read again
+    pub(crate) fn due_retries(&self, now: Instant) -> Vec<String> {
+        self.to_load
+            .iter()
+            .filter(|(_, state)| state.retry_at.is_some_and(|at| at <= now))
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
+
+    pub(crate) fn restart(&mut self, id: &str, reader: impl Into<FileReader>) {
+        if let Some(state) = self.to_load.get_mut(id) {
+            state.restart(reader.into());
+        }
+    }
+
+    /// Returns true if the file is read in chunks for a stream parser
+    pub(crate) fn is_stream(&self, id: &str) -> bool {
+        self.to_load.get(id).is_some_and(|state| state.is_stream())
+    }
+
+    /// Returns true if the stream parser already received chunks of the file
+    pub(crate) fn is_streaming(&self, id: &str) -> bool {
+        self.to_load
+            .get(id)
+            .is_some_and(|state| state.is_streaming())
+    }
+
+    /// Returns the ids of the files streamed, from the highest priority
+    pub(crate) fn streams(&self) -> Vec<String> {
+        let mut streams = self
+            .to_load
+            .iter()
+            .filter(|(_, state)| state.is_stream())
+            .map(|(id, state)| (state.priority, id.clone()))
+            .collect::<Vec<_>>();
+        streams.sort_by_key(|(priority, _)| std::cmp::Reverse(*priority));
+        streams.into_iter().map(|(_, id)| id).collect()
+    }
+
+    /// Returns the next chunk of the streamed file if it's ready
+    pub(crate) fn poll_chunk(&mut self, id: &str) -> Poll<Option<Result<Vec<u8>, String>>> {
+        match self.to_load.get_mut(id) {
+            Some(state) => state.poll_chunk(),
+            None => Poll::Pending,
+        }
+    }
+
+    /// Returns the bytes read of the file
+    pub(crate) fn bytes(&self, id: &str) -> usize {
+        self.to_load
+            .get(id)
+            .map_or(0, |state| state.progress.loaded() as _)
+    }
+
     #[inline]
-    pub(crate) fn try_load(&mut self) -> Option<Vec<(String, Vec<u8>)>> {
+    pub(crate) fn try_load(&mut self) -> Option<Vec<FileRead>> {
         if self.to_load.is_empty() {
             return None;
         }
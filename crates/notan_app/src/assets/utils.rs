@@ -1,12 +1,16 @@
+use super::progress::LoadProgress;
+use super::queue::Priority;
+use super::source::{AssetSourceFuture, AssetSourceStream};
 use super::waker::*;
-use futures::future::LocalBoxFuture;
 use futures::task::{Context, Poll};
 use hashbrown::HashMap;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use std::any::{Any, TypeId};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
+use notan_utils::Instant;
+
 #[derive(Clone, Debug)]
 pub(crate) struct DoneSignal(pub Arc<AtomicBool>);
 impl DoneSignal {
@@ -31,42 +35,124 @@ impl DoneSignal {
     }
 }
 
+/// How the file is read, at once or in chunks for the stream parsers
+pub(crate) enum FileReader {
+    Future(AssetSourceFuture),
+    Stream(AssetSourceStream),
+}
+
+impl From<AssetSourceFuture> for FileReader {
+    fn from(fut: AssetSourceFuture) -> Self {
+        FileReader::Future(fut)
+    }
+}
+
+impl From<AssetSourceStream> for FileReader {
+    fn from(stream: AssetSourceStream) -> Self {
+        FileReader::Stream(stream)
+    }
+}
+
 pub(crate) struct LoadWrapper {
-    fut: LocalBoxFuture<'static, Result<Vec<u8>, String>>,
+    reader: FileReader,
+    // the reader cannot be polled again once it's done
+    fetched: bool,
+    // bytes given to the stream parser
+    streamed: Option<usize>,
     pub loaded: DoneSignal,
-    pub type_id: TypeId,
-    id: String,
+    pub progress: LoadProgress,
+    // unknown until the file is read if the loader is selected by its content
+    pub type_id: Option<TypeId>,
+    // failed reads retried
+    pub retries: u32,
+    pub retry_at: Option<Instant>,
+    pub priority: Priority,
 }
 
 impl LoadWrapper {
-    pub fn new(
-        id: &str,
-        fut: LocalBoxFuture<'static, Result<Vec<u8>, String>>,
-        type_id: TypeId,
-    ) -> Self {
+    pub fn new(reader: FileReader, type_id: Option<TypeId>, progress: LoadProgress) -> Self {
         Self {
-            id: id.to_string(),
-            fut,
+            reader,
+            fetched: false,
+            streamed: None,
             loaded: DoneSignal::new(),
+            progress,
             type_id,
+            retries: 0,
+            retry_at: None,
+            priority: Priority::default(),
         }
     }
 
-    pub fn try_load(&mut self) -> Option<Vec<u8>> {
+    /// Reads again the file with the new reader
+    pub fn restart(&mut self, reader: FileReader) {
+        self.reader = reader;
+        self.fetched = false;
+        self.streamed = None;
+        self.retry_at = None;
+        self.progress.reset();
+    }
+
+    pub fn try_load(&mut self) -> Option<Result<Vec<u8>, String>> {
+        let FileReader::Future(fut) = &mut self.reader else {
+            return None;
+        };
+
+        if self.fetched {
+            return None;
+        }
+
         let waker = DummyWaker.into_task_waker();
         let mut ctx = Context::from_waker(&waker);
-        match self.fut.as_mut().poll(&mut ctx) {
-            Poll::Ready(r_buff) => match r_buff {
-                Ok(buff) => Some(buff),
-                Err(err) => {
-                    log::error!("File: {} -> {}", self.id, err);
-                    None
+        match fut.as_mut().poll(&mut ctx) {
+            Poll::Ready(r_buff) => {
+                self.fetched = true;
+                if let Ok(buff) = &r_buff {
+                    self.progress.finish(buff.len());
                 }
-            },
+                Some(r_buff)
+            }
             _ => None,
         }
     }
 
+    /// Returns the next chunk of the file if it's streamed and the chunk is ready,
+    /// `Ready(None)` once the stream ends
+    pub fn poll_chunk(&mut self) -> Poll<Option<Result<Vec<u8>, String>>> {
+        let FileReader::Stream(stream) = &mut self.reader else {
+            return Poll::Pending;
+        };
+
+        if self.fetched {
+            return Poll::Pending;
+        }
+
+        let waker = DummyWaker.into_task_waker();
+        let mut ctx = Context::from_waker(&waker);
+        let res = stream.as_mut().poll_next(&mut ctx);
+        match &res {
+            Poll::Ready(Some(Ok(chunk))) => {
+                *self.streamed.get_or_insert(0) += chunk.len();
+            }
+            Poll::Ready(Some(Err(_))) => self.fetched = true,
+            Poll::Ready(None) => {
+                self.fetched = true;
+                self.progress.finish(self.streamed.unwrap_or(0));
+            }
+            Poll::Pending => {}
+        }
+        res
+    }
+
+    pub fn is_stream(&self) -> bool {
+        matches!(self.reader, FileReader::Stream(_))
+    }
+
+    /// Returns true if the stream parser already received chunks of the file
+    pub fn is_streaming(&self) -> bool {
+        self.streamed.is_some()
+    }
+
     #[inline(always)]
     pub fn is_loaded(&self) -> bool {
         self.loaded.is_done()
@@ -99,6 +185,8 @@ impl ClaimTracker {
 #[derive(Default, Clone)]
 pub(crate) struct AssetLoadTracker {
     assets: Arc<RwLock<HashMap<String, ClaimTracker>>>,
+    // ids cancelled by the lists, the assets cancel them on the next tick
+    cancelled: Arc<Mutex<Vec<String>>>,
 }
 
 impl AssetLoadTracker {
@@ -160,6 +248,46 @@ impl AssetLoadTracker {
             .map_err(|_| "Invalid asset type".to_string())
     }
 
+    /// Returns the inner asset while it's tracked
+    pub fn asset(&self, id: &str) -> Option<Arc<dyn Any + Send + Sync>> {
+        self.assets
+            .read()
+            .get(id)
+            .map(|tracker| tracker.tracker.asset.clone())
+    }
+
+    /// Tracks an asset that already exists, so the loader writes the new data into it
+    #[cfg(all(feature = "hot_reload", not(target_arch = "wasm32")))]
+    pub fn insert_existing(&self, id: &str, loaded: DoneSignal, asset: Arc<dyn Any + Send + Sync>) {
+        let tracker = LoadTracker { loaded, asset };
+        self.assets.write().insert(
+            id.to_string(),
+            ClaimTracker {
+                tracker,
+                claim: true,
+            },
+        );
+    }
+
+    /// Marks the asset as claimed, so it stops being tracked once it's loaded
+    pub fn claim(&self, id: &str) {
+        if let Some(tracker) = self.assets.write().get_mut(id) {
+            tracker.claim = true;
+        }
+    }
+
+    pub fn remove(&self, id: &str) {
+        self.assets.write().remove(id);
+    }
+
+    pub fn request_cancel(&self, id: &str) {
+        self.cancelled.lock().push(id.to_string());
+    }
+
+    pub fn take_cancelled(&self) -> Vec<String> {
+        std::mem::take(&mut *self.cancelled.lock())
+    }
+
     #[inline]
     pub fn clean(&mut self) {
         self.assets.write().retain(|_, tracker| !tracker.is_ready());
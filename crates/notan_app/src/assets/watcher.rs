@@ -0,0 +1,99 @@
+use hashbrown::{HashMap, HashSet};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::any::{Any, TypeId};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, Receiver};
+use std::sync::{Arc, Weak};
+
+/// Asset loaded from a watched file that can be reloaded while someone keeps a handle to it
+struct WatchedAsset {
+    id: String,
+    type_id: TypeId,
+    asset: Weak<dyn Any + Send + Sync>,
+}
+
+/// Watches the files of the loaded assets to reload them when they change
+pub(crate) struct AssetWatcher {
+    watcher: RecommendedWatcher,
+    rx: Receiver<notify::Result<Event>>,
+    dirs: HashSet<PathBuf>,
+    files: HashMap<PathBuf, WatchedAsset>,
+}
+
+impl AssetWatcher {
+    pub fn new() -> Result<Self, String> {
+        let (tx, rx) = channel();
+        let watcher = notify::recommended_watcher(move |evt| {
+            let _ = tx.send(evt);
+        })
+        .map_err(|e| e.to_string())?;
+
+        Ok(Self {
+            watcher,
+            rx,
+            dirs: HashSet::new(),
+            files: HashMap::new(),
+        })
+    }
+
+    /// Starts watching the file of the asset, ids that are not local files are ignored
+    pub fn watch(&mut self, id: &str, type_id: TypeId, asset: &Arc<dyn Any + Send + Sync>) {
+        let Ok(path) = Path::new(id).canonicalize() else {
+            return;
+        };
+
+        // the directory is watched because some editors replace the file when it's saved
+        if let Some(dir) = path.parent() {
+            if !self.dirs.contains(dir) {
+                match self.watcher.watch(dir, RecursiveMode::NonRecursive) {
+                    Ok(_) => {
+                        self.dirs.insert(dir.to_path_buf());
+                    }
+                    Err(e) => {
+                        log::warn!("Cannot watch '{}' to reload '{}': {}", dir.display(), id, e);
+                        return;
+                    }
+                }
+            }
+        }
+
+        self.files.insert(
+            path,
+            WatchedAsset {
+                id: id.to_string(),
+                type_id,
+                asset: Arc::downgrade(asset),
+            },
+        );
+    }
+
+    /// Returns the assets changed since the last call that still have a handle alive
+    pub fn changed(&mut self) -> Vec<(String, TypeId, Arc<dyn Any + Send + Sync>)> {
+        // a save usually triggers more than one event
+        let mut paths = HashSet::new();
+        while let Ok(evt) = self.rx.try_recv() {
+            match evt {
+                Ok(evt) => {
+                    if matches!(evt.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+                        let paths_iter = evt.paths.into_iter();
+                        paths.extend(paths_iter.map(|p| p.canonicalize().unwrap_or(p)));
+                    }
+                }
+                Err(e) => log::error!("Asset watcher: {}", e),
+            }
+        }
+
+        // nobody uses the assets dropped, so they don't need to be reloaded
+        self.files
+            .retain(|_, watched| watched.asset.strong_count() > 0);
+
+        paths
+            .iter()
+            .filter_map(|path| {
+                let watched = self.files.get(path)?;
+                let asset = watched.asset.upgrade()?;
+                Some((watched.id.clone(), watched.type_id, asset))
+            })
+            .collect()
+    }
+}
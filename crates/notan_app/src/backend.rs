@@ -146,6 +146,11 @@ pub trait WindowBackend {
     /// Returns true if the window has focus
     fn is_focused(&self) -> bool;
 
+    /// Returns true if the window is minimized
+    fn is_minimized(&self) -> bool {
+        false
+    }
+
     /// Returns true if the lazy mode is enabled
     fn lazy_loop(&self) -> bool;
 
@@ -1,6 +1,6 @@
 #![allow(clippy::type_complexity)]
 
-use crate::assets::{AssetLoader, Assets};
+use crate::assets::{AssetLoader, AssetSource, Assets, Preprocessor};
 use crate::config::*;
 use crate::graphics::Graphics;
 use crate::handlers::{
@@ -9,7 +9,9 @@ use crate::handlers::{
 };
 use crate::parsers::*;
 use crate::plugins::*;
-use crate::{App, Backend, BackendSystem, FrameState, GfxExtension, GfxRenderer};
+use crate::{
+    App, Backend, BackendSystem, FrameLimiter, FrameState, FrameWait, GfxExtension, GfxRenderer,
+};
 use indexmap::IndexMap;
 #[cfg(feature = "audio")]
 use notan_audio::Audio;
@@ -19,6 +21,7 @@ use notan_input::internals::{
     clear_keyboard, clear_mouse, process_keyboard_events, process_mouse_events,
     process_touch_events,
 };
+use notan_utils::Duration;
 
 pub use crate::handlers::SetupHandler;
 
@@ -55,6 +58,8 @@ pub struct AppBuilder<S, B> {
     late_config: Option<IndexMap<std::any::TypeId, Box<dyn BuildConfig<S, B>>>>,
 
     use_touch_as_mouse: bool,
+    fixed_timestep: Option<Duration>,
+    limiter: FrameLimiter,
 
     pub(crate) window: WindowConfig,
 }
@@ -83,6 +88,8 @@ where
             window: Default::default(),
             late_config: Some(Default::default()),
             use_touch_as_mouse: true,
+            fixed_timestep: None,
+            limiter: Default::default(),
         };
 
         builder.default_loaders()
@@ -108,6 +115,32 @@ where
         self
     }
 
+    /// Runs the update callback at a fixed rate, like `Duration::from_secs_f64(1.0 / 60.0)`,
+    /// independently of the frame rate. The draw callback can use `app.timer.alpha()`
+    /// to interpolate between the last two updates
+    pub fn fixed_timestep(mut self, timestep: Duration) -> Self {
+        self.fixed_timestep = Some(timestep);
+        self
+    }
+
+    /// Caps the frame rate to save CPU, like on menu screens that don't need to run at full speed
+    pub fn frame_limit(mut self, fps: f64) -> Self {
+        self.limiter.set_fps(Some(fps));
+        self
+    }
+
+    /// Throttles the frame rate while the window is unfocused or minimized
+    pub fn idle_frame_limit(mut self, fps: f64) -> Self {
+        self.limiter.set_idle_fps(Some(fps));
+        self
+    }
+
+    /// Sets how the loop waits for the next frame when the frame rate is limited
+    pub fn frame_wait(mut self, wait: FrameWait) -> Self {
+        self.limiter.set_wait(wait);
+        self
+    }
+
     /// Applies a configuration
     pub fn add_config<C>(mut self, config: C) -> Self
     where
@@ -205,6 +238,32 @@ where
         self
     }
 
+    /// Adds a loader that deserializes the files with the extension into `T`
+    #[cfg(feature = "serde_loaders")]
+    pub fn add_serde_loader<T>(mut self, ext: &str) -> Self
+    where
+        T: serde::de::DeserializeOwned + Send + Sync + 'static,
+    {
+        self.assets.add_serde_loader::<T>(ext);
+        self
+    }
+
+    /// Adds a function to transform the bytes of the files before their loader parses them
+    pub fn add_preprocessor(mut self, preprocessor: Preprocessor) -> Self {
+        self.assets.add_preprocessor(preprocessor);
+        self
+    }
+
+    /// Reads the assets with an id starting with the prefix from the [AssetSource]
+    pub fn add_asset_source<Src: AssetSource + 'static>(
+        mut self,
+        prefix: &str,
+        source: Src,
+    ) -> Self {
+        self.assets.add_source(prefix, source);
+        self
+    }
+
     /// Creates and run the application
     pub fn build(self) -> Result<(), String> {
         let mut builder = self;
@@ -226,11 +285,14 @@ where
             event_callback,
             mut plugin_callbacks,
             mut extension_callbacks,
-            window,
+            mut window,
             use_touch_as_mouse,
+            fixed_timestep,
+            limiter,
             ..
         } = builder;
 
+        window.validate()?;
         let initialize = backend.initialize(window)?;
 
         let mut graphics = Graphics::new(backend.get_graphics_backend())?;
@@ -244,6 +306,8 @@ where
         let mut app = App::new(Box::new(backend));
 
         app.window().set_touch_as_mouse(use_touch_as_mouse);
+        app.timer.set_fixed_timestep(fixed_timestep);
+        app.limiter = limiter;
 
         let (width, height) = app.window().size();
         let win_dpi = app.window().dpi();
@@ -282,6 +346,13 @@ where
 
         let mut first_loop = true;
         if let Err(e) = initialize(app, state, move |app, mut state| {
+            // wait for the next frame if the frame rate is limited, on web the frame is skipped
+            let idle = app.limiter.has_idle_limit()
+                && (!app.window().is_focused() || app.window().is_minimized());
+            if !app.limiter.next_frame(idle) {
+                return Ok(FrameState::Skip);
+            }
+
             // update system delta time and fps here
             app.system_timer.update();
 
@@ -304,6 +375,9 @@ where
             // update delta time and fps here
             app.timer.update();
 
+            // drop the user events sent two frames ago
+            app.bus.update();
+
             assets.tick((app, &mut graphics, &mut plugins, &mut state))?;
 
             let delta = app.timer.delta_f32();
@@ -312,6 +386,10 @@ where
 
             // Manage each event
             let mut events = app.backend.events_iter();
+            assets
+                .take_events()
+                .into_iter()
+                .for_each(|evt| events.push(evt));
             while let Some(evt) = events.next() {
                 if use_touch_as_mouse {
                     touch_as_mouse(&mut current_touch_id, &mut events, &evt);
@@ -332,16 +410,27 @@ where
                 }
             }
 
-            // Manage update callback
-            match plugins.update(app, &mut assets)? {
-                AppFlow::Skip => {}
-                AppFlow::Next => {
-                    if let Some(cb) = &update_callback {
-                        cb.exec(app, &mut assets, &mut plugins, state);
+            // Manage update callback, with a fixed timestep it can run many times or none
+            let steps = app.timer.fixed_steps();
+            app.timer.begin_fixed_update();
+            for step in 0..steps {
+                // only the first update of the frame sees the keys pressed or released
+                if step > 0 {
+                    clear_mouse(&mut app.mouse);
+                    clear_keyboard(&mut app.keyboard);
+                }
+
+                match plugins.update(app, &mut assets)? {
+                    AppFlow::Skip => {}
+                    AppFlow::Next => {
+                        if let Some(cb) = &update_callback {
+                            cb.exec(app, &mut assets, &mut plugins, state);
+                        }
                     }
+                    AppFlow::SkipFrame => return Ok(FrameState::Skip),
                 }
-                AppFlow::SkipFrame => return Ok(FrameState::Skip),
             }
+            app.timer.end_fixed_update();
 
             // Manage draw callback
             match plugins.draw(app, &mut assets, &mut graphics)? {
@@ -363,8 +452,11 @@ where
                 }
             }
 
-            clear_mouse(&mut app.mouse);
-            clear_keyboard(&mut app.keyboard);
+            // the input is kept for the next frame if the update didn't run
+            if steps > 0 {
+                clear_mouse(&mut app.mouse);
+                clear_keyboard(&mut app.keyboard);
+            }
 
             // Manage post frame event
             let _ = plugins.post_frame(app, &mut assets, &mut graphics)?;
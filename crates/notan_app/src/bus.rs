@@ -0,0 +1,199 @@
+use downcast_rs::{impl_downcast, Downcast};
+use hashbrown::HashMap;
+use std::any::TypeId;
+use std::marker::PhantomData;
+
+/// Queue of user-defined events of the type `T`. The events sent in a frame
+/// can be read until the end of the next frame, then they are dropped
+pub struct Events<T> {
+    previous: Vec<(usize, T)>,
+    current: Vec<(usize, T)>,
+    next_id: usize,
+}
+
+impl<T> Default for Events<T> {
+    fn default() -> Self {
+        Self {
+            previous: vec![],
+            current: vec![],
+            next_id: 0,
+        }
+    }
+}
+
+impl<T> Events<T> {
+    /// Adds an event to the queue
+    pub fn send(&mut self, event: T) {
+        self.current.push((self.next_id, event));
+        self.next_id += 1;
+    }
+
+    /// Returns all the events in the queue, from the oldest to the newest
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.previous
+            .iter()
+            .chain(self.current.iter())
+            .map(|(_, evt)| evt)
+    }
+
+    /// Returns a reader that will read the events sent from now on
+    pub fn reader(&self) -> EventReader<T> {
+        EventReader {
+            next_id: self.next_id,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Number of events in the queue
+    pub fn len(&self) -> usize {
+        self.previous.len() + self.current.len()
+    }
+
+    /// Returns true if the queue is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drops the events of the previous frame
+    fn update(&mut self) {
+        std::mem::swap(&mut self.previous, &mut self.current);
+        self.current.clear();
+    }
+}
+
+/// Keeps which events were already read, to read each event of the type `T` once.
+/// Each system that subscribes to the events needs its own reader
+pub struct EventReader<T> {
+    next_id: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Default for EventReader<T> {
+    fn default() -> Self {
+        Self {
+            next_id: 0,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for EventReader<T> {
+    fn clone(&self) -> Self {
+        Self {
+            next_id: self.next_id,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> EventReader<T> {
+    /// Returns the events sent since the last read, they are marked as read
+    /// even if the iterator is not consumed
+    pub fn read<'a>(&mut self, events: &'a Events<T>) -> impl Iterator<Item = &'a T> {
+        let from = self.next_id;
+        self.next_id = events.next_id;
+        events
+            .previous
+            .iter()
+            .chain(events.current.iter())
+            .filter(move |(id, _)| *id >= from)
+            .map(|(_, evt)| evt)
+    }
+}
+
+// helper trait to update the queues without knowing their type
+trait EventQueue: Downcast {
+    fn update(&mut self);
+}
+impl_downcast!(EventQueue);
+
+impl<T: 'static> EventQueue for Events<T> {
+    fn update(&mut self) {
+        Events::update(self);
+    }
+}
+
+/// Queues of user-defined events by type, used to communicate the update and event
+/// callbacks, the plugins and the asset loaders without storing everything in the state
+#[derive(Default)]
+pub struct EventBus {
+    queues: HashMap<TypeId, Box<dyn EventQueue>>,
+}
+
+impl EventBus {
+    /// Sends an event to the queue of its type
+    pub fn send<T: 'static>(&mut self, event: T) {
+        self.events_mut::<T>().send(event);
+    }
+
+    /// Returns the queue of the type
+    pub fn events<T: 'static>(&self) -> Option<&Events<T>> {
+        self.queues
+            .get(&TypeId::of::<T>())
+            .and_then(|queue| queue.downcast_ref())
+    }
+
+    /// Returns the queue of the type, creating it if needed
+    pub fn events_mut<T: 'static>(&mut self) -> &mut Events<T> {
+        self.queues
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::<Events<T>>::default())
+            .downcast_mut()
+            .unwrap()
+    }
+
+    /// Returns all the events of the type in the queue
+    pub fn iter<T: 'static>(&self) -> impl Iterator<Item = &T> {
+        self.events::<T>()
+            .into_iter()
+            .flat_map(|events| events.iter())
+    }
+
+    /// Returns a reader to read the events of the type sent from now on
+    pub fn subscribe<T: 'static>(&self) -> EventReader<T> {
+        self.events::<T>()
+            .map(|events| events.reader())
+            .unwrap_or_default()
+    }
+
+    /// Returns the events of the type sent since the last read of the reader
+    pub fn read<'a, T: 'static>(
+        &'a self,
+        reader: &mut EventReader<T>,
+    ) -> impl Iterator<Item = &'a T> {
+        self.events::<T>()
+            .map(|events| reader.read(events))
+            .into_iter()
+            .flatten()
+    }
+
+    /// Drops the events of the previous frame, called at the beginning of each frame
+    pub(crate) fn update(&mut self) {
+        self.queues.values_mut().for_each(|queue| queue.update());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn read_events_once_until_next_frame() {
+        let mut bus = EventBus::default();
+        let mut reader = bus.subscribe::<u32>();
+        bus.send(1u32);
+        bus.send("other");
+        assert_eq!(bus.read(&mut reader).collect::<Vec<_>>(), vec![&1]);
+        assert_eq!(bus.read(&mut reader).count(), 0);
+
+        bus.update();
+        bus.send(2u32);
+        assert_eq!(bus.iter::<u32>().collect::<Vec<_>>(), vec![&1, &2]);
+        assert_eq!(bus.read(&mut reader).collect::<Vec<_>>(), vec![&2]);
+
+        bus.update();
+        bus.update();
+        assert_eq!(bus.iter::<u32>().count(), 0);
+        assert_eq!(bus.iter::<&str>().count(), 0);
+    }
+}
@@ -257,6 +257,80 @@ impl WindowConfig {
         self.taskbar_icon_data = taskbar_icon_data;
         self
     }
+
+    /// Returns an error if the options are invalid. The size is clamped to the min and max size,
+    /// and a warning is logged for it and for each option that the current platform doesn't support
+    pub fn validate(&mut self) -> Result<(), String> {
+        if self.width == 0 || self.height == 0 {
+            return Err(format!(
+                "Invalid window size {}x{}, it must be greater than zero.",
+                self.width, self.height
+            ));
+        }
+
+        if let (Some(min), Some(max)) = (self.min_size, self.max_size) {
+            if min.0 > max.0 || min.1 > max.1 {
+                return Err(format!(
+                    "Invalid window min size {}x{}, it's bigger than the max size {}x{}.",
+                    min.0, min.1, max.0, max.1
+                ));
+            }
+        }
+
+        let (min_w, min_h) = self.min_size.unwrap_or((0, 0));
+        let (max_w, max_h) = self.max_size.unwrap_or((u32::MAX, u32::MAX));
+        let width = self.width.clamp(min_w, max_w);
+        let height = self.height.clamp(min_h, max_h);
+        if (width, height) != (self.width, self.height) {
+            log::warn!(
+                "The window size {}x{} is out of the min and max size, using {}x{} instead.",
+                self.width,
+                self.height,
+                width,
+                height
+            );
+            self.width = width;
+            self.height = height;
+        }
+
+        if self.multisampling > 0 && !self.multisampling.is_power_of_two() {
+            return Err(format!(
+                "Invalid multisampling {}, it must be a power of two.",
+                self.multisampling
+            ));
+        }
+
+        if self.fullscreen && self.maximized {
+            log::warn!("The window starts in fullscreen mode, so it will not be maximized.");
+        }
+
+        self.unsupported_options().into_iter().for_each(|option| {
+            log::warn!("The window option '{option}' is not supported on this platform.");
+        });
+
+        Ok(())
+    }
+
+    /// Options set by the user that the platform ignores
+    fn unsupported_options(&self) -> Vec<&'static str> {
+        let is_web = cfg!(target_arch = "wasm32");
+        let is_windows = cfg!(target_os = "windows");
+        let has_window_icon = self.window_icon_path.is_some() || self.window_icon_data.is_some();
+        let has_taskbar_icon = self.taskbar_icon_path.is_some() || self.taskbar_icon_data.is_some();
+
+        [
+            ("fullscreen", is_web && self.fullscreen),
+            ("position", is_web && self.position.is_some()),
+            ("vsync", is_web && self.vsync),
+            ("always_on_top", is_web && self.always_on_top),
+            ("decorations", is_web && !self.decorations),
+            ("window_icon", is_web && has_window_icon),
+            ("taskbar_icon", !is_windows && has_taskbar_icon),
+        ]
+        .into_iter()
+        .filter_map(|(option, unsupported)| unsupported.then_some(option))
+        .collect()
+    }
 }
 
 impl<S, B> BuildConfig<S, B> for WindowConfig
@@ -268,3 +342,30 @@ where
         builder
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn validate_sizes() {
+        assert!(WindowConfig::new().validate().is_ok());
+        assert!(WindowConfig::new().set_size(0, 600).validate().is_err());
+        assert!(WindowConfig::new()
+            .set_min_size(400, 300)
+            .set_max_size(200, 300)
+            .validate()
+            .is_err());
+
+        let mut config = WindowConfig::new().set_max_size(640, 480);
+        assert!(config.validate().is_ok());
+        assert_eq!((config.width, config.height), (640, 480));
+
+        let mut config = WindowConfig::new().set_min_size(1024, 300);
+        assert!(config.validate().is_ok());
+        assert_eq!((config.width, config.height), (1024, 600));
+
+        assert!(WindowConfig::new().set_multisampling(3).validate().is_err());
+        assert!(WindowConfig::new().set_multisampling(4).validate().is_ok());
+    }
+}
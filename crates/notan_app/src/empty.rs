@@ -248,8 +248,31 @@ impl DeviceBackend for EmptyDeviceBackend {
         Ok(self.id_count)
     }
 
+    fn create_storage_buffer(&mut self, _slot: u32) -> Result<u64, String> {
+        self.id_count += 1;
+        Ok(self.id_count)
+    }
+
     fn set_buffer_data(&mut self, _id: u64, _data: &[u8]) {}
 
+    fn read_buffer(&mut self, _buffer: u64, _bytes: &mut [u8]) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn create_compute_pipeline(&mut self, _source: &[u8]) -> Result<u64, String> {
+        self.id_count += 1;
+        Ok(self.id_count)
+    }
+
+    fn dispatch_compute(
+        &mut self,
+        _pipeline: u64,
+        _buffers: &[u64],
+        _groups: [u32; 3],
+    ) -> Result<(), String> {
+        Ok(())
+    }
+
     fn render(&mut self, commands: &[Commands], _target: Option<u64>) {
         commands.iter().for_each(|cmd| log::info!("{:?}", cmd));
     }
@@ -280,6 +303,15 @@ impl DeviceBackend for EmptyDeviceBackend {
         Ok(self.id_count)
     }
 
+    fn set_texture_filter(
+        &mut self,
+        _texture: u64,
+        _min: TextureFilter,
+        _mag: TextureFilter,
+    ) -> Result<(), String> {
+        Ok(())
+    }
+
     fn update_texture(
         &mut self,
         _texture: u64,
@@ -298,6 +330,34 @@ impl DeviceBackend for EmptyDeviceBackend {
         Ok(())
     }
 
+    fn read_screen_pixels(
+        &mut self,
+        _width: u32,
+        _height: u32,
+        _bytes: &mut [u8],
+    ) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn read_pixels_async(&mut self, _texture: u64, _opts: &TextureRead) -> Result<u64, String> {
+        Ok(0)
+    }
+
+    fn poll_read_pixels(&mut self, _id: u64, _bytes: &mut [u8]) -> Result<bool, String> {
+        Ok(true)
+    }
+
+    fn blit(
+        &mut self,
+        _src: u64,
+        _dst: u64,
+        _src_rect: [i32; 4],
+        _dst_rect: [i32; 4],
+        _filter: TextureFilter,
+    ) -> Result<(), String> {
+        Ok(())
+    }
+
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
@@ -1,8 +1,10 @@
 use hashbrown::HashMap;
 pub use notan_graphics::prelude::*;
 pub use notan_graphics::*;
+use notan_math::Rect;
 use std::any::{Any, TypeId};
 use std::cell::{Ref, RefCell, RefMut};
+use std::time::Duration;
 
 /// Graphic interface to interact with the GPU
 /// It's a wrapper for the Device interface and
@@ -71,6 +73,65 @@ impl Graphics {
         self.device.create_pipeline()
     }
 
+    /// Measures the time that the GPU spends on the renders, see [Device::begin_gpu_timer]
+    #[inline]
+    pub fn begin_gpu_timer(&mut self, label: &str) -> Result<(), String> {
+        self.device.begin_gpu_timer(label)
+    }
+
+    #[inline]
+    pub fn end_gpu_timer(&mut self) -> Result<(), String> {
+        self.device.end_gpu_timer()
+    }
+
+    /// Returns the last GPU time measured for the label
+    #[inline]
+    pub fn gpu_time(&mut self, label: &str) -> Option<Duration> {
+        self.device.gpu_time(label)
+    }
+
+    /// Creates a query to test the visibility of some draws, see [Device::create_occlusion_query]
+    #[inline]
+    pub fn create_occlusion_query(&mut self) -> Result<OcclusionQuery, String> {
+        self.device.create_occlusion_query()
+    }
+
+    /// Returns if any sample passed the last time the query was used
+    #[inline]
+    pub fn occlusion_query_result(&mut self, query: &OcclusionQuery) -> Option<bool> {
+        self.device.occlusion_query_result(query)
+    }
+
+    /// Groups the next renders under a name visible on graphics debuggers like RenderDoc
+    #[inline]
+    pub fn push_debug_group(&mut self, name: &str) {
+        self.device.push_debug_group(name);
+    }
+
+    /// Closes the last debug group opened
+    #[inline]
+    pub fn pop_debug_group(&mut self) {
+        self.device.pop_debug_group();
+    }
+
+    /// Sets a name for the resource visible on graphics debuggers
+    #[inline]
+    pub fn set_debug_label<R: GpuResource>(&mut self, resource: &R, label: &str) {
+        self.device.set_debug_label(resource, label);
+    }
+
+    /// Returns the inputs used by the pipeline's shaders, see [Device::pipeline_reflection]
+    #[inline]
+    pub fn pipeline_reflection(&self, pipeline: &Pipeline) -> Option<ShaderReflection> {
+        self.device.pipeline_reflection(pipeline)
+    }
+
+    /// Drops the pipelines kept alive by [PipelineBuilder::prewarm]
+    #[inline]
+    pub fn clear_pipeline_cache(&mut self) {
+        self.device.clear_pipeline_cache();
+    }
+
     /// Creates a texture builder
     #[inline]
     pub fn create_texture(&mut self) -> TextureBuilder {
@@ -101,18 +162,75 @@ impl Graphics {
         self.device.create_uniform_buffer(slot, name)
     }
 
+    /// Creates a storage buffer builder
+    #[inline]
+    pub fn create_storage_buffer(&mut self, slot: u32) -> StorageBufferBuilder<'_> {
+        self.device.create_storage_buffer(slot)
+    }
+
+    /// Creates a compute pipeline builder
+    #[inline]
+    pub fn create_compute_pipeline(&mut self) -> ComputePipelineBuilder<'_, '_> {
+        self.device.create_compute_pipeline()
+    }
+
+    /// Creates a dispatcher to run the compute pipeline
+    #[inline]
+    pub fn dispatch_compute<'a>(
+        &'a mut self,
+        pipeline: &'a ComputePipeline,
+    ) -> ComputeDispatcher<'a> {
+        self.device.dispatch_compute(pipeline)
+    }
+
+    /// Read the data of a buffer
+    #[inline]
+    pub fn read_buffer(&mut self, buffer: &Buffer, bytes: &mut [u8]) -> Result<(), String> {
+        self.device.read_buffer(buffer, bytes)
+    }
+
     /// Update the texture data
     #[inline]
     pub fn update_texture<'a>(&'a mut self, texture: &'a mut Texture) -> TextureUpdater {
         self.device.update_texture(texture)
     }
 
+    /// Change the min and mag filters of the texture
+    #[inline]
+    pub fn set_texture_filter(
+        &mut self,
+        texture: &mut Texture,
+        min: TextureFilter,
+        mag: TextureFilter,
+    ) -> Result<(), String> {
+        self.device.set_texture_filter(texture, min, mag)
+    }
+
     /// Read pixels from a texture
     #[inline]
     pub fn read_pixels<'a>(&'a mut self, texture: &'a Texture) -> TextureReader {
         self.device.read_pixels(texture)
     }
 
+    /// Copies a region of a render texture to another one, see [Device::blit]
+    #[inline]
+    pub fn blit(
+        &mut self,
+        src: &RenderTexture,
+        dst: &RenderTexture,
+        src_rect: Rect,
+        dst_rect: Rect,
+        filter: TextureFilter,
+    ) -> Result<(), String> {
+        self.device.blit(src, dst, src_rect, dst_rect, filter)
+    }
+
+    /// Read the pixels of the screen, see [Device::screenshot]
+    #[inline]
+    pub fn screenshot(&mut self) -> Result<Screenshot, String> {
+        self.device.screenshot()
+    }
+
     /// Render to the screen
     #[inline]
     pub fn render<G: GfxRenderer>(&mut self, renderer: &G) {
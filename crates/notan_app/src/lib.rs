@@ -5,9 +5,12 @@ pub mod prelude;
 mod app;
 mod backend;
 mod builder;
+mod bus;
 pub mod graphics;
 mod handlers;
+mod limiter;
 mod parsers;
+mod scenes;
 mod timer;
 
 pub mod assets;
@@ -18,8 +21,16 @@ pub use backend::*;
 pub use notan_core::events::*;
 
 pub use builder::*;
+pub use bus::{EventBus, EventReader, Events};
 pub use plugins::*;
+pub use scenes::*;
 
 pub use graphics::*;
+pub use limiter::{FrameLimiter, FrameWait};
+pub use parsers::create_premultiplied_texture_parser;
+#[cfg(feature = "csv_loader")]
+pub use parsers::{create_csv_parser, CsvTable};
+#[cfg(feature = "xml_loader")]
+pub use parsers::{create_xml_parser, XmlDocument, XmlElement, XmlNode};
 
 pub use config::WindowConfig;
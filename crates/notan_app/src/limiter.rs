@@ -0,0 +1,174 @@
+use notan_utils::{Duration, Instant};
+
+/// How the loop waits for the next frame when the frame rate is limited
+/// `Web: no-op, the frames are skipped until it's time for the next one`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FrameWait {
+    /// Sleeps the thread, uses less CPU but the OS can wake it up a bit late
+    Sleep,
+    /// Sleeps the thread and yields the last millisecond to wake up on time
+    #[default]
+    SleepYield,
+    /// Yields the thread until the next frame, precise but keeps the core busy
+    Yield,
+}
+
+// time yielded instead of sleeping to wake up on time using FrameWait::SleepYield
+const YIELD_MARGIN: Duration = Duration::from_millis(1);
+
+/// Caps the frame rate of the app, and throttles it when the window
+/// is unfocused or minimized using the idle frame rate
+#[derive(Debug, Clone, Default)]
+pub struct FrameLimiter {
+    frame: Option<Duration>,
+    idle_frame: Option<Duration>,
+    wait: FrameWait,
+    last_frame: Option<Instant>,
+}
+
+impl FrameLimiter {
+    /// Sets the max frames per second, `None` runs as fast as possible (or vsync)
+    pub fn set_fps(&mut self, fps: Option<f64>) {
+        self.frame = fps.map(fps_to_duration);
+    }
+
+    /// Returns the max frames per second
+    pub fn fps(&self) -> Option<f64> {
+        self.frame.map(|frame| 1.0 / frame.as_secs_f64())
+    }
+
+    /// Sets the max frames per second while the window is unfocused or minimized
+    pub fn set_idle_fps(&mut self, fps: Option<f64>) {
+        self.idle_frame = fps.map(fps_to_duration);
+    }
+
+    /// Returns the max frames per second while the window is unfocused or minimized
+    pub fn idle_fps(&self) -> Option<f64> {
+        self.idle_frame.map(|frame| 1.0 / frame.as_secs_f64())
+    }
+
+    /// Sets how the loop waits for the next frame
+    pub fn set_wait(&mut self, wait: FrameWait) {
+        self.wait = wait;
+    }
+
+    /// Returns how the loop waits for the next frame
+    pub fn wait(&self) -> FrameWait {
+        self.wait
+    }
+
+    /// Returns true if the idle frame rate is used when the window is unfocused or minimized
+    pub(crate) fn has_idle_limit(&self) -> bool {
+        self.idle_frame.is_some()
+    }
+
+    /// Duration of the frame, the idle one is used only if it's slower
+    fn frame_duration(&self, idle: bool) -> Option<Duration> {
+        match (self.frame, self.idle_frame) {
+            (Some(frame), Some(idle_frame)) if idle => Some(frame.max(idle_frame)),
+            (None, Some(idle_frame)) if idle => Some(idle_frame),
+            (frame, _) => frame,
+        }
+    }
+
+    /// Time left until the next frame can start
+    fn remaining(&self, now: Instant, idle: bool) -> Option<Duration> {
+        let frame = self.frame_duration(idle)?;
+        let last_frame = self.last_frame?;
+        (last_frame + frame)
+            .checked_duration_since(now)
+            .filter(|remaining| !remaining.is_zero())
+    }
+
+    /// Marks the start of a frame, keeping the cadence unless the app is more than a frame late
+    fn start_frame(&mut self, now: Instant, idle: bool) {
+        let next =
+            self.frame_duration(idle)
+                .zip(self.last_frame)
+                .and_then(|(frame, last_frame)| {
+                    let next = last_frame + frame;
+                    (now.saturating_duration_since(next) < frame).then_some(next)
+                });
+
+        self.last_frame = Some(next.unwrap_or(now));
+    }
+
+    /// Waits until the next frame can start, always returns true
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn next_frame(&mut self, idle: bool) -> bool {
+        if let Some(remaining) = self.remaining(Instant::now(), idle) {
+            let deadline = Instant::now() + remaining;
+            match self.wait {
+                FrameWait::Sleep => std::thread::sleep(remaining),
+                FrameWait::SleepYield => {
+                    if remaining > YIELD_MARGIN {
+                        std::thread::sleep(remaining - YIELD_MARGIN);
+                    }
+                    yield_until(deadline);
+                }
+                FrameWait::Yield => yield_until(deadline),
+            }
+        }
+
+        self.start_frame(Instant::now(), idle);
+        true
+    }
+
+    /// Returns false if the frame must be skipped because it's too early,
+    /// the browser cannot sleep the thread
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) fn next_frame(&mut self, idle: bool) -> bool {
+        let now = Instant::now();
+        if self.remaining(now, idle).is_some() {
+            return false;
+        }
+
+        self.start_frame(now, idle);
+        true
+    }
+}
+
+fn fps_to_duration(fps: f64) -> Duration {
+    debug_assert!(fps > 0.0, "The frame rate must be greater than 0.");
+    Duration::from_secs_f64(1.0 / fps.max(f64::EPSILON))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn yield_until(deadline: Instant) {
+    while Instant::now() < deadline {
+        std::thread::yield_now();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn limit_frames_and_idle_frames() {
+        let mut limiter = FrameLimiter::default();
+        let start = Instant::now();
+        limiter.start_frame(start, false);
+        assert_eq!(limiter.remaining(start, false), None);
+
+        limiter.set_fps(Some(10.0));
+        limiter.set_idle_fps(Some(2.0));
+        let now = start + Duration::from_millis(30);
+        assert_eq!(
+            limiter.remaining(now, false),
+            Some(Duration::from_millis(70))
+        );
+        assert_eq!(
+            limiter.remaining(now, true),
+            Some(Duration::from_millis(470))
+        );
+
+        // the next frame starts when it was due to keep the cadence
+        limiter.start_frame(start + Duration::from_millis(101), false);
+        assert_eq!(limiter.last_frame, Some(start + Duration::from_millis(100)));
+
+        // unless the app is late for more than a frame
+        limiter.start_frame(start + Duration::from_millis(400), false);
+        assert_eq!(limiter.last_frame, Some(start + Duration::from_millis(400)));
+    }
+}
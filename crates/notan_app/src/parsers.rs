@@ -1,7 +1,15 @@
 #[cfg(feature = "audio")]
 mod audio;
+#[cfg(feature = "csv_loader")]
+mod csv;
 mod texture;
+#[cfg(feature = "xml_loader")]
+mod xml;
 
+#[cfg(feature = "csv_loader")]
+pub use self::csv::*;
 #[cfg(feature = "audio")]
 pub use audio::*;
 pub use texture::*;
+#[cfg(feature = "xml_loader")]
+pub use xml::*;
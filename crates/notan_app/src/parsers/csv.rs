@@ -0,0 +1,98 @@
+use crate::assets::AssetLoader;
+
+/// Rows of a CSV file, the first row is used as the headers
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CsvTable {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl CsvTable {
+    pub fn from_bytes(data: &[u8]) -> Result<Self, String> {
+        let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(data);
+        let headers = reader
+            .headers()
+            .map_err(|e| e.to_string())?
+            .iter()
+            .map(|header| header.to_string())
+            .collect();
+
+        let rows = reader
+            .records()
+            .map(|record| {
+                record
+                    .map(|record| record.iter().map(|field| field.to_string()).collect())
+                    .map_err(|e| e.to_string())
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { headers, rows })
+    }
+
+    pub fn headers(&self) -> &[String] {
+        &self.headers
+    }
+
+    pub fn rows(&self) -> &[Vec<String>] {
+        &self.rows
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Returns the field of the row under the header given
+    pub fn get(&self, row: usize, header: &str) -> Option<&str> {
+        let column = self.column_index(header)?;
+        self.rows.get(row)?.get(column).map(|field| field.as_str())
+    }
+
+    /// Returns the fields under the header given, the rows without the field are skipped
+    pub fn column(&self, header: &str) -> impl Iterator<Item = &str> + '_ {
+        let column = self.column_index(header);
+        self.rows.iter().filter_map(move |row| {
+            column
+                .and_then(|column| row.get(column))
+                .map(|field| field.as_str())
+        })
+    }
+
+    fn column_index(&self, header: &str) -> Option<usize> {
+        self.headers.iter().position(|h| h == header)
+    }
+}
+
+/// Loader for `.csv` files as [CsvTable]
+pub fn create_csv_parser() -> AssetLoader {
+    AssetLoader::new()
+        .use_async_parser(parse_csv)
+        .extension("csv")
+}
+
+fn parse_csv(id: &str, data: Vec<u8>) -> Result<CsvTable, String> {
+    let table = CsvTable::from_bytes(&data)?;
+    log::debug!("Asset '{}' parsed as CsvTable", id);
+    Ok(table)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn read_rows_by_header() {
+        let data = b"name,hp,speed\nslime,10,1.5\n\"bat, giant\",4\n";
+        let table = CsvTable::from_bytes(data).unwrap();
+
+        assert_eq!(table.headers(), ["name", "hp", "speed"]);
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.get(0, "hp"), Some("10"));
+        assert_eq!(table.get(1, "name"), Some("bat, giant"));
+        assert_eq!(table.get(1, "speed"), None);
+        assert_eq!(table.column("speed").collect::<Vec<_>>(), ["1.5"]);
+    }
+}
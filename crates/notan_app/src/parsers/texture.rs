@@ -8,8 +8,30 @@ pub fn create_texture_parser() -> AssetLoader {
         .extensions(&["png", "jpg", "jpeg"])
 }
 
+/// Same as [create_texture_parser] but multiplying the colors by the alpha when the image is decoded,
+/// added after the default loaders it replaces them
+pub fn create_premultiplied_texture_parser() -> AssetLoader {
+    AssetLoader::new()
+        .use_parser(parse_premultiplied_image)
+        .extensions(&["png", "jpg", "jpeg"])
+}
+
 fn parse_image(id: &str, data: Vec<u8>, gfx: &mut Graphics) -> Result<Texture, String> {
     let texture = gfx.create_texture().from_image(&data).build()?;
     log::debug!("Asset '{}' parsed as Texture", id);
     Ok(texture)
 }
+
+fn parse_premultiplied_image(
+    id: &str,
+    data: Vec<u8>,
+    gfx: &mut Graphics,
+) -> Result<Texture, String> {
+    let texture = gfx
+        .create_texture()
+        .from_image(&data)
+        .with_premultiplied_alpha()
+        .build()?;
+    log::debug!("Asset '{}' parsed as premultiplied Texture", id);
+    Ok(texture)
+}
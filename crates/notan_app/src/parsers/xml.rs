@@ -0,0 +1,194 @@
+use crate::assets::AssetLoader;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+/// Content of an [XmlElement]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum XmlNode {
+    Element(XmlElement),
+    Text(String),
+}
+
+/// Element with its attributes and children, like `<tile id="1">grass</tile>`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct XmlElement {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<XmlNode>,
+}
+
+impl XmlElement {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn attributes(&self) -> &[(String, String)] {
+        &self.attributes
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn children(&self) -> &[XmlNode] {
+        &self.children
+    }
+
+    /// Returns the child elements, skipping the text
+    pub fn elements(&self) -> impl Iterator<Item = &XmlElement> + '_ {
+        self.children.iter().filter_map(|node| match node {
+            XmlNode::Element(element) => Some(element),
+            XmlNode::Text(_) => None,
+        })
+    }
+
+    /// Returns the first child element with the name given
+    pub fn child(&self, name: &str) -> Option<&XmlElement> {
+        self.elements().find(|element| element.name == name)
+    }
+
+    /// Returns the text of the element without the text of its child elements
+    pub fn text(&self) -> String {
+        self.children
+            .iter()
+            .filter_map(|node| match node {
+                XmlNode::Text(text) => Some(text.as_str()),
+                XmlNode::Element(_) => None,
+            })
+            .collect()
+    }
+
+    fn from_start(start: &BytesStart) -> Result<Self, String> {
+        let name = String::from_utf8_lossy(start.name().as_ref()).to_string();
+        let attributes = start
+            .attributes()
+            .map(|attr| {
+                let attr = attr.map_err(|e| e.to_string())?;
+                let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
+                let value = attr.unescape_value().map_err(|e| e.to_string())?;
+                Ok((key, value.to_string()))
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Self {
+            name,
+            attributes,
+            children: vec![],
+        })
+    }
+}
+
+/// XML file read as a tree of [XmlElement]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XmlDocument {
+    root: XmlElement,
+}
+
+impl XmlDocument {
+    pub fn from_bytes(data: &[u8]) -> Result<Self, String> {
+        let mut reader = Reader::from_reader(data);
+        reader.trim_text(true);
+
+        // open elements, the last one is the parent of the next nodes
+        let mut stack: Vec<XmlElement> = vec![];
+        let mut root = None;
+        let mut buf = vec![];
+
+        loop {
+            let node = match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(start)) => {
+                    stack.push(XmlElement::from_start(&start)?);
+                    None
+                }
+                Ok(Event::End(_)) => stack.pop().map(XmlNode::Element),
+                Ok(Event::Empty(start)) => Some(XmlNode::Element(XmlElement::from_start(&start)?)),
+                Ok(Event::Text(text)) => {
+                    let text = text.unescape().map_err(|e| e.to_string())?;
+                    Some(XmlNode::Text(text.to_string()))
+                }
+                Ok(Event::CData(data)) => Some(XmlNode::Text(
+                    String::from_utf8_lossy(&data.into_inner()).to_string(),
+                )),
+                Ok(Event::Eof) => break,
+                Ok(_) => None,
+                Err(e) => {
+                    return Err(format!(
+                        "Invalid XML at position {}: {}",
+                        reader.buffer_position(),
+                        e
+                    ))
+                }
+            };
+
+            if let Some(node) = node {
+                match (stack.last_mut(), node) {
+                    (Some(parent), node) => parent.children.push(node),
+                    (None, XmlNode::Element(element)) if root.is_none() => root = Some(element),
+                    (None, XmlNode::Element(_)) => {
+                        return Err("XML documents must have only one root element".to_string())
+                    }
+                    (None, XmlNode::Text(_)) => {}
+                }
+            }
+
+            buf.clear();
+        }
+
+        if !stack.is_empty() {
+            return Err("XML document ended before closing all the elements".to_string());
+        }
+
+        let root = root.ok_or_else(|| "XML document without a root element".to_string())?;
+        Ok(Self { root })
+    }
+
+    pub fn root(&self) -> &XmlElement {
+        &self.root
+    }
+}
+
+/// Loader for `.xml` files as [XmlDocument]
+pub fn create_xml_parser() -> AssetLoader {
+    AssetLoader::new()
+        .use_async_parser(parse_xml)
+        .extension("xml")
+}
+
+fn parse_xml(id: &str, data: Vec<u8>) -> Result<XmlDocument, String> {
+    let doc = XmlDocument::from_bytes(&data)?;
+    log::debug!("Asset '{}' parsed as XmlDocument", id);
+    Ok(doc)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn read_element_tree() {
+        let data = br#"<?xml version="1.0"?>
+            <map width="2">
+                <tile id="1">grass &amp; dirt</tile>
+                <tile id="2"/>
+                <note><![CDATA[<raw>]]></note>
+            </map>"#;
+        let doc = XmlDocument::from_bytes(data).unwrap();
+
+        let map = doc.root();
+        assert_eq!(map.name(), "map");
+        assert_eq!(map.attribute("width"), Some("2"));
+
+        let ids = map
+            .elements()
+            .filter_map(|tile| tile.attribute("id"))
+            .collect::<Vec<_>>();
+        assert_eq!(ids, ["1", "2"]);
+        assert_eq!(map.child("tile").unwrap().text(), "grass & dirt");
+        assert_eq!(map.child("note").unwrap().text(), "<raw>");
+
+        assert!(XmlDocument::from_bytes(b"<map><tile></map>").is_err());
+    }
+}
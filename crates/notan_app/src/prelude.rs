@@ -1,6 +1,17 @@
 pub use crate::app::{App, AppState};
-pub use crate::assets::{Asset, AssetList, AssetLoader, Assets};
+pub use crate::assets::{
+    Asset, AssetList, AssetLoader, AssetSource, Assets, Dependencies, Preprocessor, Priority,
+    StreamChunk,
+};
 pub use crate::backend::*;
+pub use crate::bus::{EventBus, EventReader, Events};
 pub use crate::config::*;
 pub use crate::graphics::Graphics;
+pub use crate::limiter::{FrameLimiter, FrameWait};
+pub use crate::parsers::create_premultiplied_texture_parser;
+#[cfg(feature = "csv_loader")]
+pub use crate::parsers::{create_csv_parser, CsvTable};
+#[cfg(feature = "xml_loader")]
+pub use crate::parsers::{create_xml_parser, XmlDocument};
 pub use crate::plugins::{Plugin, Plugins};
+pub use crate::scenes::{Scene, SceneChange, SceneEffect, SceneStack};
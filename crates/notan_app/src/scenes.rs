@@ -0,0 +1,307 @@
+use crate::app::App;
+use crate::assets::Assets;
+use crate::Graphics;
+use notan_core::events::Event;
+
+/// Change of the scenes requested by the scene on top of the stack
+pub enum SceneChange<S> {
+    /// Keeps the current scenes
+    None,
+    /// Adds a scene on top, the current one stays below
+    Push(Box<dyn Scene<S>>),
+    /// Removes the scene on top
+    Pop,
+    /// Removes the scene on top and adds a new one
+    Replace(Box<dyn Scene<S>>),
+}
+
+impl<S> SceneChange<S> {
+    /// Adds a scene on top, the current one stays below
+    pub fn push<T: Scene<S> + 'static>(scene: T) -> Self {
+        Self::Push(Box::new(scene))
+    }
+
+    /// Removes the scene on top and adds a new one
+    pub fn replace<T: Scene<S> + 'static>(scene: T) -> Self {
+        Self::Replace(Box::new(scene))
+    }
+}
+
+#[allow(unused_variables)]
+/// A screen of the app, like a menu or a level, managed by a `SceneStack`.
+/// The type `S` is the data shared between scenes
+pub trait Scene<S> {
+    /// Executed when the scene is added to the stack
+    fn enter(&mut self, app: &mut App, assets: &mut Assets, state: &mut S) {}
+
+    /// Executed when the scene is removed from the stack
+    fn exit(&mut self, app: &mut App, assets: &mut Assets, state: &mut S) {}
+
+    /// Executed each frame if the scene is on top of the stack
+    fn update(&mut self, app: &mut App, assets: &mut Assets, state: &mut S) -> SceneChange<S> {
+        SceneChange::None
+    }
+
+    /// Executed each frame if the scene is visible
+    fn draw(&mut self, app: &mut App, gfx: &mut Graphics, state: &mut S) {}
+
+    /// Executed for each event received if the scene is on top of the stack
+    fn event(
+        &mut self,
+        app: &mut App,
+        assets: &mut Assets,
+        state: &mut S,
+        event: &Event,
+    ) -> SceneChange<S> {
+        SceneChange::None
+    }
+
+    /// Returns true to draw the scenes below it, like a pause menu over the game
+    fn is_overlay(&self) -> bool {
+        false
+    }
+}
+
+/// Effect drawn over the scenes while they change, `progress` goes from 0 to 1
+/// while the current scene leaves, and from 1 to 0 while the next one enters
+pub trait SceneEffect {
+    fn draw(&mut self, app: &mut App, gfx: &mut Graphics, progress: f32);
+}
+
+impl<F> SceneEffect for F
+where
+    F: FnMut(&mut App, &mut Graphics, f32),
+{
+    fn draw(&mut self, app: &mut App, gfx: &mut Graphics, progress: f32) {
+        self(app, gfx, progress)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Phase {
+    Idle,
+    Leave(f32),
+    Enter(f32),
+}
+
+/// Stack of scenes where only the one on top is updated and receives the events.
+/// The stack is usually kept in the app state next to the data shared by the scenes,
+/// and its methods are called from the `update`, `draw` and `event` callbacks
+pub struct SceneStack<S> {
+    scenes: Vec<Box<dyn Scene<S>>>,
+    pending: Vec<SceneChange<S>>,
+    effect: Option<Box<dyn SceneEffect>>,
+    duration: f32,
+    phase: Phase,
+}
+
+impl<S> SceneStack<S> {
+    /// Creates the stack with the first scene, which enters on the first update
+    pub fn new<T: Scene<S> + 'static>(scene: T) -> Self {
+        Self {
+            scenes: vec![],
+            pending: vec![SceneChange::push(scene)],
+            effect: None,
+            duration: 0.0,
+            phase: Phase::Idle,
+        }
+    }
+
+    /// Sets an effect used when the scenes change, half of the duration
+    /// is used for the leaving scene and the other half for the entering one
+    pub fn set_effect<E: SceneEffect + 'static>(&mut self, effect: E, duration: f32) {
+        self.effect = Some(Box::new(effect));
+        self.duration = duration.max(0.0);
+    }
+
+    /// Removes the effect, the scenes will change instantly
+    pub fn remove_effect(&mut self) {
+        self.effect = None;
+        self.duration = 0.0;
+    }
+
+    /// Adds a scene on top on the next update
+    pub fn push<T: Scene<S> + 'static>(&mut self, scene: T) {
+        self.pending.push(SceneChange::push(scene));
+    }
+
+    /// Removes the scene on top on the next update
+    pub fn pop(&mut self) {
+        self.pending.push(SceneChange::Pop);
+    }
+
+    /// Replaces the scene on top on the next update
+    pub fn replace<T: Scene<S> + 'static>(&mut self, scene: T) {
+        self.pending.push(SceneChange::replace(scene));
+    }
+
+    /// Number of scenes in the stack
+    pub fn len(&self) -> usize {
+        self.scenes.len()
+    }
+
+    /// Returns true if the stack has no scenes
+    pub fn is_empty(&self) -> bool {
+        self.scenes.is_empty()
+    }
+
+    /// Returns true while the scenes are changing with an effect
+    pub fn is_changing(&self) -> bool {
+        self.phase != Phase::Idle
+    }
+
+    /// Updates the scene on top and applies the changes requested
+    pub fn update(&mut self, app: &mut App, assets: &mut Assets, state: &mut S) {
+        if !self.is_changing() {
+            if let Some(scene) = self.scenes.last_mut() {
+                let change = scene.update(app, assets, state);
+                self.request(change);
+            }
+        }
+
+        // without effects all the pending changes are applied at once
+        let mut delta = app.timer.delta_f32();
+        while self.advance(delta) {
+            delta = 0.0;
+            let change = self.pending.remove(0);
+            self.apply(change, app, assets, state);
+        }
+    }
+
+    /// Draws the scene on top and the ones below it if it's an overlay
+    pub fn draw(&mut self, app: &mut App, gfx: &mut Graphics, state: &mut S) {
+        let first = self
+            .scenes
+            .iter()
+            .rposition(|scene| !scene.is_overlay())
+            .unwrap_or(0);
+
+        self.scenes
+            .iter_mut()
+            .skip(first)
+            .for_each(|scene| scene.draw(app, gfx, state));
+
+        let progress = match self.phase {
+            Phase::Idle => return,
+            Phase::Leave(elapsed) => elapsed / self.half_duration(),
+            Phase::Enter(elapsed) => 1.0 - elapsed / self.half_duration(),
+        };
+
+        if let Some(effect) = &mut self.effect {
+            effect.draw(app, gfx, progress.clamp(0.0, 1.0));
+        }
+    }
+
+    /// Passes the event to the scene on top
+    pub fn event(&mut self, app: &mut App, assets: &mut Assets, state: &mut S, event: &Event) {
+        if self.is_changing() {
+            return;
+        }
+
+        if let Some(scene) = self.scenes.last_mut() {
+            let change = scene.event(app, assets, state, event);
+            self.request(change);
+        }
+    }
+
+    fn request(&mut self, change: SceneChange<S>) {
+        if !matches!(change, SceneChange::None) {
+            self.pending.push(change);
+        }
+    }
+
+    fn half_duration(&self) -> f32 {
+        self.duration * 0.5
+    }
+
+    /// Moves the effect forward, returns true when the next pending change must be applied
+    fn advance(&mut self, delta: f32) -> bool {
+        let use_effect = self.effect.is_some() && self.duration > 0.0;
+        let half = self.half_duration();
+
+        match self.phase {
+            Phase::Idle if self.pending.is_empty() => false,
+            // there is nothing to leave with the first scene
+            Phase::Idle if !use_effect || self.scenes.is_empty() => {
+                if use_effect {
+                    self.phase = Phase::Enter(0.0);
+                }
+                true
+            }
+            Phase::Idle => {
+                self.phase = Phase::Leave(0.0);
+                false
+            }
+            Phase::Leave(elapsed) => {
+                let elapsed = elapsed + delta;
+                if elapsed >= half {
+                    self.phase = Phase::Enter(0.0);
+                    true
+                } else {
+                    self.phase = Phase::Leave(elapsed);
+                    false
+                }
+            }
+            Phase::Enter(elapsed) => {
+                let elapsed = elapsed + delta;
+                self.phase = if elapsed >= half {
+                    Phase::Idle
+                } else {
+                    Phase::Enter(elapsed)
+                };
+                false
+            }
+        }
+    }
+
+    fn apply(&mut self, change: SceneChange<S>, app: &mut App, assets: &mut Assets, state: &mut S) {
+        match change {
+            SceneChange::None => {}
+            SceneChange::Push(mut scene) => {
+                scene.enter(app, assets, state);
+                self.scenes.push(scene);
+            }
+            SceneChange::Pop => {
+                if let Some(mut scene) = self.scenes.pop() {
+                    scene.exit(app, assets, state);
+                }
+            }
+            SceneChange::Replace(mut scene) => {
+                if let Some(mut old) = self.scenes.pop() {
+                    old.exit(app, assets, state);
+                }
+                scene.enter(app, assets, state);
+                self.scenes.push(scene);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Empty;
+    impl Scene<()> for Empty {}
+
+    #[test]
+    fn change_scenes_with_effect() {
+        let mut stack = SceneStack::new(Empty);
+        stack.set_effect(|_: &mut App, _: &mut Graphics, _| {}, 1.0);
+
+        // the first scene only enters
+        assert!(stack.advance(0.1));
+        stack.pending.remove(0);
+        stack.scenes.push(Box::new(Empty));
+        assert_eq!(stack.phase, Phase::Enter(0.0));
+        assert!(!stack.advance(0.5));
+        assert!(!stack.is_changing());
+
+        // the next change waits until the current scene leaves
+        stack.pop();
+        assert!(!stack.advance(0.1));
+        assert!(!stack.advance(0.2));
+        assert!(stack.advance(0.3));
+        assert!(stack.is_changing());
+    }
+}
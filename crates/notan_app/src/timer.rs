@@ -1,6 +1,10 @@
 use notan_utils::{Duration, Instant};
 use std::collections::VecDeque;
 
+// max updates in a frame with a fixed timestep, the time left is discarded
+// to avoid spending more time updating each frame after a slow one
+const MAX_FIXED_STEPS: u32 = 8;
+
 /// Helper to measure and expose application's time events
 #[derive(Debug, Clone)]
 pub struct AppTimer {
@@ -12,6 +16,11 @@ pub struct AppTimer {
     elapsed_time: f32,
     fps_cache: VecDeque<f32>,
     fps: f32,
+    fixed_timestep: Option<Duration>,
+    accumulator: Duration,
+    alpha: f32,
+    // delta of the frame while the fixed updates use the timestep
+    frame_delta: Option<Duration>,
 }
 
 impl Default for AppTimer {
@@ -32,6 +41,10 @@ impl Default for AppTimer {
             elapsed_time: 0.0,
             fps_cache,
             fps,
+            fixed_timestep: None,
+            accumulator: Duration::from_secs(0),
+            alpha: 1.0,
+            frame_delta: None,
         }
     }
 }
@@ -56,6 +69,62 @@ impl AppTimer {
         self.fps = 1.0 / (self.fps_cache.iter().sum::<f32>() / self.fps_cache.len() as f32);
     }
 
+    /// Returns how many times the update callback runs in this frame, always once without a fixed timestep
+    pub(crate) fn fixed_steps(&mut self) -> u32 {
+        let Some(step) = self.fixed_timestep else {
+            return 1;
+        };
+
+        self.accumulator += self.delta;
+        let (acc, step_nanos) = (self.accumulator.as_nanos(), step.as_nanos());
+        let steps = acc / step_nanos;
+        self.accumulator = Duration::from_nanos((acc % step_nanos) as u64);
+        self.alpha = self.accumulator.as_secs_f32() / step.as_secs_f32();
+        steps.min(MAX_FIXED_STEPS as u128) as u32
+    }
+
+    /// The delta is the timestep while the fixed updates run
+    pub(crate) fn begin_fixed_update(&mut self) {
+        if let Some(step) = self.fixed_timestep {
+            self.frame_delta = Some(self.delta);
+            self.set_delta(step);
+        }
+    }
+
+    pub(crate) fn end_fixed_update(&mut self) {
+        if let Some(delta) = self.frame_delta.take() {
+            self.set_delta(delta);
+        }
+    }
+
+    fn set_delta(&mut self, delta: Duration) {
+        self.delta = delta;
+        self.delta_seconds = delta.as_secs_f32();
+    }
+
+    /// Runs the update callback at a fixed rate, like `1/60` seconds, independently of the frame rate.
+    /// It can run many times or none in a frame, and the delta time is the timestep while it runs.
+    /// `None` updates once per frame
+    pub fn set_fixed_timestep(&mut self, timestep: Option<Duration>) {
+        self.fixed_timestep = timestep.filter(|step| !step.is_zero());
+        self.accumulator = Duration::from_secs(0);
+        self.alpha = 1.0;
+    }
+
+    /// Fixed time between updates if it's set
+    #[inline]
+    pub fn fixed_timestep(&self) -> Option<Duration> {
+        self.fixed_timestep
+    }
+
+    /// Time accumulated for the next fixed update as a fraction of the timestep, between 0.0
+    /// and 1.0, used to interpolate the state of the last two updates when it's drawn.
+    /// Without a fixed timestep it's always 1.0
+    #[inline]
+    pub fn alpha(&self) -> f32 {
+        self.alpha
+    }
+
     /// Average frames per second (calculated using the last 60 frames)
     #[inline]
     pub fn fps(&self) -> f32 {
@@ -98,3 +167,29 @@ impl AppTimer {
         self.last_time
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn accumulate_fixed_steps() {
+        let mut timer = AppTimer::default();
+        assert_eq!(timer.fixed_steps(), 1);
+
+        timer.set_fixed_timestep(Some(Duration::from_millis(10)));
+        timer.set_delta(Duration::from_millis(25));
+        assert_eq!(timer.fixed_steps(), 2);
+        assert!((timer.alpha() - 0.5).abs() < 0.001);
+
+        timer.set_delta(Duration::from_millis(4));
+        assert_eq!(timer.fixed_steps(), 0);
+        timer.set_delta(Duration::from_millis(1));
+        assert_eq!(timer.fixed_steps(), 1);
+
+        // a slow frame doesn't run all the updates
+        timer.set_delta(Duration::from_secs(1));
+        assert_eq!(timer.fixed_steps(), MAX_FIXED_STEPS);
+        assert!(timer.alpha() < 1.0);
+    }
+}
@@ -88,6 +88,15 @@ pub enum Event {
 
     /// Text pasted from the clipboard
     Paste(String),
+
+    /// An asset was loaded, the type is the output of its loader
+    AssetLoaded { id: String, type_name: String },
+
+    /// An asset failed to load
+    AssetFailed { id: String, error: String },
+
+    /// All the assets of the list with this id were loaded, failed or cancelled
+    AssetListCompleted { id: u64 },
 }
 
 #[cfg(feature = "drop_files")]
@@ -1,26 +1,127 @@
+use crate::images::TextureRegion;
+use notan_app::assets::{Asset, AssetLoader, Dependencies};
 use notan_graphics::Texture;
+use notan_math::Rect;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::Path;
 
 /// Returns a HashMap containing a list of textures created from the Atlas data
 pub fn create_textures_from_atlas(
     data: &[u8],
     base_texture: &Texture,
 ) -> Result<HashMap<String, Texture>, String> {
-    let data = atlas_from_bytes(data)?;
-    let mut textures = HashMap::new();
-    data.frames.iter().for_each(|af| {
-        textures.insert(
-            af.filename.clone(),
-            base_texture.with_frame(
-                af.frame.x as _,
-                af.frame.y as _,
-                af.frame.w as _,
-                af.frame.h as _,
-            ),
-        );
-    });
-    Ok(textures)
+    let atlas = TextureAtlas::from_bytes(data)?;
+    Ok(atlas.textures(base_texture))
+}
+
+/// Returns a loader that parses TexturePacker JSON files (hash or array) as [TextureAtlas],
+/// the image of the atlas is loaded as a dependency before parsing it
+pub fn create_atlas_parser() -> AssetLoader {
+    AssetLoader::new()
+        .use_dependencies(|id, data| {
+            let atlas = TextureAtlas::from_bytes(data)?;
+            Ok(vec![image_path(id, &atlas.image)])
+        })
+        .use_parser(parse_atlas)
+        .extension("json")
+}
+
+fn parse_atlas(
+    id: &str,
+    data: Vec<u8>,
+    dependencies: &Dependencies,
+) -> Result<TextureAtlas, String> {
+    let mut atlas = TextureAtlas::from_bytes(&data)?;
+    atlas.image = image_path(id, &atlas.image);
+    atlas.base_texture = Some(dependencies.get::<Texture>(&atlas.image)?);
+
+    log::debug!("Asset '{}' parsed as TextureAtlas", id);
+    Ok(atlas)
+}
+
+// the image is relative to the json file
+fn image_path(id: &str, image: &str) -> String {
+    match Path::new(id).parent() {
+        Some(parent) => parent.join(image).to_string_lossy().to_string(),
+        None => image.to_string(),
+    }
+}
+
+/// Named frames of a texture atlas
+#[derive(Debug, Clone, Default)]
+pub struct TextureAtlas {
+    image: String,
+    frames: HashMap<String, Rect>,
+    base_texture: Option<Asset<Texture>>,
+}
+
+impl TextureAtlas {
+    /// Parses a TexturePacker JSON file using the hash or the array format
+    pub fn from_bytes(data: &[u8]) -> Result<Self, String> {
+        let data = atlas_from_bytes(data)?;
+        let frames = match data.frames {
+            AtlasFrames::Array(frames) => frames
+                .into_iter()
+                .map(|af| (af.filename.clone(), af.frame.into()))
+                .collect(),
+            AtlasFrames::Hash(frames) => frames
+                .into_iter()
+                .map(|(filename, af)| (filename, af.frame.into()))
+                .collect(),
+        };
+
+        Ok(Self {
+            image: data.meta.image,
+            frames,
+            base_texture: None,
+        })
+    }
+
+    /// Path of the image used by the atlas.
+    /// Using the loader it's relative to the working directory, otherwise the one in the file
+    pub fn image_path(&self) -> &str {
+        &self.image
+    }
+
+    /// Texture of the atlas image, only set using the loader
+    pub fn base_texture(&self) -> Option<&Asset<Texture>> {
+        self.base_texture.as_ref()
+    }
+
+    /// Frame in pixels of the name given
+    pub fn frame(&self, name: &str) -> Option<&Rect> {
+        self.frames.get(name)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &String> {
+        self.frames.keys()
+    }
+
+    /// Returns the texture of the frame using the atlas image as base
+    pub fn texture(&self, name: &str, base_texture: &Texture) -> Option<Texture> {
+        self.frames
+            .get(name)
+            .map(|r| base_texture.with_frame(r.x, r.y, r.width, r.height))
+    }
+
+    /// Returns the region of the frame using the atlas image as base, without cloning the texture
+    pub fn region<'a>(&self, name: &str, base_texture: &'a Texture) -> Option<TextureRegion<'a>> {
+        self.frames
+            .get(name)
+            .map(|r| TextureRegion::with_frame(base_texture, r.x, r.y, r.width, r.height))
+    }
+
+    /// Returns all the frames as textures using the atlas image as base
+    pub fn textures(&self, base_texture: &Texture) -> HashMap<String, Texture> {
+        self.frames
+            .iter()
+            .map(|(name, r)| {
+                let texture = base_texture.with_frame(r.x, r.y, r.width, r.height);
+                (name.clone(), texture)
+            })
+            .collect()
+    }
 }
 
 #[inline]
@@ -30,12 +131,20 @@ fn atlas_from_bytes(data: &[u8]) -> Result<AtlasRoot, String> {
 
 #[derive(Serialize, Deserialize, Debug)]
 struct AtlasRoot {
-    frames: Vec<AtlasFrame>,
+    frames: AtlasFrames,
     meta: AtlasMeta,
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(untagged)]
+enum AtlasFrames {
+    Array(Vec<AtlasFrame>),
+    Hash(HashMap<String, AtlasFrame>),
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 struct AtlasFrame {
+    #[serde(default)]
     filename: String,
     frame: AtlasRect,
     rotated: bool,
@@ -83,3 +192,70 @@ struct AtlasRect {
     w: i32,
     h: i32,
 }
+
+impl From<AtlasRect> for Rect {
+    fn from(r: AtlasRect) -> Self {
+        Rect {
+            x: r.x as _,
+            y: r.y as _,
+            width: r.w as _,
+            height: r.h as _,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const META: &str = r#""meta": {
+        "app": "test",
+        "version": "1.0",
+        "image": "atlas.png",
+        "format": "RGBA8888",
+        "size": {"w": 64, "h": 64},
+        "scale": "1"
+    }"#;
+
+    const FRAME: &str = r#"{
+        "frame": {"x": 32, "y": 0, "w": 16, "h": 8},
+        "rotated": false,
+        "trimmed": false,
+        "spriteSourceSize": {"x": 0, "y": 0, "w": 16, "h": 8},
+        "sourceSize": {"w": 16, "h": 8}
+    }"#;
+
+    fn expected_frame() -> Rect {
+        Rect {
+            x: 32.0,
+            y: 0.0,
+            width: 16.0,
+            height: 8.0,
+        }
+    }
+
+    #[test]
+    fn parse_hash_format() {
+        let json = format!(r#"{{"frames": {{"block": {FRAME}}}, {META}}}"#);
+        let atlas = TextureAtlas::from_bytes(json.as_bytes()).unwrap();
+        assert_eq!(atlas.image_path(), "atlas.png");
+        assert_eq!(atlas.frame("block"), Some(&expected_frame()));
+    }
+
+    #[test]
+    fn parse_array_format() {
+        let frame = FRAME.replacen('{', r#"{"filename": "block","#, 1);
+        let json = format!(r#"{{"frames": [{frame}], {META}}}"#);
+        let atlas = TextureAtlas::from_bytes(json.as_bytes()).unwrap();
+        assert_eq!(atlas.frame("block"), Some(&expected_frame()));
+    }
+
+    #[test]
+    fn loader_resolves_image_path() {
+        let json = format!(r#"{{"frames": [], {META}}}"#);
+        let atlas = TextureAtlas::from_bytes(json.as_bytes()).unwrap();
+        let path = image_path("assets/sprites/atlas.json", atlas.image_path());
+        let expected = Path::new("assets/sprites").join("atlas.png");
+        assert_eq!(Path::new(&path), expected);
+    }
+}
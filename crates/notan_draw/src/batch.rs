@@ -1,3 +1,7 @@
+use crate::config::BatchGrowth;
+use crate::images::IMAGE_TEXTURE_UNITS;
+use crate::meshes::StaticMesh;
+use crate::texts::SdfStyle;
 use notan_glyph::OwnedSection;
 use notan_graphics::prelude::*;
 use notan_math::{Mat3, Vec3};
@@ -7,16 +11,32 @@ pub(crate) struct TextData {
     pub transform: Mat3,
     pub section: OwnedSection,
     pub alpha: f32,
-    pub count: usize,
+    pub depth: f32,
     pub flip: (bool, bool),
+    pub justify: bool,
+    pub sdf: Option<SdfStyle>,
 }
 
 #[derive(Clone, Debug)]
 pub(crate) enum BatchType {
-    Image { texture: Texture },
-    Pattern { texture: Texture },
+    Image {
+        textures: Vec<Texture>,
+    },
+    Pattern {
+        texture: Texture,
+    },
     Shape,
-    Text { texts: Vec<TextData> },
+    Text {
+        texts: Vec<TextData>,
+    },
+    SdfText {
+        texts: Vec<TextData>,
+    },
+    StaticMesh {
+        mesh: StaticMesh,
+        matrix: Mat3,
+        color: Color,
+    },
 }
 
 #[derive(Clone, Debug)]
@@ -30,6 +50,9 @@ pub(crate) struct Batch {
     pub alpha_mode: Option<BlendMode>,
     pub is_mask: bool,
     pub masking: bool,
+    pub premultiplied_alpha: bool,
+    pub depth_test: bool,
+    pub growth: BatchGrowth,
 }
 
 impl Batch {
@@ -41,34 +64,147 @@ impl Batch {
         matches!(self.typ, BatchType::Text { .. })
     }
 
-    pub fn add(&mut self, indices: &[u32], vertices: &[f32], matrix: Mat3, alpha: f32) {
-        let offset = self.offset();
+    pub fn is_sdf_text(&self) -> bool {
+        matches!(self.typ, BatchType::SdfText { .. })
+    }
+
+    /// Number of vertices stored in the batch
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len() / self.stride()
+    }
+
+    pub fn is_image(&self) -> bool {
+        matches!(self.typ, BatchType::Image { .. })
+    }
+
+    pub fn add(&mut self, indices: &[u32], vertices: &[f32], matrix: Mat3, alpha: f32, depth: f32) {
+        debug_assert!(!self.is_image(), "Images need the index of their texture");
+        self.reserve(vertices.len() / self.offset(), indices.len());
+        self.add_indices(indices);
+
+        vertices.chunks_exact(self.offset()).for_each(|v| {
+            self.push_vertex(v, matrix, alpha, depth);
+        });
+    }
+
+    /// Adds the vertices of an image that uses the batch texture at `texture` index
+    pub fn add_textured(
+        &mut self,
+        indices: &[u32],
+        vertices: &[f32],
+        matrix: Mat3,
+        alpha: f32,
+        depth: f32,
+        texture: usize,
+    ) {
+        self.reserve(vertices.len() / self.offset(), indices.len());
+        self.add_indices(indices);
+
+        vertices.chunks_exact(self.offset()).for_each(|v| {
+            self.push_vertex(v, matrix, alpha, depth);
+            self.vertices.push(texture as _);
+        });
+    }
+
+    /// Adds the vertices of a shape to an image batch, with a negative texture
+    /// index so the painter uses the color without sampling any texture
+    pub fn add_solid(
+        &mut self,
+        indices: &[u32],
+        vertices: &[f32],
+        matrix: Mat3,
+        alpha: f32,
+        depth: f32,
+    ) {
+        debug_assert!(self.is_image(), "Only image batches can contain shapes");
+        self.reserve(vertices.len() / 6, indices.len());
+        self.add_indices(indices);
+
+        vertices.chunks_exact(6).for_each(|v| {
+            let [x, y, r, g, b, a] = [v[0], v[1], v[2], v[3], v[4], v[5]];
+            self.push_vertex(&[x, y, 0.0, 0.0, r, g, b, a], matrix, alpha, depth);
+            self.vertices.push(-1.0); //no texture
+        });
+    }
 
-        //compute indices
-        let last_index = (self.vertices.len() / offset) as u32;
+    /// Turns a shape batch into an image batch, so the images using
+    /// this texture can be added to it without starting a new batch
+    pub fn shapes_to_image(&mut self, texture: Texture) {
+        debug_assert!(self.is_shape(), "Only shape batches can be converted");
+        let shapes = std::mem::take(&mut self.vertices);
+        self.vertices.reserve(shapes.len() / 7 * 10);
+        shapes.chunks_exact(7).for_each(|v| {
+            let [x, y, z, r, g, b, a] = [v[0], v[1], v[2], v[3], v[4], v[5], v[6]];
+            self.vertices.extend([x, y, z, 0.0, 0.0, r, g, b, a, -1.0]);
+        });
+        self.typ = BatchType::Image {
+            textures: vec![texture],
+        };
+    }
+
+    /// Index of the texture in the image batch, the texture is added if there is room for it
+    pub fn texture_index(&mut self, texture: &Texture) -> Option<usize> {
+        let BatchType::Image { textures } = &mut self.typ else {
+            return None;
+        };
+
+        match textures.iter().position(|t| t == texture) {
+            Some(index) => Some(index),
+            // custom pipelines only know about one texture
+            None if self.pipeline.is_none() && textures.len() < IMAGE_TEXTURE_UNITS => {
+                textures.push(texture.clone());
+                Some(textures.len() - 1)
+            }
+            None => None,
+        }
+    }
+
+    /// Makes room for the vertices and indices following the growth strategy
+    pub fn reserve(&mut self, vertices: usize, indices: usize) {
+        let stride = self.stride();
+        self.growth
+            .reserve(&mut self.vertices, vertices * stride, 4 * stride);
+        self.growth.reserve(&mut self.indices, indices, 6);
+    }
+
+    fn add_indices(&mut self, indices: &[u32]) {
+        let last_index = (self.vertices.len() / self.stride()) as u32;
         self.indices.extend(indices.iter().map(|i| i + last_index));
+    }
 
-        //compute vertices
-        vertices
-            .iter()
-            .enumerate()
-            .step_by(offset)
-            .for_each(|(i, _)| {
-                let start = i + 2;
-                let end = i + offset - 1;
-                let xyz = matrix * Vec3::new(vertices[i], vertices[i + 1], 1.0);
-                self.vertices.extend([xyz.x, xyz.y]); //pos
-                self.vertices.extend(&vertices[start..end]); //pipeline attrs and rgb
-                self.vertices.push(vertices[i + offset - 1] * alpha); //alpha
-            });
+    fn push_vertex(&mut self, vertex: &[f32], matrix: Mat3, alpha: f32, depth: f32) {
+        let end = vertex.len() - 1;
+        let a = vertex[end] * alpha;
+        let xyz = matrix * Vec3::new(vertex[0], vertex[1], 1.0);
+        self.vertices.extend([xyz.x, xyz.y, depth]); //pos
+        if self.premultiplied_alpha {
+            let rgb = end - 3;
+            self.vertices.extend(&vertex[2..rgb]); //pipeline attrs
+            self.vertices.extend(vertex[rgb..end].iter().map(|c| c * a)); //rgb
+        } else {
+            self.vertices.extend(&vertex[2..end]); //pipeline attrs and rgb
+        }
+        self.vertices.push(a); //alpha
     }
 
+    /// Floats per vertex of the elements added to the batch
     fn offset(&self) -> usize {
         match &self.typ {
             BatchType::Image { .. } => 8,
             BatchType::Pattern { .. } => 12,
             BatchType::Shape => 6,
             BatchType::Text { .. } => 8,
+            BatchType::SdfText { .. } => 18,
+            // the vertices are already on the gpu
+            BatchType::StaticMesh { .. } => 0,
+        }
+    }
+
+    /// Floats per vertex stored in the batch, with the depth and the image's texture index
+    fn stride(&self) -> usize {
+        match &self.typ {
+            BatchType::Image { .. } => 10,
+            _ => self.offset() + 1,
         }
     }
 }
@@ -0,0 +1,135 @@
+use crate::draw::Draw;
+use crate::transform::{local_to_screen_position, screen_to_local_position};
+use notan_math::{vec2, vec3, Mat3, Mat4, Vec2};
+
+/// Orthographic 2D camera that can be used as the projection of [Draw]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Camera2D {
+    position: Vec2,
+    zoom: f32,
+    rotation: f32,
+    size: Vec2,
+}
+
+impl Camera2D {
+    /// Creates a camera looking at the center of an area with the size given
+    pub fn new(width: f32, height: f32) -> Self {
+        Self {
+            position: vec2(width, height) * 0.5,
+            zoom: 1.0,
+            rotation: 0.0,
+            size: vec2(width, height),
+        }
+    }
+
+    /// Sets the world position that will be at the center of the viewport
+    pub fn set_position(&mut self, x: f32, y: f32) -> &mut Self {
+        self.position = vec2(x, y);
+        self
+    }
+
+    pub fn position(&self) -> Vec2 {
+        self.position
+    }
+
+    /// Sets the zoom level, 1.0 means no zoom
+    pub fn set_zoom(&mut self, zoom: f32) -> &mut Self {
+        self.zoom = zoom;
+        self
+    }
+
+    pub fn zoom(&self) -> f32 {
+        self.zoom
+    }
+
+    /// Sets the rotation of the camera using radians
+    pub fn set_rotation(&mut self, angle: f32) -> &mut Self {
+        self.rotation = angle;
+        self
+    }
+
+    pub fn rotation(&self) -> f32 {
+        self.rotation
+    }
+
+    /// Sets the size of the viewport, usually the size of the screen or the render texture
+    pub fn set_size(&mut self, width: f32, height: f32) -> &mut Self {
+        self.size = vec2(width, height);
+        self
+    }
+
+    pub fn size(&self) -> Vec2 {
+        self.size
+    }
+
+    /// Centers the camera on the target
+    pub fn follow(&mut self, x: f32, y: f32) -> &mut Self {
+        self.set_position(x, y)
+    }
+
+    /// Moves the camera towards the target, `t` is the amount moved between 0.0 and 1.0
+    pub fn follow_smooth(&mut self, x: f32, y: f32, t: f32) -> &mut Self {
+        self.position = self.position.lerp(vec2(x, y), t.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Returns the view matrix, it transforms world positions to viewport positions
+    pub fn view(&self) -> Mat4 {
+        let half = self.size * 0.5;
+        Mat4::from_translation(vec3(half.x, half.y, 0.0))
+            * Mat4::from_rotation_z(-self.rotation)
+            * Mat4::from_scale(vec3(self.zoom, self.zoom, 1.0))
+            * Mat4::from_translation(vec3(-self.position.x, -self.position.y, 0.0))
+    }
+
+    /// Returns the projection to use with [Draw::set_projection]
+    pub fn projection(&self) -> Mat4 {
+        let base = Mat4::orthographic_rh_gl(0.0, self.size.x, self.size.y, 0.0, -1.0, 1.0);
+        base * self.view()
+    }
+
+    /// Converts a position on the viewport to a world position
+    pub fn screen_to_world(&self, x: f32, y: f32) -> Vec2 {
+        screen_to_local_position(
+            vec2(x, y),
+            self.size,
+            self.projection().inverse(),
+            Mat3::IDENTITY,
+        )
+    }
+
+    /// Converts a world position to a position on the viewport
+    pub fn world_to_screen(&self, x: f32, y: f32) -> Vec2 {
+        local_to_screen_position(vec2(x, y), self.size, self.projection(), Mat3::IDENTITY)
+    }
+
+    /// Sets the camera's projection on the draw
+    pub fn apply(&self, draw: &mut Draw) {
+        draw.set_projection(Some(self.projection()));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Camera2D;
+    use notan_math::vec2;
+
+    #[test]
+    fn world_screen_conversion() {
+        let mut camera = Camera2D::new(800.0, 600.0);
+
+        let pos = camera.world_to_screen(100.0, 100.0);
+        assert!(pos.distance(vec2(100.0, 100.0)) < 0.01);
+
+        camera.set_position(1000.0, 1000.0).set_zoom(2.0);
+
+        let pos = camera.world_to_screen(1000.0, 1000.0);
+        assert!(pos.distance(vec2(400.0, 300.0)) < 0.01);
+
+        let pos = camera.world_to_screen(1010.0, 990.0);
+        assert!(pos.distance(vec2(420.0, 280.0)) < 0.01);
+
+        let world = camera.screen_to_world(420.0, 280.0);
+        assert!(world.distance(vec2(1010.0, 990.0)) < 0.01);
+    }
+}
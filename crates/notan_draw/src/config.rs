@@ -20,3 +20,61 @@ where
         })
     }
 }
+
+/// How the batch buffers grow when an element doesn't fit in them
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BatchGrowth {
+    /// Doubles the capacity, fewer reallocations but it can waste memory
+    #[default]
+    Double,
+    /// Grows by the number of quads given
+    Linear(usize),
+    /// Grows just what the element needs
+    Exact,
+}
+
+impl BatchGrowth {
+    /// Makes room for `additional` items, `quad_len` is the number of items used by a quad
+    pub(crate) fn reserve<T>(self, buffer: &mut Vec<T>, additional: usize, quad_len: usize) {
+        if buffer.capacity() - buffer.len() >= additional {
+            return;
+        }
+
+        match self {
+            BatchGrowth::Double => buffer.reserve(additional),
+            BatchGrowth::Linear(quads) => buffer.reserve_exact(additional.max(quads * quad_len)),
+            BatchGrowth::Exact => buffer.reserve_exact(additional),
+        }
+    }
+}
+
+/// Sizes of the buffers used to batch the elements of a [Draw](crate::Draw).
+/// A quad counts as 4 vertices and 6 indices, the rest of shapes are measured by their indices.
+/// Small values save memory on constrained platforms, and big ones avoid
+/// reallocating the buffers while drawing heavy scenes
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BatchConfig {
+    /// Quads reserved when a batch is created
+    pub initial_quads: usize,
+    /// Max quads in a batch, a new batch is started when it's full
+    pub max_quads: Option<usize>,
+    /// How the batches grow once the initial capacity is used
+    pub growth: BatchGrowth,
+}
+
+impl BatchConfig {
+    pub fn with_initial_quads(mut self, quads: usize) -> Self {
+        self.initial_quads = quads;
+        self
+    }
+
+    pub fn with_max_quads(mut self, quads: usize) -> Self {
+        self.max_quads = Some(quads);
+        self
+    }
+
+    pub fn with_growth(mut self, growth: BatchGrowth) -> Self {
+        self.growth = growth;
+        self
+    }
+}
@@ -25,6 +25,7 @@ pub trait DrawCustomPipeline {
     fn shape_pipeline(&mut self) -> CustomPipelineBuilder;
     fn pattern_pipeline(&mut self) -> CustomPipelineBuilder;
     fn text_pipeline(&mut self) -> CustomPipelineBuilder;
+    fn sdf_text_pipeline(&mut self) -> CustomPipelineBuilder;
 }
 
 impl DrawCustomPipeline for Draw {
@@ -43,6 +44,10 @@ impl DrawCustomPipeline for Draw {
     fn text_pipeline(&mut self) -> CustomPipelineBuilder {
         CustomPipelineBuilder::new(self, CustomPipelineType::Text)
     }
+
+    fn sdf_text_pipeline(&mut self) -> CustomPipelineBuilder {
+        CustomPipelineBuilder::new(self, CustomPipelineType::SdfText)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -51,6 +56,7 @@ enum CustomPipelineType {
     Shape,
     Pattern,
     Text,
+    SdfText,
 }
 
 pub struct CustomPipelineBuilder<'a> {
@@ -132,5 +138,6 @@ fn get_custom_pipeline(draw: &mut Draw, typ: CustomPipelineType) -> &mut CustomP
         CustomPipelineType::Shape => &mut draw.shape_pipeline,
         CustomPipelineType::Pattern => &mut draw.pattern_pipeline,
         CustomPipelineType::Text => &mut draw.text_pipeline,
+        CustomPipelineType::SdfText => &mut draw.sdf_text_pipeline,
     }
 }
@@ -1,17 +1,28 @@
 use crate::batch::*;
+use crate::config::BatchConfig;
 pub(crate) use crate::custom_pipeline::CustomPipeline;
+use crate::images::IMAGE_TEXTURE_UNITS;
+use crate::list::{DrawCommand, RecordedCommand};
+use crate::meshes::StaticMesh;
+use crate::stats::{BatchBreak, DrawStats};
+use crate::texts::SdfStyle;
 use crate::transform::Transform;
 use crate::{local_to_screen_position, screen_to_local_position};
 use notan_glyph::Section;
 use notan_graphics::color::Color;
 use notan_graphics::prelude::*;
-use notan_math::{vec2, Mat3, Mat4, Rect, Vec2};
+use notan_math::{vec2, vec3, vec4, Mat3, Mat4, Rect, Vec2, Vec4Swizzles};
 use notan_text::{Calculator, Font};
 
 #[derive(Debug)]
 pub struct Draw {
-    pub(crate) clear_color: Option<Color>,
+    pub(crate) clear_options: ClearOptions,
     alpha: f32,
+    depth: f32,
+    depth_test: bool,
+    culling: bool,
+    batch_config: BatchConfig,
+    stats: DrawStats,
     transform: Transform,
     base_projection: Mat4,
     projection: Option<Mat4>,
@@ -19,24 +30,50 @@ pub struct Draw {
     size: (f32, f32),
     blend_mode: Option<BlendMode>,
     alpha_mode: Option<BlendMode>,
+    premultiplied_alpha: bool,
     pub(crate) batches: Vec<Batch>,
     pub(crate) current_batch: Option<Batch>,
     pub(crate) shape_pipeline: CustomPipeline,
     pub(crate) image_pipeline: CustomPipeline,
     pub(crate) pattern_pipeline: CustomPipeline,
     pub(crate) text_pipeline: CustomPipeline,
+    pub(crate) sdf_text_pipeline: CustomPipeline,
     pub(crate) text_batch_indices: Option<Vec<usize>>,
     pub(crate) masking: bool,
     pub(crate) needs_to_clean_stencil: bool,
+    pub(crate) needs_to_clean_depth: bool,
     pub(crate) glyphs_calculator: Calculator,
+    pub(crate) last_bitmap_text_bounds: Option<Rect>,
     mask_batches: Option<Vec<Batch>>,
+    states: Vec<DrawState>,
+    pub(crate) render_targets: Vec<(RenderTexture, Draw)>,
+    pub(crate) recording: Option<Vec<RecordedCommand>>,
+}
+
+/// Values saved by [Draw::push_state] to be restored later
+#[derive(Debug, Clone)]
+struct DrawState {
+    alpha: f32,
+    depth: f32,
+    depth_test: bool,
+    blend_mode: Option<BlendMode>,
+    alpha_mode: Option<BlendMode>,
+    premultiplied_alpha: bool,
+    culling: bool,
+    transform: Transform,
+    projection: Option<Mat4>,
 }
 
 impl Clone for Draw {
     fn clone(&self) -> Self {
         Self {
             alpha: self.alpha,
-            clear_color: self.clear_color,
+            depth: self.depth,
+            depth_test: self.depth_test,
+            culling: self.culling,
+            batch_config: self.batch_config,
+            stats: self.stats,
+            clear_options: self.clear_options,
             batches: self.batches.clone(),
             current_batch: self.current_batch.clone(),
             transform: self.transform.clone(),
@@ -46,15 +83,22 @@ impl Clone for Draw {
             size: self.size,
             blend_mode: self.blend_mode,
             alpha_mode: self.alpha_mode,
+            premultiplied_alpha: self.premultiplied_alpha,
             shape_pipeline: self.shape_pipeline.clone(),
             image_pipeline: self.image_pipeline.clone(),
             pattern_pipeline: self.pattern_pipeline.clone(),
             text_pipeline: self.text_pipeline.clone(),
+            sdf_text_pipeline: self.sdf_text_pipeline.clone(),
             masking: self.masking,
             needs_to_clean_stencil: self.needs_to_clean_stencil,
+            needs_to_clean_depth: self.needs_to_clean_depth,
             text_batch_indices: self.text_batch_indices.clone(),
             glyphs_calculator: Calculator::new(),
+            last_bitmap_text_bounds: self.last_bitmap_text_bounds,
             mask_batches: self.mask_batches.clone(),
+            states: self.states.clone(),
+            render_targets: self.render_targets.clone(),
+            recording: self.recording.clone(),
         }
     }
 }
@@ -66,7 +110,12 @@ impl Draw {
 
         Draw {
             alpha: 1.0,
-            clear_color: None,
+            depth: 0.0,
+            depth_test: false,
+            culling: false,
+            batch_config: BatchConfig::default(),
+            stats: DrawStats::default(),
+            clear_options: ClearOptions::none(),
             batches: vec![],
             current_batch: None,
             transform: Transform::new(),
@@ -76,18 +125,32 @@ impl Draw {
             size: (width as _, height as _),
             blend_mode: Some(BlendMode::NORMAL),
             alpha_mode: None,
+            premultiplied_alpha: false,
             shape_pipeline: Default::default(),
             image_pipeline: Default::default(),
             pattern_pipeline: Default::default(),
             text_pipeline: Default::default(),
+            sdf_text_pipeline: Default::default(),
             masking: false,
             needs_to_clean_stencil: false,
+            needs_to_clean_depth: false,
             text_batch_indices: None,
             glyphs_calculator: Calculator::new(),
+            last_bitmap_text_bounds: None,
             mask_batches: None,
+            states: vec![],
+            render_targets: vec![],
+            recording: None,
         }
     }
 
+    /// Creates a draw that sizes its batches using the config given
+    pub fn with_batch_config(width: u32, height: u32, config: BatchConfig) -> Self {
+        let mut draw = Self::new(width, height);
+        draw.batch_config = config;
+        draw
+    }
+
     /// Returns the global matrix
     #[inline]
     pub fn matrix(&self) -> &Mat3 {
@@ -99,6 +162,7 @@ impl Draw {
             //Move the current batch to the queue
             if let Some(b) = self.current_batch.take() {
                 self.batches.push(b);
+                self.stats.breaks.count(BatchBreak::Mask);
             }
 
             self.batches.extend(mask_batches.iter().map(|batch| {
@@ -131,6 +195,7 @@ impl Draw {
                 //Move the current batch to the queue
                 if let Some(b) = self.current_batch.take() {
                     self.batches.push(b);
+                    self.stats.breaks.count(BatchBreak::Mask);
                 }
             }
             _ => {
@@ -180,6 +245,136 @@ impl Draw {
         self.alpha
     }
 
+    /// Sets the z value of the next elements, between -1.0 and 1.0.
+    /// With [Draw::set_depth_test] enabled the higher values are drawn on top
+    pub fn set_depth(&mut self, depth: f32) {
+        self.depth = depth;
+    }
+
+    pub fn depth(&self) -> f32 {
+        self.depth
+    }
+
+    /// Enables the depth test for the next elements, so they are sorted by the GPU
+    /// using their depth instead of the order they were drawn. The elements with the
+    /// same depth keep the draw order. Transparent pixels write depth too, so semi
+    /// transparent elements should be drawn after the opaque ones.
+    /// Render textures need a depth buffer to use it
+    pub fn set_depth_test(&mut self, enabled: bool) {
+        self.depth_test = enabled;
+    }
+
+    pub fn depth_test(&self) -> bool {
+        self.depth_test
+    }
+
+    /// Discards the shapes, images and patterns that are outside of the projection
+    /// before adding them to the batches. Circles, ellipses and rounded rectangles
+    /// are discarded before they are tessellated
+    pub fn set_culling(&mut self, enabled: bool) {
+        self.culling = enabled;
+    }
+
+    pub fn culling(&self) -> bool {
+        self.culling
+    }
+
+    /// Sets the sizes of the batches created from now on
+    pub fn set_batch_config(&mut self, config: BatchConfig) {
+        self.batch_config = config;
+    }
+
+    pub fn batch_config(&self) -> BatchConfig {
+        self.batch_config
+    }
+
+    /// Checks if any part of the bounds transformed by the global matrix and
+    /// the `matrix` given is inside of the projection
+    pub fn is_visible(&self, bounds: Rect, matrix: Option<&Mat3>) -> bool {
+        let matrix = match matrix {
+            Some(m) => *self.transform.matrix() * *m,
+            None => *self.transform.matrix(),
+        };
+
+        let projection = self.projection();
+        let corners = [
+            (bounds.min_x(), bounds.min_y()),
+            (bounds.max_x(), bounds.min_y()),
+            (bounds.max_x(), bounds.max_y()),
+            (bounds.min_x(), bounds.max_y()),
+        ];
+
+        let (mut min, mut max) = (Vec2::splat(f32::MAX), Vec2::splat(f32::MIN));
+        for (x, y) in corners {
+            let pos = matrix * vec3(x, y, 1.0);
+            let clip = projection * vec4(pos.x, pos.y, self.depth, 1.0);
+
+            // points behind a perspective camera cannot be projected, keep the element
+            if clip.w <= 0.0 {
+                return true;
+            }
+
+            let ndc = clip.xy() / clip.w;
+            min = min.min(ndc);
+            max = max.max(ndc);
+        }
+
+        min.x <= 1.0 && max.x >= -1.0 && min.y <= 1.0 && max.y >= -1.0
+    }
+
+    /// Checks if the element must be discarded because culling is enabled and it's not visible
+    pub(crate) fn cull(&mut self, bounds: Rect, matrix: Option<&Mat3>) -> bool {
+        // the projection of a draw list is not the one used to render it
+        let culled = self.culling && self.recording.is_none() && !self.is_visible(bounds, matrix);
+        if culled {
+            self.stats.culled += 1;
+        }
+        culled
+    }
+
+    /// Like [Draw::cull] using the bounds of the element's vertices
+    fn cull_vertices<I: DrawInfo>(&mut self, info: &I, offset: usize) -> bool {
+        if !self.culling || self.recording.is_some() {
+            return false;
+        }
+
+        let (mut min, mut max) = (Vec2::splat(f32::MAX), Vec2::splat(f32::MIN));
+        info.vertices().chunks_exact(offset).for_each(|v| {
+            let pos = vec2(v[0], v[1]);
+            min = min.min(pos);
+            max = max.max(pos);
+        });
+
+        if min.x > max.x {
+            self.stats.culled += 1;
+            return true;
+        }
+
+        let size = max - min;
+        let bounds = Rect {
+            x: min.x,
+            y: min.y,
+            width: size.x,
+            height: size.y,
+        };
+        self.cull(bounds, *info.transform())
+    }
+
+    /// Returns the number of elements, batches, vertices and why the batches were split
+    pub fn stats(&self) -> DrawStats {
+        let batches = self.batches.iter().chain(self.current_batch.as_ref());
+        let (vertices, indices) = batches.clone().fold((0, 0), |(v, i), b| {
+            (v + b.vertex_count(), i + b.indices.len())
+        });
+
+        DrawStats {
+            batches: batches.count(),
+            vertices,
+            indices,
+            ..self.stats
+        }
+    }
+
     pub fn blend_mode(&self) -> Option<BlendMode> {
         self.blend_mode
     }
@@ -196,15 +391,127 @@ impl Draw {
         self.alpha_mode = mode;
     }
 
+    pub fn premultiplied_alpha(&self) -> bool {
+        self.premultiplied_alpha
+    }
+
+    /// Treats the textures of images and patterns as premultiplied alpha, the colors are
+    /// premultiplied too and the normal blend mode is replaced by [BlendMode::OVER].
+    /// Textures created with premultiplied alpha are always treated this way
+    pub fn set_premultiplied_alpha(&mut self, enabled: bool) {
+        self.premultiplied_alpha = enabled;
+    }
+
+    fn is_premultiplied(&self, typ: &BatchType) -> bool {
+        match typ {
+            BatchType::Image { textures } => {
+                self.premultiplied_alpha || textures.iter().any(|t| t.is_premultiplied_alpha())
+            }
+            BatchType::Pattern { texture } => {
+                self.premultiplied_alpha || texture.is_premultiplied_alpha()
+            }
+            _ => false,
+        }
+    }
+
     pub fn transform(&mut self) -> &mut Transform {
         &mut self.transform
     }
 
+    /// Saves the alpha, depth, blending modes, premultiplied alpha, culling, transform stack
+    /// and projection so they can be restored later using [Draw::pop_state]
+    pub fn push_state(&mut self) {
+        self.states.push(DrawState {
+            alpha: self.alpha,
+            depth: self.depth,
+            depth_test: self.depth_test,
+            blend_mode: self.blend_mode,
+            alpha_mode: self.alpha_mode,
+            premultiplied_alpha: self.premultiplied_alpha,
+            culling: self.culling,
+            transform: self.transform.clone(),
+            projection: self.projection,
+        });
+    }
+
+    /// Restores the last state saved with [Draw::push_state]
+    pub fn pop_state(&mut self) {
+        match self.states.pop() {
+            Some(state) => {
+                self.alpha = state.alpha;
+                self.depth = state.depth;
+                self.depth_test = state.depth_test;
+                self.blend_mode = state.blend_mode;
+                self.alpha_mode = state.alpha_mode;
+                self.premultiplied_alpha = state.premultiplied_alpha;
+                self.culling = state.culling;
+                self.transform = state.transform;
+                self.set_projection(state.projection);
+            }
+            None => {
+                #[cfg(debug_assertions)]
+                {
+                    log::warn!("'draw.pop_state()' called without a previous 'draw.push_state()'");
+                }
+            }
+        }
+    }
+
     pub fn clear(&mut self, color: Color) {
-        self.clear_color = Some(color);
+        self.clear_options.color = Some(color);
+    }
+
+    /// Clears the depth buffer with the value given before drawing
+    pub fn clear_depth(&mut self, depth: f32) {
+        self.clear_options.depth = Some(depth);
+    }
+
+    /// Clears the stencil buffer with the value given before drawing
+    pub fn clear_stencil(&mut self, stencil: i32) {
+        self.clear_options.stencil = Some(stencil);
+    }
+
+    /// Sets which planes are cleared before drawing and their values. The depth and stencil
+    /// buffers are still cleared when the depth test or masks need it if they are not set
+    pub fn set_clear_options(&mut self, options: ClearOptions) {
+        self.clear_options = options;
+    }
+
+    pub fn clear_options(&self) -> ClearOptions {
+        self.clear_options
+    }
+
+    /// Draws to the render texture using a new [Draw] with the texture's size.
+    /// The render textures are drawn before this draw, in the same order, so
+    /// their textures can be used by it
+    pub fn render_to<F: FnOnce(&mut Draw)>(&mut self, target: &RenderTexture, f: F) {
+        let (width, height) = target.size();
+        let mut draw = Draw::with_batch_config(width as _, height as _, self.batch_config);
+        f(&mut draw);
+        self.render_targets.push((target.clone(), draw));
     }
 
     fn add_batch<I, F1, F2>(&mut self, info: &I, is_diff_type: F1, create_type: F2)
+    where
+        I: DrawInfo,
+        F1: Fn(&Batch, &I) -> bool,
+        F2: Fn(&I) -> BatchType,
+    {
+        let matrix = self.prepare_batch(info, is_diff_type, create_type);
+        if let Some(b) = &mut self.current_batch {
+            b.add(
+                info.indices(),
+                info.vertices(),
+                matrix,
+                self.alpha,
+                self.depth,
+            );
+        }
+    }
+
+    /// Starts a new batch if the element cannot be added to the current one
+    /// and returns the matrix to transform the element's vertices
+    fn prepare_batch<I, F1, F2>(&mut self, info: &I, is_diff_type: F1, create_type: F2) -> Mat3
     where
         I: DrawInfo,
         F1: Fn(&Batch, &I) -> bool,
@@ -215,60 +522,142 @@ impl Draw {
             self.process_mask_batches();
         }
 
-        let needs_new_batch = needs_new_batch(self, info, is_diff_type);
-        if needs_new_batch {
+        self.stats.elements += 1;
+        self.last_bitmap_text_bounds = None;
+
+        let batch_break = batch_break(self, info, is_diff_type, &create_type);
+        if let Some(reason) = batch_break {
+            self.stats.breaks.count(reason);
             if let Some(old) = self.current_batch.take() {
                 self.batches.push(old);
             }
 
             let typ = create_type(info);
-            let custom = match typ {
-                BatchType::Image { .. } => &self.image_pipeline,
-                BatchType::Pattern { .. } => &self.pattern_pipeline,
-                BatchType::Shape => &self.shape_pipeline,
-                BatchType::Text { .. } => &self.text_pipeline,
-            };
+            let (pipeline, uniform_buffers) = self
+                .custom_pipeline(&typ)
+                .map(|c| (c.pipeline.clone(), c.uniforms.clone()))
+                .unwrap_or_default();
 
             // blending modes, by priority:
             // 1. element draw
             // 2. global draw blending
             // 3. in some cases (like text), default mode
-            let cbm = info.blend_mode().or(self.blend_mode);
+            let premultiplied_alpha = self.is_premultiplied(&typ);
+            let cbm = color_blend_mode(info.blend_mode().or(self.blend_mode), premultiplied_alpha);
             let abm = info.alpha_mode().or(self.alpha_mode).or(match typ {
                 // text is drawn from a RT we need to set Over alpha by default
-                BatchType::Text { .. } => Some(BlendMode::OVER),
+                BatchType::Text { .. } | BatchType::SdfText { .. } => Some(BlendMode::OVER),
                 _ => None,
             });
 
-            self.current_batch = Some(Batch {
+            if self.depth_test {
+                self.needs_to_clean_depth = true;
+            }
+
+            let mut batch = Batch {
                 typ: create_type(info),
                 vertices: vec![],
                 indices: vec![],
-                pipeline: custom.pipeline.clone(),
-                uniform_buffers: custom.uniforms.clone(),
+                pipeline,
+                uniform_buffers,
                 blend_mode: cbm,
                 alpha_mode: abm,
                 is_mask: false,
                 masking: self.masking,
-            });
+                premultiplied_alpha,
+                depth_test: self.depth_test,
+                growth: self.batch_config.growth,
+            };
+
+            // texts and meshes don't store vertices in the batch
+            let quads = self.batch_config.initial_quads;
+            if matches!(
+                batch.typ,
+                BatchType::Image { .. } | BatchType::Shape | BatchType::Pattern { .. }
+            ) {
+                batch.reserve(quads * 4, quads * 6);
+            }
+
+            self.current_batch = Some(batch);
         }
 
         let global_matrix = *self.transform.matrix();
-        let matrix = match *info.transform() {
+        match *info.transform() {
             Some(m) => global_matrix * *m,
             _ => global_matrix,
-        };
+        }
+    }
 
-        if let Some(b) = &mut self.current_batch {
-            b.add(info.indices(), info.vertices(), matrix, self.alpha);
+    /// Custom pipeline set by the user for the type of batch
+    fn custom_pipeline(&self, typ: &BatchType) -> Option<&CustomPipeline> {
+        match typ {
+            BatchType::Image { .. } => Some(&self.image_pipeline),
+            BatchType::Pattern { .. } => Some(&self.pattern_pipeline),
+            BatchType::Shape => Some(&self.shape_pipeline),
+            BatchType::Text { .. } => Some(&self.text_pipeline),
+            BatchType::SdfText { .. } => Some(&self.sdf_text_pipeline),
+            BatchType::StaticMesh { .. } => None,
         }
     }
 
+    /// Shapes can share the batch with images when both use the default pipelines,
+    /// the image painter draws them without sampling the texture
+    fn can_mix_shapes_and_images(&self) -> bool {
+        self.shape_pipeline.pipeline.is_none() && self.image_pipeline.pipeline.is_none()
+    }
+
+    /// Saves the element instead of batching it when the draw belongs to a [DrawList](crate::DrawList)
+    fn record<I: DrawInfo>(&mut self, info: &I, command: impl FnOnce() -> DrawCommand) -> bool {
+        let Some(commands) = &mut self.recording else {
+            return false;
+        };
+
+        let global_matrix = *self.transform.matrix();
+        commands.push(RecordedCommand {
+            command: command(),
+            matrix: match *info.transform() {
+                Some(m) => global_matrix * *m,
+                _ => global_matrix,
+            },
+            alpha: self.alpha,
+            depth: self.depth,
+            blend_mode: info.blend_mode().or(self.blend_mode),
+            alpha_mode: info.alpha_mode().or(self.alpha_mode),
+        });
+
+        true
+    }
+
     pub fn add_image(&mut self, info: &ImageInfo) {
+        let command = || DrawCommand::Image {
+            texture: info.texture.clone(),
+            vertices: info.vertices.to_vec(),
+            indices: info.indices.to_vec(),
+        };
+        if self.record(info, command) {
+            return;
+        }
+
+        if self.cull_vertices(info, 8) {
+            return;
+        }
+
+        let premultiplied = self.premultiplied_alpha || info.texture.is_premultiplied_alpha();
+        let can_mix = self.can_mix_shapes_and_images() && !premultiplied;
+
         let is_diff_type = |b: &Batch, i: &ImageInfo| {
             match &b.typ {
-                //different texture
-                BatchType::Image { texture } => texture != i.texture,
+                //the texture is already in the batch, or there is room to add it
+                BatchType::Image { textures } => {
+                    let has_texture = textures.contains(i.texture);
+                    let has_room = b.pipeline.is_none()
+                        && textures.len() < IMAGE_TEXTURE_UNITS
+                        && b.premultiplied_alpha == premultiplied;
+                    !(has_texture || has_room)
+                }
+
+                //shapes are converted to images
+                BatchType::Shape => !can_mix,
 
                 //different batch type
                 _ => true,
@@ -276,20 +665,68 @@ impl Draw {
         };
 
         let create_type = |i: &ImageInfo| BatchType::Image {
-            texture: i.texture.clone(),
+            textures: vec![i.texture.clone()],
         };
 
-        self.add_batch(info, is_diff_type, create_type);
+        let matrix = self.prepare_batch(info, is_diff_type, create_type);
+        if let Some(b) = &mut self.current_batch {
+            if b.is_shape() {
+                b.shapes_to_image(info.texture.clone());
+            }
+
+            if let Some(index) = b.texture_index(info.texture) {
+                let (indices, vertices) = (info.indices, info.vertices);
+                b.add_textured(indices, vertices, matrix, self.alpha, self.depth, index);
+            }
+        }
     }
 
     pub fn add_shape(&mut self, info: &ShapeInfo) {
-        let is_diff_type = |b: &Batch, _: &ShapeInfo| !b.is_shape();
+        let command = || DrawCommand::Shape {
+            vertices: info.vertices.to_vec(),
+            indices: info.indices.to_vec(),
+        };
+        if self.record(info, command) {
+            return;
+        }
+
+        if self.cull_vertices(info, 6) {
+            return;
+        }
+
+        let can_mix = self.can_mix_shapes_and_images();
+        let is_diff_type = |b: &Batch, _: &ShapeInfo| match &b.typ {
+            BatchType::Shape => false,
+            BatchType::Image { .. } => !can_mix || b.premultiplied_alpha,
+            _ => true,
+        };
         let create_type = |_: &ShapeInfo| BatchType::Shape;
 
-        self.add_batch(info, is_diff_type, create_type);
+        let matrix = self.prepare_batch(info, is_diff_type, create_type);
+        if let Some(b) = &mut self.current_batch {
+            let (indices, vertices) = (info.indices, info.vertices);
+            if b.is_image() {
+                b.add_solid(indices, vertices, matrix, self.alpha, self.depth);
+            } else {
+                b.add(indices, vertices, matrix, self.alpha, self.depth);
+            }
+        }
     }
 
     pub fn add_pattern(&mut self, info: &ImageInfo) {
+        let command = || DrawCommand::Pattern {
+            texture: info.texture.clone(),
+            vertices: info.vertices.to_vec(),
+            indices: info.indices.to_vec(),
+        };
+        if self.record(info, command) {
+            return;
+        }
+
+        if self.cull_vertices(info, 12) {
+            return;
+        }
+
         let is_diff_type = |b: &Batch, i: &ImageInfo| {
             match &b.typ {
                 //different texture
@@ -307,16 +744,62 @@ impl Draw {
         self.add_batch(info, is_diff_type, create_type);
     }
 
+    pub fn add_static_mesh(&mut self, info: &StaticMeshInfo) {
+        let command = || DrawCommand::StaticMesh {
+            mesh: info.mesh.clone(),
+            color: info.color,
+        };
+        if self.record(info, command) {
+            return;
+        }
+
+        // each mesh has its own buffers and uniforms
+        let is_diff_type = |_: &Batch, _: &StaticMeshInfo| true;
+        let create_type = |i: &StaticMeshInfo| BatchType::StaticMesh {
+            mesh: i.mesh.clone(),
+            matrix: Mat3::IDENTITY,
+            color: i.color,
+        };
+
+        let matrix = self.prepare_batch(info, is_diff_type, create_type);
+        if let Some(b) = &mut self.current_batch {
+            if let BatchType::StaticMesh {
+                matrix: m, color, ..
+            } = &mut b.typ
+            {
+                *m = matrix;
+                color.a *= self.alpha;
+            }
+        }
+    }
+
     pub fn add_text(&mut self, info: &TextInfo) {
-        let is_diff_type = |b: &Batch, _: &TextInfo| !b.is_text();
-        let create_type = |_: &TextInfo| BatchType::Text { texts: vec![] };
+        let command = || DrawCommand::Text {
+            section: info.section.to_owned(),
+            font: *info.font,
+            flip: info.flip,
+            justify: info.justify,
+            sdf: info.sdf,
+        };
+        if self.record(info, command) {
+            return;
+        }
+
+        let is_diff_type = |b: &Batch, i: &TextInfo| match i.sdf {
+            Some(_) => !b.is_sdf_text(),
+            None => !b.is_text(),
+        };
+        let create_type = |i: &TextInfo| match i.sdf {
+            Some(_) => BatchType::SdfText { texts: vec![] },
+            None => BatchType::Text { texts: vec![] },
+        };
 
         self.add_batch(info, is_diff_type, create_type);
 
         if let Some(b) = &mut self.current_batch {
             // vertices and indices are calculated before the flush to the gpu,
             // so we need to store the text until that time
-            if let BatchType::Text { texts } = &mut b.typ {
+            if let BatchType::Text { texts } | BatchType::SdfText { texts } = &mut b.typ {
                 let global_matrix = *self.transform.matrix();
                 let matrix = match *info.transform() {
                     Some(m) => global_matrix * *m,
@@ -327,8 +810,10 @@ impl Draw {
                     section: info.section.to_owned(),
                     transform: matrix,
                     alpha: self.alpha,
-                    count: info.count,
+                    depth: self.depth,
                     flip: info.flip,
+                    justify: info.justify,
+                    sdf: info.sdf,
                 });
             }
         }
@@ -341,10 +826,17 @@ impl Draw {
     /// Get the bounds of the last text immediately after draw it
     /// The bounds doesn't take in account the Transformation matrix
     pub fn last_text_bounds(&mut self) -> Rect {
+        if let Some(bounds) = self.last_bitmap_text_bounds {
+            return bounds;
+        }
+
         if let Some(batch) = &self.current_batch {
-            if let BatchType::Text { texts } = &batch.typ {
+            if let BatchType::Text { texts } | BatchType::SdfText { texts } = &batch.typ {
                 if let Some(text) = texts.last() {
-                    return self.glyphs_calculator.bounds(&text.section.to_borrowed());
+                    // the layout used to draw it, justified or shaped
+                    return self
+                        .glyphs_calculator
+                        .bounds_custom_layout(&text.section.to_borrowed(), &text.layout());
                 }
             }
         }
@@ -359,6 +851,8 @@ impl Draw {
         Rect::default()
     }
 
+    /// Converts a screen position (like the mouse position) to the space used to draw,
+    /// inverting the projection and the matrix on top of the transform stack
     pub fn screen_to_world_position(&mut self, screen_x: f32, screen_y: f32) -> Vec2 {
         let inverse = *self
             .inverse_projection
@@ -368,9 +862,11 @@ impl Draw {
         screen_to_local_position(vec2(screen_x, screen_y), self.size.into(), inverse, view)
     }
 
-    pub fn world_to_screen_position(&mut self, world_x: f32, world_y: f32) -> Vec2 {
+    /// Converts a position in the space used to draw to a screen position,
+    /// applying the projection and the matrix on top of the transform stack
+    pub fn world_to_screen_position(&self, world_x: f32, world_y: f32) -> Vec2 {
         let projection = self.projection();
-        let view = *self.transform().matrix();
+        let view = *self.transform.matrix();
         local_to_screen_position(vec2(world_x, world_y), self.size.into(), projection, view)
     }
 }
@@ -446,14 +942,46 @@ impl DrawInfo for ShapeInfo<'_> {
     }
 }
 
+/// Information to render the static mesh
+pub struct StaticMeshInfo<'a> {
+    pub mesh: &'a StaticMesh,
+    pub transform: Option<&'a Mat3>,
+    pub color: Color,
+    pub blend_mode: Option<BlendMode>,
+    pub alpha_mode: Option<BlendMode>,
+}
+
+impl DrawInfo for StaticMeshInfo<'_> {
+    fn transform(&self) -> &Option<&Mat3> {
+        &self.transform
+    }
+
+    fn vertices(&self) -> &[f32] {
+        &[]
+    }
+
+    fn indices(&self) -> &[u32] {
+        &[]
+    }
+
+    fn blend_mode(&self) -> Option<BlendMode> {
+        self.blend_mode
+    }
+
+    fn alpha_mode(&self) -> Option<BlendMode> {
+        self.alpha_mode
+    }
+}
+
 pub struct TextInfo<'a> {
-    pub count: usize,
     pub transform: Option<&'a Mat3>,
     pub section: &'a Section<'a>,
     pub font: &'a Font,
     pub blend_mode: Option<BlendMode>,
     pub alpha_mode: Option<BlendMode>,
     pub flip: (bool, bool),
+    pub justify: bool,
+    pub sdf: Option<SdfStyle>,
 }
 
 impl DrawInfo for TextInfo<'_> {
@@ -478,50 +1006,171 @@ impl DrawInfo for TextInfo<'_> {
     }
 }
 
-fn needs_new_batch<I: DrawInfo, F: Fn(&Batch, &I) -> bool>(
+/// Premultiplied colors are already multiplied by the alpha, so the normal mode would do it twice
+fn color_blend_mode(mode: Option<BlendMode>, premultiplied_alpha: bool) -> Option<BlendMode> {
+    match mode {
+        Some(BlendMode::NORMAL) if premultiplied_alpha => Some(BlendMode::OVER),
+        _ => mode,
+    }
+}
+
+/// Returns why the element needs a new batch, or None if it can be added to the current one
+fn batch_break<I, F1, F2>(
     draw: &Draw,
     info: &I,
-    is_diff_type: F,
-) -> bool {
+    is_diff_type: F1,
+    create_type: F2,
+) -> Option<BatchBreak>
+where
+    I: DrawInfo,
+    F1: Fn(&Batch, &I) -> bool,
+    F2: Fn(&I) -> BatchType,
+{
     match &draw.current_batch {
-        None => true, // no previous batch, so we need a new one
+        None => Some(BatchBreak::First), // no previous batch, so we need a new one
         Some(b) => {
             // if the current and the new batch type are different
             if is_diff_type(b, info) {
-                return true;
+                let texture_change = matches!(
+                    (&b.typ, create_type(info)),
+                    (BatchType::Image { .. }, BatchType::Image { .. })
+                        | (BatchType::Pattern { .. }, BatchType::Pattern { .. })
+                );
+                return Some(if texture_change {
+                    BatchBreak::Texture
+                } else {
+                    BatchBreak::ElementType
+                });
             }
 
             // we need to check the custom pipeline to see if it's different
-            let custom = match b.typ {
-                BatchType::Image { .. } => &draw.image_pipeline,
-                BatchType::Pattern { .. } => &draw.pattern_pipeline,
-                BatchType::Shape => &draw.shape_pipeline,
-                BatchType::Text { .. } => &draw.text_pipeline,
-            };
+            let custom = draw.custom_pipeline(&b.typ);
+            if b.pipeline.as_ref() != custom.and_then(|c| c.pipeline.as_ref()) {
+                return Some(BatchBreak::Pipeline);
+            }
 
-            if b.pipeline.as_ref() != custom.pipeline.as_ref() {
-                return true;
+            if draw.is_premultiplied(&b.typ) != b.premultiplied_alpha {
+                return Some(BatchBreak::PremultipliedAlpha);
+            }
+
+            if draw.depth_test != b.depth_test {
+                return Some(BatchBreak::DepthTest);
             }
 
             // new batch if the blend_mode is different
-            let cbm = info.blend_mode().or(draw.blend_mode);
+            let cbm =
+                color_blend_mode(info.blend_mode().or(draw.blend_mode), b.premultiplied_alpha);
             if cbm != b.blend_mode {
-                return true;
+                return Some(BatchBreak::BlendMode);
             }
 
             let abm = info.alpha_mode().or(draw.alpha_mode);
             if abm != b.alpha_mode {
-                return true;
+                return Some(BatchBreak::AlphaMode);
             }
 
-            // if cfg!(not(target_os = "osx")) {
-            // if b.indices.len() + info.indices().len() >= u16::MAX as usize {
-            //     return true;
-            // }
-            // }
+            if let Some(max) = draw.batch_config.max_quads {
+                let indices = b.indices.len() + info.indices().len();
+                if !b.indices.is_empty() && indices > max * 6 {
+                    return Some(BatchBreak::Full);
+                }
+            }
 
             // by default we batch calls
-            false
+            None
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::BatchGrowth;
+    use crate::shapes::DrawShapes;
+
+    #[test]
+    fn push_and_pop_state() {
+        let mut draw = Draw::new(800, 600);
+        draw.set_alpha(0.5);
+        draw.push_state();
+
+        draw.set_alpha(0.2);
+        draw.set_depth(0.5);
+        draw.set_blend_mode(Some(BlendMode::ADD));
+        draw.set_premultiplied_alpha(true);
+        draw.set_culling(true);
+        draw.transform().push_translate(10.0, 10.0);
+        draw.set_projection(Some(Mat4::IDENTITY));
+
+        draw.pop_state();
+        assert_eq!(draw.alpha(), 0.5);
+        assert_eq!(draw.depth(), 0.0);
+        assert_eq!(draw.blend_mode(), Some(BlendMode::NORMAL));
+        assert!(!draw.premultiplied_alpha());
+        assert!(!draw.culling());
+        assert_eq!(*draw.matrix(), Mat3::IDENTITY);
+        assert_eq!(draw.projection(), draw.base_projection);
+    }
+
+    #[test]
+    fn batch_indices_follow_the_stored_vertices() {
+        let mut draw = Draw::new(800, 600);
+        draw.rect((0.0, 0.0), (10.0, 10.0));
+        draw.rect((20.0, 0.0), (10.0, 10.0));
+
+        let batch = draw.current_batch.as_ref().unwrap();
+        assert_eq!(batch.vertices.len(), 8 * 7);
+        assert_eq!(batch.indices, [0, 1, 2, 0, 2, 3, 4, 5, 6, 4, 6, 7]);
+    }
+
+    #[test]
+    fn culling_discards_elements_outside_of_the_projection() {
+        let mut draw = Draw::new(800, 600);
+        draw.set_culling(true);
+        draw.circle(10.0).position(-50.0, 300.0);
+        draw.rect((900.0, 0.0), (10.0, 10.0));
+        assert!(draw.current_batch.is_none());
+        assert_eq!(draw.stats().culled, 2);
+
+        draw.transform().push_translate(-100.0, 0.0);
+        draw.rect((90.0, 0.0), (20.0, 20.0));
+        assert!(draw.current_batch.is_some());
+    }
+
+    #[test]
+    fn stats_count_batches_and_breaks() {
+        let mut draw = Draw::new(800, 600);
+        draw.rect((0.0, 0.0), (10.0, 10.0));
+        draw.rect((20.0, 0.0), (10.0, 10.0));
+        draw.set_blend_mode(Some(BlendMode::ADD));
+        draw.rect((40.0, 0.0), (10.0, 10.0));
+
+        let stats = draw.stats();
+        assert_eq!(stats.elements, 3);
+        assert_eq!(stats.batches, 2);
+        assert_eq!(stats.vertices, 12);
+        assert_eq!(stats.indices, 18);
+        assert_eq!(stats.breaks.blend_mode, 1);
+        assert_eq!(stats.breaks.total(), 1);
+    }
+
+    #[test]
+    fn full_batches_are_split() {
+        let config = BatchConfig::default()
+            .with_initial_quads(2)
+            .with_max_quads(2)
+            .with_growth(BatchGrowth::Exact);
+        let mut draw = Draw::with_batch_config(800, 600, config);
+        draw.rect((0.0, 0.0), (10.0, 10.0));
+
+        let batch = draw.current_batch.as_ref().unwrap();
+        assert_eq!(batch.indices.capacity(), 12);
+
+        draw.rect((20.0, 0.0), (10.0, 10.0));
+        draw.rect((40.0, 0.0), (10.0, 10.0));
+
+        let stats = draw.stats();
+        assert_eq!(stats.batches, 2);
+        assert_eq!(stats.breaks.full, 1);
+    }
+}
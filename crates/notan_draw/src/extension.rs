@@ -1,4 +1,4 @@
-use crate::{Draw, DrawManager};
+use crate::{BatchConfig, Draw, DrawManager};
 use notan_app::graphics::*;
 use notan_text::{Text, TextExtension};
 
@@ -9,7 +9,11 @@ pub trait CreateDraw {
 impl CreateDraw for Graphics {
     fn create_draw(&self) -> Draw {
         let (width, height) = self.device.size();
-        Draw::new(width, height)
+        let config = self
+            .extension::<Draw, DrawExtension>()
+            .map(|ext| ext.batch_config())
+            .unwrap_or_default();
+        Draw::with_batch_config(width, height, config)
     }
 }
 
@@ -22,14 +26,31 @@ impl CreateDraw for RenderTexture {
 
 pub struct DrawExtension {
     manager: DrawManager,
+    glyph_cache_size: Option<(u32, u32)>,
 }
 
 impl DrawExtension {
     pub fn new(gfx: &mut Graphics) -> Result<Self, String> {
         Ok(Self {
             manager: DrawManager::new(gfx)?,
+            glyph_cache_size: None,
         })
     }
+
+    /// Sets the batch config of the draws created with `gfx.create_draw()`
+    pub fn set_batch_config(&mut self, config: BatchConfig) {
+        self.manager.set_batch_config(config);
+    }
+
+    pub fn batch_config(&self) -> BatchConfig {
+        self.manager.batch_config()
+    }
+
+    /// Resizes the texture used to cache the glyphs of the texts before the next draw.
+    /// A bigger size avoids resizing and rasterizing again every glyph when using many sizes or characters
+    pub fn set_glyph_cache_size(&mut self, width: u32, height: u32) {
+        self.glyph_cache_size = Some((width, height));
+    }
 }
 
 impl GfxExtension<Draw> for DrawExtension {}
@@ -41,6 +62,10 @@ impl GfxRenderer for Draw {
         extensions: &mut ExtContainer,
         target: Option<&RenderTexture>,
     ) -> Result<(), String> {
+        self.render_targets
+            .iter()
+            .try_for_each(|(rt, draw)| draw.render(device, extensions, Some(rt)))?;
+
         let mut text_ext = extensions.get_mut::<Text, TextExtension>().ok_or_else(|| {
             "Missing TextExtension. You may need to add 'DrawConfig' to notan.".to_string()
         })?;
@@ -48,9 +73,14 @@ impl GfxRenderer for Draw {
             "Missing DrawExtension. You may need to add 'DrawConfig' to notan.".to_string()
         })?;
 
-        let cmds =
-            ext.manager
-                .process_draw(self, device, text_ext.glyph_brush_mut(), target.is_some());
+        let glyph_brush = text_ext.glyph_brush_mut();
+        if let Some((width, height)) = ext.glyph_cache_size.take() {
+            glyph_brush.resize_cache(device, width, height);
+        }
+
+        let cmds = ext
+            .manager
+            .process_draw(self, device, glyph_brush, target.is_some());
         match target {
             None => device.render(cmds),
             Some(rt) => device.render_to(rt, cmds),
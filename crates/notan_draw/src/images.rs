@@ -3,6 +3,10 @@ mod image;
 mod instanced;
 mod nine_slice;
 mod painter;
+mod quad;
+mod region;
+mod sprite_animation;
+mod sprites;
 
 //pub use instanced::*;
 use crate::builder::DrawBuilder;
@@ -13,10 +17,26 @@ pub use nine_slice::*;
 use notan_graphics::Texture;
 pub use painter::create_image_pipeline;
 pub(crate) use painter::*;
+pub use quad::*;
+pub use region::*;
+pub use sprite_animation::*;
+pub use sprites::*;
 
 pub trait DrawImages {
     fn image<'a>(&mut self, texture: &'a Texture) -> DrawBuilder<Image<'a>>;
+    fn image_region<'a>(&mut self, region: TextureRegion<'a>) -> DrawBuilder<Image<'a>>;
+    fn image_quad<'a>(
+        &mut self,
+        texture: &'a Texture,
+        corners: [(f32, f32); 4],
+    ) -> DrawBuilder<ImageQuad<'a>>;
+    fn images<'a>(
+        &mut self,
+        texture: &'a Texture,
+        instances: &'a [SpriteInstance],
+    ) -> DrawBuilder<Sprites<'a>>;
     fn nine_slice<'a>(&mut self, texture: &'a Texture) -> DrawBuilder<NineSlice<'a>>;
+    fn nine_slice_region<'a>(&mut self, region: TextureRegion<'a>) -> DrawBuilder<NineSlice<'a>>;
     fn animation_grid<'a>(
         &mut self,
         texture: &'a Texture,
@@ -24,6 +44,7 @@ pub trait DrawImages {
         rows: usize,
     ) -> DrawBuilder<ImageAnimation<'a>>;
     fn animation_list<'a>(&mut self, list: &'a [&'a Texture]) -> DrawBuilder<ImageAnimation<'a>>;
+    fn animation<'a>(&mut self, animation: &'a Animation) -> Option<DrawBuilder<'_, Image<'a>>>;
     //fn instanced_image<'a>(&mut self, texture: &'a Texture) -> DrawBuilder<InstancedImage<'a>>;
 }
 
@@ -32,10 +53,35 @@ impl DrawImages for Draw {
         DrawBuilder::new(self, Image::new(texture))
     }
 
+    fn image_region<'a>(&mut self, region: TextureRegion<'a>) -> DrawBuilder<Image<'a>> {
+        DrawBuilder::new(self, Image::new(region))
+    }
+
+    fn image_quad<'a>(
+        &mut self,
+        texture: &'a Texture,
+        corners: [(f32, f32); 4],
+    ) -> DrawBuilder<ImageQuad<'a>> {
+        DrawBuilder::new(self, ImageQuad::new(texture, corners))
+    }
+
+    /// Draws the texture once per instance in a single call
+    fn images<'a>(
+        &mut self,
+        texture: &'a Texture,
+        instances: &'a [SpriteInstance],
+    ) -> DrawBuilder<Sprites<'a>> {
+        DrawBuilder::new(self, Sprites::new(texture, instances))
+    }
+
     fn nine_slice<'a>(&mut self, texture: &'a Texture) -> DrawBuilder<NineSlice<'a>> {
         DrawBuilder::new(self, NineSlice::new(texture))
     }
 
+    fn nine_slice_region<'a>(&mut self, region: TextureRegion<'a>) -> DrawBuilder<NineSlice<'a>> {
+        DrawBuilder::new(self, NineSlice::new(region))
+    }
+
     fn animation_grid<'a>(
         &mut self,
         texture: &'a Texture,
@@ -48,4 +94,10 @@ impl DrawImages for Draw {
     fn animation_list<'a>(&mut self, list: &'a [&'a Texture]) -> DrawBuilder<ImageAnimation<'a>> {
         DrawBuilder::new(self, ImageAnimation::from_list(list))
     }
+
+    /// Draws the current frame of the animation, returns `None` if the animation has no frames
+    fn animation<'a>(&mut self, animation: &'a Animation) -> Option<DrawBuilder<'_, Image<'a>>> {
+        let texture = animation.current_frame()?;
+        Some(DrawBuilder::new(self, Image::new(texture)))
+    }
 }
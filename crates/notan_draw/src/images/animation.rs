@@ -26,6 +26,7 @@ pub struct ImageAnimation<'a> {
     frames: Option<&'a [usize]>,
     matrix: Option<Mat3>,
     blend_mode: Option<BlendMode>,
+    alpha_mode: Option<BlendMode>,
     flip: (bool, bool),
 }
 
@@ -43,6 +44,7 @@ impl<'a> ImageAnimation<'a> {
             size: None,
             matrix: None,
             blend_mode: None,
+            alpha_mode: None,
             frames: None,
             time: 0.0,
             flip: (false, false),
@@ -58,6 +60,7 @@ impl<'a> ImageAnimation<'a> {
             size: None,
             matrix: None,
             blend_mode: None,
+            alpha_mode: None,
             frames: None,
             time: 0.0,
             flip: (false, false),
@@ -104,6 +107,11 @@ impl<'a> ImageAnimation<'a> {
         self
     }
 
+    pub fn alpha_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.alpha_mode = Some(mode);
+        self
+    }
+
     pub fn flip_x(&mut self, flip: bool) -> &mut Self {
         self.flip.0 = flip;
         self
@@ -132,6 +140,7 @@ impl DrawProcess for ImageAnimation<'_> {
             source,
             matrix,
             blend_mode,
+            alpha_mode,
             frames,
             flip: (flip_x, flip_y),
         } = self;
@@ -166,7 +175,7 @@ impl DrawProcess for ImageAnimation<'_> {
 
                 let size = size.unwrap_or((tw as _, th as _));
 
-                img(draw, texture, matrix, blend_mode)
+                img(draw, texture, matrix, blend_mode, alpha_mode)
                     .crop((tx as _, ty as _), (tw as _, th as _))
                     .size(size.0, size.1)
                     .position(x, y)
@@ -188,7 +197,7 @@ impl DrawProcess for ImageAnimation<'_> {
 
                 let texture = list[i];
                 let size = size.unwrap_or_else(|| texture.size());
-                img(draw, texture, matrix, blend_mode)
+                img(draw, texture, matrix, blend_mode, alpha_mode)
                     .size(size.0, size.1)
                     .position(x, y)
                     .color(color)
@@ -206,11 +215,15 @@ fn img<'a>(
     tex: &'a Texture,
     mat: Option<Mat3>,
     blend_mode: Option<BlendMode>,
+    alpha_mode: Option<BlendMode>,
 ) -> DrawBuilder<'a, Image<'a>> {
     let mut img = Image::new(tex);
     if let Some(bm) = blend_mode {
         img.blend_mode(bm);
     }
+    if let Some(abm) = alpha_mode {
+        img.alpha_mode(abm);
+    }
     if let Some(m) = mat {
         img.transform(m);
     }
@@ -1,51 +1,61 @@
+use super::region::TextureRegion;
 use crate::builder::DrawProcess;
 use crate::draw::{Draw, ImageInfo};
 use crate::transform::DrawTransform;
 use notan_graphics::color::Color;
 use notan_graphics::pipeline::BlendMode;
-use notan_graphics::Texture;
 use notan_math::Mat3;
 use notan_math::Rect;
 
+/// Draws a texture, or a region of it, as a quad
 pub struct Image<'a> {
     matrix: Option<Mat3>,
-    texture: &'a Texture,
+    region: TextureRegion<'a>,
     pos: (f32, f32),
     size: Option<(f32, f32)>,
     crop: Option<Rect>,
     color: Color,
+    corner_colors: Option<[Color; 4]>,
     alpha: f32,
     blend_mode: Option<BlendMode>,
     alpha_mode: Option<BlendMode>,
     flip: (bool, bool),
+    rotation: f32,
+    origin: (f32, f32),
 }
 
 impl<'a> Image<'a> {
-    pub fn new(texture: &'a Texture) -> Self {
+    pub fn new<T: Into<TextureRegion<'a>>>(texture: T) -> Self {
         Self {
             matrix: None,
-            texture,
+            region: texture.into(),
             pos: (0.0, 0.0),
             color: Color::WHITE,
+            corner_colors: None,
             alpha: 1.0,
             size: None,
             crop: None,
             blend_mode: None,
             alpha_mode: None,
             flip: (false, false),
+            rotation: 0.0,
+            origin: (0.5, 0.5),
         }
     }
 
+    /// Top-left position of the image
     pub fn position(&mut self, x: f32, y: f32) -> &mut Self {
         self.pos = (x, y);
         self
     }
 
+    /// Size of the quad, by default the size of the texture
     pub fn size(&mut self, width: f32, height: f32) -> &mut Self {
         self.size = Some((width, height));
         self
     }
 
+    /// Draws only the region of the texture given in pixels
     pub fn crop(&mut self, xy: (f32, f32), size: (f32, f32)) -> &mut Self {
         let (x, y) = xy;
         let (width, height) = size;
@@ -58,11 +68,19 @@ impl<'a> Image<'a> {
         self
     }
 
+    /// Color used to tint the texture
     pub fn color(&mut self, color: Color) -> &mut Self {
         self.color = color;
         self
     }
 
+    /// Tints each corner with its own color instead of using `color`,
+    /// in order: top-left, top-right, bottom-left and bottom-right
+    pub fn corner_colors(&mut self, colors: [Color; 4]) -> &mut Self {
+        self.corner_colors = Some(colors);
+        self
+    }
+
     pub fn alpha(&mut self, alpha: f32) -> &mut Self {
         self.alpha = alpha;
         self
@@ -78,15 +96,35 @@ impl<'a> Image<'a> {
         self
     }
 
+    /// Mirrors the texture horizontally keeping the quad in place
     pub fn flip_x(&mut self, flip: bool) -> &mut Self {
         self.flip.0 = flip;
         self
     }
 
+    /// Mirrors the texture vertically keeping the quad in place
     pub fn flip_y(&mut self, flip: bool) -> &mut Self {
         self.flip.1 = flip;
         self
     }
+
+    /// Rotates the quad around its origin using radians, without using the transformation matrix
+    pub fn rotation(&mut self, angle: f32) -> &mut Self {
+        self.rotation = angle;
+        self
+    }
+
+    /// Rotates the quad around its origin using degrees, without using the transformation matrix
+    pub fn rotation_degrees(&mut self, deg: f32) -> &mut Self {
+        self.rotation(deg.to_radians())
+    }
+
+    /// Point used to rotate the quad, relative to its size.
+    /// (0.0, 0.0) is the top-left corner and (0.5, 0.5), the default, is the center
+    pub fn origin(&mut self, x: f32, y: f32) -> &mut Self {
+        self.origin = (x, y);
+        self
+    }
 }
 
 impl DrawTransform for Image<'_> {
@@ -99,8 +137,9 @@ impl DrawProcess for Image<'_> {
     fn draw_process(self, draw: &mut Draw) {
         let Self {
             pos: (x1, y1),
-            texture,
+            region,
             color,
+            corner_colors,
             matrix,
             alpha,
             size,
@@ -108,10 +147,15 @@ impl DrawProcess for Image<'_> {
             blend_mode,
             alpha_mode,
             flip: (flip_x, flip_y),
+            rotation,
+            origin: (ox, oy),
         } = self;
 
-        let c = color.with_alpha(color.a * alpha);
-        let frame = texture.frame();
+        let [c1, c2, c3, c4] = corner_colors
+            .unwrap_or([color; 4])
+            .map(|c| c.with_alpha(c.a * alpha));
+        let texture = region.texture();
+        let frame = region.frame();
 
         let (ww, hh) = size.unwrap_or((frame.width, frame.height));
         let x2 = x1 + ww;
@@ -159,12 +203,31 @@ impl DrawProcess for Image<'_> {
             (u1, v1, u2, v2)
         };
 
+        let [(x1, y1), (x2, y2), (x3, y3), (x4, y4)] = if rotation != 0.0 {
+            let pivot_x = x1 + ww * ox;
+            let pivot_y = y1 + hh * oy;
+            let (sin, cos) = rotation.sin_cos();
+            let rotate = |x: f32, y: f32| {
+                let (dx, dy) = (x - pivot_x, y - pivot_y);
+                (pivot_x + dx * cos - dy * sin, pivot_y + dx * sin + dy * cos)
+            };
+
+            [
+                rotate(x1, y1),
+                rotate(x2, y1),
+                rotate(x1, y2),
+                rotate(x2, y2),
+            ]
+        } else {
+            [(x1, y1), (x2, y1), (x1, y2), (x2, y2)]
+        };
+
         #[rustfmt::skip]
         let vertices = [
-            x1, y1, u1, v1, c.r, c.g, c.b, c.a,
-            x2, y1, u2, v1, c.r, c.g, c.b, c.a,
-            x1, y2, u1, v2, c.r, c.g, c.b, c.a,
-            x2, y2, u2, v2, c.r, c.g, c.b, c.a,
+            x1, y1, u1, v1, c1.r, c1.g, c1.b, c1.a,
+            x2, y2, u2, v1, c2.r, c2.g, c2.b, c2.a,
+            x3, y3, u1, v2, c3.r, c3.g, c3.b, c3.a,
+            x4, y4, u2, v2, c4.r, c4.g, c4.b, c4.a,
         ];
 
         draw.add_image(&ImageInfo {
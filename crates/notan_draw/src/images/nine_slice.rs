@@ -1,14 +1,15 @@
 use super::image::Image;
+use super::region::TextureRegion;
 use crate::builder::{DrawBuilder, DrawProcess};
 use crate::draw::Draw;
+use crate::patterns::Pattern;
 use crate::transform::DrawTransform;
 use notan_graphics::color::Color;
 use notan_graphics::pipeline::BlendMode;
-use notan_graphics::Texture;
 use notan_math::Mat3;
 
 pub struct NineSlice<'a> {
-    texture: &'a Texture,
+    region: TextureRegion<'a>,
     color: Color,
     alpha: f32,
     pos: (f32, f32),
@@ -19,12 +20,15 @@ pub struct NineSlice<'a> {
     bottom: Option<f32>,
     matrix: Option<Mat3>,
     blend_mode: Option<BlendMode>,
+    alpha_mode: Option<BlendMode>,
+    flip: (bool, bool),
+    tiled: bool,
 }
 
 impl<'a> NineSlice<'a> {
-    pub fn new(texture: &'a Texture) -> Self {
+    pub fn new<T: Into<TextureRegion<'a>>>(texture: T) -> Self {
         Self {
-            texture,
+            region: texture.into(),
             color: Color::WHITE,
             alpha: 1.0,
             pos: (0.0, 0.0),
@@ -35,6 +39,9 @@ impl<'a> NineSlice<'a> {
             bottom: None,
             matrix: None,
             blend_mode: None,
+            alpha_mode: None,
+            flip: (false, false),
+            tiled: false,
         }
     }
 
@@ -82,6 +89,29 @@ impl<'a> NineSlice<'a> {
         self.blend_mode = Some(mode);
         self
     }
+
+    pub fn alpha_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.alpha_mode = Some(mode);
+        self
+    }
+
+    /// Mirrors the slices horizontally keeping the size of the borders
+    pub fn flip_x(&mut self, flip: bool) -> &mut Self {
+        self.flip.0 = flip;
+        self
+    }
+
+    /// Mirrors the slices vertically keeping the size of the borders
+    pub fn flip_y(&mut self, flip: bool) -> &mut Self {
+        self.flip.1 = flip;
+        self
+    }
+
+    /// Repeats the edges and the center instead of stretching them
+    pub fn tiled(&mut self, tiled: bool) -> &mut Self {
+        self.tiled = tiled;
+        self
+    }
 }
 
 impl DrawTransform for NineSlice<'_> {
@@ -93,7 +123,7 @@ impl DrawTransform for NineSlice<'_> {
 impl DrawProcess for NineSlice<'_> {
     fn draw_process(self, draw: &mut Draw) {
         let Self {
-            texture,
+            region,
             color,
             alpha,
             pos: (x, y),
@@ -104,10 +134,13 @@ impl DrawProcess for NineSlice<'_> {
             bottom,
             matrix,
             blend_mode,
+            alpha_mode,
+            flip: (flip_x, flip_y),
+            tiled,
         } = self;
 
-        let img_ww = texture.width();
-        let img_hh = texture.height();
+        let img_ww = region.width();
+        let img_hh = region.height();
 
         let (width, height) = size.unwrap_or((img_ww, img_hh));
         let left = left.unwrap_or(img_ww / 3.0);
@@ -127,71 +160,47 @@ impl DrawProcess for NineSlice<'_> {
             _ => false,
         };
 
-        //top-left
-        img(draw, texture, blend_mode)
-            .color(color)
-            .alpha(alpha)
-            .position(x, y)
-            .size(left, top)
-            .crop((0.0, 0.0), (left, top));
-        //top-center
-        img(draw, texture, blend_mode)
-            .color(color)
-            .alpha(alpha)
-            .position(x + left, y)
-            .size(center_w, top)
-            .crop((left, 0.0), (center_img_w, top));
-        //top-right
-        img(draw, texture, blend_mode)
-            .color(color)
-            .alpha(alpha)
-            .position(x + left + center_w, y)
-            .size(right, top)
-            .crop((left + center_img_w, 0.0), (right, top));
-
-        //center-left
-        img(draw, texture, blend_mode)
-            .color(color)
-            .alpha(alpha)
-            .position(x, y + top)
-            .size(left, center_h)
-            .crop((0.0, top), (left, center_img_h));
-        //center-center
-        img(draw, texture, blend_mode)
-            .color(color)
-            .alpha(alpha)
-            .position(x + left, y + top)
-            .size(center_w, center_h)
-            .crop((left, top), (center_img_w, center_img_h));
-        //center-right
-        img(draw, texture, blend_mode)
-            .color(color)
-            .alpha(alpha)
-            .position(x + left + center_w, y + top)
-            .size(right, center_h)
-            .crop((left + center_img_w, top), (right, center_img_h));
-
-        //bottom-left
-        img(draw, texture, blend_mode)
-            .color(color)
-            .alpha(alpha)
-            .position(x, y + top + center_h)
-            .size(left, bottom)
-            .crop((0.0, top + center_img_h), (left, bottom));
-        //bottom-center
-        img(draw, texture, blend_mode)
-            .color(color)
-            .alpha(alpha)
-            .position(x + left, y + top + center_h)
-            .size(center_w, bottom)
-            .crop((left, top + center_img_h), (center_img_w, bottom));
-        //bottom-right
-        img(draw, texture, blend_mode)
-            .color(color)
-            .alpha(alpha)
-            .size(right, bottom)
-            .position(x + left + center_w, y + top + center_h)
-            .crop((left + center_img_w, top + center_img_h), (right, top));
+        // (position, size, crop position, crop size) of each column and row
+        let cols = [
+            (0.0, left, 0.0, left),
+            (left, center_w, left, center_img_w),
+            (left + center_w, right, left + center_img_w, right),
+        ];
+        let rows = [
+            (0.0, top, 0.0, top),
+            (top, center_h, top, center_img_h),
+            (top + center_h, bottom, top + center_img_h, bottom),
+        ];
+
+        for (row, (yy, hh, crop_y, crop_h)) in rows.into_iter().enumerate() {
+            for (col, (xx, ww, crop_x, crop_w)) in cols.into_iter().enumerate() {
+                // mirror the position of the slice inside the final rect
+                let xx = if flip_x { width - xx - ww } else { xx };
+                let yy = if flip_y { height - yy - hh } else { yy };
+
+                let is_corner = row != 1 && col != 1;
+                if tiled && !is_corner {
+                    let slice = region.sub_region(crop_x, crop_y, crop_w, crop_h);
+                    pattern(draw, slice, blend_mode, alpha_mode)
+                        .color(color)
+                        .alpha(alpha)
+                        .position(x + xx, y + yy)
+                        .size(ww, hh)
+                        .flip_x(flip_x)
+                        .flip_y(flip_y);
+                    continue;
+                }
+
+                img(draw, region, blend_mode, alpha_mode)
+                    .color(color)
+                    .alpha(alpha)
+                    .position(x + xx, y + yy)
+                    .size(ww, hh)
+                    .crop((crop_x, crop_y), (crop_w, crop_h))
+                    .flip_x(flip_x)
+                    .flip_y(flip_y);
+            }
+        }
 
         if uses_matrix {
             draw.transform().pop();
@@ -202,13 +211,35 @@ impl DrawProcess for NineSlice<'_> {
 #[inline(always)]
 fn img<'a>(
     draw: &'a mut Draw,
-    tex: &'a Texture,
+    tex: TextureRegion<'a>,
     blend_mode: Option<BlendMode>,
+    alpha_mode: Option<BlendMode>,
 ) -> DrawBuilder<'a, Image<'a>> {
     let mut img = Image::new(tex);
     if let Some(bm) = blend_mode {
         img.blend_mode(bm);
     }
+    if let Some(abm) = alpha_mode {
+        img.alpha_mode(abm);
+    }
 
     DrawBuilder::new(draw, img)
 }
+
+#[inline(always)]
+fn pattern<'a>(
+    draw: &'a mut Draw,
+    tex: TextureRegion<'a>,
+    blend_mode: Option<BlendMode>,
+    alpha_mode: Option<BlendMode>,
+) -> DrawBuilder<'a, Pattern<'a>> {
+    let mut pattern = Pattern::new(tex);
+    if let Some(bm) = blend_mode {
+        pattern.blend_mode(bm);
+    }
+    if let Some(abm) = alpha_mode {
+        pattern.alpha_mode(abm);
+    }
+
+    DrawBuilder::new(draw, pattern)
+}
@@ -4,23 +4,29 @@ use notan_graphics::prelude::*;
 use notan_macro::{fragment_shader, vertex_shader};
 use notan_math::Mat4;
 
+/// Textures that the default image pipeline can use in the same draw call
+pub(crate) const IMAGE_TEXTURE_UNITS: usize = 8;
+
 fn vertex_info() -> VertexInfo {
     VertexInfo::new()
-        .attr(0, VertexFormat::Float32x2)
+        .attr(0, VertexFormat::Float32x3)
         .attr(1, VertexFormat::Float32x2)
         .attr(2, VertexFormat::Float32x4)
+        .attr(3, VertexFormat::Float32)
 }
 
 //language=glsl
 const IMAGE_VERTEX: ShaderSource = vertex_shader! {
     r#"
     #version 450
-    layout(location = 0) in vec2 a_pos;
+    layout(location = 0) in vec3 a_pos;
     layout(location = 1) in vec2 a_uvs;
     layout(location = 2) in vec4 a_color;
+    layout(location = 3) in float a_texture;
 
     layout(location = 0) out vec4 v_color;
     layout(location = 1) out vec2 v_uvs;
+    layout(location = 2) out float v_texture;
     layout(set = 0, binding = 0) uniform Locals {
         mat4 u_projection;
     };
@@ -28,7 +34,8 @@ const IMAGE_VERTEX: ShaderSource = vertex_shader! {
     void main() {
         v_color = a_color;
         v_uvs = a_uvs;
-        gl_Position = u_projection * vec4(a_pos, 0.0, 1.0);
+        v_texture = a_texture;
+        gl_Position = u_projection * vec4(a_pos, 1.0);
     }
     "#
 };
@@ -41,13 +48,35 @@ const IMAGE_FRAGMENT: ShaderSource = fragment_shader! {
 
     layout(location = 0) in vec2 v_uvs;
     layout(location = 1) in vec4 v_color;
+    layout(location = 2) in float v_texture;
 
     layout(binding = 0) uniform sampler2D u_texture;
+    layout(binding = 1) uniform sampler2D u_texture1;
+    layout(binding = 2) uniform sampler2D u_texture2;
+    layout(binding = 3) uniform sampler2D u_texture3;
+    layout(binding = 4) uniform sampler2D u_texture4;
+    layout(binding = 5) uniform sampler2D u_texture5;
+    layout(binding = 6) uniform sampler2D u_texture6;
+    layout(binding = 7) uniform sampler2D u_texture7;
 
     layout(location = 0) out vec4 color;
 
+    // samplers cannot be indexed with a dynamic value on webgl
+    vec4 batch_texture(int index) {
+        if (index == 0) return texture(u_texture, v_uvs);
+        if (index == 1) return texture(u_texture1, v_uvs);
+        if (index == 2) return texture(u_texture2, v_uvs);
+        if (index == 3) return texture(u_texture3, v_uvs);
+        if (index == 4) return texture(u_texture4, v_uvs);
+        if (index == 5) return texture(u_texture5, v_uvs);
+        if (index == 6) return texture(u_texture6, v_uvs);
+        return texture(u_texture7, v_uvs);
+    }
+
     void main() {
-        color = texture(u_texture, v_uvs) * v_color;
+        // shapes batched with the images use a negative index to draw only their color
+        vec4 tex = v_texture < 0.0 ? vec4(1.0) : batch_texture(int(v_texture + 0.5));
+        color = tex * v_color;
     }
     "#
 };
@@ -56,14 +85,22 @@ pub fn create_image_pipeline(
     device: &mut Device,
     fragment: Option<&ShaderSource>,
 ) -> Result<Pipeline, String> {
-    let fragment = fragment.unwrap_or(&IMAGE_FRAGMENT);
-    device
+    let builder = device
         .create_pipeline()
-        .from(&IMAGE_VERTEX, fragment)
+        .from(&IMAGE_VERTEX, fragment.unwrap_or(&IMAGE_FRAGMENT))
         .with_vertex_info(&vertex_info())
         .with_color_blend(BlendMode::NORMAL)
-        .with_texture_location(0, "u_texture")
-        .build()
+        .with_texture_location(0, "u_texture");
+
+    // custom fragments only sample the first texture
+    match fragment {
+        Some(_) => builder.build(),
+        None => (1..IMAGE_TEXTURE_UNITS)
+            .fold(builder, |builder, i| {
+                builder.with_texture_location(i as _, &format!("u_texture{i}"))
+            })
+            .build(),
+    }
 }
 
 pub(crate) struct ImagePainter {
@@ -81,6 +118,13 @@ pub(crate) struct ImagePainter {
 
 impl ImagePainter {
     pub fn new(device: &mut Device) -> Result<Self, String> {
+        let units = device.limits().max_texture_units;
+        if (units as usize) < IMAGE_TEXTURE_UNITS {
+            return Err(format!(
+                "The image pipeline needs {IMAGE_TEXTURE_UNITS} texture units but the device has {units}."
+            ));
+        }
+
         let pipeline = create_image_pipeline(device, None)?;
 
         let uniforms = [0.0; 16];
@@ -111,7 +155,7 @@ impl ImagePainter {
     }
 
     pub fn push(&mut self, renderer: &mut Renderer, batch: &Batch, projection: &Mat4, is_rt: bool) {
-        if let BatchType::Image { texture } = &batch.typ {
+        if let BatchType::Image { textures } = &batch.typ {
             process_pipeline(renderer, batch, &self.pipeline, is_rt);
 
             let len = (self.count_vertices / self.pipeline.offset()) as u32;
@@ -125,7 +169,9 @@ impl ImagePainter {
 
             self.uniforms.copy_from_slice(&projection.to_cols_array());
 
-            renderer.bind_texture_slot(0, 0, texture);
+            textures.iter().enumerate().for_each(|(i, texture)| {
+                renderer.bind_texture_slot(i as _, i as _, texture);
+            });
             renderer.bind_buffers(&[&self.vbo, &self.ebo, &self.ubo]);
             renderer.draw(offset as _, batch.indices.len() as _);
 
@@ -143,6 +189,12 @@ impl ImagePainter {
         }
     }
 
+    /// Reserves room for the vertices and indices of the quads given
+    pub fn reserve(&mut self, quads: usize) {
+        self.vertices.reserve(quads * 4 * self.pipeline.offset());
+        self.indices.reserve(quads * 6);
+    }
+
     pub fn clear(&mut self) {
         self.count_vertices = 0;
         self.count_indices = 0;
@@ -0,0 +1,144 @@
+use super::region::TextureRegion;
+use crate::builder::DrawProcess;
+use crate::draw::{Draw, ImageInfo};
+use crate::transform::DrawTransform;
+use notan_graphics::color::Color;
+use notan_graphics::pipeline::BlendMode;
+use notan_math::{vec2, Mat3, Vec2};
+
+/// Draws a texture mapped on a quad with any shape
+pub struct ImageQuad<'a> {
+    matrix: Option<Mat3>,
+    region: TextureRegion<'a>,
+    corners: [Vec2; 4],
+    uvs: [Vec2; 4],
+    subdivisions: u32,
+    color: Color,
+    alpha: f32,
+    blend_mode: Option<BlendMode>,
+    alpha_mode: Option<BlendMode>,
+}
+
+impl<'a> ImageQuad<'a> {
+    /// Corners in order: top-left, top-right, bottom-left and bottom-right
+    pub fn new<T: Into<TextureRegion<'a>>>(texture: T, corners: [(f32, f32); 4]) -> Self {
+        Self {
+            matrix: None,
+            region: texture.into(),
+            corners: corners.map(Vec2::from),
+            uvs: [
+                vec2(0.0, 0.0),
+                vec2(1.0, 0.0),
+                vec2(0.0, 1.0),
+                vec2(1.0, 1.0),
+            ],
+            subdivisions: 1,
+            color: Color::WHITE,
+            alpha: 1.0,
+            blend_mode: None,
+            alpha_mode: None,
+        }
+    }
+
+    /// Coordinates of the texture for each corner, from 0.0 to 1.0 inside the texture's frame
+    pub fn uvs(&mut self, uvs: [(f32, f32); 4]) -> &mut Self {
+        self.uvs = uvs.map(Vec2::from);
+        self
+    }
+
+    /// Splits the quad in a grid of cells to reduce the distortion of the texture
+    /// when the quad is not a parallelogram, by default it's 1 (just two triangles)
+    pub fn subdivisions(&mut self, subdivisions: u32) -> &mut Self {
+        self.subdivisions = subdivisions.max(1);
+        self
+    }
+
+    pub fn color(&mut self, color: Color) -> &mut Self {
+        self.color = color;
+        self
+    }
+
+    pub fn alpha(&mut self, alpha: f32) -> &mut Self {
+        self.alpha = alpha;
+        self
+    }
+
+    pub fn blend_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.blend_mode = Some(mode);
+        self
+    }
+
+    pub fn alpha_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.alpha_mode = Some(mode);
+        self
+    }
+}
+
+impl DrawTransform for ImageQuad<'_> {
+    fn matrix(&mut self) -> &mut Option<Mat3> {
+        &mut self.matrix
+    }
+}
+
+impl DrawProcess for ImageQuad<'_> {
+    fn draw_process(self, draw: &mut Draw) {
+        let Self {
+            matrix,
+            region,
+            corners,
+            uvs,
+            subdivisions,
+            color,
+            alpha,
+            blend_mode,
+            alpha_mode,
+        } = self;
+
+        let c = color.with_alpha(color.a * alpha);
+        let texture = region.texture();
+        let frame = region.frame();
+        let base_width = texture.base_width();
+        let base_height = texture.base_height();
+        let is_rt = texture.is_render_texture();
+
+        let blend_mode = blend_mode.or(is_rt.then_some(BlendMode::OVER));
+
+        let bilinear = |points: &[Vec2; 4], s: f32, t: f32| {
+            let top = points[0].lerp(points[1], s);
+            let bottom = points[2].lerp(points[3], s);
+            top.lerp(bottom, t)
+        };
+
+        let n = subdivisions;
+        let mut vertices = Vec::with_capacity(((n + 1) * (n + 1) * 8) as usize);
+        for row in 0..=n {
+            for col in 0..=n {
+                let (s, t) = (col as f32 / n as f32, row as f32 / n as f32);
+                let pos = bilinear(&corners, s, t);
+                let uv = bilinear(&uvs, s, t);
+                let v = if is_rt { 1.0 - uv.y } else { uv.y };
+                let u = (frame.x + uv.x * frame.width) / base_width;
+                let v = (frame.y + v * frame.height) / base_height;
+                vertices.extend_from_slice(&[pos.x, pos.y, u, v, c.r, c.g, c.b, c.a]);
+            }
+        }
+
+        let mut indices = Vec::with_capacity((n * n * 6) as usize);
+        for row in 0..n {
+            for col in 0..n {
+                let i = row * (n + 1) + col;
+                let j = i + n + 1;
+                indices.extend_from_slice(&[i, i + 1, j, j, i + 1, j + 1]);
+            }
+        }
+
+        draw.add_image(&ImageInfo {
+            texture,
+            transform: matrix.as_ref(),
+            vertices: &vertices,
+            indices: &indices,
+            blend_mode,
+            alpha_mode,
+        });
+    }
+}
@@ -0,0 +1,72 @@
+use notan_graphics::Texture;
+use notan_math::Rect;
+
+/// Borrowed area of a texture that can be drawn like a texture without cloning it
+#[derive(Debug, Clone, Copy)]
+pub struct TextureRegion<'a> {
+    texture: &'a Texture,
+    frame: Rect,
+}
+
+impl<'a> TextureRegion<'a> {
+    /// Creates a region using the frame of the texture
+    pub fn new(texture: &'a Texture) -> Self {
+        Self {
+            texture,
+            frame: *texture.frame(),
+        }
+    }
+
+    /// Creates a region using the area of the base texture given in pixels
+    pub fn with_frame(texture: &'a Texture, x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            texture,
+            frame: Rect {
+                x,
+                y,
+                width,
+                height,
+            },
+        }
+    }
+
+    /// Returns a region inside this one, the position is relative to this region
+    pub fn sub_region(&self, x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            texture: self.texture,
+            frame: Rect {
+                x: self.frame.x + x,
+                y: self.frame.y + y,
+                width,
+                height,
+            },
+        }
+    }
+
+    pub fn texture(&self) -> &'a Texture {
+        self.texture
+    }
+
+    /// Area of the base texture in pixels
+    pub fn frame(&self) -> &Rect {
+        &self.frame
+    }
+
+    pub fn width(&self) -> f32 {
+        self.frame.width
+    }
+
+    pub fn height(&self) -> f32 {
+        self.frame.height
+    }
+
+    pub fn size(&self) -> (f32, f32) {
+        (self.frame.width, self.frame.height)
+    }
+}
+
+impl<'a> From<&'a Texture> for TextureRegion<'a> {
+    fn from(texture: &'a Texture) -> Self {
+        Self::new(texture)
+    }
+}
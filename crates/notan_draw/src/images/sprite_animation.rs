@@ -0,0 +1,182 @@
+use notan_graphics::Texture;
+
+/// How the frames are played once the animation reaches the end
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnimationMode {
+    /// Starts again from the first frame
+    #[default]
+    Loop,
+    /// Stays on the last frame
+    Once,
+    /// Goes back to the first frame and then forward again
+    PingPong,
+}
+
+/// Sequence of frames with their own duration that keeps track of the elapsed time
+#[derive(Debug, Clone, Default)]
+pub struct Animation {
+    frames: Vec<Texture>,
+    durations: Vec<f32>,
+    mode: AnimationMode,
+    time: f32,
+}
+
+impl Animation {
+    pub fn new(mode: AnimationMode) -> Self {
+        Self {
+            frames: vec![],
+            durations: vec![],
+            mode,
+            time: 0.0,
+        }
+    }
+
+    /// Creates an animation using each cell of the grid as a frame, from left to right and top to bottom
+    pub fn from_grid(
+        texture: &Texture,
+        cols: usize,
+        rows: usize,
+        frame_duration: f32,
+        mode: AnimationMode,
+    ) -> Self {
+        let frame = texture.frame();
+        let width = frame.width / cols as f32;
+        let height = frame.height / rows as f32;
+
+        let mut animation = Self::new(mode);
+        for row in 0..rows {
+            for col in 0..cols {
+                let x = frame.x + col as f32 * width;
+                let y = frame.y + row as f32 * height;
+                animation.add_frame(texture.with_frame(x, y, width, height), frame_duration);
+            }
+        }
+
+        animation
+    }
+
+    /// Adds a frame displayed for the duration in seconds given
+    pub fn add_frame(&mut self, texture: Texture, duration: f32) -> &mut Self {
+        self.frames.push(texture);
+        self.durations.push(duration.max(0.0));
+        self
+    }
+
+    pub fn frames(&self) -> &[Texture] {
+        &self.frames
+    }
+
+    /// Duration in seconds of each frame
+    pub fn durations(&self) -> &[f32] {
+        &self.durations
+    }
+
+    pub fn set_mode(&mut self, mode: AnimationMode) -> &mut Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn mode(&self) -> AnimationMode {
+        self.mode
+    }
+
+    /// Advances the animation, usually with `app.timer.delta_f32()`
+    pub fn update(&mut self, delta: f32) {
+        self.time += delta;
+    }
+
+    /// Sets the elapsed time in seconds
+    pub fn set_time(&mut self, time: f32) -> &mut Self {
+        self.time = time.max(0.0);
+        self
+    }
+
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    /// Goes back to the first frame
+    pub fn reset(&mut self) {
+        self.time = 0.0;
+    }
+
+    /// Sum of the duration of all the frames
+    pub fn duration(&self) -> f32 {
+        self.durations.iter().sum()
+    }
+
+    /// Returns true if the mode is [AnimationMode::Once] and the last frame was reached
+    pub fn is_finished(&self) -> bool {
+        self.mode == AnimationMode::Once && self.time >= self.duration()
+    }
+
+    /// Index of the frame that must be displayed at the current time
+    pub fn frame_index(&self) -> usize {
+        frame_index(&self.durations, self.mode, self.time)
+    }
+
+    /// Texture to display at the current time
+    pub fn current_frame(&self) -> Option<&Texture> {
+        self.frames.get(self.frame_index())
+    }
+}
+
+fn frame_index(durations: &[f32], mode: AnimationMode, time: f32) -> usize {
+    let duration: f32 = durations.iter().sum();
+    if durations.is_empty() || duration <= 0.0 {
+        return 0;
+    }
+
+    let time = match mode {
+        AnimationMode::Loop => time % duration,
+        AnimationMode::Once => time.min(duration),
+        AnimationMode::PingPong => {
+            let time = time % (duration * 2.0);
+            if time < duration {
+                time
+            } else {
+                duration * 2.0 - time
+            }
+        }
+    };
+
+    let mut elapsed = 0.0;
+    durations
+        .iter()
+        .position(|d| {
+            elapsed += d;
+            time < elapsed
+        })
+        .unwrap_or(durations.len() - 1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn frame_index_by_mode() {
+        let durations = [0.1, 0.2, 0.1];
+
+        let expected = [
+            (AnimationMode::Loop, 0.05, 0),
+            (AnimationMode::Loop, 0.25, 1),
+            (AnimationMode::Loop, 0.35, 2),
+            (AnimationMode::Loop, 0.45, 0),
+            (AnimationMode::Once, 0.35, 2),
+            (AnimationMode::Once, 10.0, 2),
+            (AnimationMode::PingPong, 0.35, 2),
+            (AnimationMode::PingPong, 0.55, 1),
+            (AnimationMode::PingPong, 0.75, 0),
+            (AnimationMode::PingPong, 0.85, 0),
+        ];
+
+        expected.into_iter().for_each(|(mode, time, index)| {
+            assert_eq!(
+                frame_index(&durations, mode, time),
+                index,
+                "{mode:?} {time}"
+            );
+        });
+    }
+}
@@ -0,0 +1,157 @@
+use super::region::TextureRegion;
+use crate::builder::DrawProcess;
+use crate::draw::{Draw, ImageInfo};
+use crate::transform::DrawTransform;
+use notan_graphics::color::Color;
+use notan_graphics::pipeline::BlendMode;
+use notan_math::Mat3;
+
+/// Position, scale, rotation and color of one sprite drawn with [Sprites]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpriteInstance {
+    pub x: f32,
+    pub y: f32,
+    pub scale: (f32, f32),
+    /// Rotation around the center of the sprite in radians
+    pub rotation: f32,
+    pub color: Color,
+}
+
+impl SpriteInstance {
+    pub fn new(x: f32, y: f32) -> Self {
+        Self {
+            x,
+            y,
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for SpriteInstance {
+    fn default() -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            scale: (1.0, 1.0),
+            rotation: 0.0,
+            color: Color::WHITE,
+        }
+    }
+}
+
+/// Draws many copies of the same texture at once
+pub struct Sprites<'a> {
+    matrix: Option<Mat3>,
+    region: TextureRegion<'a>,
+    instances: &'a [SpriteInstance],
+    alpha: f32,
+    blend_mode: Option<BlendMode>,
+    alpha_mode: Option<BlendMode>,
+}
+
+impl<'a> Sprites<'a> {
+    pub fn new<T: Into<TextureRegion<'a>>>(texture: T, instances: &'a [SpriteInstance]) -> Self {
+        Self {
+            matrix: None,
+            region: texture.into(),
+            instances,
+            alpha: 1.0,
+            blend_mode: None,
+            alpha_mode: None,
+        }
+    }
+
+    pub fn alpha(&mut self, alpha: f32) -> &mut Self {
+        self.alpha = alpha;
+        self
+    }
+
+    pub fn blend_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.blend_mode = Some(mode);
+        self
+    }
+
+    pub fn alpha_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.alpha_mode = Some(mode);
+        self
+    }
+}
+
+impl DrawTransform for Sprites<'_> {
+    fn matrix(&mut self) -> &mut Option<Mat3> {
+        &mut self.matrix
+    }
+}
+
+impl DrawProcess for Sprites<'_> {
+    fn draw_process(self, draw: &mut Draw) {
+        let Self {
+            matrix,
+            region,
+            instances,
+            alpha,
+            blend_mode,
+            alpha_mode,
+        } = self;
+
+        if instances.is_empty() {
+            return;
+        }
+
+        let texture = region.texture();
+        let frame = region.frame();
+        let base_width = texture.base_width();
+        let base_height = texture.base_height();
+        let is_rt = texture.is_render_texture();
+
+        let u1 = frame.x / base_width;
+        let u2 = (frame.x + frame.width) / base_width;
+        let (v1, v2) = {
+            let v1 = frame.y / base_height;
+            let v2 = (frame.y + frame.height) / base_height;
+            if is_rt {
+                (v2, v1)
+            } else {
+                (v1, v2)
+            }
+        };
+
+        let mut vertices = Vec::with_capacity(instances.len() * 32);
+        let mut indices = Vec::with_capacity(instances.len() * 6);
+        instances.iter().enumerate().for_each(|(i, sprite)| {
+            let hw = frame.width * sprite.scale.0 * 0.5;
+            let hh = frame.height * sprite.scale.1 * 0.5;
+            let cx = sprite.x + hw;
+            let cy = sprite.y + hh;
+
+            let (sin, cos) = sprite.rotation.sin_cos();
+            let corner = |x: f32, y: f32| (cx + x * cos - y * sin, cy + x * sin + y * cos);
+            let (x1, y1) = corner(-hw, -hh);
+            let (x2, y2) = corner(hw, -hh);
+            let (x3, y3) = corner(-hw, hh);
+            let (x4, y4) = corner(hw, hh);
+
+            let c = sprite.color.with_alpha(sprite.color.a * alpha);
+
+            #[rustfmt::skip]
+            vertices.extend_from_slice(&[
+                x1, y1, u1, v1, c.r, c.g, c.b, c.a,
+                x2, y2, u2, v1, c.r, c.g, c.b, c.a,
+                x3, y3, u1, v2, c.r, c.g, c.b, c.a,
+                x4, y4, u2, v2, c.r, c.g, c.b, c.a,
+            ]);
+
+            let n = i as u32 * 4;
+            indices.extend_from_slice(&[n, n + 1, n + 2, n + 2, n + 1, n + 3]);
+        });
+
+        draw.add_image(&ImageInfo {
+            texture,
+            transform: matrix.as_ref(),
+            vertices: &vertices,
+            indices: &indices,
+            blend_mode: blend_mode.or(is_rt.then_some(BlendMode::OVER)),
+            alpha_mode,
+        });
+    }
+}
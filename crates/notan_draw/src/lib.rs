@@ -1,13 +1,17 @@
 mod batch;
 mod builder;
+mod camera;
 mod config;
 mod custom_pipeline;
 mod draw;
 mod extension;
 mod images;
+mod list;
 mod manager;
+mod meshes;
 mod patterns;
 mod shapes;
+mod stats;
 mod texts;
 mod transform;
 
@@ -15,13 +19,17 @@ mod atlas;
 
 pub use atlas::*;
 pub use builder::*;
+pub use camera::*;
 pub use config::*;
 pub use custom_pipeline::*;
 pub use draw::*;
 pub use extension::*;
 pub use images::*;
+pub use list::*;
 pub use manager::*;
+pub use meshes::*;
 pub use patterns::*;
 pub use shapes::*;
+pub use stats::*;
 pub use texts::*;
 pub use transform::*;
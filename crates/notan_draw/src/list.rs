@@ -0,0 +1,303 @@
+use crate::builder::{DrawBuilder, DrawProcess};
+use crate::draw::{Draw, ImageInfo, ShapeInfo, StaticMeshInfo, TextInfo};
+use crate::meshes::StaticMesh;
+use crate::texts::SdfStyle;
+use crate::transform::DrawTransform;
+use notan_glyph::OwnedSection;
+use notan_graphics::color::Color;
+use notan_graphics::pipeline::BlendMode;
+use notan_graphics::Texture;
+use notan_math::Mat3;
+use notan_text::Font;
+use std::ops::{Deref, DerefMut};
+
+/// Element saved by a [DrawList], with its vertices already tessellated
+#[derive(Clone, Debug)]
+pub(crate) enum DrawCommand {
+    Shape {
+        vertices: Vec<f32>,
+        indices: Vec<u32>,
+    },
+    Image {
+        texture: Texture,
+        vertices: Vec<f32>,
+        indices: Vec<u32>,
+    },
+    Pattern {
+        texture: Texture,
+        vertices: Vec<f32>,
+        indices: Vec<u32>,
+    },
+    Text {
+        section: OwnedSection,
+        font: Font,
+        flip: (bool, bool),
+        justify: bool,
+        sdf: Option<SdfStyle>,
+    },
+    StaticMesh {
+        mesh: StaticMesh,
+        color: Color,
+    },
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct RecordedCommand {
+    pub command: DrawCommand,
+    pub matrix: Mat3,
+    pub alpha: f32,
+    pub depth: f32,
+    pub blend_mode: Option<BlendMode>,
+    pub alpha_mode: Option<BlendMode>,
+}
+
+/// Records shapes, images, patterns, texts and static meshes without a graphics context,
+/// to add them later to a [Draw] as many times as needed using `draw.draw_list(&list)`.
+/// The list is used like a [Draw], but only the elements are saved, with the transform,
+/// alpha, depth and blend modes set when they were added. Masks, custom pipelines,
+/// clear values and render targets are ignored. The lists can be built on other
+/// threads, [build_draw_lists] does it using all the available threads.
+#[derive(Clone)]
+pub struct DrawList {
+    draw: Draw,
+}
+
+impl Default for DrawList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DrawList {
+    pub fn new() -> Self {
+        let mut draw = Draw::new(1, 1);
+        draw.recording = Some(vec![]);
+        Self { draw }
+    }
+
+    /// Number of elements recorded
+    pub fn len(&self) -> usize {
+        self.commands().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands().is_empty()
+    }
+
+    /// Removes the recorded elements, keeping the transform and the other values
+    pub fn clear_list(&mut self) {
+        if let Some(commands) = &mut self.draw.recording {
+            commands.clear();
+        }
+    }
+
+    pub(crate) fn commands(&self) -> &[RecordedCommand] {
+        self.draw.recording.as_deref().unwrap_or_default()
+    }
+}
+
+// the lists must be `Send` to be built on other threads
+const _: () = {
+    const fn assert_send<T: Send>() {}
+    assert_send::<DrawList>();
+};
+
+/// Builds `count` lists using the available threads, calling `f` with the index of each list.
+/// The lists are returned in the same order as the indices, so they can be added to the
+/// draw in that order. Tessellating shapes is done on the threads, rendering is not
+#[cfg(not(target_arch = "wasm32"))]
+pub fn build_draw_lists<F>(count: usize, f: F) -> Vec<DrawList>
+where
+    F: Fn(usize, &mut DrawList) + Sync,
+{
+    let threads = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(count.max(1));
+    let chunk_size = count.div_ceil(threads).max(1);
+
+    let mut lists = vec![DrawList::new(); count];
+    std::thread::scope(|scope| {
+        lists
+            .chunks_mut(chunk_size)
+            .enumerate()
+            .for_each(|(chunk, lists)| {
+                let f = &f;
+                scope.spawn(move || {
+                    lists.iter_mut().enumerate().for_each(|(i, list)| {
+                        f(chunk * chunk_size + i, list);
+                    });
+                });
+            });
+    });
+
+    lists
+}
+
+impl Deref for DrawList {
+    type Target = Draw;
+
+    fn deref(&self) -> &Self::Target {
+        &self.draw
+    }
+}
+
+impl DerefMut for DrawList {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.draw
+    }
+}
+
+pub trait DrawLists {
+    fn draw_list<'a>(&mut self, list: &'a DrawList) -> DrawBuilder<DrawListReplay<'a>>;
+}
+
+impl DrawLists for Draw {
+    fn draw_list<'a>(&mut self, list: &'a DrawList) -> DrawBuilder<DrawListReplay<'a>> {
+        DrawBuilder::new(self, DrawListReplay::new(list))
+    }
+}
+
+/// Adds the elements of a [DrawList] to the draw
+pub struct DrawListReplay<'a> {
+    list: &'a DrawList,
+    alpha: f32,
+    matrix: Option<Mat3>,
+}
+
+impl<'a> DrawListReplay<'a> {
+    pub fn new(list: &'a DrawList) -> Self {
+        Self {
+            list,
+            alpha: 1.0,
+            matrix: None,
+        }
+    }
+
+    pub fn alpha(&mut self, alpha: f32) -> &mut Self {
+        self.alpha = alpha;
+        self
+    }
+}
+
+impl DrawTransform for DrawListReplay<'_> {
+    fn matrix(&mut self) -> &mut Option<Mat3> {
+        &mut self.matrix
+    }
+}
+
+impl DrawProcess for DrawListReplay<'_> {
+    fn draw_process(self, draw: &mut Draw) {
+        let matrix = self.matrix.unwrap_or(Mat3::IDENTITY);
+        let (alpha, depth) = (draw.alpha(), draw.depth());
+
+        self.list.commands().iter().for_each(|rc| {
+            let transform = matrix * rc.matrix;
+            let transform = Some(&transform);
+            let (blend_mode, alpha_mode) = (rc.blend_mode, rc.alpha_mode);
+            draw.set_alpha(alpha * self.alpha * rc.alpha);
+            draw.set_depth(rc.depth);
+
+            match &rc.command {
+                DrawCommand::Shape { vertices, indices } => draw.add_shape(&ShapeInfo {
+                    transform,
+                    vertices,
+                    indices,
+                    blend_mode,
+                    alpha_mode,
+                }),
+                DrawCommand::Image {
+                    texture,
+                    vertices,
+                    indices,
+                } => draw.add_image(&ImageInfo {
+                    texture,
+                    transform,
+                    vertices,
+                    indices,
+                    blend_mode,
+                    alpha_mode,
+                }),
+                DrawCommand::Pattern {
+                    texture,
+                    vertices,
+                    indices,
+                } => draw.add_pattern(&ImageInfo {
+                    texture,
+                    transform,
+                    vertices,
+                    indices,
+                    blend_mode,
+                    alpha_mode,
+                }),
+                DrawCommand::Text {
+                    section,
+                    font,
+                    flip,
+                    justify,
+                    sdf,
+                } => draw.add_text(&TextInfo {
+                    transform,
+                    section: &section.to_borrowed(),
+                    font,
+                    blend_mode,
+                    alpha_mode,
+                    flip: *flip,
+                    justify: *justify,
+                    sdf: *sdf,
+                }),
+                DrawCommand::StaticMesh { mesh, color } => draw.add_static_mesh(&StaticMeshInfo {
+                    mesh,
+                    transform,
+                    color: *color,
+                    blend_mode,
+                    alpha_mode,
+                }),
+            }
+        });
+
+        draw.set_alpha(alpha);
+        draw.set_depth(depth);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::shapes::DrawShapes;
+
+    #[test]
+    fn build_lists_in_order() {
+        let lists = build_draw_lists(10, |i, list| {
+            (0..i).for_each(|_| {
+                list.circle(5.0);
+            });
+        });
+
+        let lengths = lists.iter().map(|l| l.len()).collect::<Vec<_>>();
+        assert_eq!(lengths, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn replay_with_transform() {
+        let mut list = DrawList::new();
+        list.set_alpha(0.5);
+        list.rect((0.0, 0.0), (10.0, 10.0));
+        list.transform().push_translate(20.0, 0.0);
+        list.rect((0.0, 0.0), (10.0, 10.0));
+        assert_eq!(list.len(), 2);
+
+        let mut draw = Draw::new(800, 600);
+        draw.draw_list(&list);
+        draw.draw_list(&list).translate(100.0, 50.0);
+        assert_eq!(draw.stats().elements, 4);
+        assert_eq!(draw.alpha(), 1.0);
+
+        // x, y, depth, r, g, b, a of the first vertex of the last rect
+        let batch = draw.current_batch.as_ref().unwrap();
+        assert_eq!(batch.vertices.len(), 16 * 7);
+        let last = &batch.vertices[12 * 7..13 * 7];
+        assert_eq!(&last[..2], &[120.0, 50.0]);
+        assert_eq!(last[6], 0.5);
+    }
+}
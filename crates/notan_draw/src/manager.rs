@@ -1,8 +1,10 @@
 use super::images::*;
+use super::meshes::*;
 use super::patterns::*;
 use super::shapes::*;
 use super::texts::*;
 use crate::batch::*;
+use crate::config::BatchConfig;
 use crate::draw::*;
 use notan_glyph::GlyphBrush;
 use notan_graphics::prelude::*;
@@ -13,8 +15,11 @@ pub struct DrawManager {
     image_painter: ImagePainter,
     pattern_painter: PatternPainter,
     text_painter: TextPainter,
+    sdf_text_painter: SdfTextPainter,
+    static_mesh_painter: StaticMeshPainter,
     renderer: Renderer,
     drawing_mask: bool,
+    batch_config: BatchConfig,
 }
 
 impl DrawManager {
@@ -23,17 +28,35 @@ impl DrawManager {
         let image_painter = ImagePainter::new(device)?;
         let pattern_painter = PatternPainter::new(device)?;
         let text_painter = TextPainter::new(device)?;
+        let sdf_text_painter = SdfTextPainter::new(device)?;
+        let static_mesh_painter = StaticMeshPainter::new(device)?;
         let renderer = device.create_renderer();
         Ok(Self {
             shape_painter,
             image_painter,
             pattern_painter,
             text_painter,
+            sdf_text_painter,
+            static_mesh_painter,
             renderer,
             drawing_mask: false,
+            batch_config: BatchConfig::default(),
         })
     }
 
+    /// Sets the batch config used by the new draws, the painters reserve
+    /// room for the initial quads so the first frames don't need to grow
+    pub fn set_batch_config(&mut self, config: BatchConfig) {
+        self.batch_config = config;
+        self.shape_painter.reserve(config.initial_quads);
+        self.image_painter.reserve(config.initial_quads);
+        self.pattern_painter.reserve(config.initial_quads);
+    }
+
+    pub fn batch_config(&self) -> BatchConfig {
+        self.batch_config
+    }
+
     pub(crate) fn process_draw(
         &mut self,
         draw: &Draw,
@@ -47,7 +70,7 @@ impl DrawManager {
     }
 
     pub fn create_draw(&self, width: u32, height: u32) -> Draw {
-        Draw::new(width, height)
+        Draw::with_batch_config(width, height, self.batch_config)
     }
 
     pub fn create_image_pipeline(
@@ -81,6 +104,14 @@ impl DrawManager {
     ) -> Result<Pipeline, String> {
         create_text_pipeline(device, fragment)
     }
+
+    pub fn create_sdf_text_pipeline(
+        &self,
+        device: &mut Device,
+        fragment: Option<&ShaderSource>,
+    ) -> Result<Pipeline, String> {
+        create_sdf_text_pipeline(device, fragment)
+    }
 }
 
 fn paint_batch(
@@ -118,6 +149,19 @@ fn paint_batch(
                 .text_painter
                 .push(device, glyphs, &mut manager.renderer, b, projection, is_rt)
         }
+        BatchType::SdfText { .. } => manager.sdf_text_painter.push(
+            device,
+            glyphs,
+            &mut manager.renderer,
+            b,
+            projection,
+            is_rt,
+        ),
+        BatchType::StaticMesh { .. } => {
+            manager
+                .static_mesh_painter
+                .push(device, &mut manager.renderer, b, projection, is_rt)
+        }
     }
 }
 
@@ -128,6 +172,8 @@ fn process_glyphs(
     glyphs: &mut GlyphBrush,
 ) {
     if let Some(indices) = &draw.text_batch_indices {
+        manager.text_painter.clear_queue();
+
         let batch_len = draw.batches.len();
         let mut last_index = usize::MAX;
         indices.iter().for_each(|i| {
@@ -145,9 +191,9 @@ fn process_glyphs(
 
             if let Some(b) = batch {
                 if let BatchType::Text { texts } = &b.typ {
-                    texts.iter().for_each(|data| {
-                        glyphs.queue(&data.section);
-                    });
+                    texts
+                        .iter()
+                        .for_each(|data| manager.text_painter.queue(device, glyphs, data));
                 }
             }
         });
@@ -169,12 +215,20 @@ fn process_draw(
     manager.shape_painter.clear();
     manager.pattern_painter.clear();
     manager.text_painter.clear();
+    manager.sdf_text_painter.clear();
+    manager.static_mesh_painter.clear();
 
-    let stencil = draw.needs_to_clean_stencil.then_some(0x00);
+    let opts = draw.clear_options;
+    let stencil = opts
+        .stencil
+        .or_else(|| draw.needs_to_clean_stencil.then_some(0x00));
+    let depth = opts
+        .depth
+        .or_else(|| draw.needs_to_clean_depth.then_some(1.0));
     manager.renderer.begin(Some(ClearOptions {
-        color: draw.clear_color,
+        color: opts.color,
+        depth,
         stencil,
-        ..Default::default()
     }));
 
     let projection = draw.projection();
@@ -191,6 +245,7 @@ fn process_draw(
     manager.shape_painter.upload_buffers(device);
     manager.pattern_painter.upload_buffers(device);
     manager.text_painter.upload_buffers(device);
+    manager.sdf_text_painter.upload_buffers(device);
 }
 
 fn override_pipeline_options(
@@ -240,17 +295,42 @@ pub(crate) fn process_pipeline(
     is_rt: bool,
 ) {
     let pip = batch.pipeline.as_ref().unwrap_or(default_pipeline);
-    let masked = masked_pip(pip, batch.is_mask, batch.masking);
-    let pip_to_use = masked.as_ref().unwrap_or(pip);
-    let blended = blended_pip(pip_to_use, batch.blend_mode, batch.alpha_mode, is_rt);
-    let final_pip = blended.as_ref().unwrap_or(pip_to_use);
-    renderer.set_pipeline(final_pip);
+    set_batch_pipeline(renderer, batch, pip, is_rt);
 
     if let Some(buffers) = &batch.uniform_buffers {
         buffers.iter().for_each(|u| renderer.bind_buffer(u));
     }
 }
 
+/// Sets the pipeline applying the mask, blend and depth options of the batch, ignoring its custom pipeline
+pub(crate) fn set_batch_pipeline(
+    renderer: &mut Renderer,
+    batch: &Batch,
+    pipeline: &Pipeline,
+    is_rt: bool,
+) {
+    let masked = masked_pip(pipeline, batch.is_mask, batch.masking);
+    let pip_to_use = masked.as_ref().unwrap_or(pipeline);
+    let blended = blended_pip(pip_to_use, batch.blend_mode, batch.alpha_mode, is_rt);
+    let pip_to_use = blended.as_ref().unwrap_or(pip_to_use);
+    let depth = depth_pip(pip_to_use, batch.depth_test);
+    let final_pip = depth.as_ref().unwrap_or(pip_to_use);
+    renderer.set_pipeline(final_pip);
+}
+
+fn depth_pip(pip: &Pipeline, depth_test: bool) -> Option<Pipeline> {
+    if !depth_test {
+        return None;
+    }
+
+    let mut pipeline = pip.clone();
+    pipeline.options.depth_stencil = DepthStencil {
+        write: true,
+        compare: CompareMode::LEqual,
+    };
+    Some(pipeline)
+}
+
 fn masked_pip(pip: &Pipeline, is_mask: bool, masking: bool) -> Option<Pipeline> {
     override_pipeline_options(pip, is_mask, masking)
 }
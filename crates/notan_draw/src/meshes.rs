@@ -0,0 +1,17 @@
+mod painter;
+mod static_mesh;
+
+use crate::builder::DrawBuilder;
+use crate::draw::Draw;
+pub(crate) use painter::*;
+pub use static_mesh::*;
+
+pub trait DrawMeshes {
+    fn static_mesh<'a>(&mut self, mesh: &'a StaticMesh) -> DrawBuilder<StaticMeshShape<'a>>;
+}
+
+impl DrawMeshes for Draw {
+    fn static_mesh<'a>(&mut self, mesh: &'a StaticMesh) -> DrawBuilder<StaticMeshShape<'a>> {
+        DrawBuilder::new(self, StaticMeshShape::new(mesh))
+    }
+}
@@ -0,0 +1,128 @@
+use crate::batch::*;
+use crate::manager::process_pipeline;
+use notan_graphics::prelude::*;
+use notan_macro::{fragment_shader, vertex_shader};
+use notan_math::{Mat3, Mat4, Vec4};
+
+//language=glsl
+const STATIC_MESH_VERTEX: ShaderSource = vertex_shader! {
+    r#"
+    #version 450
+    layout(location = 0) in vec3 a_pos;
+    layout(location = 1) in vec4 a_color;
+
+    layout(location = 0) out vec4 v_color;
+    layout(set = 0, binding = 0) uniform Locals {
+        mat4 u_transform;
+        vec4 u_color;
+    };
+
+    void main() {
+        v_color = a_color * u_color;
+        gl_Position = u_transform * vec4(a_pos, 1.0);
+    }
+    "#
+};
+
+//language=glsl
+const STATIC_MESH_FRAGMENT: ShaderSource = fragment_shader! {
+    r#"
+    #version 450
+    precision mediump float;
+
+    layout(location = 0) in vec4 v_color;
+    layout(location = 0) out vec4 color;
+
+    void main() {
+        color = v_color;
+    }
+    "#
+};
+
+pub(crate) fn vertex_info() -> VertexInfo {
+    VertexInfo::new()
+        .attr(0, VertexFormat::Float32x3)
+        .attr(1, VertexFormat::Float32x4)
+}
+
+/// The matrix of the draw converted to 3d, keeping the depth of the vertices
+fn mat3_to_mat4(m: Mat3) -> Mat4 {
+    Mat4::from_cols(
+        Vec4::new(m.x_axis.x, m.x_axis.y, 0.0, 0.0),
+        Vec4::new(m.y_axis.x, m.y_axis.y, 0.0, 0.0),
+        Vec4::Z,
+        Vec4::new(m.z_axis.x, m.z_axis.y, 0.0, 1.0),
+    )
+}
+
+pub(crate) struct StaticMeshPainter {
+    pipeline: Pipeline,
+    // each mesh drawn in the frame needs its own uniforms
+    ubos: Vec<Buffer>,
+    count: usize,
+}
+
+impl StaticMeshPainter {
+    pub fn new(device: &mut Device) -> Result<Self, String> {
+        let pipeline = device
+            .create_pipeline()
+            .from(&STATIC_MESH_VERTEX, &STATIC_MESH_FRAGMENT)
+            .with_vertex_info(&vertex_info())
+            .with_color_blend(BlendMode::NORMAL)
+            .build()?;
+
+        Ok(Self {
+            pipeline,
+            ubos: vec![],
+            count: 0,
+        })
+    }
+
+    pub fn push(
+        &mut self,
+        device: &mut Device,
+        renderer: &mut Renderer,
+        batch: &Batch,
+        projection: &Mat4,
+        is_rt: bool,
+    ) {
+        if let BatchType::StaticMesh {
+            mesh,
+            matrix,
+            color,
+        } = &batch.typ
+        {
+            let transform = *projection * mat3_to_mat4(*matrix);
+            let mut uniforms = [0.0; 20];
+            uniforms[..16].copy_from_slice(&transform.to_cols_array());
+            uniforms[16..].copy_from_slice(&color.rgba());
+
+            if self.count == self.ubos.len() {
+                match device
+                    .create_uniform_buffer(0, "Locals")
+                    .with_data(&uniforms)
+                    .build()
+                {
+                    Ok(ubo) => self.ubos.push(ubo),
+                    Err(e) => {
+                        log::error!("Cannot create the uniforms for the static mesh: {e}");
+                        return;
+                    }
+                }
+            } else {
+                device.set_buffer_data(&self.ubos[self.count], &uniforms);
+            }
+
+            let ubo = &self.ubos[self.count];
+            self.count += 1;
+
+            process_pipeline(renderer, batch, &self.pipeline, is_rt);
+            renderer.bind_buffers(&[&mesh.vbo, &mesh.ebo, ubo]);
+            renderer.draw(0, mesh.count as _);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.count = 0;
+    }
+}
@@ -0,0 +1,155 @@
+use crate::batch::BatchType;
+use crate::builder::DrawProcess;
+use crate::draw::{Draw, StaticMeshInfo};
+use crate::transform::DrawTransform;
+use notan_graphics::color::Color;
+use notan_graphics::pipeline::BlendMode;
+use notan_graphics::prelude::{Buffer, Device};
+use notan_math::{Mat3, Rect, Vec2};
+
+use super::painter::vertex_info;
+
+/// Shapes baked into GPU buffers, so they are not tessellated and uploaded again each frame.
+/// The vertices keep the transform, alpha and depth used when they were drawn
+#[derive(Clone, Debug)]
+pub struct StaticMesh {
+    pub(crate) vbo: Buffer,
+    pub(crate) ebo: Buffer,
+    pub(crate) count: u32,
+    bounds: Rect,
+}
+
+impl StaticMesh {
+    /// Bounds of the vertices, before the transform used to draw the mesh
+    pub fn bounds(&self) -> Rect {
+        self.bounds
+    }
+}
+
+pub trait CreateStaticMesh {
+    /// Bakes the shapes of the draw into a [StaticMesh], other elements like images,
+    /// texts or masks cannot be baked. The blend modes are the ones used to draw the mesh
+    fn create_static_mesh(&mut self, draw: &Draw) -> Result<StaticMesh, String>;
+}
+
+impl CreateStaticMesh for Device {
+    fn create_static_mesh(&mut self, draw: &Draw) -> Result<StaticMesh, String> {
+        let mut vertices: Vec<f32> = vec![];
+        let mut indices: Vec<u32> = vec![];
+        for b in draw.batches.iter().chain(draw.current_batch.as_ref()) {
+            if !matches!(b.typ, BatchType::Shape) || b.is_mask || b.masking {
+                return Err("Only shapes can be baked into a static mesh.".to_string());
+            }
+
+            let offset = (vertices.len() / 7) as u32;
+            indices.extend(b.indices.iter().map(|i| i + offset));
+            vertices.extend(&b.vertices);
+        }
+
+        if indices.is_empty() {
+            return Err("The draw doesn't contain any shape to bake.".to_string());
+        }
+
+        let (min, max) = vertices.chunks_exact(7).fold(
+            (Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)),
+            |(min, max), v| {
+                let pos = Vec2::new(v[0], v[1]);
+                (min.min(pos), max.max(pos))
+            },
+        );
+
+        let vbo = self
+            .create_vertex_buffer()
+            .with_info(&vertex_info())
+            .with_data(&vertices)
+            .build()?;
+
+        let ebo = self.create_index_buffer().with_data(&indices).build()?;
+
+        Ok(StaticMesh {
+            vbo,
+            ebo,
+            count: indices.len() as _,
+            bounds: Rect {
+                x: min.x,
+                y: min.y,
+                width: max.x - min.x,
+                height: max.y - min.y,
+            },
+        })
+    }
+}
+
+/// Draws a [StaticMesh] tinted with a color
+pub struct StaticMeshShape<'a> {
+    mesh: &'a StaticMesh,
+    color: Color,
+    alpha: f32,
+    matrix: Option<Mat3>,
+    blend_mode: Option<BlendMode>,
+    alpha_mode: Option<BlendMode>,
+}
+
+impl<'a> StaticMeshShape<'a> {
+    pub fn new(mesh: &'a StaticMesh) -> Self {
+        Self {
+            mesh,
+            color: Color::WHITE,
+            alpha: 1.0,
+            matrix: None,
+            blend_mode: None,
+            alpha_mode: None,
+        }
+    }
+
+    pub fn color(&mut self, color: Color) -> &mut Self {
+        self.color = color;
+        self
+    }
+
+    pub fn alpha(&mut self, alpha: f32) -> &mut Self {
+        self.alpha = alpha;
+        self
+    }
+
+    pub fn blend_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.blend_mode = Some(mode);
+        self
+    }
+
+    pub fn alpha_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.alpha_mode = Some(mode);
+        self
+    }
+}
+
+impl DrawTransform for StaticMeshShape<'_> {
+    fn matrix(&mut self) -> &mut Option<Mat3> {
+        &mut self.matrix
+    }
+}
+
+impl DrawProcess for StaticMeshShape<'_> {
+    fn draw_process(self, draw: &mut Draw) {
+        let Self {
+            mesh,
+            color,
+            alpha,
+            matrix,
+            blend_mode,
+            alpha_mode,
+        } = self;
+
+        if draw.cull(mesh.bounds, matrix.as_ref()) {
+            return;
+        }
+
+        draw.add_static_mesh(&StaticMeshInfo {
+            mesh,
+            transform: matrix.as_ref(),
+            color: color.with_alpha(color.a * alpha),
+            blend_mode,
+            alpha_mode,
+        });
+    }
+}
@@ -3,6 +3,7 @@ mod pattern;
 
 use crate::builder::DrawBuilder;
 use crate::draw::Draw;
+use crate::images::TextureRegion;
 use notan_graphics::Texture;
 pub use painter::create_pattern_pipeline;
 pub(crate) use painter::*;
@@ -10,10 +11,15 @@ pub use pattern::*;
 
 pub trait DrawPattern {
     fn pattern<'a>(&mut self, texture: &'a Texture) -> DrawBuilder<Pattern<'a>>;
+    fn pattern_region<'a>(&mut self, region: TextureRegion<'a>) -> DrawBuilder<Pattern<'a>>;
 }
 
 impl DrawPattern for Draw {
     fn pattern<'a>(&mut self, texture: &'a Texture) -> DrawBuilder<Pattern<'a>> {
         DrawBuilder::new(self, Pattern::new(texture))
     }
+
+    fn pattern_region<'a>(&mut self, region: TextureRegion<'a>) -> DrawBuilder<Pattern<'a>> {
+        DrawBuilder::new(self, Pattern::new(region))
+    }
 }
@@ -8,7 +8,7 @@ use notan_math::Mat4;
 const PATTERN_VERTEX: ShaderSource = vertex_shader! {
     r#"
     #version 450
-    layout(location = 0) in vec2 a_pos;
+    layout(location = 0) in vec3 a_pos;
     layout(location = 1) in vec2 a_uvs;
     layout(location = 2) in vec4 a_frame;
     layout(location = 3) in vec4 a_color;
@@ -25,7 +25,7 @@ const PATTERN_VERTEX: ShaderSource = vertex_shader! {
         v_uvs = a_uvs;
         v_frame = a_frame;
         v_color = a_color;
-        gl_Position = u_projection * vec4(a_pos, 0.0, 1.0);
+        gl_Position = u_projection * vec4(a_pos, 1.0);
     }
     "#
 };
@@ -45,7 +45,8 @@ const PATTERN_FRAGMENT: ShaderSource = fragment_shader! {
     layout(location = 0) out vec4 color;
 
     void main() {
-        vec2 coords = v_frame.xy + fract(v_uvs) * v_frame.zw;
+        // negative frames means that the texture repeats by itself
+        vec2 coords = v_frame.z < 0.0 ? v_uvs : v_frame.xy + fract(v_uvs) * v_frame.zw;
         color = texture(u_texture, coords) * v_color;
     }
     "#
@@ -53,7 +54,7 @@ const PATTERN_FRAGMENT: ShaderSource = fragment_shader! {
 
 fn vertex_info() -> VertexInfo {
     VertexInfo::new()
-        .attr(0, VertexFormat::Float32x2)
+        .attr(0, VertexFormat::Float32x3)
         .attr(1, VertexFormat::Float32x2)
         .attr(2, VertexFormat::Float32x4)
         .attr(3, VertexFormat::Float32x4)
@@ -149,6 +150,12 @@ impl PatternPainter {
         }
     }
 
+    /// Reserves room for the vertices and indices of the quads given
+    pub fn reserve(&mut self, quads: usize) {
+        self.vertices.reserve(quads * 4 * self.pipeline.offset());
+        self.indices.reserve(quads * 6);
+    }
+
     pub fn clear(&mut self) {
         self.count_vertices = 0;
         self.count_indices = 0;
@@ -1,14 +1,15 @@
 use crate::builder::DrawProcess;
 use crate::draw::{Draw, ImageInfo};
+use crate::images::TextureRegion;
 use crate::transform::DrawTransform;
 use notan_graphics::color::Color;
 use notan_graphics::pipeline::BlendMode;
-use notan_graphics::Texture;
+use notan_graphics::TextureWrap;
 use notan_math::Mat3;
 
 pub struct Pattern<'a> {
     matrix: Option<Mat3>,
-    texture: &'a Texture,
+    region: TextureRegion<'a>,
     pos: (f32, f32),
     size: (f32, f32),
     offset: (f32, f32),
@@ -18,22 +19,27 @@ pub struct Pattern<'a> {
     blend_mode: Option<BlendMode>,
     alpha_mode: Option<BlendMode>,
     flip: (bool, bool),
+    rotation: f32,
+    anchor: (f32, f32),
 }
 
 impl<'a> Pattern<'a> {
-    pub fn new(texture: &'a Texture) -> Self {
+    pub fn new<T: Into<TextureRegion<'a>>>(texture: T) -> Self {
+        let region = texture.into();
         Self {
             matrix: None,
-            texture,
+            region,
             pos: (0.0, 0.0),
             color: Color::WHITE,
             alpha: 1.0,
-            size: (texture.width(), texture.height()),
+            size: region.size(),
             offset: (0.0, 0.0),
             scale: (1.0, 1.0),
             blend_mode: None,
             alpha_mode: None,
             flip: (false, false),
+            rotation: 0.0,
+            anchor: (0.0, 0.0),
         }
     }
 
@@ -57,6 +63,24 @@ impl<'a> Pattern<'a> {
         self
     }
 
+    /// Rotates the tiled image using radians, the quad is not rotated
+    pub fn image_rotation(&mut self, angle: f32) -> &mut Self {
+        self.rotation = angle;
+        self
+    }
+
+    /// Rotates the tiled image using degrees, the quad is not rotated
+    pub fn image_rotation_degrees(&mut self, deg: f32) -> &mut Self {
+        self.image_rotation(deg.to_radians())
+    }
+
+    /// Point where the tiling starts and the image rotates, relative to the size.
+    /// (0.0, 0.0), the default, is the top-left corner and (0.5, 0.5) is the center
+    pub fn image_anchor(&mut self, x: f32, y: f32) -> &mut Self {
+        self.anchor = (x, y);
+        self
+    }
+
     pub fn color(&mut self, color: Color) -> &mut Self {
         self.color = color;
         self
@@ -98,7 +122,7 @@ impl DrawProcess for Pattern<'_> {
     fn draw_process(self, draw: &mut Draw) {
         let Self {
             pos: (x1, y1),
-            texture,
+            region,
             color,
             matrix,
             alpha,
@@ -108,10 +132,13 @@ impl DrawProcess for Pattern<'_> {
             blend_mode,
             alpha_mode,
             flip: (flip_x, flip_y),
+            rotation,
+            anchor: (ax, ay),
         } = self;
 
         let c = color.with_alpha(color.a * alpha);
-        let frame = texture.frame();
+        let texture = region.texture();
+        let frame = region.frame();
 
         let x2 = x1 + width;
         let y2 = y1 + height;
@@ -122,21 +149,38 @@ impl DrawProcess for Pattern<'_> {
         let ox = ((ox * sx) / sw).fract();
         let oy = ((oy * sy) / sh).fract();
 
-        let uv_w = width / sw;
-        let uv_h = height / sh;
-
-        let u1 = ox;
-        let v1 = oy;
-        let u2 = uv_w + ox;
-        let v2 = uv_h + oy;
+        let anchor_x = width * ax;
+        let anchor_y = height * ay;
+        let (sin, cos) = rotation.sin_cos();
+
+        // the uvs are not clamped so the shader repeats the frame,
+        // rotating them rotates the tiled image around the anchor
+        let uvs = |x: f32, y: f32| {
+            let (dx, dy) = (x - anchor_x, y - anchor_y);
+            let rx = dx * cos + dy * sin;
+            let ry = dy * cos - dx * sin;
+            (rx / sw + ox, ry / sh + oy)
+        };
 
         let base_width = texture.base_width();
         let base_height = texture.base_height();
 
-        let fx = frame.x / base_width;
-        let fy = frame.y / base_height;
-        let fw = frame.width / base_width;
-        let fh = frame.height / base_height;
+        // wrapping the whole texture avoids the seams of repeating the frame in the shader
+        let wraps = |w: &TextureWrap| !matches!(w, TextureWrap::Clamp);
+        let is_whole = frame.x == 0.0
+            && frame.y == 0.0
+            && frame.width == base_width
+            && frame.height == base_height;
+        let (fx, fy, fw, fh) = if is_whole && wraps(texture.wrap_x()) && wraps(texture.wrap_y()) {
+            (0.0, 0.0, -1.0, -1.0)
+        } else {
+            (
+                frame.x / base_width,
+                frame.y / base_height,
+                frame.width / base_width,
+                frame.height / base_height,
+            )
+        };
 
         let flip_y = if texture.is_render_texture() {
             !flip_y
@@ -152,15 +196,20 @@ impl DrawProcess for Pattern<'_> {
             }
         });
 
-        let (u1, u2) = if flip_x { (u2, u1) } else { (u1, u2) };
-        let (v1, v2) = if flip_y { (v2, v1) } else { (v1, v2) };
+        let (left, right) = if flip_x { (width, 0.0) } else { (0.0, width) };
+        let (top, bottom) = if flip_y { (height, 0.0) } else { (0.0, height) };
+
+        let (u1, v1) = uvs(left, top);
+        let (u2, v2) = uvs(right, top);
+        let (u3, v3) = uvs(left, bottom);
+        let (u4, v4) = uvs(right, bottom);
 
         #[rustfmt::skip]
         let vertices = [
             x1, y1, u1, v1, fx, fy, fw, fh, c.r, c.g, c.b, c.a,
-            x2, y1, u2, v1, fx, fy, fw, fh, c.r, c.g, c.b, c.a,
-            x1, y2, u1, v2, fx, fy, fw, fh, c.r, c.g, c.b, c.a,
-            x2, y2, u2, v2, fx, fy, fw, fh, c.r, c.g, c.b, c.a,
+            x2, y1, u2, v2, fx, fy, fw, fh, c.r, c.g, c.b, c.a,
+            x1, y2, u3, v3, fx, fy, fw, fh, c.r, c.g, c.b, c.a,
+            x2, y2, u4, v4, fx, fy, fw, fh, c.r, c.g, c.b, c.a,
         ];
 
         draw.add_pattern(&ImageInfo {
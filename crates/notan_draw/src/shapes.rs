@@ -1,11 +1,18 @@
+mod arc;
+mod bezier;
 mod circle;
+mod complex_polygon;
 mod ellipse;
 mod geometry;
+mod gradient;
 mod line;
+mod mesh;
 mod painter;
 mod path;
+mod path_builder;
 mod point;
 mod polygon;
+mod polyline;
 mod rect;
 mod star;
 mod tess;
@@ -13,14 +20,21 @@ mod triangle;
 
 pub use crate::builder::DrawBuilder;
 pub use crate::draw::Draw;
+pub use arc::Arc;
+pub use bezier::Bezier;
 pub use circle::Circle;
+pub use complex_polygon::ComplexPolygon;
 pub use ellipse::Ellipse;
+pub use gradient::Gradient;
 pub use line::Line;
+pub use mesh::{Mesh, MeshVertex};
 pub use painter::create_shape_pipeline;
 pub(crate) use painter::*;
 pub use path::Path;
+pub use path_builder::{PathBuilder, TessellatedPath, TessellatedShape};
 pub use point::{Point, XAlignment, YAlignment};
 pub use polygon::Polygon;
+pub use polyline::Polyline;
 pub use rect::Rectangle;
 pub use star::Star;
 pub use triangle::Triangle;
@@ -28,13 +42,38 @@ pub use triangle::Triangle;
 pub trait DrawShapes {
     fn point(&mut self, x: f32, y: f32) -> DrawBuilder<Point>;
     fn line(&mut self, p1: (f32, f32), p2: (f32, f32)) -> DrawBuilder<Line>;
+    fn quadratic_bezier(
+        &mut self,
+        from: (f32, f32),
+        ctrl: (f32, f32),
+        to: (f32, f32),
+    ) -> DrawBuilder<Bezier>;
+    fn cubic_bezier(
+        &mut self,
+        from: (f32, f32),
+        ctrl1: (f32, f32),
+        ctrl2: (f32, f32),
+        to: (f32, f32),
+    ) -> DrawBuilder<Bezier>;
     fn triangle(&mut self, a: (f32, f32), b: (f32, f32), c: (f32, f32)) -> DrawBuilder<Triangle>;
     fn path(&mut self) -> DrawBuilder<Path>;
+    fn polyline(&mut self, points: &[(f32, f32)]) -> DrawBuilder<Polyline>;
+    fn tessellated<'a>(&mut self, path: &'a TessellatedPath) -> DrawBuilder<TessellatedShape<'a>>;
+    fn mesh<'a>(&mut self, vertices: &'a [MeshVertex], indices: &'a [u32])
+        -> DrawBuilder<Mesh<'a>>;
     fn rect(&mut self, position: (f32, f32), size: (f32, f32)) -> DrawBuilder<Rectangle>;
     fn circle(&mut self, radius: f32) -> DrawBuilder<Circle>;
+    fn arc(&mut self, radius: f32, start_angle: f32, end_angle: f32) -> DrawBuilder<Arc>;
+    fn pie(&mut self, radius: f32, start_angle: f32, end_angle: f32) -> DrawBuilder<Arc>;
     fn ellipse(&mut self, position: (f32, f32), size: (f32, f32)) -> DrawBuilder<Ellipse>;
     fn star(&mut self, spikes: u8, outer_radius: f32, inner_radius: f32) -> DrawBuilder<Star>;
     fn polygon(&mut self, sides: u8, radius: f32) -> DrawBuilder<Polygon>;
+    fn complex_polygon(&mut self, points: &[(f32, f32)]) -> DrawBuilder<ComplexPolygon>;
+    fn polygon_with_holes(
+        &mut self,
+        outer: &[(f32, f32)],
+        holes: &[&[(f32, f32)]],
+    ) -> DrawBuilder<ComplexPolygon>;
 }
 
 impl DrawShapes for Draw {
@@ -46,6 +85,25 @@ impl DrawShapes for Draw {
         DrawBuilder::new(self, Line::new(p1, p2))
     }
 
+    fn quadratic_bezier(
+        &mut self,
+        from: (f32, f32),
+        ctrl: (f32, f32),
+        to: (f32, f32),
+    ) -> DrawBuilder<Bezier> {
+        DrawBuilder::new(self, Bezier::quadratic(from, ctrl, to))
+    }
+
+    fn cubic_bezier(
+        &mut self,
+        from: (f32, f32),
+        ctrl1: (f32, f32),
+        ctrl2: (f32, f32),
+        to: (f32, f32),
+    ) -> DrawBuilder<Bezier> {
+        DrawBuilder::new(self, Bezier::cubic(from, ctrl1, ctrl2, to))
+    }
+
     fn triangle(&mut self, a: (f32, f32), b: (f32, f32), c: (f32, f32)) -> DrawBuilder<Triangle> {
         DrawBuilder::new(self, Triangle::new(a, b, c))
     }
@@ -54,6 +112,22 @@ impl DrawShapes for Draw {
         DrawBuilder::new(self, Path::new())
     }
 
+    fn polyline(&mut self, points: &[(f32, f32)]) -> DrawBuilder<Polyline> {
+        DrawBuilder::new(self, Polyline::new(points))
+    }
+
+    fn tessellated<'a>(&mut self, path: &'a TessellatedPath) -> DrawBuilder<TessellatedShape<'a>> {
+        DrawBuilder::new(self, TessellatedShape::new(path))
+    }
+
+    fn mesh<'a>(
+        &mut self,
+        vertices: &'a [MeshVertex],
+        indices: &'a [u32],
+    ) -> DrawBuilder<Mesh<'a>> {
+        DrawBuilder::new(self, Mesh::new(vertices, indices))
+    }
+
     fn rect(&mut self, position: (f32, f32), size: (f32, f32)) -> DrawBuilder<Rectangle> {
         DrawBuilder::new(self, Rectangle::new(position, size))
     }
@@ -62,6 +136,14 @@ impl DrawShapes for Draw {
         DrawBuilder::new(self, Circle::new(radius))
     }
 
+    fn arc(&mut self, radius: f32, start_angle: f32, end_angle: f32) -> DrawBuilder<Arc> {
+        DrawBuilder::new(self, Arc::new(radius, start_angle, end_angle))
+    }
+
+    fn pie(&mut self, radius: f32, start_angle: f32, end_angle: f32) -> DrawBuilder<Arc> {
+        DrawBuilder::new(self, Arc::new_pie(radius, start_angle, end_angle))
+    }
+
     fn ellipse(&mut self, position: (f32, f32), size: (f32, f32)) -> DrawBuilder<Ellipse> {
         DrawBuilder::new(self, Ellipse::new(position, size))
     }
@@ -73,4 +155,20 @@ impl DrawShapes for Draw {
     fn polygon(&mut self, sides: u8, radius: f32) -> DrawBuilder<Polygon> {
         DrawBuilder::new(self, Polygon::new(sides, radius))
     }
+
+    fn complex_polygon(&mut self, points: &[(f32, f32)]) -> DrawBuilder<ComplexPolygon> {
+        DrawBuilder::new(self, ComplexPolygon::new(points))
+    }
+
+    fn polygon_with_holes(
+        &mut self,
+        outer: &[(f32, f32)],
+        holes: &[&[(f32, f32)]],
+    ) -> DrawBuilder<ComplexPolygon> {
+        let mut polygon = ComplexPolygon::new(outer);
+        holes.iter().for_each(|hole| {
+            polygon.hole(hole);
+        });
+        DrawBuilder::new(self, polygon)
+    }
 }
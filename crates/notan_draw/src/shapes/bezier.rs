@@ -0,0 +1,138 @@
+use super::path::Path;
+use crate::builder::DrawProcess;
+use crate::draw::Draw;
+use crate::transform::DrawTransform;
+use lyon::tessellation::StrokeOptions;
+use notan_graphics::color::Color;
+use notan_graphics::pipeline::BlendMode;
+use notan_math::Mat3;
+
+#[derive(Clone, Copy)]
+enum Curve {
+    Quadratic {
+        ctrl: (f32, f32),
+    },
+    Cubic {
+        ctrl1: (f32, f32),
+        ctrl2: (f32, f32),
+    },
+}
+
+/// Stroked quadratic or cubic bezier curve
+pub struct Bezier {
+    from: (f32, f32),
+    to: (f32, f32),
+    curve: Curve,
+    color: Color,
+    stroke_width: f32,
+    alpha: f32,
+    tolerance: f32,
+    matrix: Option<Mat3>,
+    blend_mode: Option<BlendMode>,
+    alpha_mode: Option<BlendMode>,
+}
+
+impl Bezier {
+    pub fn quadratic(from: (f32, f32), ctrl: (f32, f32), to: (f32, f32)) -> Self {
+        Self::new(from, to, Curve::Quadratic { ctrl })
+    }
+
+    pub fn cubic(from: (f32, f32), ctrl1: (f32, f32), ctrl2: (f32, f32), to: (f32, f32)) -> Self {
+        Self::new(from, to, Curve::Cubic { ctrl1, ctrl2 })
+    }
+
+    fn new(from: (f32, f32), to: (f32, f32), curve: Curve) -> Self {
+        Self {
+            from,
+            to,
+            curve,
+            color: Color::WHITE,
+            stroke_width: 1.0,
+            alpha: 1.0,
+            tolerance: StrokeOptions::DEFAULT_TOLERANCE,
+            matrix: None,
+            blend_mode: None,
+            alpha_mode: None,
+        }
+    }
+
+    pub fn color(&mut self, color: Color) -> &mut Self {
+        self.color = color;
+        self
+    }
+
+    pub fn width(&mut self, width: f32) -> &mut Self {
+        self.stroke_width = width;
+        self
+    }
+
+    pub fn alpha(&mut self, alpha: f32) -> &mut Self {
+        self.alpha = alpha;
+        self
+    }
+
+    pub fn blend_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.blend_mode = Some(mode);
+        self
+    }
+
+    pub fn alpha_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.alpha_mode = Some(mode);
+        self
+    }
+
+    /// Maximum distance allowed between the curve and the segments used to approximate it
+    pub fn tolerance(&mut self, value: f32) -> &mut Self {
+        self.tolerance = value;
+        self
+    }
+}
+
+impl DrawTransform for Bezier {
+    fn matrix(&mut self) -> &mut Option<Mat3> {
+        &mut self.matrix
+    }
+}
+
+impl DrawProcess for Bezier {
+    fn draw_process(self, draw: &mut Draw) {
+        let Self {
+            from: (x, y),
+            to,
+            curve,
+            color,
+            stroke_width,
+            alpha,
+            tolerance,
+            matrix,
+            blend_mode,
+            alpha_mode,
+        } = self;
+
+        let mut path = Path::new();
+        path.move_to(x, y);
+
+        match curve {
+            Curve::Quadratic { ctrl } => path.quadratic_bezier_to(ctrl, to),
+            Curve::Cubic { ctrl1, ctrl2 } => path.cubic_bezier_to(ctrl1, ctrl2, to),
+        };
+
+        path.tolerance(tolerance)
+            .stroke(stroke_width)
+            .color(color.with_alpha(color.a * alpha));
+
+        if let Some(bm) = blend_mode {
+            path.blend_mode(bm);
+        }
+
+        if let Some(abm) = alpha_mode {
+            path.alpha_mode(abm);
+        }
+
+        if let Some(m) = matrix {
+            path.transform(m);
+        }
+
+        path.draw_process(draw);
+    }
+}
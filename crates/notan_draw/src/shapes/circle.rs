@@ -1,4 +1,5 @@
 use super::geometry;
+use super::gradient::{apply_gradient, Gradient};
 use super::tess::TessMode;
 use super::tess::*;
 use crate::builder::DrawProcess;
@@ -7,7 +8,7 @@ use crate::transform::DrawTransform;
 use lyon::tessellation::*;
 use notan_graphics::color::Color;
 use notan_graphics::pipeline::BlendMode;
-use notan_math::Mat3;
+use notan_math::{Mat3, Rect};
 
 pub struct Circle {
     color: Color,
@@ -22,6 +23,8 @@ pub struct Circle {
     modes: [Option<TessMode>; 2],
     mode_index: usize,
     fill_color: Option<Color>,
+    gradient: Option<Gradient>,
+    radial_colors: Option<(Color, Color)>,
     stroke_color: Option<Color>,
 }
 
@@ -40,6 +43,8 @@ impl Circle {
             modes: [None; 2],
             mode_index: 0,
             fill_color: None,
+            gradient: None,
+            radial_colors: None,
             stroke_color: None,
         }
     }
@@ -54,6 +59,18 @@ impl Circle {
         self
     }
 
+    /// Fills the shape using a gradient instead of a flat color
+    pub fn fill_gradient(&mut self, gradient: Gradient) -> &mut Self {
+        self.gradient = Some(gradient);
+        self
+    }
+
+    /// Fills the circle with a radial gradient from its center to its border
+    pub fn fill_radial_gradient(&mut self, inner: Color, outer: Color) -> &mut Self {
+        self.radial_colors = Some((inner, outer));
+        self
+    }
+
     pub fn fill_color(&mut self, color: Color) -> &mut Self {
         self.fill_color = Some(color);
         self
@@ -106,6 +123,18 @@ impl DrawTransform for Circle {
 
 impl DrawProcess for Circle {
     fn draw_process(self, draw: &mut Draw) {
+        let (x, y) = self.pos;
+        let extent = self.radius + self.stroke_width * 0.5;
+        let bounds = Rect {
+            x: x - extent,
+            y: y - extent,
+            width: extent * 2.0,
+            height: extent * 2.0,
+        };
+        if draw.cull(bounds, self.matrix.as_ref()) {
+            return;
+        }
+
         let modes = self.modes;
         modes.iter().enumerate().for_each(|(i, mode)| match mode {
             None => {
@@ -145,14 +174,14 @@ fn stroke(circle: &Circle, draw: &mut Draw) {
     let color = color.with_alpha(color.a * alpha);
 
     let path = geometry::circle(x, y, radius);
-    let (vertices, indices) = stroke_lyon_path(&path, color, &stroke_options);
-
-    draw.add_shape(&ShapeInfo {
-        transform: matrix.as_ref(),
-        vertices: &vertices,
-        indices: &indices,
-        blend_mode,
-        alpha_mode,
+    stroke_lyon_path(&path, color, &stroke_options, |vertices, indices| {
+        draw.add_shape(&ShapeInfo {
+            transform: matrix.as_ref(),
+            vertices,
+            indices,
+            blend_mode,
+            alpha_mode,
+        });
     });
 }
 
@@ -176,13 +205,23 @@ fn fill(circle: &Circle, draw: &mut Draw) {
     let color = color.with_alpha(color.a * alpha);
 
     let path = geometry::circle(x, y, radius);
-    let (vertices, indices) = fill_lyon_path(&path, color, &fill_options);
+    fill_lyon_path(&path, color, &fill_options, |vertices, indices| {
+        let radial = circle.radial_colors.map(|(inner, outer)| {
+            Gradient::radial((x, y), radius)
+                .color_stop(0.0, inner)
+                .color_stop(1.0, outer)
+        });
 
-    draw.add_shape(&ShapeInfo {
-        transform: matrix.as_ref(),
-        vertices: &vertices,
-        indices: &indices,
-        blend_mode,
-        alpha_mode,
+        if let Some(gradient) = radial.as_ref().or(circle.gradient.as_ref()) {
+            apply_gradient(gradient, alpha, vertices, indices);
+        }
+
+        draw.add_shape(&ShapeInfo {
+            transform: matrix.as_ref(),
+            vertices,
+            indices,
+            blend_mode,
+            alpha_mode,
+        });
     });
 }
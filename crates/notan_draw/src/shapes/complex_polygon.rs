@@ -0,0 +1,189 @@
+use super::geometry;
+use super::gradient::{apply_gradient, Gradient};
+use super::tess::TessMode;
+use super::tess::*;
+use crate::builder::DrawProcess;
+use crate::draw::{Draw, ShapeInfo};
+use crate::transform::DrawTransform;
+use lyon::tessellation::*;
+use notan_graphics::color::Color;
+use notan_graphics::pipeline::BlendMode;
+use notan_math::Mat3;
+
+/// Polygon defined by its points, it can be concave and contain holes
+pub struct ComplexPolygon {
+    color: Color,
+    points: Vec<(f32, f32)>,
+    holes: Vec<Vec<(f32, f32)>>,
+    stroke_width: f32,
+    alpha: f32,
+    matrix: Option<Mat3>,
+    blend_mode: Option<BlendMode>,
+    alpha_mode: Option<BlendMode>,
+    modes: [Option<TessMode>; 2],
+    mode_index: usize,
+    fill_color: Option<Color>,
+    gradient: Option<Gradient>,
+    stroke_color: Option<Color>,
+}
+
+impl ComplexPolygon {
+    pub fn new(points: &[(f32, f32)]) -> Self {
+        Self {
+            color: Color::WHITE,
+            points: points.to_vec(),
+            holes: vec![],
+            stroke_width: 1.0,
+            alpha: 1.0,
+            matrix: None,
+            blend_mode: None,
+            alpha_mode: None,
+            modes: [None; 2],
+            mode_index: 0,
+            fill_color: None,
+            gradient: None,
+            stroke_color: None,
+        }
+    }
+
+    /// Cuts a hole inside the polygon
+    pub fn hole(&mut self, points: &[(f32, f32)]) -> &mut Self {
+        self.holes.push(points.to_vec());
+        self
+    }
+
+    /// Fills the shape using a gradient instead of a flat color
+    pub fn fill_gradient(&mut self, gradient: Gradient) -> &mut Self {
+        self.gradient = Some(gradient);
+        self
+    }
+
+    pub fn fill_color(&mut self, color: Color) -> &mut Self {
+        self.fill_color = Some(color);
+        self
+    }
+
+    pub fn stroke_color(&mut self, color: Color) -> &mut Self {
+        self.stroke_color = Some(color);
+        self
+    }
+
+    pub fn color(&mut self, color: Color) -> &mut Self {
+        self.color = color;
+        self
+    }
+
+    pub fn alpha(&mut self, alpha: f32) -> &mut Self {
+        self.alpha = alpha;
+        self
+    }
+
+    pub fn fill(&mut self) -> &mut Self {
+        self.modes[self.mode_index] = Some(TessMode::Fill);
+        self.mode_index = (self.mode_index + 1) % 2;
+        self
+    }
+
+    pub fn stroke(&mut self, width: f32) -> &mut Self {
+        self.modes[self.mode_index] = Some(TessMode::Stroke);
+        self.stroke_width = width;
+        self.mode_index = (self.mode_index + 1) % 2;
+        self
+    }
+
+    pub fn blend_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.blend_mode = Some(mode);
+        self
+    }
+
+    pub fn alpha_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.alpha_mode = Some(mode);
+        self
+    }
+}
+
+impl DrawTransform for ComplexPolygon {
+    fn matrix(&mut self) -> &mut Option<Mat3> {
+        &mut self.matrix
+    }
+}
+
+impl DrawProcess for ComplexPolygon {
+    fn draw_process(self, draw: &mut Draw) {
+        let modes = self.modes;
+        modes.iter().enumerate().for_each(|(i, mode)| match mode {
+            None => {
+                if i == 0 {
+                    // fill by default
+                    fill(&self, draw);
+                }
+            }
+            Some(mode) => match mode {
+                TessMode::Fill => fill(&self, draw),
+                TessMode::Stroke => stroke(&self, draw),
+            },
+        });
+    }
+}
+
+fn stroke(polygon: &ComplexPolygon, draw: &mut Draw) {
+    let ComplexPolygon {
+        color,
+        stroke_width,
+        alpha,
+        matrix,
+        blend_mode,
+        alpha_mode,
+        stroke_color,
+        ..
+    } = *polygon;
+
+    let stroke_options = StrokeOptions::default().with_line_width(stroke_width);
+
+    let color = stroke_color.unwrap_or(color);
+    let color = color.with_alpha(color.a * alpha);
+
+    let path = geometry::polygon_with_holes(&polygon.points, &polygon.holes);
+    stroke_lyon_path(&path, color, &stroke_options, |vertices, indices| {
+        draw.add_shape(&ShapeInfo {
+            transform: matrix.as_ref(),
+            vertices,
+            indices,
+            blend_mode,
+            alpha_mode,
+        });
+    });
+}
+
+fn fill(polygon: &ComplexPolygon, draw: &mut Draw) {
+    let ComplexPolygon {
+        color,
+        alpha,
+        matrix,
+        blend_mode,
+        alpha_mode,
+        fill_color,
+        ..
+    } = *polygon;
+
+    // even-odd makes any contour inside the outline a hole regardless of its winding
+    let fill_options = FillOptions::default().with_fill_rule(FillRule::EvenOdd);
+
+    let color = fill_color.unwrap_or(color);
+    let color = color.with_alpha(color.a * alpha);
+
+    let path = geometry::polygon_with_holes(&polygon.points, &polygon.holes);
+    fill_lyon_path(&path, color, &fill_options, |vertices, indices| {
+        if let Some(gradient) = &polygon.gradient {
+            apply_gradient(gradient, alpha, vertices, indices);
+        }
+
+        draw.add_shape(&ShapeInfo {
+            transform: matrix.as_ref(),
+            vertices,
+            indices,
+            blend_mode,
+            alpha_mode,
+        });
+    });
+}
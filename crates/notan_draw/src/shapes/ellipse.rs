@@ -1,4 +1,5 @@
 use super::geometry;
+use super::gradient::{apply_gradient, Gradient};
 use super::tess::TessMode;
 use super::tess::*;
 use crate::builder::DrawProcess;
@@ -7,8 +8,9 @@ use crate::transform::DrawTransform;
 use lyon::tessellation::*;
 use notan_graphics::color::Color;
 use notan_graphics::pipeline::BlendMode;
-use notan_math::Mat3;
+use notan_math::{Mat3, Rect};
 
+/// Ellipse defined by its center and its horizontal and vertical radius
 pub struct Ellipse {
     color: Color,
     pos: (f32, f32),
@@ -23,6 +25,7 @@ pub struct Ellipse {
     modes: [Option<TessMode>; 2],
     mode_index: usize,
     fill_color: Option<Color>,
+    gradient: Option<Gradient>,
     stroke_color: Option<Color>,
 }
 
@@ -42,15 +45,18 @@ impl Ellipse {
             modes: [None; 2],
             mode_index: 0,
             fill_color: None,
+            gradient: None,
             stroke_color: None,
         }
     }
 
+    /// Rotates the ellipse around its center without using the transformation matrix
     pub fn rotate(&mut self, radians: f32) -> &mut Self {
         self.rotation = radians;
         self
     }
 
+    /// Same as [Ellipse::rotate] but using degrees
     pub fn rotate_degrees(&mut self, deg: f32) -> &mut Self {
         self.rotation = deg.to_radians();
         self
@@ -61,6 +67,12 @@ impl Ellipse {
         self
     }
 
+    /// Fills the shape using a gradient instead of a flat color
+    pub fn fill_gradient(&mut self, gradient: Gradient) -> &mut Self {
+        self.gradient = Some(gradient);
+        self
+    }
+
     pub fn fill_color(&mut self, color: Color) -> &mut Self {
         self.fill_color = Some(color);
         self
@@ -113,6 +125,19 @@ impl DrawTransform for Ellipse {
 
 impl DrawProcess for Ellipse {
     fn draw_process(self, draw: &mut Draw) {
+        // the biggest radius covers any rotation
+        let (x, y) = self.pos;
+        let extent = self.size.0.max(self.size.1) + self.stroke_width * 0.5;
+        let bounds = Rect {
+            x: x - extent,
+            y: y - extent,
+            width: extent * 2.0,
+            height: extent * 2.0,
+        };
+        if draw.cull(bounds, self.matrix.as_ref()) {
+            return;
+        }
+
         let modes = self.modes;
         modes.iter().enumerate().for_each(|(i, mode)| match mode {
             None => {
@@ -153,14 +178,14 @@ fn stroke(ellipse: &Ellipse, draw: &mut Draw) {
     let color = color.with_alpha(color.a * alpha);
 
     let path = geometry::ellipse(x, y, width, height, rotation);
-    let (vertices, indices) = stroke_lyon_path(&path, color, &stroke_options);
-
-    draw.add_shape(&ShapeInfo {
-        transform: matrix.as_ref(),
-        vertices: &vertices,
-        indices: &indices,
-        blend_mode,
-        alpha_mode,
+    stroke_lyon_path(&path, color, &stroke_options, |vertices, indices| {
+        draw.add_shape(&ShapeInfo {
+            transform: matrix.as_ref(),
+            vertices,
+            indices,
+            blend_mode,
+            alpha_mode,
+        });
     });
 }
 
@@ -185,13 +210,17 @@ fn fill(ellipse: &Ellipse, draw: &mut Draw) {
     let color = color.with_alpha(color.a * alpha);
 
     let path = geometry::ellipse(x, y, width, height, rotation);
-    let (vertices, indices) = fill_lyon_path(&path, color, &fill_options);
+    fill_lyon_path(&path, color, &fill_options, |vertices, indices| {
+        if let Some(gradient) = &ellipse.gradient {
+            apply_gradient(gradient, alpha, vertices, indices);
+        }
 
-    draw.add_shape(&ShapeInfo {
-        transform: matrix.as_ref(),
-        vertices: &vertices,
-        indices: &indices,
-        blend_mode,
-        alpha_mode,
+        draw.add_shape(&ShapeInfo {
+            transform: matrix.as_ref(),
+            vertices,
+            indices,
+            blend_mode,
+            alpha_mode,
+        });
     });
 }
@@ -47,7 +47,7 @@ pub(super) fn rounded_rect(
     builder.build()
 }
 
-pub fn ellipse(x: f32, y: f32, width: f32, height: f32, rotation: f32) -> Path {
+pub(super) fn ellipse(x: f32, y: f32, width: f32, height: f32, rotation: f32) -> Path {
     let mut builder = Path::builder();
     builder.add_ellipse(
         point(x, y),
@@ -57,3 +57,52 @@ pub fn ellipse(x: f32, y: f32, width: f32, height: f32, rotation: f32) -> Path {
     );
     builder.build()
 }
+
+pub(super) fn arc(
+    x: f32,
+    y: f32,
+    radius: f32,
+    start_angle: f32,
+    end_angle: f32,
+    closed: bool,
+) -> Path {
+    let arc = lyon::geom::Arc {
+        center: point(x, y),
+        radii: vector(radius, radius),
+        start_angle: Angle::radians(start_angle),
+        sweep_angle: Angle::radians(end_angle - start_angle),
+        x_rotation: Angle::zero(),
+    };
+
+    let mut builder = Path::builder();
+    if closed {
+        builder.begin(point(x, y));
+        builder.line_to(arc.from());
+    } else {
+        builder.begin(arc.from());
+    }
+
+    arc.for_each_quadratic_bezier(&mut |segment| {
+        builder.quadratic_bezier_to(segment.ctrl, segment.to);
+    });
+
+    builder.end(closed);
+    builder.build()
+}
+
+pub(super) fn polygon_with_holes(outer: &[(f32, f32)], holes: &[Vec<(f32, f32)>]) -> Path {
+    let mut builder = Path::builder();
+    std::iter::once(outer)
+        .chain(holes.iter().map(|hole| hole.as_slice()))
+        .filter(|points| points.len() > 2)
+        .for_each(|points| {
+            builder.add_polygon(lyon::path::Polygon {
+                points: &points
+                    .iter()
+                    .map(|(x, y)| point(*x, *y))
+                    .collect::<Vec<_>>(),
+                closed: true,
+            });
+        });
+    builder.build()
+}
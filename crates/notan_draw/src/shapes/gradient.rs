@@ -0,0 +1,284 @@
+use notan_graphics::color::Color;
+use notan_math::{vec2, Vec2};
+use std::collections::HashMap;
+
+// avoid splitting forever degenerated or huge geometry
+const MAX_SUBDIVISION_DEPTH: usize = 12;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum GradientKind {
+    Linear { start: Vec2, end: Vec2 },
+    Radial { center: Vec2, radius: f32 },
+}
+
+/// Color gradient used to fill shapes instead of a flat color.
+/// Positions are in the same space as the shape's coordinates.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradient {
+    kind: GradientKind,
+    stops: Vec<(f32, Color)>,
+}
+
+impl Gradient {
+    /// Creates a gradient that goes along the line from `start` to `end`
+    pub fn linear(start: (f32, f32), end: (f32, f32)) -> Self {
+        Self {
+            kind: GradientKind::Linear {
+                start: start.into(),
+                end: end.into(),
+            },
+            stops: vec![],
+        }
+    }
+
+    /// Creates a linear gradient crossing the area with the given angle in radians.
+    /// An angle of 0.0 goes from left to right and PI/2 from top to bottom
+    pub fn linear_with_angle(position: (f32, f32), size: (f32, f32), angle: f32) -> Self {
+        let center = vec2(position.0 + size.0 * 0.5, position.1 + size.1 * 0.5);
+        let dir = vec2(angle.cos(), angle.sin());
+
+        // half length of the projection of the area over the direction
+        let half = (size.0 * dir.x.abs() + size.1 * dir.y.abs()) * 0.5;
+        let start = center - dir * half;
+        let end = center + dir * half;
+        Self::linear(start.into(), end.into())
+    }
+
+    /// Creates a gradient that goes from the center to the radius given
+    pub fn radial(center: (f32, f32), radius: f32) -> Self {
+        Self {
+            kind: GradientKind::Radial {
+                center: center.into(),
+                radius,
+            },
+            stops: vec![],
+        }
+    }
+
+    /// Adds a color at the offset given, it must be a value between 0.0 and 1.0
+    pub fn color_stop(mut self, offset: f32, color: Color) -> Self {
+        let offset = offset.clamp(0.0, 1.0);
+        let index = self.stops.partition_point(|(o, _)| *o <= offset);
+        self.stops.insert(index, (offset, color));
+        self
+    }
+
+    /// Returns the color of the gradient on the point given
+    pub fn color_at(&self, x: f32, y: f32) -> Color {
+        let t = match self.kind {
+            GradientKind::Linear { start, end } => {
+                let dir = end - start;
+                let len = dir.length_squared();
+                if len <= f32::EPSILON {
+                    0.0
+                } else {
+                    (vec2(x, y) - start).dot(dir) / len
+                }
+            }
+            GradientKind::Radial { center, radius } => {
+                if radius <= f32::EPSILON {
+                    1.0
+                } else {
+                    center.distance(vec2(x, y)) / radius
+                }
+            }
+        };
+
+        self.sample(t)
+    }
+
+    fn sample(&self, t: f32) -> Color {
+        let (first, last) = match (self.stops.first(), self.stops.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return Color::WHITE,
+        };
+
+        if t <= first.0 {
+            return first.1;
+        }
+
+        if t >= last.0 {
+            return last.1;
+        }
+
+        let index = self.stops.partition_point(|(o, _)| *o <= t);
+        let (o1, c1) = self.stops[index - 1];
+        let (o2, c2) = self.stops[index];
+        let n = (t - o1) / (o2 - o1);
+        Color::new(
+            c1.r + (c2.r - c1.r) * n,
+            c1.g + (c2.g - c1.g) * n,
+            c1.b + (c2.b - c1.b) * n,
+            c1.a + (c2.a - c1.a) * n,
+        )
+    }
+
+    /// Max length that the edges of the triangles can have to represent the gradient
+    /// properly using colors per vertex, `None` means that any size is fine
+    fn max_edge_length(&self) -> Option<f32> {
+        match self.kind {
+            GradientKind::Linear { start, end } => {
+                // colors are interpolated linearly between vertices,
+                // so two stops doesn't need any extra vertex
+                if self.stops.len() <= 2 {
+                    return None;
+                }
+
+                let len = start.distance(end);
+                Some(len / (self.stops.len() * 4) as f32)
+            }
+
+            // the distance to the center is not linear so it always needs detail
+            GradientKind::Radial { radius, .. } => {
+                Some(radius / (self.stops.len().max(2) * 4) as f32)
+            }
+        }
+    }
+}
+
+/// Replace the color of the vertices `[x, y, r, g, b, a]` with the gradient's color,
+/// splitting the triangles if the gradient needs more detail
+pub(super) fn apply_gradient(
+    gradient: &Gradient,
+    alpha: f32,
+    vertices: &mut Vec<f32>,
+    indices: &mut Vec<u32>,
+) {
+    if let Some(max_len) = gradient.max_edge_length() {
+        if max_len > 0.0 {
+            subdivide(vertices, indices, max_len);
+        }
+    }
+
+    vertices.chunks_mut(6).for_each(|v| {
+        let color = gradient.color_at(v[0], v[1]);
+        v[2] = color.r;
+        v[3] = color.g;
+        v[4] = color.b;
+        v[5] = color.a * alpha;
+    });
+}
+
+fn subdivide(vertices: &mut Vec<f32>, indices: &mut Vec<u32>, max_len: f32) {
+    let max_len_sq = max_len * max_len;
+    let mut midpoints: HashMap<(u32, u32), u32> = HashMap::new();
+    let mut stack = indices
+        .chunks_exact(3)
+        .map(|t| ([t[0], t[1], t[2]], 0))
+        .collect::<Vec<_>>();
+
+    let pos = |vertices: &[f32], i: u32| {
+        let i = i as usize * 6;
+        vec2(vertices[i], vertices[i + 1])
+    };
+
+    let mut result = Vec::with_capacity(indices.len());
+    while let Some((tri, depth)) = stack.pop() {
+        // search the longest edge
+        let (edge, len) = (0..3)
+            .map(|i| {
+                let (a, b) = (tri[i], tri[(i + 1) % 3]);
+                (i, pos(vertices, a).distance_squared(pos(vertices, b)))
+            })
+            .fold((0, 0.0), |acc, e| if e.1 > acc.1 { e } else { acc });
+
+        if len <= max_len_sq || depth >= MAX_SUBDIVISION_DEPTH {
+            result.extend_from_slice(&tri);
+            continue;
+        }
+
+        // split the triangle in two using the middle point of the longest edge
+        let (a, b, c) = (tri[edge], tri[(edge + 1) % 3], tri[(edge + 2) % 3]);
+        let key = (a.min(b), a.max(b));
+        let mid = *midpoints.entry(key).or_insert_with(|| {
+            let index = (vertices.len() / 6) as u32;
+            let (ia, ib) = (a as usize * 6, b as usize * 6);
+            for n in 0..6 {
+                let value = (vertices[ia + n] + vertices[ib + n]) * 0.5;
+                vertices.push(value);
+            }
+            index
+        });
+
+        stack.push(([a, mid, c], depth + 1));
+        stack.push(([mid, b, c], depth + 1));
+    }
+
+    *indices = result;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn linear_color_at() {
+        let gradient = Gradient::linear((0.0, 0.0), (100.0, 0.0))
+            .color_stop(1.0, Color::BLUE)
+            .color_stop(0.0, Color::RED);
+
+        let pos_expected = [
+            ((-10.0, 0.0), Color::RED),
+            ((0.0, 50.0), Color::RED),
+            ((50.0, 0.0), Color::new(0.5, 0.0, 0.5, 1.0)),
+            ((100.0, 0.0), Color::BLUE),
+            ((200.0, 0.0), Color::BLUE),
+        ];
+
+        pos_expected.into_iter().for_each(|((x, y), expect)| {
+            assert_eq!(gradient.color_at(x, y), expect);
+        });
+    }
+
+    #[test]
+    fn radial_color_at() {
+        let gradient = Gradient::radial((50.0, 50.0), 50.0)
+            .color_stop(0.0, Color::WHITE)
+            .color_stop(1.0, Color::BLACK);
+
+        let pos_expected = [
+            ((50.0, 50.0), Color::WHITE),
+            ((75.0, 50.0), Color::new(0.5, 0.5, 0.5, 1.0)),
+            ((50.0, 25.0), Color::new(0.5, 0.5, 0.5, 1.0)),
+            ((100.0, 50.0), Color::BLACK),
+            ((200.0, 200.0), Color::BLACK),
+        ];
+
+        pos_expected.into_iter().for_each(|((x, y), expect)| {
+            assert_eq!(gradient.color_at(x, y), expect);
+        });
+    }
+
+    #[test]
+    fn subdivide_keeps_area() {
+        let gradient = Gradient::linear((0.0, 0.0), (100.0, 0.0))
+            .color_stop(0.0, Color::RED)
+            .color_stop(0.5, Color::GREEN)
+            .color_stop(1.0, Color::BLUE);
+
+        #[rustfmt::skip]
+        let mut vertices = vec![
+            0.0, 0.0, 1.0, 1.0, 1.0, 1.0,
+            0.0, 100.0, 1.0, 1.0, 1.0, 1.0,
+            100.0, 100.0, 1.0, 1.0, 1.0, 1.0,
+            100.0, 0.0, 1.0, 1.0, 1.0, 1.0,
+        ];
+        let mut indices = vec![0, 1, 2, 0, 2, 3];
+        apply_gradient(&gradient, 1.0, &mut vertices, &mut indices);
+
+        assert!(indices.len() > 6);
+
+        let pos = |i: u32| {
+            let i = i as usize * 6;
+            vec2(vertices[i], vertices[i + 1])
+        };
+        let area: f32 = indices
+            .chunks_exact(3)
+            .map(|t| {
+                let (a, b, c) = (pos(t[0]), pos(t[1]), pos(t[2]));
+                (b - a).perp_dot(c - a).abs() * 0.5
+            })
+            .sum();
+        assert!((area - 10000.0).abs() < 0.1);
+    }
+}
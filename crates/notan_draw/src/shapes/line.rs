@@ -3,6 +3,7 @@ use crate::builder::DrawProcess;
 use crate::draw::Draw;
 use crate::transform::DrawTransform;
 use notan_graphics::color::Color;
+use notan_graphics::pipeline::BlendMode;
 use notan_math::Mat3;
 
 pub struct Line {
@@ -12,6 +13,8 @@ pub struct Line {
     stroke_width: f32,
     alpha: f32,
     matrix: Option<Mat3>,
+    blend_mode: Option<BlendMode>,
+    alpha_mode: Option<BlendMode>,
 }
 
 impl Line {
@@ -23,6 +26,8 @@ impl Line {
             stroke_width: 1.0,
             alpha: 1.0,
             matrix: None,
+            blend_mode: None,
+            alpha_mode: None,
         }
     }
 
@@ -40,6 +45,16 @@ impl Line {
         self.alpha = alpha;
         self
     }
+
+    pub fn blend_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.blend_mode = Some(mode);
+        self
+    }
+
+    pub fn alpha_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.alpha_mode = Some(mode);
+        self
+    }
 }
 
 impl DrawTransform for Line {
@@ -57,6 +72,8 @@ impl DrawProcess for Line {
             stroke_width,
             alpha,
             matrix,
+            blend_mode,
+            alpha_mode,
         } = self;
 
         let mut path = Path::new();
@@ -66,6 +83,14 @@ impl DrawProcess for Line {
             .color(color.with_alpha(color.a * alpha))
             .close();
 
+        if let Some(bm) = blend_mode {
+            path.blend_mode(bm);
+        }
+
+        if let Some(abm) = alpha_mode {
+            path.alpha_mode(abm);
+        }
+
         if let Some(m) = matrix {
             path.transform(m);
         }
@@ -0,0 +1,98 @@
+use crate::builder::DrawProcess;
+use crate::draw::{Draw, ShapeInfo};
+use crate::transform::DrawTransform;
+use notan_graphics::color::Color;
+use notan_graphics::pipeline::BlendMode;
+use notan_math::Mat3;
+
+/// Vertex of a [Mesh] with its own color
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MeshVertex {
+    pub x: f32,
+    pub y: f32,
+    pub color: Color,
+}
+
+impl MeshVertex {
+    pub fn new(x: f32, y: f32, color: Color) -> Self {
+        Self { x, y, color }
+    }
+}
+
+/// Triangles defined by the user, the colors are interpolated between the vertices
+pub struct Mesh<'a> {
+    vertices: &'a [MeshVertex],
+    indices: &'a [u32],
+    alpha: f32,
+    matrix: Option<Mat3>,
+    blend_mode: Option<BlendMode>,
+    alpha_mode: Option<BlendMode>,
+}
+
+impl<'a> Mesh<'a> {
+    pub fn new(vertices: &'a [MeshVertex], indices: &'a [u32]) -> Self {
+        Self {
+            vertices,
+            indices,
+            alpha: 1.0,
+            matrix: None,
+            blend_mode: None,
+            alpha_mode: None,
+        }
+    }
+
+    pub fn alpha(&mut self, alpha: f32) -> &mut Self {
+        self.alpha = alpha;
+        self
+    }
+
+    pub fn blend_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.blend_mode = Some(mode);
+        self
+    }
+
+    pub fn alpha_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.alpha_mode = Some(mode);
+        self
+    }
+}
+
+impl DrawTransform for Mesh<'_> {
+    fn matrix(&mut self) -> &mut Option<Mat3> {
+        &mut self.matrix
+    }
+}
+
+impl DrawProcess for Mesh<'_> {
+    fn draw_process(self, draw: &mut Draw) {
+        let Self {
+            vertices,
+            indices,
+            alpha,
+            matrix,
+            blend_mode,
+            alpha_mode,
+        } = self;
+
+        debug_assert!(
+            indices.iter().all(|i| (*i as usize) < vertices.len()),
+            "Mesh indices out of bounds"
+        );
+
+        let vertices = vertices
+            .iter()
+            .flat_map(|v| {
+                let c = v.color;
+                [v.x, v.y, c.r, c.g, c.b, c.a * alpha]
+            })
+            .collect::<Vec<_>>();
+
+        draw.add_shape(&ShapeInfo {
+            transform: matrix.as_ref(),
+            vertices: &vertices,
+            indices,
+            blend_mode,
+            alpha_mode,
+        });
+    }
+}
@@ -7,7 +7,7 @@ use notan_macro::{fragment_shader, vertex_shader};
 const SHAPES_VERTEX: ShaderSource = vertex_shader! {
     r#"
     #version 450
-    layout(location = 0) in vec2 a_pos;
+    layout(location = 0) in vec3 a_pos;
     layout(location = 1) in vec4 a_color;
 
     layout(location = 0) out vec4 v_color;
@@ -17,7 +17,7 @@ const SHAPES_VERTEX: ShaderSource = vertex_shader! {
 
     void main() {
         v_color = a_color;
-        gl_Position = u_projection * vec4(a_pos, 0.0, 1.0);
+        gl_Position = u_projection * vec4(a_pos, 1.0);
     }
     "#
 };
@@ -39,7 +39,7 @@ const SHAPES_FRAGMENT: ShaderSource = fragment_shader! {
 
 fn vertex_info() -> VertexInfo {
     VertexInfo::new()
-        .attr(0, VertexFormat::Float32x2)
+        .attr(0, VertexFormat::Float32x3)
         .attr(1, VertexFormat::Float32x4)
 }
 
@@ -137,6 +137,12 @@ impl ShapePainter {
         }
     }
 
+    /// Reserves room for the vertices and indices of the quads given
+    pub fn reserve(&mut self, quads: usize) {
+        self.vertices.reserve(quads * 4 * self.pipeline.offset());
+        self.indices.reserve(quads * 6);
+    }
+
     pub fn clear(&mut self) {
         self.count_vertices = 0;
         self.count_indices = 0;
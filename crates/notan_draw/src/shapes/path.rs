@@ -1,3 +1,4 @@
+use super::gradient::{apply_gradient, Gradient};
 use super::tess::{fill_lyon_path, stroke_lyon_path, TessMode};
 use crate::builder::DrawProcess;
 use crate::draw::{Draw, ShapeInfo};
@@ -22,6 +23,7 @@ pub struct Path {
     modes: [Option<TessMode>; 2],
     mode_index: usize,
     fill_color: Option<Color>,
+    gradient: Option<Gradient>,
     stroke_color: Option<Color>,
 }
 
@@ -48,6 +50,7 @@ impl Path {
             modes: [None; 2],
             mode_index: 0,
             fill_color: None,
+            gradient: None,
             stroke_color: None,
         }
     }
@@ -162,6 +165,12 @@ impl Path {
         self
     }
 
+    /// Fills the shape using a gradient instead of a flat color
+    pub fn fill_gradient(&mut self, gradient: Gradient) -> &mut Self {
+        self.gradient = Some(gradient);
+        self
+    }
+
     pub fn fill_color(&mut self, color: Color) -> &mut Self {
         self.fill_color = Some(color);
         self
@@ -231,15 +240,19 @@ fn fill(path: &Path, draw: &mut Draw) {
     let color = fill_color.unwrap_or(color);
     let color = color.with_alpha(color.a * alpha);
 
-    let path = path.builder.clone().build();
-    let (vertices, indices) = fill_lyon_path(&path, color, &fill_options);
+    let lyon_path = path.builder.clone().build();
+    fill_lyon_path(&lyon_path, color, &fill_options, |vertices, indices| {
+        if let Some(gradient) = &path.gradient {
+            apply_gradient(gradient, alpha, vertices, indices);
+        }
 
-    draw.add_shape(&ShapeInfo {
-        transform: matrix.as_ref(),
-        vertices: &vertices,
-        indices: &indices,
-        blend_mode,
-        alpha_mode,
+        draw.add_shape(&ShapeInfo {
+            transform: matrix.as_ref(),
+            vertices,
+            indices,
+            blend_mode,
+            alpha_mode,
+        });
     });
 }
 
@@ -259,13 +272,13 @@ fn stroke(path: &Path, draw: &mut Draw) {
     let color = color.with_alpha(color.a * alpha);
 
     let path = path.builder.clone().build();
-    let (vertices, indices) = stroke_lyon_path(&path, color, &stroke_options);
-
-    draw.add_shape(&ShapeInfo {
-        transform: matrix.as_ref(),
-        vertices: &vertices,
-        indices: &indices,
-        blend_mode,
-        alpha_mode,
+    stroke_lyon_path(&path, color, &stroke_options, |vertices, indices| {
+        draw.add_shape(&ShapeInfo {
+            transform: matrix.as_ref(),
+            vertices,
+            indices,
+            blend_mode,
+            alpha_mode,
+        });
     });
 }
@@ -0,0 +1,260 @@
+use super::tess::{fill_lyon_path, stroke_lyon_path};
+use crate::builder::DrawProcess;
+use crate::draw::{Draw, ShapeInfo};
+use crate::transform::DrawTransform;
+use lyon::math::point;
+use lyon::path::path::Builder;
+use lyon::tessellation::*;
+use notan_graphics::color::Color;
+use notan_graphics::pipeline::BlendMode;
+use notan_math::Mat3;
+
+/// Builds a vector path that can be tessellated once and drawn as many times as needed
+#[derive(Clone)]
+pub struct PathBuilder {
+    builder: Builder,
+    initialized: bool,
+    stroke_options: StrokeOptions,
+    fill_options: FillOptions,
+}
+
+impl Default for PathBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PathBuilder {
+    pub fn new() -> Self {
+        Self {
+            builder: lyon::path::Path::builder(),
+            initialized: false,
+            stroke_options: StrokeOptions::DEFAULT.with_miter_limit(f32::MAX),
+            fill_options: FillOptions::default(),
+        }
+    }
+
+    /// Start a new sub-path on the point given
+    pub fn move_to(&mut self, x: f32, y: f32) -> &mut Self {
+        if self.initialized {
+            self.builder.end(false);
+        }
+        self.builder.begin(point(x, y));
+        self.initialized = true;
+        self
+    }
+
+    /// Draw a line from the previous point to the new point
+    pub fn line_to(&mut self, x: f32, y: f32) -> &mut Self {
+        debug_assert!(self.initialized, "You should use move_to first");
+        self.builder.line_to(point(x, y));
+        self
+    }
+
+    pub fn quadratic_bezier_to(&mut self, ctrl: (f32, f32), to: (f32, f32)) -> &mut Self {
+        debug_assert!(self.initialized, "You should use move_to first");
+        self.builder
+            .quadratic_bezier_to(point(ctrl.0, ctrl.1), point(to.0, to.1));
+        self
+    }
+
+    pub fn cubic_bezier_to(
+        &mut self,
+        ctrl1: (f32, f32),
+        ctrl2: (f32, f32),
+        to: (f32, f32),
+    ) -> &mut Self {
+        debug_assert!(self.initialized, "You should use move_to first");
+        self.builder.cubic_bezier_to(
+            point(ctrl1.0, ctrl1.1),
+            point(ctrl2.0, ctrl2.1),
+            point(to.0, to.1),
+        );
+        self
+    }
+
+    /// Closes the current sub-path drawing a line to the last move_to point
+    pub fn close(&mut self) -> &mut Self {
+        debug_assert!(self.initialized, "You should use move_to first");
+        self.initialized = false;
+        self.builder.end(true);
+        self
+    }
+
+    pub fn tolerance(&mut self, tolerance: f32) -> &mut Self {
+        self.stroke_options = self.stroke_options.with_tolerance(tolerance);
+        self.fill_options = self.fill_options.with_tolerance(tolerance);
+        self
+    }
+
+    pub fn round_cap(&mut self) -> &mut Self {
+        self.stroke_options = self
+            .stroke_options
+            .with_start_cap(LineCap::Round)
+            .with_end_cap(LineCap::Round);
+        self
+    }
+
+    pub fn butt_cap(&mut self) -> &mut Self {
+        self.stroke_options = self
+            .stroke_options
+            .with_start_cap(LineCap::Butt)
+            .with_end_cap(LineCap::Butt);
+        self
+    }
+
+    pub fn square_cap(&mut self) -> &mut Self {
+        self.stroke_options = self
+            .stroke_options
+            .with_start_cap(LineCap::Square)
+            .with_end_cap(LineCap::Square);
+        self
+    }
+
+    pub fn miter_join(&mut self) -> &mut Self {
+        self.stroke_options = self.stroke_options.with_line_join(LineJoin::Miter);
+        self
+    }
+
+    pub fn round_join(&mut self) -> &mut Self {
+        self.stroke_options = self.stroke_options.with_line_join(LineJoin::Round);
+        self
+    }
+
+    pub fn bevel_join(&mut self) -> &mut Self {
+        self.stroke_options = self.stroke_options.with_line_join(LineJoin::Bevel);
+        self
+    }
+
+    fn build(&self) -> lyon::path::Path {
+        let mut builder = self.builder.clone();
+        if self.initialized {
+            builder.end(false);
+        }
+        builder.build()
+    }
+
+    /// Tessellates the inside of the path
+    pub fn fill(&self) -> TessellatedPath {
+        fill_lyon_path(
+            &self.build(),
+            Color::WHITE,
+            &self.fill_options,
+            |vertices, indices| TessellatedPath {
+                vertices: vertices.clone(),
+                indices: indices.clone(),
+            },
+        )
+    }
+
+    /// Tessellates the outline of the path
+    pub fn stroke(&self, width: f32) -> TessellatedPath {
+        let options = self.stroke_options.with_line_width(width);
+        stroke_lyon_path(
+            &self.build(),
+            Color::WHITE,
+            &options,
+            |vertices, indices| TessellatedPath {
+                vertices: vertices.clone(),
+                indices: indices.clone(),
+            },
+        )
+    }
+}
+
+/// Triangles generated by a [PathBuilder], ready to be drawn without tessellating again
+#[derive(Clone, Debug, Default)]
+pub struct TessellatedPath {
+    vertices: Vec<f32>,
+    indices: Vec<u32>,
+}
+
+impl TessellatedPath {
+    /// Vertices as `[x, y, r, g, b, a]`
+    pub fn vertices(&self) -> &[f32] {
+        &self.vertices
+    }
+
+    pub fn indices(&self) -> &[u32] {
+        &self.indices
+    }
+}
+
+/// Draws a [TessellatedPath] tinted with a color
+pub struct TessellatedShape<'a> {
+    path: &'a TessellatedPath,
+    color: Color,
+    alpha: f32,
+    matrix: Option<Mat3>,
+    blend_mode: Option<BlendMode>,
+    alpha_mode: Option<BlendMode>,
+}
+
+impl<'a> TessellatedShape<'a> {
+    pub fn new(path: &'a TessellatedPath) -> Self {
+        Self {
+            path,
+            color: Color::WHITE,
+            alpha: 1.0,
+            matrix: None,
+            blend_mode: None,
+            alpha_mode: None,
+        }
+    }
+
+    pub fn color(&mut self, color: Color) -> &mut Self {
+        self.color = color;
+        self
+    }
+
+    pub fn alpha(&mut self, alpha: f32) -> &mut Self {
+        self.alpha = alpha;
+        self
+    }
+
+    pub fn blend_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.blend_mode = Some(mode);
+        self
+    }
+
+    pub fn alpha_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.alpha_mode = Some(mode);
+        self
+    }
+}
+
+impl DrawTransform for TessellatedShape<'_> {
+    fn matrix(&mut self) -> &mut Option<Mat3> {
+        &mut self.matrix
+    }
+}
+
+impl DrawProcess for TessellatedShape<'_> {
+    fn draw_process(self, draw: &mut Draw) {
+        let Self {
+            path,
+            color,
+            alpha,
+            matrix,
+            blend_mode,
+            alpha_mode,
+        } = self;
+
+        let tint = [color.r, color.g, color.b, color.a * alpha];
+        let mut vertices = path.vertices.clone();
+        vertices.chunks_mut(6).for_each(|v| {
+            v[2..]
+                .iter_mut()
+                .zip(tint.iter())
+                .for_each(|(channel, tint)| *channel *= tint);
+        });
+
+        draw.add_shape(&ShapeInfo {
+            transform: matrix.as_ref(),
+            vertices: &vertices,
+            indices: &path.indices,
+            blend_mode,
+            alpha_mode,
+        });
+    }
+}
@@ -3,6 +3,7 @@ use crate::builder::DrawProcess;
 use crate::draw::Draw;
 use crate::transform::DrawTransform;
 use notan_graphics::color::Color;
+use notan_graphics::pipeline::BlendMode;
 use notan_math::Mat3;
 
 /// Point alignment in the X axis.
@@ -36,6 +37,8 @@ pub struct Point {
     y_align: YAlignment,
     alpha: f32,
     matrix: Option<Mat3>,
+    blend_mode: Option<BlendMode>,
+    alpha_mode: Option<BlendMode>,
 }
 
 impl Point {
@@ -49,6 +52,8 @@ impl Point {
             y_align: YAlignment::Center,
             alpha: 1.0,
             matrix: None,
+            blend_mode: None,
+            alpha_mode: None,
         }
     }
 
@@ -67,6 +72,16 @@ impl Point {
         self
     }
 
+    pub fn blend_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.blend_mode = Some(mode);
+        self
+    }
+
+    pub fn alpha_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.alpha_mode = Some(mode);
+        self
+    }
+
     pub fn align(&mut self, x_align: XAlignment, y_align: YAlignment) -> &mut Self {
         self.x_align = x_align;
         self.y_align = y_align;
@@ -123,6 +138,8 @@ impl DrawProcess for Point {
             y_align,
             alpha,
             matrix,
+            blend_mode,
+            alpha_mode,
         } = self;
 
         let mut path = Path::new();
@@ -154,6 +171,14 @@ impl DrawProcess for Point {
             .color(color.with_alpha(color.a * alpha))
             .close();
 
+        if let Some(bm) = blend_mode {
+            path.blend_mode(bm);
+        }
+
+        if let Some(abm) = alpha_mode {
+            path.alpha_mode(abm);
+        }
+
         if let Some(m) = matrix {
             path.transform(m);
         }
@@ -1,3 +1,4 @@
+use super::gradient::Gradient;
 use super::path::Path;
 use super::tess::TessMode;
 use crate::builder::DrawProcess;
@@ -12,13 +13,16 @@ use std::f32::consts::PI;
 pub struct Polygon {
     color: Color,
     pos: (f32, f32),
+    rotation: f32,
     stroke_width: f32,
     alpha: f32,
     matrix: Option<Mat3>,
     blend_mode: Option<BlendMode>,
+    alpha_mode: Option<BlendMode>,
     modes: [Option<TessMode>; 2],
     mode_index: usize,
     fill_color: Option<Color>,
+    gradient: Option<Gradient>,
     stroke_color: Option<Color>,
     sides: u8,
     radius: f32,
@@ -30,12 +34,15 @@ impl Polygon {
             color: Color::WHITE,
             stroke_width: 1.0,
             pos: (0.0, 0.0),
+            rotation: 0.0,
             alpha: 1.0,
             matrix: None,
             blend_mode: None,
+            alpha_mode: None,
             modes: [None; 2],
             mode_index: 0,
             fill_color: None,
+            gradient: None,
             stroke_color: None,
             sides,
             radius,
@@ -47,6 +54,24 @@ impl Polygon {
         self
     }
 
+    /// Rotates the shape around its center without using the transformation matrix
+    pub fn rotate(&mut self, radians: f32) -> &mut Self {
+        self.rotation = radians;
+        self
+    }
+
+    /// Same as [Polygon::rotate] but using degrees
+    pub fn rotate_degrees(&mut self, deg: f32) -> &mut Self {
+        self.rotation = deg.to_radians();
+        self
+    }
+
+    /// Fills the shape using a gradient instead of a flat color
+    pub fn fill_gradient(&mut self, gradient: Gradient) -> &mut Self {
+        self.gradient = Some(gradient);
+        self
+    }
+
     pub fn fill_color(&mut self, color: Color) -> &mut Self {
         self.fill_color = Some(color);
         self
@@ -84,6 +109,11 @@ impl Polygon {
         self.blend_mode = Some(mode);
         self
     }
+
+    pub fn alpha_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.alpha_mode = Some(mode);
+        self
+    }
 }
 
 impl DrawTransform for Polygon {
@@ -101,6 +131,7 @@ impl DrawProcess for Polygon {
             self.pos.1,
             self.sides as _,
             self.radius,
+            self.rotation,
         );
         path_builder.color(self.color).alpha(self.alpha);
 
@@ -108,6 +139,10 @@ impl DrawProcess for Polygon {
             path_builder.blend_mode(bm);
         }
 
+        if let Some(abm) = self.alpha_mode {
+            path_builder.alpha_mode(abm);
+        }
+
         if let Some(m) = self.matrix {
             path_builder.transform(m);
         }
@@ -120,6 +155,10 @@ impl DrawProcess for Polygon {
                         path_builder.fill_color(c);
                     }
 
+                    if let Some(g) = &self.gradient {
+                        path_builder.fill_gradient(g.clone());
+                    }
+
                     path_builder.fill();
                 }
             }
@@ -128,6 +167,10 @@ impl DrawProcess for Polygon {
                     if let Some(c) = self.fill_color {
                         path_builder.fill_color(c);
                     }
+
+                    if let Some(g) = &self.gradient {
+                        path_builder.fill_gradient(g.clone());
+                    }
                     path_builder.fill();
                 }
                 TessMode::Stroke => {
@@ -148,6 +191,7 @@ fn draw_polygon(
     center_y: f32,
     sides: usize,
     radius: f32,
+    rotation: f32,
 ) {
     for n in 0..sides {
         let i = n as f32;
@@ -156,7 +200,7 @@ fn draw_polygon(
         let is_even = sides % 2 == 0;
         let offset = if is_even { pi_sides } else { pi_sides * 0.5 };
 
-        let angle = i * 2.0 * pi_sides - offset;
+        let angle = i * 2.0 * pi_sides - offset + rotation;
         let x = center_x + radius * angle.cos();
         let y = center_y + radius * angle.sin();
 
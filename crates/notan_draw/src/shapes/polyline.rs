@@ -0,0 +1,168 @@
+use super::path::Path;
+use crate::builder::DrawProcess;
+use crate::draw::Draw;
+use crate::transform::DrawTransform;
+use lyon::tessellation::{LineCap, LineJoin};
+use notan_graphics::color::Color;
+use notan_graphics::pipeline::BlendMode;
+use notan_math::Mat3;
+
+/// Connected lines stroked as a single shape, so corners are joined without gaps or overlaps
+pub struct Polyline {
+    points: Vec<(f32, f32)>,
+    color: Color,
+    stroke_width: f32,
+    alpha: f32,
+    matrix: Option<Mat3>,
+    blend_mode: Option<BlendMode>,
+    alpha_mode: Option<BlendMode>,
+    join: LineJoin,
+    cap: LineCap,
+    closed: bool,
+}
+
+impl Polyline {
+    pub fn new(points: &[(f32, f32)]) -> Self {
+        Self {
+            points: points.to_vec(),
+            color: Color::WHITE,
+            stroke_width: 1.0,
+            alpha: 1.0,
+            matrix: None,
+            blend_mode: None,
+            alpha_mode: None,
+            join: LineJoin::Miter,
+            cap: LineCap::Butt,
+            closed: false,
+        }
+    }
+
+    pub fn color(&mut self, color: Color) -> &mut Self {
+        self.color = color;
+        self
+    }
+
+    pub fn width(&mut self, width: f32) -> &mut Self {
+        self.stroke_width = width;
+        self
+    }
+
+    pub fn alpha(&mut self, alpha: f32) -> &mut Self {
+        self.alpha = alpha;
+        self
+    }
+
+    pub fn blend_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.blend_mode = Some(mode);
+        self
+    }
+
+    pub fn alpha_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.alpha_mode = Some(mode);
+        self
+    }
+
+    /// Connects the last point with the first one
+    pub fn close(&mut self) -> &mut Self {
+        self.closed = true;
+        self
+    }
+
+    pub fn round_cap(&mut self) -> &mut Self {
+        self.cap = LineCap::Round;
+        self
+    }
+
+    pub fn butt_cap(&mut self) -> &mut Self {
+        self.cap = LineCap::Butt;
+        self
+    }
+
+    pub fn square_cap(&mut self) -> &mut Self {
+        self.cap = LineCap::Square;
+        self
+    }
+
+    pub fn miter_join(&mut self) -> &mut Self {
+        self.join = LineJoin::Miter;
+        self
+    }
+
+    pub fn round_join(&mut self) -> &mut Self {
+        self.join = LineJoin::Round;
+        self
+    }
+
+    pub fn bevel_join(&mut self) -> &mut Self {
+        self.join = LineJoin::Bevel;
+        self
+    }
+}
+
+impl DrawTransform for Polyline {
+    fn matrix(&mut self) -> &mut Option<Mat3> {
+        &mut self.matrix
+    }
+}
+
+impl DrawProcess for Polyline {
+    fn draw_process(self, draw: &mut Draw) {
+        let Self {
+            points,
+            color,
+            stroke_width,
+            alpha,
+            matrix,
+            blend_mode,
+            alpha_mode,
+            join,
+            cap,
+            closed,
+        } = self;
+
+        let mut iter = points.iter();
+        let (x, y) = match iter.next() {
+            Some(p) => *p,
+            None => return,
+        };
+
+        let mut path = Path::new();
+        path.move_to(x, y);
+        iter.for_each(|&(x, y)| {
+            path.line_to(x, y);
+        });
+
+        if closed {
+            path.close();
+        }
+
+        match join {
+            LineJoin::Round => path.round_join(),
+            LineJoin::Bevel => path.bevel_join(),
+            _ => path.miter_join(),
+        };
+
+        match cap {
+            LineCap::Round => path.round_cap(),
+            LineCap::Square => path.square_cap(),
+            LineCap::Butt => path.butt_cap(),
+        };
+
+        path.stroke(stroke_width)
+            .color(color.with_alpha(color.a * alpha));
+
+        if let Some(bm) = blend_mode {
+            path.blend_mode(bm);
+        }
+
+        if let Some(abm) = alpha_mode {
+            path.alpha_mode(abm);
+        }
+
+        if let Some(m) = matrix {
+            path.transform(m);
+        }
+
+        path.draw_process(draw);
+    }
+}
@@ -1,4 +1,5 @@
 use super::geometry;
+use super::gradient::{apply_gradient, Gradient};
 use super::tess::TessMode;
 use super::tess::*;
 use crate::builder::DrawProcess;
@@ -7,7 +8,7 @@ use crate::transform::DrawTransform;
 use lyon::tessellation::*;
 use notan_graphics::color::Color;
 use notan_graphics::pipeline::BlendMode;
-use notan_math::Mat3;
+use notan_math::{Mat3, Rect};
 
 pub struct Rectangle {
     colors: [Color; 4],
@@ -23,6 +24,7 @@ pub struct Rectangle {
     modes: [Option<TessMode>; 2],
     mode_index: usize,
     fill_color: Option<Color>,
+    gradient: Option<Gradient>,
     stroke_color: Option<Color>,
 }
 
@@ -42,6 +44,7 @@ impl Rectangle {
             modes: [None; 2],
             mode_index: 0,
             fill_color: None,
+            gradient: None,
             stroke_color: None,
         }
     }
@@ -80,6 +83,12 @@ impl Rectangle {
         self
     }
 
+    /// Fills the shape using a gradient instead of a flat color
+    pub fn fill_gradient(&mut self, gradient: Gradient) -> &mut Self {
+        self.gradient = Some(gradient);
+        self
+    }
+
     pub fn fill_color(&mut self, color: Color) -> &mut Self {
         self.fill_color = Some(color);
         self
@@ -140,6 +149,19 @@ impl DrawTransform for Rectangle {
 
 impl DrawProcess for Rectangle {
     fn draw_process(self, draw: &mut Draw) {
+        let (x, y) = self.pos;
+        let (width, height) = self.size;
+        let half_stroke = self.stroke_width * 0.5;
+        let bounds = Rect {
+            x: x - half_stroke,
+            y: y - half_stroke,
+            width: width + self.stroke_width,
+            height: height + self.stroke_width,
+        };
+        if draw.cull(bounds, self.matrix.as_ref()) {
+            return;
+        }
+
         let modes = self.modes;
         modes.iter().enumerate().for_each(|(i, mode)| match mode {
             None => {
@@ -185,14 +207,14 @@ fn stroke(quad: &Rectangle, draw: &mut Draw) {
         _ => geometry::rectangle(x, y, width, height),
     };
 
-    let (vertices, indices) = stroke_lyon_path(&path, color, &stroke_options);
-
-    draw.add_shape(&ShapeInfo {
-        transform: matrix.as_ref(),
-        vertices: &vertices,
-        indices: &indices,
-        blend_mode,
-        alpha_mode,
+    stroke_lyon_path(&path, color, &stroke_options, |vertices, indices| {
+        draw.add_shape(&ShapeInfo {
+            transform: matrix.as_ref(),
+            vertices,
+            indices,
+            blend_mode,
+            alpha_mode,
+        });
     });
 }
 
@@ -230,9 +252,14 @@ fn fill(quad: &Rectangle, draw: &mut Draw) {
         Some([tl, tr, bl, br]) => {
             let path = geometry::rounded_rect(x1, y1, width, height, (tl, tr, bl, br));
             let options = FillOptions::default().with_tolerance(corner_tolerance);
-            let (vertices, indices) = fill_lyon_path(&path, ca.with_alpha(ca.a * alpha), &options);
+            let color = ca.with_alpha(ca.a * alpha);
+            fill_lyon_path(&path, color, &options, |vertices, indices| {
+                if let Some(gradient) = &quad.gradient {
+                    apply_gradient(gradient, alpha, vertices, indices);
+                }
 
-            draw_shape(&vertices, &indices);
+                draw_shape(vertices, indices);
+            });
         }
         _ => {
             let x2 = x1 + width;
@@ -248,7 +275,15 @@ fn fill(quad: &Rectangle, draw: &mut Draw) {
                 x2, y1, cd.r, cd.g, cd.b, cd.a * alpha,
             ];
 
-            draw_shape(&vertices, &indices);
+            match &quad.gradient {
+                Some(gradient) => with_shape_buffers(|buffer_vertices, buffer_indices| {
+                    buffer_vertices.extend_from_slice(&vertices);
+                    buffer_indices.extend_from_slice(&indices);
+                    apply_gradient(gradient, alpha, buffer_vertices, buffer_indices);
+                    draw_shape(buffer_vertices, buffer_indices);
+                }),
+                None => draw_shape(&vertices, &indices),
+            }
         }
     };
 }
@@ -1,3 +1,4 @@
+use super::gradient::Gradient;
 use super::path::Path;
 use super::tess::TessMode;
 use crate::builder::DrawProcess;
@@ -12,13 +13,16 @@ use std::f32::consts::PI;
 pub struct Star {
     color: Color,
     pos: (f32, f32),
+    rotation: f32,
     stroke_width: f32,
     alpha: f32,
     matrix: Option<Mat3>,
     blend_mode: Option<BlendMode>,
+    alpha_mode: Option<BlendMode>,
     modes: [Option<TessMode>; 2],
     mode_index: usize,
     fill_color: Option<Color>,
+    gradient: Option<Gradient>,
     stroke_color: Option<Color>,
     spikes: u8,
     outer_radius: f32,
@@ -31,12 +35,15 @@ impl Star {
             color: Color::WHITE,
             stroke_width: 1.0,
             pos: (0.0, 0.0),
+            rotation: 0.0,
             alpha: 1.0,
             matrix: None,
             blend_mode: None,
+            alpha_mode: None,
             modes: [None; 2],
             mode_index: 0,
             fill_color: None,
+            gradient: None,
             stroke_color: None,
             spikes,
             outer_radius,
@@ -49,6 +56,24 @@ impl Star {
         self
     }
 
+    /// Rotates the shape around its center without using the transformation matrix
+    pub fn rotate(&mut self, radians: f32) -> &mut Self {
+        self.rotation = radians;
+        self
+    }
+
+    /// Same as [Star::rotate] but using degrees
+    pub fn rotate_degrees(&mut self, deg: f32) -> &mut Self {
+        self.rotation = deg.to_radians();
+        self
+    }
+
+    /// Fills the shape using a gradient instead of a flat color
+    pub fn fill_gradient(&mut self, gradient: Gradient) -> &mut Self {
+        self.gradient = Some(gradient);
+        self
+    }
+
     pub fn fill_color(&mut self, color: Color) -> &mut Self {
         self.fill_color = Some(color);
         self
@@ -86,6 +111,11 @@ impl Star {
         self.blend_mode = Some(mode);
         self
     }
+
+    pub fn alpha_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.alpha_mode = Some(mode);
+        self
+    }
 }
 
 impl DrawTransform for Star {
@@ -104,6 +134,7 @@ impl DrawProcess for Star {
             self.spikes as _,
             self.outer_radius,
             self.inner_radius,
+            self.rotation,
         );
         path_builder.color(self.color).alpha(self.alpha);
 
@@ -111,6 +142,10 @@ impl DrawProcess for Star {
             path_builder.blend_mode(bm);
         }
 
+        if let Some(abm) = self.alpha_mode {
+            path_builder.alpha_mode(abm);
+        }
+
         if let Some(m) = self.matrix {
             path_builder.transform(m);
         }
@@ -123,6 +158,10 @@ impl DrawProcess for Star {
                         path_builder.fill_color(c);
                     }
 
+                    if let Some(g) = &self.gradient {
+                        path_builder.fill_gradient(g.clone());
+                    }
+
                     path_builder.fill();
                 }
             }
@@ -131,6 +170,10 @@ impl DrawProcess for Star {
                     if let Some(c) = self.fill_color {
                         path_builder.fill_color(c);
                     }
+
+                    if let Some(g) = &self.gradient {
+                        path_builder.fill_gradient(g.clone());
+                    }
                     path_builder.fill();
                 }
                 TessMode::Stroke => {
@@ -152,12 +195,15 @@ fn draw_star(
     spikes: usize,
     outer_radius: f32,
     inner_radius: f32,
+    rotation: f32,
 ) {
     let step = PI / spikes as f32;
 
-    path_builder.move_to(center_x, center_y - outer_radius);
+    let mut rot = PI / 2.0 * 3.0 + rotation;
+    let start_x = center_x + rot.cos() * outer_radius;
+    let start_y = center_y + rot.sin() * outer_radius;
+    path_builder.move_to(start_x, start_y);
 
-    let mut rot = PI / 2.0 * 3.0;
     for _ in 0..spikes {
         let mut x = center_x + rot.cos() * outer_radius;
         let mut y = center_y + rot.sin() * outer_radius;
@@ -172,7 +218,5 @@ fn draw_star(
         path_builder.line_to(x, y);
     }
 
-    path_builder
-        .line_to(center_x, center_y - outer_radius)
-        .close();
+    path_builder.line_to(start_x, start_y).close();
 }
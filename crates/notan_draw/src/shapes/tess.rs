@@ -6,6 +6,7 @@ use std::cell::RefCell;
 thread_local! {
     static STROKE_TESSELLATOR:RefCell<StrokeTessellator> = RefCell::new(StrokeTessellator::new());
     static FILL_TESSELLATOR:RefCell<FillTessellator> = RefCell::new(FillTessellator::new());
+    static SHAPE_BUFFERS:RefCell<(Vec<f32>, Vec<u32>)> = const { RefCell::new((vec![], vec![])) };
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -14,52 +15,112 @@ pub(super) enum TessMode {
     Stroke,
 }
 
-pub(super) fn fill_lyon_path(
+/// Writes the vertices as `[x, y, r, g, b, a]` directly into the buffers
+struct ShapeGeometry<'a> {
+    vertices: &'a mut Vec<f32>,
+    indices: &'a mut Vec<u32>,
+    color: Color,
+}
+
+impl ShapeGeometry<'_> {
+    fn add_vertex(&mut self, x: f32, y: f32) -> Result<VertexId, GeometryBuilderError> {
+        let id = (self.vertices.len() / 6) as u32;
+        let Color { r, g, b, a } = self.color;
+        self.vertices.extend_from_slice(&[x, y, r, g, b, a]);
+        Ok(VertexId(id))
+    }
+}
+
+impl GeometryBuilder for ShapeGeometry<'_> {
+    fn add_triangle(&mut self, a: VertexId, b: VertexId, c: VertexId) {
+        self.indices.extend_from_slice(&[a.0, b.0, c.0]);
+    }
+
+    fn abort_geometry(&mut self) {
+        self.vertices.clear();
+        self.indices.clear();
+    }
+}
+
+impl FillGeometryBuilder for ShapeGeometry<'_> {
+    fn add_fill_vertex(&mut self, vertex: FillVertex) -> Result<VertexId, GeometryBuilderError> {
+        let [x, y] = vertex.position().to_array();
+        self.add_vertex(x, y)
+    }
+}
+
+impl StrokeGeometryBuilder for ShapeGeometry<'_> {
+    fn add_stroke_vertex(
+        &mut self,
+        vertex: StrokeVertex,
+    ) -> Result<VertexId, GeometryBuilderError> {
+        let [x, y] = vertex.position().to_array();
+        self.add_vertex(x, y)
+    }
+}
+
+/// Calls the closure with empty buffers that are reused between calls,
+/// so the shapes don't allocate once the buffers are big enough
+pub(super) fn with_shape_buffers<R>(f: impl FnOnce(&mut Vec<f32>, &mut Vec<u32>) -> R) -> R {
+    // the buffers are taken out of the cell, so a nested call just gets new ones
+    let (mut vertices, mut indices) = SHAPE_BUFFERS.with(|buffers| buffers.take());
+    vertices.clear();
+    indices.clear();
+
+    let res = f(&mut vertices, &mut indices);
+
+    SHAPE_BUFFERS.with(|buffers| buffers.replace((vertices, indices)));
+    res
+}
+
+pub(super) fn fill_lyon_path<R>(
     path: &Path,
     color: Color,
     options: &FillOptions,
-) -> (Vec<f32>, Vec<u32>) {
-    let mut geometry: VertexBuffers<[f32; 6], u32> = VertexBuffers::new();
-    {
+    f: impl FnOnce(&mut Vec<f32>, &mut Vec<u32>) -> R,
+) -> R {
+    with_shape_buffers(|vertices, indices| {
         FILL_TESSELLATOR.with(|tessellator| {
             tessellator
                 .borrow_mut()
                 .tessellate_path(
                     path,
                     options,
-                    &mut BuffersBuilder::new(&mut geometry, |vertex: FillVertex| {
-                        let [x, y] = vertex.position().to_array();
-                        [x, y, color.r, color.g, color.b, color.a]
-                    }),
+                    &mut ShapeGeometry {
+                        vertices,
+                        indices,
+                        color,
+                    },
                 )
                 .unwrap()
         });
-    }
 
-    (geometry.vertices.concat(), geometry.indices)
+        f(vertices, indices)
+    })
 }
 
-pub(super) fn stroke_lyon_path(
+pub(super) fn stroke_lyon_path<R>(
     path: &Path,
     color: Color,
     options: &StrokeOptions,
-) -> (Vec<f32>, Vec<u32>) {
-    let mut geometry: VertexBuffers<[f32; 6], u32> = VertexBuffers::new();
-    {
+    f: impl FnOnce(&mut Vec<f32>, &mut Vec<u32>) -> R,
+) -> R {
+    with_shape_buffers(|vertices, indices| {
         STROKE_TESSELLATOR.with(|tessellator| {
             tessellator
                 .borrow_mut()
                 .tessellate_path(
                     path,
                     options,
-                    &mut BuffersBuilder::new(&mut geometry, |vertex: StrokeVertex| {
-                        let [x, y] = vertex.position().to_array();
-                        [x, y, color.r, color.g, color.b, color.a]
-                    }),
+                    &mut ShapeGeometry {
+                        vertices,
+                        indices,
+                        color,
+                    },
                 )
                 .unwrap()
         });
-    }
 
-    (geometry.vertices.concat(), geometry.indices)
+        f(vertices, indices)
+    })
 }
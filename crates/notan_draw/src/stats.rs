@@ -0,0 +1,87 @@
+/// Reasons to start a new batch, each batch is at least one draw call
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BatchBreaks {
+    /// The element uses a different painter, like a text after a shape
+    pub element_type: usize,
+    /// The image or pattern texture cannot be added to the batch
+    pub texture: usize,
+    /// The custom pipeline changed
+    pub pipeline: usize,
+    /// The premultiplied alpha option or texture changed
+    pub premultiplied_alpha: usize,
+    /// The depth test was enabled or disabled
+    pub depth_test: usize,
+    /// The color blend mode changed
+    pub blend_mode: usize,
+    /// The alpha blend mode changed
+    pub alpha_mode: usize,
+    /// A mask was set or removed
+    pub mask: usize,
+    /// The batch reached the max size set in its [BatchConfig](crate::BatchConfig)
+    pub full: usize,
+}
+
+impl BatchBreaks {
+    pub fn total(&self) -> usize {
+        self.element_type
+            + self.texture
+            + self.pipeline
+            + self.premultiplied_alpha
+            + self.depth_test
+            + self.blend_mode
+            + self.alpha_mode
+            + self.mask
+            + self.full
+    }
+
+    pub(crate) fn count(&mut self, reason: BatchBreak) {
+        match reason {
+            BatchBreak::First => {}
+            BatchBreak::ElementType => self.element_type += 1,
+            BatchBreak::Texture => self.texture += 1,
+            BatchBreak::Pipeline => self.pipeline += 1,
+            BatchBreak::PremultipliedAlpha => self.premultiplied_alpha += 1,
+            BatchBreak::DepthTest => self.depth_test += 1,
+            BatchBreak::BlendMode => self.blend_mode += 1,
+            BatchBreak::AlphaMode => self.alpha_mode += 1,
+            BatchBreak::Mask => self.mask += 1,
+            BatchBreak::Full => self.full += 1,
+        }
+    }
+}
+
+/// Why an element could not be added to the current batch
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum BatchBreak {
+    /// There is no batch yet
+    First,
+    ElementType,
+    Texture,
+    Pipeline,
+    PremultipliedAlpha,
+    DepthTest,
+    BlendMode,
+    AlphaMode,
+    Mask,
+    Full,
+}
+
+/// What was added to a [Draw](crate::Draw), useful to know why it needs many draw calls.
+/// The vertices of the texts are generated when the draw is rendered, so they are not
+/// included, and the draws created with `render_to` have their own stats.
+/// Use `gfx.stats()` to know what the GPU did in the last frame
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DrawStats {
+    /// Shapes, images, patterns and texts added
+    pub elements: usize,
+    /// Elements discarded by the culling
+    pub culled: usize,
+    /// Batches to render, including the masks
+    pub batches: usize,
+    /// Vertices added to the batches
+    pub vertices: usize,
+    /// Indices added to the batches
+    pub indices: usize,
+    /// Why the batches were split
+    pub breaks: BatchBreaks,
+}
@@ -1,19 +1,45 @@
+mod bitmap_font;
+mod layout;
 mod painter;
+mod rich_text;
+mod sdf;
 mod text;
 
 use crate::builder::DrawBuilder;
 use crate::draw::Draw;
+pub use bitmap_font::*;
 pub use notan_text::{CreateFont, Font};
 pub use painter::create_text_pipeline;
 pub(crate) use painter::*;
+pub use rich_text::*;
+pub(crate) use sdf::SdfTextPainter;
+pub use sdf::{create_sdf_text_pipeline, SdfStyle};
 pub use text::*;
 
 pub trait DrawTextSection {
-    fn text<'a>(&mut self, font: &'a Font, text: &'a str) -> DrawBuilder<TextSection<'a>>;
+    fn text<'a, F: Into<TextFont<'a>>>(
+        &mut self,
+        font: F,
+        text: &'a str,
+    ) -> DrawBuilder<TextSection<'a>>;
+    fn rich_text<'a>(&mut self, font: &'a Font, spans: &'a [Span<'a>])
+        -> DrawBuilder<RichText<'a>>;
 }
 
 impl DrawTextSection for Draw {
-    fn text<'a>(&mut self, font: &'a Font, text: &'a str) -> DrawBuilder<TextSection<'a>> {
+    fn text<'a, F: Into<TextFont<'a>>>(
+        &mut self,
+        font: F,
+        text: &'a str,
+    ) -> DrawBuilder<TextSection<'a>> {
         DrawBuilder::new(self, TextSection::new(font, text))
     }
+
+    fn rich_text<'a>(
+        &mut self,
+        font: &'a Font,
+        spans: &'a [Span<'a>],
+    ) -> DrawBuilder<RichText<'a>> {
+        DrawBuilder::new(self, RichText::new(font, spans))
+    }
 }
@@ -0,0 +1,353 @@
+use notan_app::assets::{AssetLoader, Dependencies};
+use notan_graphics::Texture;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Returns a loader that parses AngelCode BMFont descriptors (text or XML format) as [BitmapFont],
+/// the page textures are loaded as dependencies and set before parsing it
+pub fn create_bitmap_font_parser() -> AssetLoader {
+    AssetLoader::new()
+        .use_dependencies(|id, data| {
+            let font = BitmapFont::from_bytes(data)?;
+            let paths = page_paths(id, &font.page_paths);
+            Ok(paths.into_iter().filter(|p| !p.is_empty()).collect())
+        })
+        .use_parser(parse_bitmap_font)
+        .extension("fnt")
+}
+
+fn parse_bitmap_font(
+    id: &str,
+    data: Vec<u8>,
+    dependencies: &Dependencies,
+) -> Result<BitmapFont, String> {
+    let mut font = BitmapFont::from_bytes(&data)?;
+    font.page_paths = page_paths(id, &font.page_paths);
+
+    for index in 0..font.page_paths.len() {
+        let path = &font.page_paths[index];
+        if path.is_empty() {
+            continue;
+        }
+
+        let texture = dependencies
+            .get::<Texture>(path)?
+            .lock()
+            .map(|texture| texture.clone())
+            .ok_or_else(|| format!("BitmapFont page '{path}' is not loaded"))?;
+        font.set_page(index, texture);
+    }
+
+    log::debug!("Asset '{}' parsed as BitmapFont", id);
+    Ok(font)
+}
+
+// the pages are relative to the descriptor
+fn page_paths(id: &str, paths: &[String]) -> Vec<String> {
+    let parent = Path::new(id).parent().unwrap_or_else(|| Path::new(""));
+    paths
+        .iter()
+        .map(|p| match p.is_empty() {
+            true => String::new(),
+            false => parent.join(p).to_string_lossy().to_string(),
+        })
+        .collect()
+}
+
+/// Glyph of a [BitmapFont], the values are in pixels
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BitmapChar {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub x_offset: f32,
+    pub y_offset: f32,
+    pub x_advance: f32,
+    pub page: usize,
+}
+
+/// Pre-rendered font described by an AngelCode BMFont file.
+/// The loader sets the page textures, otherwise they must be set with [BitmapFont::set_page]
+#[derive(Debug, Clone, Default)]
+pub struct BitmapFont {
+    face: String,
+    size: f32,
+    line_height: f32,
+    base: f32,
+    page_paths: Vec<String>,
+    pages: Vec<Option<Texture>>,
+    chars: HashMap<char, BitmapChar>,
+    kernings: HashMap<(char, char), f32>,
+}
+
+impl BitmapFont {
+    /// Parses a BMFont descriptor using the text or the XML format
+    pub fn from_bytes(data: &[u8]) -> Result<Self, String> {
+        if data.starts_with(b"BMF") {
+            return Err("The binary BMFont format is not supported".to_string());
+        }
+
+        let content = std::str::from_utf8(data).map_err(|e| e.to_string())?;
+        let mut font = BitmapFont::default();
+
+        for line in content.lines() {
+            let (tag, attrs) = parse_line(line);
+            let get = |key: &str| attrs.get(key).map(String::as_str).unwrap_or_default();
+            let num = |key: &str| get(key).parse::<f32>().unwrap_or_default();
+            let to_char = |key: &str| get(key).parse::<u32>().ok().and_then(char::from_u32);
+
+            match tag {
+                "info" => {
+                    font.face = get("face").to_string();
+                    // negative sizes means that the font was generated matching the char height
+                    font.size = num("size").abs();
+                }
+                "common" => {
+                    font.line_height = num("lineHeight");
+                    font.base = num("base");
+                }
+                "page" => {
+                    let id = get("id").parse::<usize>().map_err(|e| e.to_string())?;
+                    if font.page_paths.len() <= id {
+                        font.page_paths.resize(id + 1, String::new());
+                    }
+                    font.page_paths[id] = get("file").to_string();
+                }
+                "char" => {
+                    if let Some(c) = to_char("id") {
+                        font.chars.insert(
+                            c,
+                            BitmapChar {
+                                x: num("x"),
+                                y: num("y"),
+                                width: num("width"),
+                                height: num("height"),
+                                x_offset: num("xoffset"),
+                                y_offset: num("yoffset"),
+                                x_advance: num("xadvance"),
+                                page: num("page") as _,
+                            },
+                        );
+                    }
+                }
+                "kerning" => {
+                    if let (Some(first), Some(second)) = (to_char("first"), to_char("second")) {
+                        font.kernings.insert((first, second), num("amount"));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if font.chars.is_empty() {
+            return Err("Invalid BMFont file, no chars were found".to_string());
+        }
+
+        font.pages = vec![None; font.page_paths.len()];
+        Ok(font)
+    }
+
+    pub fn face(&self) -> &str {
+        &self.face
+    }
+
+    /// Size in pixels used to generate the font
+    pub fn size(&self) -> f32 {
+        self.size
+    }
+
+    /// Distance in pixels between lines
+    pub fn line_height(&self) -> f32 {
+        self.line_height
+    }
+
+    /// Distance in pixels from the top of the line to the baseline
+    pub fn base(&self) -> f32 {
+        self.base
+    }
+
+    /// Paths of the page images, using the loader they are relative to the working directory
+    pub fn page_paths(&self) -> &[String] {
+        &self.page_paths
+    }
+
+    /// Sets the texture of the page with the index given
+    pub fn set_page(&mut self, index: usize, texture: Texture) -> &mut Self {
+        match self.pages.get_mut(index) {
+            Some(page) => *page = Some(texture),
+            None => log::warn!(
+                "BitmapFont '{}' doesn't have a page with the index {}",
+                self.face,
+                index
+            ),
+        }
+        self
+    }
+
+    pub fn page(&self, index: usize) -> Option<&Texture> {
+        self.pages.get(index).and_then(|p| p.as_ref())
+    }
+
+    pub fn char(&self, c: char) -> Option<&BitmapChar> {
+        self.chars.get(&c)
+    }
+
+    /// Extra space to add between the two chars
+    pub fn kerning(&self, first: char, second: char) -> f32 {
+        self.kernings
+            .get(&(first, second))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Width of a line of text using the size given
+    pub fn text_width(&self, text: &str, size: f32) -> f32 {
+        let scale = self.scale(size);
+        let mut prev = None;
+        text.chars()
+            .map(|c| {
+                let advance = self.char(c).map_or(0.0, |bc| bc.x_advance);
+                let kerning = prev.map_or(0.0, |p| self.kerning(p, c));
+                prev = Some(c);
+                (advance + kerning) * scale
+            })
+            .sum()
+    }
+
+    /// Splits the text in lines using the line breaks and the max width, breaking on spaces
+    pub fn lines<'a>(&self, text: &'a str, size: f32, max_width: Option<f32>) -> Vec<&'a str> {
+        let mut lines = vec![];
+        for paragraph in text.split('\n') {
+            let paragraph = paragraph.strip_suffix('\r').unwrap_or(paragraph);
+            let max_width = match max_width {
+                Some(mw) => mw,
+                None => {
+                    lines.push(paragraph);
+                    continue;
+                }
+            };
+
+            let mut start = 0;
+            let mut end = 0;
+            for (i, _) in paragraph.match_indices(' ').chain([(paragraph.len(), "")]) {
+                let candidate = &paragraph[start..i];
+                if end > start && self.text_width(candidate, size) > max_width {
+                    lines.push(&paragraph[start..end]);
+                    start = end + 1;
+                }
+                end = i;
+            }
+
+            lines.push(&paragraph[start..]);
+        }
+
+        lines
+    }
+
+    pub(crate) fn scale(&self, size: f32) -> f32 {
+        if self.size > 0.0 {
+            size / self.size
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Splits `tag key=value key="quoted value"` lines, XML tags use the same format
+fn parse_line(line: &str) -> (&str, HashMap<&str, String>) {
+    let line = line.trim().trim_start_matches('<');
+    let line = line.trim_end_matches('>').trim_end_matches('/');
+    let (tag, mut rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+
+    let mut attrs = HashMap::new();
+    loop {
+        rest = rest.trim_start();
+        let (key, value) = match rest.split_once('=') {
+            Some(kv) => kv,
+            None => break,
+        };
+
+        let (value, next) = match value.strip_prefix('"') {
+            Some(quoted) => quoted.split_once('"').unwrap_or((quoted, "")),
+            None => value.split_once(char::is_whitespace).unwrap_or((value, "")),
+        };
+
+        attrs.insert(key.trim(), value.to_string());
+        rest = next;
+    }
+
+    (tag, attrs)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEXT_FONT: &str = r#"info face="Pixel Font" size=-10 bold=0 italic=0 padding=0,0,0,0 spacing=1,1
+common lineHeight=12 base=9 scaleW=64 scaleH=64 pages=1 packed=0
+page id=0 file="pixel font.png"
+chars count=3
+char id=32   x=0     y=0     width=0     height=0     xoffset=0     yoffset=0     xadvance=4     page=0  chnl=15
+char id=65   x=1     y=0     width=5     height=8     xoffset=0     yoffset=1     xadvance=6     page=0  chnl=15
+char id=86   x=7     y=0     width=5     height=8     xoffset=0     yoffset=1     xadvance=6     page=0  chnl=15
+kernings count=1
+kerning first=65  second=86  amount=-1
+"#;
+
+    const XML_FONT: &str = r#"<?xml version="1.0"?>
+<font>
+  <info face="Pixel Font" size="10" />
+  <common lineHeight="12" base="9" scaleW="64" scaleH="64" pages="1" packed="0" />
+  <pages>
+    <page id="0" file="pixel font.png" />
+  </pages>
+  <chars count="3">
+    <char id="32" x="0" y="0" width="0" height="0" xoffset="0" yoffset="0" xadvance="4" page="0" chnl="15" />
+    <char id="65" x="1" y="0" width="5" height="8" xoffset="0" yoffset="1" xadvance="6" page="0" chnl="15" />
+    <char id="86" x="7" y="0" width="5" height="8" xoffset="0" yoffset="1" xadvance="6" page="0" chnl="15" />
+  </chars>
+  <kernings count="1">
+    <kerning first="65" second="86" amount="-1" />
+  </kernings>
+</font>
+"#;
+
+    #[test]
+    fn parse_formats() {
+        for data in [TEXT_FONT, XML_FONT] {
+            let font = BitmapFont::from_bytes(data.as_bytes()).unwrap();
+            assert_eq!(font.face(), "Pixel Font");
+            assert_eq!(font.size(), 10.0);
+            assert_eq!(font.line_height(), 12.0);
+            assert_eq!(font.base(), 9.0);
+            assert_eq!(font.page_paths(), ["pixel font.png"]);
+            assert_eq!(
+                font.char('V'),
+                Some(&BitmapChar {
+                    x: 7.0,
+                    y: 0.0,
+                    width: 5.0,
+                    height: 8.0,
+                    x_offset: 0.0,
+                    y_offset: 1.0,
+                    x_advance: 6.0,
+                    page: 0
+                })
+            );
+            assert_eq!(font.kerning('A', 'V'), -1.0);
+            assert_eq!(font.kerning('V', 'A'), 0.0);
+        }
+    }
+
+    #[test]
+    fn measure_and_wrap() {
+        let font = BitmapFont::from_bytes(TEXT_FONT.as_bytes()).unwrap();
+        assert_eq!(font.text_width("AV", 10.0), 11.0);
+        assert_eq!(font.text_width("AV", 20.0), 22.0);
+
+        assert_eq!(font.lines("AV A\nV", 10.0, None), ["AV A", "V"]);
+        assert_eq!(font.lines("AV AV AV", 10.0, Some(30.0)), ["AV AV", "AV"]);
+        assert_eq!(font.lines("AVAVAV A", 10.0, Some(10.0)), ["AVAVAV", "A"]);
+    }
+}
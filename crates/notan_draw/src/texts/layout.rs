@@ -0,0 +1,69 @@
+use crate::batch::TextData;
+use notan_glyph::ab_glyph::{Font, Rect};
+use notan_glyph::{GlyphPositioner, JustifiedLayout, SectionGeometry, SectionGlyph, ToSectionText};
+
+#[cfg(feature = "text_shaping")]
+use notan_glyph::ShapedLayout;
+
+#[cfg(not(feature = "text_shaping"))]
+use notan_glyph::{BuiltInLineBreaker, Layout};
+
+/// Positioner used to lay out the glyphs of a text
+#[derive(Debug, Clone, Copy, Hash, PartialEq)]
+pub(crate) enum TextLayout {
+    #[cfg(not(feature = "text_shaping"))]
+    Default(Layout<BuiltInLineBreaker>),
+    Justified(JustifiedLayout),
+    #[cfg(feature = "text_shaping")]
+    Shaped(ShapedLayout),
+}
+
+impl TextData {
+    pub fn layout(&self) -> TextLayout {
+        let layout = self.section.layout;
+        if self.justify {
+            return TextLayout::Justified(JustifiedLayout::from_layout(layout));
+        }
+
+        #[cfg(feature = "text_shaping")]
+        {
+            TextLayout::Shaped(ShapedLayout::from_layout(layout))
+        }
+
+        #[cfg(not(feature = "text_shaping"))]
+        {
+            TextLayout::Default(layout)
+        }
+    }
+}
+
+impl GlyphPositioner for TextLayout {
+    fn calculate_glyphs<F, S>(
+        &self,
+        fonts: &[F],
+        geometry: &SectionGeometry,
+        sections: &[S],
+    ) -> Vec<SectionGlyph>
+    where
+        F: Font,
+        S: ToSectionText,
+    {
+        match self {
+            #[cfg(not(feature = "text_shaping"))]
+            TextLayout::Default(l) => l.calculate_glyphs(fonts, geometry, sections),
+            TextLayout::Justified(l) => l.calculate_glyphs(fonts, geometry, sections),
+            #[cfg(feature = "text_shaping")]
+            TextLayout::Shaped(l) => l.calculate_glyphs(fonts, geometry, sections),
+        }
+    }
+
+    fn bounds_rect(&self, geometry: &SectionGeometry) -> Rect {
+        match self {
+            #[cfg(not(feature = "text_shaping"))]
+            TextLayout::Default(l) => l.bounds_rect(geometry),
+            TextLayout::Justified(l) => l.bounds_rect(geometry),
+            #[cfg(feature = "text_shaping")]
+            TextLayout::Shaped(l) => l.bounds_rect(geometry),
+        }
+    }
+}
@@ -1,6 +1,10 @@
 use crate::batch::*;
-use crate::manager::process_pipeline;
-use notan_glyph::{GlyphBrush, GlyphInstance, GlyphPipeline};
+use crate::images::create_image_pipeline;
+use crate::manager::{process_pipeline, set_batch_pipeline};
+use notan_glyph::{
+    ColorGlyphCache, Extra, GlyphBrush, GlyphCruncher, GlyphInstance, GlyphPipeline,
+    GlyphPositioner, SectionGeometry,
+};
 use notan_graphics::prelude::*;
 use notan_macro::{fragment_shader, vertex_shader};
 use notan_math::Rect;
@@ -10,7 +14,7 @@ use notan_math::{Mat4, Vec3};
 const TEXT_VERTEX: ShaderSource = vertex_shader! {
     r#"
     #version 450
-    layout(location = 0) in vec2 a_pos;
+    layout(location = 0) in vec3 a_pos;
     layout(location = 1) in vec2 a_uvs;
     layout(location = 2) in vec4 a_color;
 
@@ -23,7 +27,7 @@ const TEXT_VERTEX: ShaderSource = vertex_shader! {
     void main() {
         v_color = a_color;
         v_uvs = a_uvs;
-        gl_Position = u_projection * vec4(a_pos, 0.0, 1.0);
+        gl_Position = u_projection * vec4(a_pos, 1.0);
     }
     "#
 };
@@ -54,7 +58,7 @@ const TEXT_FRAGMENT: ShaderSource = fragment_shader! {
 
 fn vertex_info() -> VertexInfo {
     VertexInfo::new()
-        .attr(0, VertexFormat::Float32x2)
+        .attr(0, VertexFormat::Float32x3)
         .attr(1, VertexFormat::Float32x2)
         .attr(2, VertexFormat::Float32x4)
 }
@@ -65,13 +69,18 @@ pub(crate) struct TextPainter {
     pub ebo: Buffer,
     pub ubo: Buffer,
 
-    count_chars: usize,
+    color_pipeline: Pipeline,
+    color_cache: ColorGlyphCache,
+    queued_texts: usize,
+    painted_texts: usize,
+    font_cursor: usize,
+    color_cursor: usize,
     vertices: Vec<f32>,
     indices: Vec<u32>,
+    color_indices: Vec<u32>,
     uniforms: [f32; 16],
-    count_vertices: usize,
-    count_indices: usize,
     font_vertices: Vec<GlyphInstance>,
+    color_vertices: Vec<GlyphInstance>,
     dirty_buffer: bool,
 }
 
@@ -80,6 +89,8 @@ impl TextPainter {
         let uniforms = [0.0; 16];
 
         let pipeline = create_text_pipeline(device, None)?;
+        // color glyphs are drawn as images, without the text color
+        let color_pipeline = create_image_pipeline(device, None)?;
         let vbo = device
             .create_vertex_buffer()
             .with_info(&vertex_info())
@@ -98,17 +109,90 @@ impl TextPainter {
             ebo,
             ubo,
 
-            count_chars: 0,
+            color_pipeline,
+            color_cache: ColorGlyphCache::new(1024, 1024),
+            queued_texts: 0,
+            painted_texts: 0,
+            font_cursor: 0,
+            color_cursor: 0,
             vertices: vec![],
             indices: vec![],
+            color_indices: vec![],
             uniforms,
-            count_vertices: 0,
-            count_indices: 0,
             font_vertices: vec![],
+            color_vertices: vec![],
             dirty_buffer: false,
         })
     }
 
+    /// Queues the text on the glyph brush, except the color glyphs that are
+    /// stored apart to be drawn with their own texture
+    pub fn queue(&mut self, device: &mut Device, glyph_brush: &mut GlyphBrush, data: &TextData) {
+        // the index of the text is used as z to know which glyphs belong to it
+        let index = self.queued_texts as f32;
+        self.queued_texts += 1;
+
+        let layout = data.layout();
+        let mut glyphs = glyph_brush
+            .glyphs_custom_layout(&data.section, &layout)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        glyphs.retain(|sg| {
+            let font = &glyph_brush.fonts()[sg.font_id.0];
+            if !self
+                .color_cache
+                .is_color_glyph(sg.font_id, font, sg.glyph.id)
+            {
+                return true;
+            }
+
+            let glyph =
+                self.color_cache
+                    .glyph(device, sg.font_id, font, sg.glyph.id, sg.glyph.scale);
+            if let (Some(glyph), Some(texture)) = (glyph, self.color_cache.texture()) {
+                let (tw, th) = (texture.width(), texture.height());
+                let frame = glyph.frame;
+                let pos = sg.glyph.position;
+                let alpha = data.section.text[sg.section_index].extra.color[3];
+
+                self.color_vertices.push(GlyphInstance {
+                    left_top: [
+                        pos.x + glyph.bounds.min.x,
+                        pos.y + glyph.bounds.max.y,
+                        index,
+                    ],
+                    right_bottom: [pos.x + glyph.bounds.max.x, pos.y + glyph.bounds.min.y],
+                    tex_left_top: [frame.x / tw, (frame.y + frame.height) / th],
+                    tex_right_bottom: [(frame.x + frame.width) / tw, frame.y / th],
+                    color: [1.0, 1.0, 1.0, alpha],
+                });
+            }
+
+            false
+        });
+
+        let extra = data
+            .section
+            .text
+            .iter()
+            .map(|t| Extra {
+                z: index,
+                ..t.extra
+            })
+            .collect();
+
+        let section = data.section.to_borrowed();
+        let bounds = layout.bounds_rect(&SectionGeometry::from(&section));
+        glyph_brush.queue_pre_positioned(glyphs, extra, bounds);
+    }
+
+    /// Clears the texts queued on the previous frame
+    pub fn clear_queue(&mut self) {
+        self.queued_texts = 0;
+        self.color_vertices.clear();
+    }
+
     pub fn push(
         &mut self,
         device: &mut Device,
@@ -119,79 +203,63 @@ impl TextPainter {
         is_rt: bool,
     ) {
         if let BatchType::Text { texts } = &batch.typ {
-            process_pipeline(renderer, batch, &self.pipeline, is_rt);
-            let mut vertices = vec![];
-            let mut indices = vec![];
+            let offset = self.indices.len();
+            self.color_indices.clear();
 
             texts.iter().for_each(|d| {
-                let count = d.count;
-                let start = self.count_chars;
-                let end = start + count;
-                if count <= self.font_vertices.len() {
-                    let vert = &self.font_vertices[start..end];
-                    let (flip_x, flip_y) = d.flip;
-                    vert.iter().enumerate().for_each(|(i, g_instance)| {
-                        let GlyphInstance {
-                            left_top: [x1, y1, _],
-                            right_bottom: [x2, y2],
-                            tex_left_top: [u1, v1],
-                            tex_right_bottom: [u2, v2],
-                            color: [r, g, b, a],
-                        } = *g_instance;
-
-                        let a = a * d.alpha;
-
-                        let matrix = d.transform;
-
-                        let (u1, u2) = if flip_x { (u2, u1) } else { (u1, u2) };
-                        let (v1, v2) = if flip_y { (v2, v1) } else { (v1, v2) };
-
-                        let verts = [
-                            [x1, y1, u1, v1],
-                            [x2, y1, u2, v1],
-                            [x1, y2, u1, v2],
-                            [x2, y2, u2, v2],
-                        ];
-
-                        // compute the matrices and push the vertices and attributes
-                        verts.into_iter().for_each(|[x, y, u, v]| {
-                            let xyz = matrix * Vec3::new(x, y, 1.0);
-                            vertices.extend_from_slice(&[xyz.x, xyz.y, u, v, r, g, b, a]);
-                        });
-
-                        let n = ((start as u32) + (i as u32)) * 4;
-
-                        #[rustfmt::skip]
-                        indices.extend_from_slice(&[
-                            n    , n + 1, n + 2,
-                            n + 2, n + 1, n + 3
-                        ]);
-                    });
-                }
-                self.count_chars = end;
+                let index = self.painted_texts as f32;
+                self.painted_texts += 1;
+
+                let belongs = |g: &GlyphInstance| g.left_top[2] == index;
+                let start = self.font_cursor;
+                let count = self.font_vertices[start..]
+                    .iter()
+                    .take_while(|g| belongs(g))
+                    .count();
+                self.font_cursor += count;
+
+                let color_start = self.color_cursor;
+                let color_count = self.color_vertices[color_start..]
+                    .iter()
+                    .take_while(|g| belongs(g))
+                    .count();
+                self.color_cursor += color_count;
+
+                let glyphs = &self.font_vertices[start..start + count];
+                push_glyphs(glyphs, d, &mut self.vertices, &mut self.indices);
+
+                let colors = &self.color_vertices[color_start..color_start + color_count];
+                push_glyphs(colors, d, &mut self.vertices, &mut self.color_indices);
             });
 
-            let offset = self.count_indices;
-
-            self.indices.extend(&indices);
-            self.count_indices = self.indices.len();
-
-            self.vertices.extend(&vertices);
-            self.count_vertices = self.vertices.len();
+            let count = self.indices.len() - offset;
+            let color_offset = self.indices.len();
+            let color_count = self.color_indices.len();
+            self.indices.extend_from_slice(&self.color_indices);
 
             self.uniforms.copy_from_slice(&projection.to_cols_array());
 
-            self.append_to_renderer(
-                device,
-                renderer,
-                glyph_brush.texture(),
-                None,
-                *projection,
-                renderer.size(),
-                None,
-            );
-
-            renderer.draw(offset as _, indices.len() as _);
+            if count > 0 {
+                process_pipeline(renderer, batch, &self.pipeline, is_rt);
+                self.append_to_renderer(
+                    device,
+                    renderer,
+                    glyph_brush.texture(),
+                    None,
+                    *projection,
+                    renderer.size(),
+                    None,
+                );
+                renderer.draw(offset as _, count as _);
+            }
+
+            if let (true, Some(texture)) = (color_count > 0, self.color_cache.texture()) {
+                set_batch_pipeline(renderer, batch, &self.color_pipeline, is_rt);
+                renderer.bind_texture_slot(0, 0, texture);
+                renderer.bind_buffers(&[&self.vbo, &self.ebo, &self.ubo]);
+                renderer.draw(color_offset as _, color_count as _);
+            }
+
             self.dirty_buffer = true;
         }
     }
@@ -207,9 +275,9 @@ impl TextPainter {
     }
 
     pub fn clear(&mut self) {
-        self.count_chars = 0;
-        self.count_vertices = 0;
-        self.count_indices = 0;
+        self.painted_texts = 0;
+        self.font_cursor = 0;
+        self.color_cursor = 0;
         self.vertices.clear();
         self.indices.clear();
     }
@@ -236,6 +304,50 @@ impl GlyphPipeline for TextPainter {
     }
 }
 
+fn push_glyphs(
+    glyphs: &[GlyphInstance],
+    data: &TextData,
+    vertices: &mut Vec<f32>,
+    indices: &mut Vec<u32>,
+) {
+    let (flip_x, flip_y) = data.flip;
+    glyphs.iter().for_each(|g_instance| {
+        let GlyphInstance {
+            left_top: [x1, y1, _],
+            right_bottom: [x2, y2],
+            tex_left_top: [u1, v1],
+            tex_right_bottom: [u2, v2],
+            color: [r, g, b, a],
+        } = *g_instance;
+
+        let a = a * data.alpha;
+
+        let (u1, u2) = if flip_x { (u2, u1) } else { (u1, u2) };
+        let (v1, v2) = if flip_y { (v2, v1) } else { (v1, v2) };
+
+        let n = (vertices.len() / 9) as u32;
+
+        // compute the matrices and push the vertices and attributes
+        [
+            [x1, y1, u1, v1],
+            [x2, y1, u2, v1],
+            [x1, y2, u1, v2],
+            [x2, y2, u2, v2],
+        ]
+        .into_iter()
+        .for_each(|[x, y, u, v]| {
+            let xyz = data.transform * Vec3::new(x, y, 1.0);
+            vertices.extend_from_slice(&[xyz.x, xyz.y, data.depth, u, v, r, g, b, a]);
+        });
+
+        #[rustfmt::skip]
+        indices.extend_from_slice(&[
+            n    , n + 1, n + 2,
+            n + 2, n + 1, n + 3
+        ]);
+    });
+}
+
 pub fn create_text_pipeline(
     device: &mut Device,
     fragment: Option<&ShaderSource>,
@@ -0,0 +1,228 @@
+use crate::builder::DrawProcess;
+use crate::draw::{Draw, TextInfo};
+use crate::transform::DrawTransform;
+use notan_glyph::{HorizontalAlign, Layout, Section, Text, VerticalAlign};
+use notan_graphics::color::Color;
+use notan_graphics::pipeline::BlendMode;
+use notan_math::Mat3;
+use notan_text::Font;
+
+/// Run of text inside a [RichText], the values not set use the ones from the [RichText]
+#[derive(Debug, Clone, Copy)]
+pub struct Span<'a> {
+    text: &'a str,
+    font: Option<&'a Font>,
+    size: Option<f32>,
+    color: Option<Color>,
+}
+
+impl<'a> Span<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            font: None,
+            size: None,
+            color: None,
+        }
+    }
+
+    pub fn colored(text: &'a str, color: Color) -> Self {
+        Self::new(text).with_color(color)
+    }
+
+    pub fn with_font(mut self, font: &'a Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Font size in pixels
+    pub fn with_size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+}
+
+/// Sequence of [Span] laid out together, spans using different sizes or fonts
+/// share the same baseline when they are in the same line
+pub struct RichText<'a> {
+    spans: &'a [Span<'a>],
+    matrix: Option<Mat3>,
+    font: &'a Font,
+    blend_mode: Option<BlendMode>,
+    alpha_mode: Option<BlendMode>,
+    pos: (f32, f32),
+    size: f32,
+    color: Color,
+    max_width: Option<f32>,
+    h_align: HorizontalAlign,
+    v_align: VerticalAlign,
+    justify: bool,
+    alpha: f32,
+}
+
+impl<'a> RichText<'a> {
+    /// Creates the text using `font` for the spans without their own font
+    pub fn new(font: &'a Font, spans: &'a [Span<'a>]) -> Self {
+        Self {
+            spans,
+            matrix: None,
+            font,
+            blend_mode: None,
+            alpha_mode: None,
+            pos: (0.0, 0.0),
+            size: 16.0,
+            color: Color::WHITE,
+            max_width: None,
+            h_align: HorizontalAlign::Left,
+            v_align: VerticalAlign::Top,
+            justify: false,
+            alpha: 1.0,
+        }
+    }
+
+    /// Position of the text, the alignment is relative to this point
+    pub fn position(&mut self, x: f32, y: f32) -> &mut Self {
+        self.pos = (x, y);
+        self
+    }
+
+    /// Font size in pixels for the spans without their own size
+    pub fn size(&mut self, size: f32) -> &mut Self {
+        self.size = size;
+        self
+    }
+
+    /// Color for the spans without their own color
+    pub fn color(&mut self, color: Color) -> &mut Self {
+        self.color = color;
+        self
+    }
+
+    pub fn alpha(&mut self, alpha: f32) -> &mut Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Wraps the text in lines that are not wider than the value given
+    pub fn max_width(&mut self, width: f32) -> &mut Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    pub fn h_align_left(&mut self) -> &mut Self {
+        self.h_align = HorizontalAlign::Left;
+        self.justify = false;
+        self
+    }
+
+    pub fn h_align_center(&mut self) -> &mut Self {
+        self.h_align = HorizontalAlign::Center;
+        self.justify = false;
+        self
+    }
+
+    pub fn h_align_right(&mut self) -> &mut Self {
+        self.h_align = HorizontalAlign::Right;
+        self.justify = false;
+        self
+    }
+
+    /// Spreads the words to fill the [max_width](Self::max_width) of the text
+    pub fn h_align_justify(&mut self) -> &mut Self {
+        self.h_align = HorizontalAlign::Left;
+        self.justify = true;
+        self
+    }
+
+    pub fn v_align_top(&mut self) -> &mut Self {
+        self.v_align = VerticalAlign::Top;
+        self
+    }
+
+    pub fn v_align_middle(&mut self) -> &mut Self {
+        self.v_align = VerticalAlign::Center;
+        self
+    }
+
+    pub fn v_align_bottom(&mut self) -> &mut Self {
+        self.v_align = VerticalAlign::Bottom;
+        self
+    }
+
+    pub fn blend_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.blend_mode = Some(mode);
+        self
+    }
+
+    pub fn alpha_mode(&mut self, mode: BlendMode) -> &mut Self {
+        self.alpha_mode = Some(mode);
+        self
+    }
+}
+
+impl DrawTransform for RichText<'_> {
+    fn matrix(&mut self) -> &mut Option<Mat3> {
+        &mut self.matrix
+    }
+}
+
+impl DrawProcess for RichText<'_> {
+    fn draw_process(self, draw: &mut Draw) {
+        let Self {
+            spans,
+            matrix,
+            font,
+            blend_mode,
+            alpha_mode,
+            pos,
+            size,
+            color,
+            max_width,
+            h_align,
+            v_align,
+            justify,
+            alpha,
+        } = self;
+
+        let texts = spans
+            .iter()
+            .map(|span| {
+                let color = span.color.unwrap_or(color);
+                let color = color.with_alpha(color.a * alpha);
+                Text::new(span.text)
+                    .with_color(color.rgba())
+                    .with_scale(span.size.unwrap_or(size).max(1.0))
+                    .with_font_id(span.font.unwrap_or(font))
+            })
+            .collect();
+
+        let mut section = Section::default()
+            .with_text(texts)
+            .with_layout(Layout::default().h_align(h_align).v_align(v_align));
+
+        section.screen_position = pos;
+        if let Some(mw) = max_width {
+            section.bounds.0 = mw;
+        }
+
+        draw.add_text(&TextInfo {
+            section: &section,
+            transform: matrix.as_ref(),
+            font,
+            blend_mode,
+            alpha_mode,
+            flip: (false, false),
+            justify: justify && max_width.is_some(),
+            sdf: None,
+        });
+    }
+}
@@ -0,0 +1,298 @@
+use crate::batch::*;
+use crate::manager::process_pipeline;
+use notan_glyph::{GlyphBrush, GlyphCruncher, SdfCache, SectionGlyph, SDF_BASE_SIZE, SDF_SPREAD};
+use notan_graphics::color::Color;
+use notan_graphics::prelude::*;
+use notan_macro::{fragment_shader, vertex_shader};
+use notan_math::{Mat4, Vec3};
+
+//language=glsl
+const SDF_TEXT_VERTEX: ShaderSource = vertex_shader! {
+    r#"
+    #version 450
+    layout(location = 0) in vec3 a_pos;
+    layout(location = 1) in vec2 a_uvs;
+    layout(location = 2) in vec4 a_color;
+    layout(location = 3) in vec4 a_outline_color;
+    layout(location = 4) in vec4 a_glow_color;
+    layout(location = 5) in vec2 a_widths;
+
+    layout(location = 0) out vec4 v_color;
+    layout(location = 1) out vec2 v_uvs;
+    layout(location = 2) out vec4 v_outline_color;
+    layout(location = 3) out vec4 v_glow_color;
+    layout(location = 4) out vec2 v_widths;
+    layout(set = 0, binding = 0) uniform Locals {
+        mat4 u_projection;
+    };
+
+    void main() {
+        v_color = a_color;
+        v_uvs = a_uvs;
+        v_outline_color = a_outline_color;
+        v_glow_color = a_glow_color;
+        v_widths = a_widths;
+        gl_Position = u_projection * vec4(a_pos, 1.0);
+    }
+    "#
+};
+
+//language=glsl
+const SDF_TEXT_FRAGMENT: ShaderSource = fragment_shader! {
+    r#"
+    #version 450
+    precision mediump float;
+
+    layout(location = 0) in vec4 v_color;
+    layout(location = 1) in vec2 v_uvs;
+    layout(location = 2) in vec4 v_outline_color;
+    layout(location = 3) in vec4 v_glow_color;
+    layout(location = 4) in vec2 v_widths;
+
+    layout(binding = 0) uniform sampler2D u_texture;
+
+    layout(location = 0) out vec4 color;
+
+    void main() {
+        float dist = texture(u_texture, v_uvs).r;
+        float aa = max(fwidth(dist) * 0.5, 0.0001);
+
+        float fill = smoothstep(0.5 - aa, 0.5 + aa, dist);
+        float outline_edge = 0.5 - v_widths.x;
+        float outline = smoothstep(outline_edge - aa, outline_edge + aa, dist);
+        vec4 text = mix(v_outline_color, v_color, fill);
+        text.a *= outline;
+
+        float glow_edge = outline_edge - max(v_widths.y, 0.0001);
+        float glow = smoothstep(glow_edge, outline_edge, dist) * v_glow_color.a;
+
+        float alpha = text.a + glow * (1.0 - text.a);
+        if(alpha <= 0.0) {
+            discard;
+        }
+
+        vec3 rgb = (text.rgb * text.a + v_glow_color.rgb * glow * (1.0 - text.a)) / alpha;
+        color = vec4(rgb, alpha);
+    }
+    "#
+};
+
+/// Outline and glow drawn around text using signed distance fields
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SdfStyle {
+    /// Width of the outline in pixels, relative to the font size
+    pub outline_width: f32,
+    pub outline_color: Color,
+    /// Width of the glow in pixels after the outline, relative to the font size
+    pub glow_width: f32,
+    pub glow_color: Color,
+}
+
+impl Default for SdfStyle {
+    fn default() -> Self {
+        Self {
+            outline_width: 0.0,
+            outline_color: Color::TRANSPARENT,
+            glow_width: 0.0,
+            glow_color: Color::TRANSPARENT,
+        }
+    }
+}
+
+fn vertex_info() -> VertexInfo {
+    VertexInfo::new()
+        .attr(0, VertexFormat::Float32x3)
+        .attr(1, VertexFormat::Float32x2)
+        .attr(2, VertexFormat::Float32x4)
+        .attr(3, VertexFormat::Float32x4)
+        .attr(4, VertexFormat::Float32x4)
+        .attr(5, VertexFormat::Float32x2)
+}
+
+pub(crate) struct SdfTextPainter {
+    pipeline: Pipeline,
+    vbo: Buffer,
+    ebo: Buffer,
+    ubo: Buffer,
+    cache: Option<SdfCache>,
+    vertices: Vec<f32>,
+    indices: Vec<u32>,
+    uniforms: [f32; 16],
+    glyphs: Vec<SectionGlyph>,
+    dirty_buffer: bool,
+}
+
+impl SdfTextPainter {
+    pub fn new(device: &mut Device) -> Result<Self, String> {
+        let uniforms = [0.0; 16];
+
+        let pipeline = create_sdf_text_pipeline(device, None)?;
+        let vbo = device
+            .create_vertex_buffer()
+            .with_info(&vertex_info())
+            .build()?;
+
+        let ebo = device.create_index_buffer().build()?;
+
+        let ubo = device
+            .create_uniform_buffer(0, "Locals")
+            .with_data(&uniforms)
+            .build()?;
+
+        Ok(Self {
+            pipeline,
+            vbo,
+            ebo,
+            ubo,
+            cache: None,
+            vertices: vec![],
+            indices: vec![],
+            uniforms,
+            glyphs: vec![],
+            dirty_buffer: false,
+        })
+    }
+
+    pub fn push(
+        &mut self,
+        device: &mut Device,
+        glyph_brush: &mut GlyphBrush,
+        renderer: &mut Renderer,
+        batch: &Batch,
+        projection: &Mat4,
+        is_rt: bool,
+    ) {
+        let texts = match &batch.typ {
+            BatchType::SdfText { texts } => texts,
+            _ => return,
+        };
+
+        // the atlas is created the first time that it's needed
+        if self.cache.is_none() {
+            match SdfCache::new(device, 1024, 1024) {
+                Ok(cache) => self.cache = Some(cache),
+                Err(e) => {
+                    log::error!("Cannot create the SDF glyph cache: {e}");
+                    return;
+                }
+            }
+        }
+
+        let cache = self.cache.as_mut().unwrap();
+        let offset = self.indices.len();
+
+        texts.iter().for_each(|data| {
+            let style = data.sdf.unwrap_or_default();
+
+            self.glyphs.clear();
+            self.glyphs.extend(
+                glyph_brush
+                    .glyphs_custom_layout(&data.section, &data.layout())
+                    .cloned(),
+            );
+
+            self.glyphs.iter().for_each(|sg| {
+                let font = &glyph_brush.fonts()[sg.font_id.0];
+                let glyph = match cache.glyph(device, sg.font_id, font, sg.glyph.id) {
+                    Some(g) => g,
+                    None => return,
+                };
+
+                let text = &data.section.text[sg.section_index];
+                let [r, g, b, a] = text.extra.color;
+                let a = a * data.alpha;
+
+                // the distance field is stored at the base size, so everything is scaled
+                let scale = sg.glyph.scale;
+                let (sx, sy) = (scale.x / SDF_BASE_SIZE, scale.y / SDF_BASE_SIZE);
+                let x1 = sg.glyph.position.x + glyph.offset.0 * sx;
+                let y1 = sg.glyph.position.y + glyph.offset.1 * sy;
+                let x2 = x1 + glyph.frame.width * sx;
+                let y2 = y1 + glyph.frame.height * sy;
+
+                let (tw, th) = (cache.texture().width(), cache.texture().height());
+                let u1 = glyph.frame.x / tw;
+                let v1 = glyph.frame.y / th;
+                let u2 = (glyph.frame.x + glyph.frame.width) / tw;
+                let v2 = (glyph.frame.y + glyph.frame.height) / th;
+
+                // widths as distance field values, where the spread goes from 0.5 to 0.0
+                let to_dist = |width: f32| (width / sy / (2.0 * SDF_SPREAD)).clamp(0.0, 0.5);
+                let outline_width = to_dist(style.outline_width);
+                let glow_width = to_dist(style.glow_width).min(0.5 - outline_width);
+
+                let oc = style.outline_color;
+                let gc = style.glow_color;
+                let n = (self.vertices.len() / 19) as u32;
+
+                [
+                    [x1, y1, u1, v1],
+                    [x2, y1, u2, v1],
+                    [x1, y2, u1, v2],
+                    [x2, y2, u2, v2],
+                ]
+                .into_iter()
+                .for_each(|[x, y, u, v]| {
+                    let xyz = data.transform * Vec3::new(x, y, 1.0);
+                    #[rustfmt::skip]
+                        self.vertices.extend_from_slice(&[
+                            xyz.x, xyz.y, data.depth, u, v,
+                            r, g, b, a,
+                            oc.r, oc.g, oc.b, oc.a * data.alpha,
+                            gc.r, gc.g, gc.b, gc.a * data.alpha,
+                            outline_width, glow_width,
+                        ]);
+                });
+
+                #[rustfmt::skip]
+                self.indices.extend_from_slice(&[
+                    n    , n + 1, n + 2,
+                    n + 2, n + 1, n + 3
+                ]);
+            });
+        });
+
+        let count = self.indices.len() - offset;
+        if count == 0 {
+            return;
+        }
+
+        self.uniforms.copy_from_slice(&projection.to_cols_array());
+
+        process_pipeline(renderer, batch, &self.pipeline, is_rt);
+        renderer.bind_texture_slot(0, 0, cache.texture());
+        renderer.bind_buffers(&[&self.vbo, &self.ebo, &self.ubo]);
+        renderer.draw(offset as _, count as _);
+        self.dirty_buffer = true;
+    }
+
+    #[inline]
+    pub fn upload_buffers(&mut self, device: &mut Device) {
+        if self.dirty_buffer {
+            self.dirty_buffer = false;
+            device.set_buffer_data(&self.vbo, &self.vertices);
+            device.set_buffer_data(&self.ebo, &self.indices);
+            device.set_buffer_data(&self.ubo, &self.uniforms);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.vertices.clear();
+        self.indices.clear();
+    }
+}
+
+pub fn create_sdf_text_pipeline(
+    device: &mut Device,
+    fragment: Option<&ShaderSource>,
+) -> Result<Pipeline, String> {
+    let fragment = fragment.unwrap_or(&SDF_TEXT_FRAGMENT);
+    device
+        .create_pipeline()
+        .from(&SDF_TEXT_VERTEX, fragment)
+        .with_vertex_info(&vertex_info())
+        .with_color_blend(BlendMode::NORMAL)
+        .with_alpha_blend(BlendMode::OVER)
+        .with_texture_location(0, "u_texture")
+        .build()
+}
@@ -1,42 +1,68 @@
 use crate::builder::DrawProcess;
-use crate::draw::{Draw, TextInfo};
+use crate::draw::{Draw, ImageInfo, TextInfo};
+use crate::texts::{BitmapFont, SdfStyle};
 use crate::transform::DrawTransform;
 use notan_glyph::{HorizontalAlign, Layout, Section, Text, VerticalAlign};
 use notan_graphics::color::Color;
 use notan_graphics::pipeline::BlendMode;
-use notan_math::Mat3;
+use notan_math::{Mat3, Rect};
 use notan_text::Font;
 
+/// Font used to draw a [TextSection]
+#[derive(Debug, Clone, Copy)]
+pub enum TextFont<'a> {
+    Font(&'a Font),
+    Bitmap(&'a BitmapFont),
+}
+
+impl<'a> From<&'a Font> for TextFont<'a> {
+    fn from(font: &'a Font) -> Self {
+        TextFont::Font(font)
+    }
+}
+
+impl<'a> From<&'a BitmapFont> for TextFont<'a> {
+    fn from(font: &'a BitmapFont) -> Self {
+        TextFont::Bitmap(font)
+    }
+}
+
+/// Text drawn with a [Font], the glyphs are cached on a texture shared by the draw calls.
+/// Text using a [BitmapFont] is drawn as images of its pages
 pub struct TextSection<'a> {
     text: &'a str,
     matrix: Option<Mat3>,
-    font: &'a Font,
+    font: TextFont<'a>,
     blend_mode: Option<BlendMode>,
     alpha_mode: Option<BlendMode>,
     pos: (f32, f32),
-    size: f32,
+    size: Option<f32>,
     color: Color,
     max_width: Option<f32>,
     h_align: HorizontalAlign,
     v_align: VerticalAlign,
+    justify: bool,
+    sdf: Option<SdfStyle>,
     alpha: f32,
     flip: (bool, bool),
 }
 
 impl<'a> TextSection<'a> {
-    pub fn new(font: &'a Font, text: &'a str) -> Self {
+    pub fn new<F: Into<TextFont<'a>>>(font: F, text: &'a str) -> Self {
         Self {
             text,
             matrix: None,
-            font,
+            font: font.into(),
             blend_mode: None,
             alpha_mode: None,
             pos: (0.0, 0.0),
-            size: 16.0,
+            size: None,
             color: Color::WHITE,
             max_width: None,
             h_align: HorizontalAlign::Left,
             v_align: VerticalAlign::Top,
+            justify: false,
+            sdf: None,
             alpha: 1.0,
             // flip only flips the texture not the spacing/trim of the letters
             // so the effect for text is best achieved using scale(1.0, -1.0).
@@ -45,14 +71,16 @@ impl<'a> TextSection<'a> {
         }
     }
 
+    /// Position of the text, the alignment is relative to this point
     pub fn position(&mut self, x: f32, y: f32) -> &mut Self {
         self.pos = (x, y);
 
         self
     }
 
+    /// Font size in pixels, by default it's 16.0 or the size of the [BitmapFont]
     pub fn size(&mut self, size: f32) -> &mut Self {
-        self.size = size;
+        self.size = Some(size);
         self
     }
 
@@ -66,6 +94,7 @@ impl<'a> TextSection<'a> {
         self
     }
 
+    /// Wraps the text in lines that are not wider than the value given
     pub fn max_width(&mut self, width: f32) -> &mut Self {
         self.max_width = Some(width);
         self
@@ -73,16 +102,28 @@ impl<'a> TextSection<'a> {
 
     pub fn h_align_left(&mut self) -> &mut Self {
         self.h_align = HorizontalAlign::Left;
+        self.justify = false;
         self
     }
 
     pub fn h_align_center(&mut self) -> &mut Self {
         self.h_align = HorizontalAlign::Center;
+        self.justify = false;
         self
     }
 
     pub fn h_align_right(&mut self) -> &mut Self {
         self.h_align = HorizontalAlign::Right;
+        self.justify = false;
+        self
+    }
+
+    /// Spreads the words to fill the [max_width](Self::max_width) of the text,
+    /// the last line and the lines ending with a line break are aligned to the left.
+    /// The position is the left edge of the text, like using [h_align_left](Self::h_align_left)
+    pub fn h_align_justify(&mut self) -> &mut Self {
+        self.h_align = HorizontalAlign::Left;
+        self.justify = true;
         self
     }
 
@@ -101,6 +142,29 @@ impl<'a> TextSection<'a> {
         self
     }
 
+    /// Draws the text using a signed distance field instead of the glyph's pixels,
+    /// it keeps the edges sharp when the text is scaled or rotated
+    pub fn sdf(&mut self, enabled: bool) -> &mut Self {
+        self.sdf = enabled.then(|| self.sdf.unwrap_or_default());
+        self
+    }
+
+    /// Draws an outline around the text, it enables [sdf](Self::sdf)
+    pub fn outline(&mut self, width: f32, color: Color) -> &mut Self {
+        let style = self.sdf.get_or_insert_with(Default::default);
+        style.outline_width = width;
+        style.outline_color = color;
+        self
+    }
+
+    /// Draws a glow around the text or its outline, it enables [sdf](Self::sdf)
+    pub fn glow(&mut self, width: f32, color: Color) -> &mut Self {
+        let style = self.sdf.get_or_insert_with(Default::default);
+        style.glow_width = width;
+        style.glow_color = color;
+        self
+    }
+
     pub fn blend_mode(&mut self, mode: BlendMode) -> &mut Self {
         self.blend_mode = Some(mode);
         self
@@ -132,10 +196,44 @@ impl DrawProcess for TextSection<'_> {
             max_width,
             h_align,
             v_align,
+            justify,
+            sdf,
             alpha,
             flip,
         } = self;
 
+        let font = match font {
+            TextFont::Font(font) => font,
+            TextFont::Bitmap(font) => {
+                #[cfg(debug_assertions)]
+                {
+                    if sdf.is_some() {
+                        log::warn!("Bitmap fonts are pre-rendered, the text is drawn without sdf");
+                    }
+                }
+
+                let info = BitmapTextInfo {
+                    font,
+                    text,
+                    matrix,
+                    blend_mode,
+                    alpha_mode,
+                    pos,
+                    size: size.unwrap_or(font.size()),
+                    color: color.with_alpha(color.a * alpha),
+                    max_width,
+                    h_align,
+                    v_align,
+                    justify: justify && max_width.is_some(),
+                    flip,
+                };
+                draw_bitmap_text(draw, info);
+                return;
+            }
+        };
+
+        let size = size.unwrap_or(16.0);
+
         #[cfg(debug_assertions)]
         {
             if size < 1.0 {
@@ -144,7 +242,6 @@ impl DrawProcess for TextSection<'_> {
         }
 
         let color = color.with_alpha(color.a * alpha);
-        let count = text.chars().filter(|c| !c.is_whitespace()).count();
 
         let g_text = Text::new(text)
             .with_color(color.rgba())
@@ -161,13 +258,184 @@ impl DrawProcess for TextSection<'_> {
         }
 
         draw.add_text(&TextInfo {
-            count,
             section: &section,
             transform: matrix.as_ref(),
             font,
             blend_mode,
             alpha_mode,
             flip,
+            justify: justify && max_width.is_some(),
+            sdf,
         });
     }
 }
+
+struct BitmapTextInfo<'a> {
+    font: &'a BitmapFont,
+    text: &'a str,
+    matrix: Option<Mat3>,
+    blend_mode: Option<BlendMode>,
+    alpha_mode: Option<BlendMode>,
+    pos: (f32, f32),
+    size: f32,
+    color: Color,
+    max_width: Option<f32>,
+    h_align: HorizontalAlign,
+    v_align: VerticalAlign,
+    justify: bool,
+    flip: (bool, bool),
+}
+
+fn draw_bitmap_text(draw: &mut Draw, info: BitmapTextInfo) {
+    let BitmapTextInfo {
+        font,
+        text,
+        matrix,
+        blend_mode,
+        alpha_mode,
+        pos,
+        size,
+        color,
+        max_width,
+        h_align,
+        v_align,
+        justify,
+        flip: (flip_x, flip_y),
+    } = info;
+
+    let scale = font.scale(size);
+    let line_height = font.line_height() * scale;
+
+    // the last line of each paragraph is not justified
+    let lines = text
+        .split('\n')
+        .flat_map(|paragraph| {
+            let lines = font.lines(paragraph, size, max_width);
+            let last = lines.len().saturating_sub(1);
+            lines
+                .into_iter()
+                .enumerate()
+                .map(move |(i, line)| (line, justify && i < last))
+        })
+        .collect::<Vec<_>>();
+
+    let height = lines.len() as f32 * line_height;
+    let y = match v_align {
+        VerticalAlign::Top => pos.1,
+        VerticalAlign::Center => pos.1 - height * 0.5,
+        VerticalAlign::Bottom => pos.1 - height,
+    };
+
+    // vertices and indices by page
+    let mut pages: Vec<(Vec<f32>, Vec<u32>)> = vec![];
+    let mut bounds_x = (f32::MAX, f32::MIN);
+    lines
+        .iter()
+        .enumerate()
+        .for_each(|(i, (line, justify_line))| {
+            let mut width = font.text_width(line, size);
+
+            // the space left is spread between the words
+            let spaces = line.matches(' ').count();
+            let space_extra = match max_width {
+                Some(mw) if *justify_line && spaces > 0 && width < mw => {
+                    let extra = (mw - width) / spaces as f32;
+                    width = mw;
+                    extra
+                }
+                _ => 0.0,
+            };
+
+            let mut pen_x = match h_align {
+                HorizontalAlign::Left => pos.0,
+                HorizontalAlign::Center => pos.0 - width * 0.5,
+                HorizontalAlign::Right => pos.0 - width,
+            };
+            let pen_y = y + i as f32 * line_height;
+            bounds_x = (bounds_x.0.min(pen_x), bounds_x.1.max(pen_x + width));
+
+            let mut prev = None;
+            line.chars().for_each(|c| {
+                if let Some(p) = prev {
+                    pen_x += font.kerning(p, c) * scale;
+                }
+                prev = Some(c);
+
+                let bc = match font.char(c) {
+                    Some(bc) => bc,
+                    None => return,
+                };
+
+                let mut advance = bc.x_advance * scale;
+                if c == ' ' {
+                    advance += space_extra;
+                }
+
+                let texture = match font.page(bc.page) {
+                    Some(t) if bc.width > 0.0 && bc.height > 0.0 => t,
+                    _ => {
+                        pen_x += advance;
+                        return;
+                    }
+                };
+
+                let x1 = pen_x + bc.x_offset * scale;
+                let y1 = pen_y + bc.y_offset * scale;
+                let x2 = x1 + bc.width * scale;
+                let y2 = y1 + bc.height * scale;
+                pen_x += advance;
+
+                let (bw, bh) = (texture.base_width(), texture.base_height());
+                let u1 = bc.x / bw;
+                let v1 = bc.y / bh;
+                let u2 = (bc.x + bc.width) / bw;
+                let v2 = (bc.y + bc.height) / bh;
+                let (u1, u2) = if flip_x { (u2, u1) } else { (u1, u2) };
+                let (v1, v2) = if flip_y { (v2, v1) } else { (v1, v2) };
+
+                if pages.len() <= bc.page {
+                    pages.resize(bc.page + 1, (vec![], vec![]));
+                }
+
+                let (vertices, indices) = &mut pages[bc.page];
+                let n = (vertices.len() / 8) as u32;
+                let Color { r, g, b, a } = color;
+
+                #[rustfmt::skip]
+            vertices.extend_from_slice(&[
+                x1, y1, u1, v1, r, g, b, a,
+                x2, y1, u2, v1, r, g, b, a,
+                x1, y2, u1, v2, r, g, b, a,
+                x2, y2, u2, v2, r, g, b, a,
+            ]);
+                indices.extend_from_slice(&[n, n + 1, n + 2, n + 2, n + 1, n + 3]);
+            });
+        });
+
+    pages
+        .iter()
+        .enumerate()
+        .filter(|(_, (vertices, _))| !vertices.is_empty())
+        .for_each(|(page, (vertices, indices))| {
+            if let Some(texture) = font.page(page) {
+                draw.add_image(&ImageInfo {
+                    texture,
+                    transform: matrix.as_ref(),
+                    vertices,
+                    indices,
+                    blend_mode,
+                    alpha_mode,
+                });
+            }
+        });
+
+    draw.last_bitmap_text_bounds = Some(match lines.is_empty() {
+        true => Rect::default(),
+        false => Rect {
+            x: bounds_x.0,
+            y,
+            width: bounds_x.1 - bounds_x.0,
+            height,
+        },
+    });
+}
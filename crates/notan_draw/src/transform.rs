@@ -53,16 +53,7 @@ pub trait DrawTransform {
     fn skew(&mut self, x: f32, y: f32) -> &mut Self {
         let old = self.matrix().unwrap_or_else(|| Mat3::IDENTITY);
 
-        let xt = x.tan();
-        let yt = y.tan();
-
-        let new = Mat3::from_cols(
-            Vec3::new(1.0, xt, 0.0),
-            Vec3::new(yt, 1.0, 0.0),
-            Vec3::new(0.0, 0.0, 1.0),
-        );
-
-        *self.matrix() = Some(new * old);
+        *self.matrix() = Some(skew_matrix(x, y) * old);
         self
     }
 
@@ -135,6 +126,31 @@ impl Transform {
         self
     }
 
+    /// Adds a translation to the stack
+    pub fn push_translate(&mut self, x: f32, y: f32) -> &mut Self {
+        self.push(Mat3::from_translation(Vec2::new(x, y)))
+    }
+
+    /// Adds a rotation using radians to the stack
+    pub fn push_rotate(&mut self, angle: f32) -> &mut Self {
+        self.push(Mat3::from_angle(angle))
+    }
+
+    /// Adds a rotation using degrees to the stack
+    pub fn push_rotate_degrees(&mut self, deg: f32) -> &mut Self {
+        self.push_rotate(deg.to_radians())
+    }
+
+    /// Adds a scale to the stack
+    pub fn push_scale(&mut self, x: f32, y: f32) -> &mut Self {
+        self.push(Mat3::from_scale(Vec2::new(x, y)))
+    }
+
+    /// Adds a skew to the stack
+    pub fn push_skew(&mut self, x: f32, y: f32) -> &mut Self {
+        self.push(skew_matrix(x, y))
+    }
+
     /// Remove the last matrix from the stack
     pub fn pop(&mut self) -> &mut Self {
         self.stack.pop();
@@ -156,6 +172,17 @@ impl Deref for Transform {
     }
 }
 
+fn skew_matrix(x: f32, y: f32) -> Mat3 {
+    let xt = x.tan();
+    let yt = y.tan();
+
+    Mat3::from_cols(
+        Vec3::new(1.0, xt, 0.0),
+        Vec3::new(yt, 1.0, 0.0),
+        Vec3::new(0.0, 0.0, 1.0),
+    )
+}
+
 pub(crate) fn screen_to_local_position(
     screen_pos: Vec2,
     screen_size: Vec2,
@@ -191,8 +218,25 @@ pub(crate) fn local_to_screen_position(
 mod test {
     use super::local_to_screen_position;
     use super::screen_to_local_position;
+    use super::Transform;
     use notan_math::{vec2, Mat3, Mat4};
 
+    #[test]
+    fn push_helpers() {
+        let mut transform = Transform::new();
+        transform.push_translate(100.0, 50.0).push_scale(2.0, 2.0);
+
+        let pos = transform.matrix().transform_point2(vec2(10.0, 10.0));
+        assert_eq!(pos, vec2(120.0, 70.0));
+
+        transform.pop();
+        let pos = transform.matrix().transform_point2(vec2(10.0, 10.0));
+        assert_eq!(pos, vec2(110.0, 60.0));
+
+        transform.pop();
+        assert_eq!(*transform.matrix(), Mat3::IDENTITY);
+    }
+
     #[test]
     fn screen_to_local() {
         let screen_size = vec2(800.0, 600.0);
@@ -1,3 +1,5 @@
 mod fps_limit;
+mod post_process;
 
 pub use fps_limit::*;
+pub use post_process::*;
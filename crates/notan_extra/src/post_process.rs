@@ -0,0 +1,210 @@
+use notan_app::graphics::*;
+use notan_macro::vertex_shader;
+
+//language=glsl
+const POST_PROCESS_VERTEX: ShaderSource = vertex_shader! {
+    r#"
+    #version 450
+    layout(location = 0) in vec2 a_pos;
+    layout(location = 1) in vec2 a_uvs;
+
+    layout(location = 0) out vec2 v_uvs;
+
+    void main() {
+        v_uvs = a_uvs;
+        gl_Position = vec4(a_pos, 0.0, 1.0);
+    }
+    "#
+};
+
+fn vertex_info() -> VertexInfo {
+    VertexInfo::new()
+        .attr(0, VertexFormat::Float32x2)
+        .attr(1, VertexFormat::Float32x2)
+}
+
+/// Fullscreen shader pass of a [PostProcess]. The fragment shader receives the
+/// uvs as `v_uvs` on the location 0 and the result of the previous pass as the
+/// texture `u_texture` on the binding 0
+pub struct PostPass {
+    pipeline: Pipeline,
+    uniforms: Vec<Buffer>,
+    enabled: bool,
+}
+
+impl PostPass {
+    pub fn new(gfx: &mut Graphics, fragment: &ShaderSource) -> Result<Self, String> {
+        let pipeline = gfx
+            .create_pipeline()
+            .from(&POST_PROCESS_VERTEX, fragment)
+            .with_vertex_info(&vertex_info())
+            .with_texture_location(0, "u_texture")
+            .build()?;
+
+        Ok(Self {
+            pipeline,
+            uniforms: vec![],
+            enabled: true,
+        })
+    }
+
+    /// Binds the uniform buffer when the pass is rendered,
+    /// the buffer can be updated at any time using [Graphics::set_buffer_data]
+    pub fn add_uniforms(&mut self, buffer: &Buffer) -> &mut Self {
+        self.uniforms.push(buffer.clone());
+        self
+    }
+
+    /// Disabled passes are skipped
+    pub fn set_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.enabled = enabled;
+        self
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+}
+
+/// Renders the frame into an internal target and then applies a chain of
+/// fullscreen passes before presenting it. The intermediate targets are
+/// reused between passes and resized along with the screen
+pub struct PostProcess {
+    targets: Vec<RenderTexture>,
+    vbo: Buffer,
+    ebo: Buffer,
+    passes: Vec<PostPass>,
+}
+
+impl PostProcess {
+    pub fn new(gfx: &mut Graphics) -> Result<Self, String> {
+        #[rustfmt::skip]
+        let vertices = [
+            //pos           //uvs
+            -1.0, -1.0,     0.0, 0.0,
+            1.0, -1.0,      1.0, 0.0,
+            -1.0, 1.0,      0.0, 1.0,
+            1.0, 1.0,       1.0, 1.0,
+        ];
+
+        #[rustfmt::skip]
+        let indices = [
+            0, 1, 2,
+            2, 1, 3,
+        ];
+
+        let vbo = gfx
+            .create_vertex_buffer()
+            .with_info(&vertex_info())
+            .with_data(&vertices)
+            .build()?;
+
+        let ebo = gfx.create_index_buffer().with_data(&indices).build()?;
+
+        Ok(Self {
+            targets: vec![],
+            vbo,
+            ebo,
+            passes: vec![],
+        })
+    }
+
+    /// Adds the pass at the end of the chain
+    pub fn add_pass(&mut self, pass: PostPass) -> &mut Self {
+        self.passes.push(pass);
+        self
+    }
+
+    pub fn passes(&self) -> &[PostPass] {
+        &self.passes
+    }
+
+    pub fn passes_mut(&mut self) -> &mut [PostPass] {
+        &mut self.passes
+    }
+
+    /// Removes the pass with the index given
+    pub fn remove_pass(&mut self, index: usize) -> PostPass {
+        self.passes.remove(index)
+    }
+
+    /// Renders the frame to the screen applying the enabled passes
+    pub fn render<G: GfxRenderer>(&mut self, gfx: &mut Graphics, frame: &G) -> Result<(), String> {
+        self.process(gfx, frame, None)
+    }
+
+    /// Renders the frame to the render texture applying the enabled passes
+    pub fn render_to<G: GfxRenderer>(
+        &mut self,
+        gfx: &mut Graphics,
+        target: &RenderTexture,
+        frame: &G,
+    ) -> Result<(), String> {
+        self.process(gfx, frame, Some(target))
+    }
+
+    fn process<G: GfxRenderer>(
+        &mut self,
+        gfx: &mut Graphics,
+        frame: &G,
+        target: Option<&RenderTexture>,
+    ) -> Result<(), String> {
+        let passes = self.passes.iter().filter(|p| p.enabled).collect::<Vec<_>>();
+
+        // without passes there is nothing to process
+        if passes.is_empty() {
+            return frame.render(&mut gfx.device, &mut gfx.extensions, target);
+        }
+
+        let size = match target {
+            Some(rt) => (rt.width() as u32, rt.height() as u32),
+            None => gfx.size(),
+        };
+
+        // the frame and every pass but the last one need a target, alternating two is enough
+        let needed = passes.len().min(2);
+        let outdated = self
+            .targets
+            .first()
+            .is_some_and(|rt| (rt.width() as u32, rt.height() as u32) != size);
+        if outdated {
+            self.targets.clear();
+        }
+
+        while self.targets.len() < needed {
+            let rt = gfx
+                .create_render_texture(size.0, size.1)
+                .with_filter(TextureFilter::Linear, TextureFilter::Linear)
+                .build()?;
+            self.targets.push(rt);
+        }
+
+        frame.render(&mut gfx.device, &mut gfx.extensions, Some(&self.targets[0]))?;
+
+        let last = passes.len() - 1;
+        passes.iter().enumerate().for_each(|(i, pass)| {
+            let source = &self.targets[i % 2];
+
+            let mut renderer = gfx.create_renderer();
+            renderer.begin(Some(ClearOptions::color(Color::TRANSPARENT)));
+            renderer.set_pipeline(&pass.pipeline);
+            renderer.bind_texture(0, source);
+            renderer.bind_buffers(&[&self.vbo, &self.ebo]);
+            pass.uniforms.iter().for_each(|u| renderer.bind_buffer(u));
+            renderer.draw(0, 6);
+            renderer.end();
+
+            if i == last {
+                match target {
+                    Some(rt) => gfx.device.render_to(rt, renderer.commands()),
+                    None => gfx.device.render(renderer.commands()),
+                }
+            } else {
+                gfx.device
+                    .render_to(&self.targets[(i + 1) % 2], renderer.commands());
+            }
+        });
+
+        Ok(())
+    }
+}
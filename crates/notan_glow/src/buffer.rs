@@ -11,6 +11,7 @@ pub(crate) enum Kind {
     Vertex(VertexAttributes),
     Index(IndexFormat),
     Uniform(u32, String),
+    Storage(u32),
 }
 
 impl std::fmt::Display for Kind {
@@ -19,12 +20,13 @@ impl std::fmt::Display for Kind {
             Kind::Vertex(_) => write!(f, "Vertex"),
             Kind::Index(_) => write!(f, "Index"),
             Kind::Uniform(loc, id) => write!(f, "Uniform(location: {loc}, id: {id})"),
+            Kind::Storage(slot) => write!(f, "Storage(slot: {slot})"),
         }
     }
 }
 
 pub(crate) struct InnerBuffer {
-    buffer: glow::Buffer,
+    pub buffer: glow::Buffer,
 
     #[cfg(target_arch = "wasm32")]
     global_ubo: Option<Vec<u8>>, //Hack, wasm doesn't use the offset for std140
@@ -35,6 +37,10 @@ pub(crate) struct InnerBuffer {
     pub(crate) kind: Kind,
     last_pipeline: Option<u64>,
     block_dirty: bool,
+    // copy of the last uniforms uploaded, to skip the upload if they don't change
+    uniforms: Option<Vec<u8>>,
+    // WebGL1 doesn't support uniform buffers, the data is set to a vec4 array named as the block
+    pub(crate) loose_uniforms: bool,
 
     #[cfg(debug_assertions)]
     pub(crate) initialized: bool,
@@ -64,6 +70,7 @@ impl InnerBuffer {
             Kind::Vertex(_) => glow::ARRAY_BUFFER,
             Kind::Index(_) => glow::ELEMENT_ARRAY_BUFFER,
             Kind::Uniform(_, _) => glow::UNIFORM_BUFFER,
+            Kind::Storage(_) => glow::SHADER_STORAGE_BUFFER,
         };
 
         Ok(InnerBuffer {
@@ -78,6 +85,8 @@ impl InnerBuffer {
             kind,
             last_pipeline: None,
             block_dirty: true,
+            uniforms: None,
+            loose_uniforms: false,
 
             #[cfg(debug_assertions)]
             initialized: false,
@@ -92,6 +101,10 @@ impl InnerBuffer {
             self.block_dirty = true;
         };
 
+        if self.loose_uniforms {
+            return;
+        }
+
         unsafe {
             gl.bind_buffer(self.draw_target, Some(self.buffer));
 
@@ -104,13 +117,40 @@ impl InnerBuffer {
                 Kind::Uniform(slot, _) => {
                     gl.bind_buffer_base(glow::UNIFORM_BUFFER, *slot, Some(self.buffer));
                 }
+                Kind::Storage(slot) => {
+                    gl.bind_buffer_base(glow::SHADER_STORAGE_BUFFER, *slot, Some(self.buffer));
+                }
                 _ => {}
             }
         }
     }
 
+    /// Returns true if this is an uniform buffer that already contains this data
+    #[inline]
+    pub fn has_same_uniforms(&self, data: &[u8]) -> bool {
+        self.uniforms.as_deref() == Some(data)
+    }
+
     #[inline]
     pub fn update(&mut self, gl: &Context, data: &[u8]) {
+        if matches!(self.kind, Kind::Uniform(_, _)) {
+            let uniforms = self.uniforms.get_or_insert_with(Vec::new);
+            uniforms.clear();
+            uniforms.extend_from_slice(data);
+        }
+
+        // the uniforms are set when the buffer is bound to the pipeline
+        if self.loose_uniforms {
+            self.block_dirty = true;
+
+            #[cfg(debug_assertions)]
+            {
+                self.initialized = true;
+            }
+
+            return;
+        }
+
         let needs_alloc = self.gpu_buff_size != data.len();
 
         unsafe {
@@ -130,6 +170,7 @@ impl InnerBuffer {
 
             if needs_alloc {
                 gl.buffer_data_u8_slice(self.draw_target, data, self.draw_usage);
+                self.gpu_buff_size = data.len();
             } else {
                 gl.buffer_sub_data_u8_slice(self.draw_target, 0, data);
             }
@@ -150,6 +191,12 @@ impl InnerBuffer {
 
         self.block_dirty = false;
         if let Kind::Uniform(slot, name) = &self.kind {
+            if self.loose_uniforms {
+                let data = self.uniforms.as_deref().unwrap_or_default();
+                set_loose_uniforms(gl, pipeline, name, data);
+                return;
+            }
+
             unsafe {
                 if let Some(index) = gl.get_uniform_block_index(pipeline.program, name) {
                     gl.uniform_block_binding(pipeline.program, index, *slot as _);
@@ -158,6 +205,39 @@ impl InnerBuffer {
         }
     }
 
+    pub fn read(&self, gl: &Context, bytes: &mut [u8]) -> Result<(), String> {
+        if bytes.len() > self.gpu_buff_size {
+            return Err(format!(
+                "Cannot read {} bytes from a buffer of {} bytes",
+                bytes.len(),
+                self.gpu_buff_size
+            ));
+        }
+
+        unsafe {
+            gl.bind_buffer(self.draw_target, Some(self.buffer));
+
+            // GLES doesn't have glGetBufferSubData, the buffer is mapped to read it
+            let res = if gl.version().is_embedded {
+                let ptr =
+                    gl.map_buffer_range(self.draw_target, 0, bytes.len() as _, glow::MAP_READ_BIT);
+                if ptr.is_null() {
+                    Err("Cannot map the buffer to read it".to_string())
+                } else {
+                    std::ptr::copy_nonoverlapping(ptr, bytes.as_mut_ptr(), bytes.len());
+                    gl.unmap_buffer(self.draw_target);
+                    Ok(())
+                }
+            } else {
+                gl.get_buffer_sub_data(self.draw_target, 0, bytes);
+                Ok(())
+            };
+
+            gl.bind_buffer(self.draw_target, None);
+            res
+        }
+    }
+
     #[inline(always)]
     pub fn clean(self, gl: &Context) {
         unsafe {
@@ -166,6 +246,31 @@ impl InnerBuffer {
     }
 }
 
+/// Sets the uniform buffer data as the uniform `vec4 {name}[N]`, where the std140 layout
+/// of the block is kept, so a `mat4` uses 4 elements of the array
+fn set_loose_uniforms(gl: &Context, pipeline: &InnerPipeline, name: &str, data: &[u8]) {
+    unsafe {
+        let location = gl
+            .get_uniform_location(pipeline.program, name)
+            .or_else(|| gl.get_uniform_location(pipeline.program, &format!("{name}[0]")));
+
+        if let Some(location) = location {
+            // std140 blocks are always aligned to 16 bytes
+            let mut values = data
+                .chunks(4)
+                .map(|bytes| {
+                    let mut b = [0; 4];
+                    b[..bytes.len()].copy_from_slice(bytes);
+                    f32::from_ne_bytes(b)
+                })
+                .collect::<Vec<_>>();
+            values.resize(values.len().div_ceil(4) * 4, 0.0);
+
+            gl.uniform_4_f32_slice(Some(&location), &values);
+        }
+    }
+}
+
 fn pipeline_changed(pipeline_id: Option<u64>, last_pipeline: Option<u64>) -> bool {
     pipeline_id.is_some() && pipeline_id != last_pipeline
 }
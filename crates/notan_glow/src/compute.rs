@@ -0,0 +1,47 @@
+use crate::pipeline::create_shader;
+use glow::{Context, HasContext, Program, Shader};
+
+pub(crate) struct InnerComputePipeline {
+    shader: Shader,
+    pub program: Program,
+}
+
+impl InnerComputePipeline {
+    pub fn new(gl: &Context, source: &str) -> Result<Self, String> {
+        let shader = create_shader(gl, glow::COMPUTE_SHADER, source)?;
+
+        unsafe {
+            let program = gl.create_program()?;
+            gl.attach_shader(program, shader);
+            gl.link_program(program);
+
+            if !gl.get_program_link_status(program) {
+                let err = gl.get_program_info_log(program);
+                gl.delete_program(program);
+                gl.delete_shader(shader);
+                return Err(err);
+            }
+
+            Ok(Self { shader, program })
+        }
+    }
+
+    pub fn clean(self, gl: &Context) {
+        unsafe {
+            gl.delete_shader(self.shader);
+            gl.delete_program(self.program);
+        }
+    }
+}
+
+/// Compute shaders need OpenGL 4.3 or OpenGL ES 3.1, WebGL2 doesn't support them.
+/// The shaders use `#version 430`, so the extensions of older versions are not enough
+pub(crate) fn is_compute_supported(gl: &Context) -> bool {
+    if cfg!(target_arch = "wasm32") {
+        return false;
+    }
+
+    let version = gl.version();
+    let min_version = if version.is_embedded { (3, 1) } else { (4, 3) };
+    (version.major, version.minor) >= min_version
+}
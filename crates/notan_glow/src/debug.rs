@@ -0,0 +1,13 @@
+use glow::{Context, HasContext};
+
+/// KHR_debug is core since OpenGL 4.3 and OpenGL ES 3.2, WebGL doesn't support it
+pub(crate) fn is_debug_supported(gl: &Context) -> bool {
+    if cfg!(target_arch = "wasm32") {
+        return false;
+    }
+
+    let version = gl.version();
+    let min_version = if version.is_embedded { (3, 2) } else { (4, 3) };
+    (version.major, version.minor) >= min_version
+        || gl.supported_extensions().contains("GL_KHR_debug")
+}
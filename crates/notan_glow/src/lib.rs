@@ -3,9 +3,14 @@ use hashbrown::HashMap;
 use notan_graphics::prelude::*;
 use notan_graphics::DeviceBackend;
 use std::any::Any;
+use std::time::Duration;
 
 mod buffer;
+mod compute;
+mod debug;
 mod pipeline;
+mod pixels;
+mod queries;
 mod render_target;
 mod texture;
 mod to_glow;
@@ -18,9 +23,15 @@ pub mod texture_source;
 mod html_image;
 
 use crate::buffer::Kind;
+use crate::compute::{is_compute_supported, InnerComputePipeline};
+use crate::debug::is_debug_supported;
 use crate::pipeline::get_inner_attrs;
+use crate::pixels::{read_texture, InnerPixelsRead};
+use crate::queries::{GpuTimers, InnerOcclusionQuery};
 use crate::texture::{texture_format, texture_type, TextureKey};
-use crate::texture_source::{add_empty_texture, add_texture_from_bytes, add_texture_from_image};
+use crate::texture_source::{
+    add_cubemap_texture, add_empty_texture, add_texture_from_bytes, add_texture_from_image,
+};
 use crate::to_glow::ToGlow;
 use buffer::InnerBuffer;
 use pipeline::{InnerPipeline, VertexAttributes};
@@ -33,20 +44,34 @@ pub struct GlowBackend {
     texture_count: u64,
     pipeline_count: u64,
     render_target_count: u64,
+    pixels_read_count: u64,
+    compute_pipeline_count: u64,
+    occlusion_query_count: u64,
     size: (u32, u32),
     dpi: f32,
     pipelines: HashMap<u64, InnerPipeline>,
     buffers: HashMap<u64, InnerBuffer>,
     textures: HashMap<u64, InnerTexture>,
     render_targets: HashMap<u64, InnerRenderTexture>,
+    pixels_reads: HashMap<u64, InnerPixelsRead>,
+    compute_pipelines: HashMap<u64, InnerComputePipeline>,
+    occlusion_queries: HashMap<u64, InnerOcclusionQuery>,
+    active_occlusion_query: Option<u64>,
     using_indices: Option<IndexFormat>,
     api_name: String,
     current_pipeline: u64,
+    current_options: Option<PipelineOptions>,
+    // state set on the context, used to skip redundant calls
+    bound_pipeline: Option<u64>,
+    applied_options: Option<PipelineOptions>,
     limits: Limits,
     stats: GpuStats,
     current_uniforms: Vec<UniformLocation>,
     target_render_texture: Option<u64>,
     render_texture_mipmaps: bool,
+    msaa_render_target: Option<u64>,
+    debug: bool,
+    timers: GpuTimers,
 }
 
 impl GlowBackend {
@@ -74,6 +99,17 @@ impl GlowBackend {
         Self::from(gl, "opengl")
     }
 
+    /// Creates the backend for an OpenGL ES context, like the EGL ones used without a window
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn new_es<F>(loader_function: F) -> Result<Self, String>
+    where
+        F: FnMut(&str) -> *const std::os::raw::c_void,
+    {
+        let gl = unsafe { Context::from_loader_function(loader_function) };
+
+        Self::from(gl, "opengl_es")
+    }
+
     #[cfg(any(target_os = "ios", target_os = "android"))]
     pub fn new<F>(mut loader_function: F) -> Result<Self, String>
     where
@@ -94,20 +130,39 @@ impl GlowBackend {
             );
         }
 
+        // WebGL1 doesn't support uniform buffers, they are set as loose uniforms
+        let uniform_blocks = api != "webgl";
         let limits = unsafe {
             Limits {
                 max_texture_size: gl.get_parameter_i32(glow::MAX_TEXTURE_SIZE) as _,
-                max_uniform_blocks: gl.get_parameter_i32(glow::MAX_UNIFORM_BLOCK_SIZE) as _,
+                max_texture_units: gl.get_parameter_i32(glow::MAX_TEXTURE_IMAGE_UNITS) as _,
+                max_uniform_blocks: if uniform_blocks {
+                    gl.get_parameter_i32(glow::MAX_UNIFORM_BUFFER_BINDINGS) as _
+                } else {
+                    0
+                },
+                max_uniform_block_size: if uniform_blocks {
+                    gl.get_parameter_i32(glow::MAX_UNIFORM_BLOCK_SIZE) as _
+                } else {
+                    0
+                },
+                max_anisotropy: max_anisotropy(&gl),
+                compute: is_compute_supported(&gl),
             }
         };
 
         let stats = GpuStats::default();
+        let debug = is_debug_supported(&gl);
+        let timers = GpuTimers::new(&gl);
 
         Ok(Self {
             pipeline_count: 0,
             buffer_count: 0,
             texture_count: 0,
             render_target_count: 0,
+            pixels_read_count: 0,
+            compute_pipeline_count: 0,
+            occlusion_query_count: 0,
             gl,
             size: (0, 0),
             dpi: 1.0,
@@ -115,14 +170,24 @@ impl GlowBackend {
             buffers: HashMap::new(),
             textures: HashMap::new(),
             render_targets: HashMap::new(),
+            pixels_reads: HashMap::new(),
+            compute_pipelines: HashMap::new(),
+            occlusion_queries: HashMap::new(),
+            active_occlusion_query: None,
             using_indices: None,
             api_name: api.to_string(),
             current_pipeline: 0,
+            current_options: None,
+            bound_pipeline: None,
+            applied_options: None,
             limits,
             stats,
             current_uniforms: vec![],
             target_render_texture: None,
             render_texture_mipmaps: false,
+            msaa_render_target: None,
+            debug,
+            timers,
         })
     }
 }
@@ -131,9 +196,28 @@ impl GlowBackend {
     #[inline(always)]
     fn clear(&mut self, color: &Option<Color>, depth: &Option<f32>, stencil: &Option<i32>) {
         clear(&self.gl, color, depth, stencil);
+        self.applied_options = None;
         self.stats.misc += 1;
     }
 
+    fn clear_attachments(
+        &mut self,
+        color: &Option<Color>,
+        depth: &Option<f32>,
+        stencil: &Option<i32>,
+    ) {
+        self.clear(color, depth, stencil);
+
+        // clearing changes the masks and tests, so the pipeline state must be restored
+        if let Some(options) = self
+            .current_options
+            .filter(|_| self.bound_pipeline.is_some())
+        {
+            pipeline::set_options(&self.gl, &options, None);
+            self.applied_options = Some(options);
+        }
+    }
+
     fn begin(
         &mut self,
         target: Option<u64>,
@@ -146,6 +230,9 @@ impl GlowBackend {
             _ => None,
         };
 
+        self.msaa_render_target =
+            target.filter(|_| render_target.is_some_and(|rt| rt.is_multisampled()));
+
         let (width, height, dpi) = match render_target {
             Some(rt) => {
                 rt.bind(&self.gl);
@@ -164,6 +251,10 @@ impl GlowBackend {
 
         self.viewport(0.0, 0.0, width as _, height as _, dpi);
 
+        // the context could be changed outside of the render passes
+        self.bound_pipeline = None;
+        self.applied_options = None;
+
         self.clear(color, depth, stencil);
     }
 
@@ -200,6 +291,14 @@ impl GlowBackend {
     }
 
     fn end(&mut self) {
+        if let Some(rt) = self
+            .msaa_render_target
+            .take()
+            .and_then(|id| self.render_targets.get(&id))
+        {
+            rt.resolve(&self.gl);
+        }
+
         unsafe {
             // generate mipmap for the framebuffer texture if needed
             if self.render_texture_mipmaps {
@@ -222,6 +321,7 @@ impl GlowBackend {
         }
 
         self.using_indices = None;
+        self.bound_pipeline = None;
         self.target_render_texture = None;
         self.render_texture_mipmaps = false;
     }
@@ -234,10 +334,21 @@ impl GlowBackend {
 
     fn set_pipeline(&mut self, id: u64, options: &PipelineOptions) {
         if let Some(pip) = self.pipelines.get(&id) {
-            pip.bind(&self.gl, options);
+            if self.bound_pipeline == Some(id) {
+                self.stats.skipped_state_changes += 1;
+            } else {
+                pip.bind(&self.gl);
+                self.stats.pipeline_binds += 1;
+                self.bound_pipeline = Some(id);
+                self.current_pipeline = id;
+                self.current_uniforms.clone_from(&pip.uniform_locations);
+            }
+
+            self.stats.skipped_state_changes +=
+                pipeline::set_options(&self.gl, options, self.applied_options.as_ref());
+            self.applied_options = Some(*options);
+            self.current_options = Some(*options);
             self.using_indices = None;
-            self.current_pipeline = id;
-            self.current_uniforms.clone_from(&pip.uniform_locations);
         }
     }
 
@@ -265,6 +376,7 @@ impl GlowBackend {
                     );
                     false
                 }
+                Kind::Storage(_) => false,
                 Kind::Vertex(attrs) => match self.pipelines.get_mut(&self.current_pipeline) {
                     Some(pip) => pip.use_attrs(id, attrs),
                     _ => false,
@@ -288,6 +400,7 @@ impl GlowBackend {
                     .get(&location)
                     .unwrap_or_else(|| self.get_texture_uniform_loc(&location));
                 texture.bind(&self.gl, slot, loc);
+                self.stats.texture_binds += 1;
             }
         }
     }
@@ -310,6 +423,47 @@ impl GlowBackend {
         }
     }
 
+    fn clean_compute_pipeline(&mut self, id: u64) {
+        if let Some(pip) = self.compute_pipelines.remove(&id) {
+            pip.clean(&self.gl);
+        }
+    }
+
+    fn clean_occlusion_query(&mut self, id: u64) {
+        if let Some(query) = self.occlusion_queries.remove(&id) {
+            query.clean(&self.gl);
+        }
+    }
+
+    fn begin_occlusion_query(&mut self, id: u64) {
+        debug_assert!(
+            self.active_occlusion_query.is_none(),
+            "Occlusion queries cannot be nested"
+        );
+
+        if let Some(query) = self.occlusion_queries.get_mut(&id) {
+            query.begin(&self.gl);
+            self.active_occlusion_query = Some(id);
+        }
+    }
+
+    fn end_occlusion_query(&mut self) {
+        let query = self
+            .active_occlusion_query
+            .take()
+            .and_then(|id| self.occlusion_queries.get_mut(&id));
+
+        if let Some(query) = query {
+            query.end(&self.gl);
+        }
+    }
+
+    fn clean_pixels_read(&mut self, id: u64) {
+        if let Some(read) = self.pixels_reads.remove(&id) {
+            read.clean(&self.gl);
+        }
+    }
+
     fn clean_texture(&mut self, id: u64) {
         if let Some(texture) = self.textures.remove(&id) {
             texture.clean(&self.gl);
@@ -325,18 +479,22 @@ impl GlowBackend {
     fn draw(&mut self, primitive: &DrawPrimitive, offset: i32, count: i32) {
         unsafe {
             self.stats.draw_calls += 1;
+            self.stats.vertices += count as usize;
             match self.using_indices {
                 None => self.gl.draw_arrays(primitive.to_glow(), offset, count),
-                Some(format) => {
-                    self.gl
-                        .draw_elements(primitive.to_glow(), count, format.to_glow(), offset * 4)
-                }
+                Some(format) => self.gl.draw_elements(
+                    primitive.to_glow(),
+                    count,
+                    format.to_glow(),
+                    offset * index_size(format),
+                ),
             }
         }
     }
     fn draw_instanced(&mut self, primitive: &DrawPrimitive, offset: i32, count: i32, length: i32) {
         unsafe {
             self.stats.draw_calls += 1;
+            self.stats.vertices += (count * length) as usize;
             match self.using_indices {
                 None => self
                     .gl
@@ -345,7 +503,7 @@ impl GlowBackend {
                     primitive.to_glow(),
                     count,
                     format.to_glow(),
-                    offset,
+                    offset * index_size(format),
                     length,
                 ),
             }
@@ -399,7 +557,6 @@ impl DeviceBackend for GlowBackend {
             vertex_attrs,
             texture_locations,
         )?;
-        inner_pipeline.bind(&self.gl, &options);
 
         self.pipeline_count += 1;
         self.pipelines.insert(self.pipeline_count, inner_pipeline);
@@ -409,6 +566,10 @@ impl DeviceBackend for GlowBackend {
         Ok(self.pipeline_count)
     }
 
+    fn pipeline_reflection(&self, pipeline: u64) -> Option<ShaderReflection> {
+        self.pipelines.get(&pipeline).map(|p| p.reflection.clone())
+    }
+
     fn create_vertex_buffer(
         &mut self,
         attrs: &[VertexAttr],
@@ -436,6 +597,7 @@ impl DeviceBackend for GlowBackend {
     fn create_uniform_buffer(&mut self, slot: u32, name: &str) -> Result<u64, String> {
         let mut inner_buffer =
             InnerBuffer::new(&self.gl, Kind::Uniform(slot, name.to_string()), true)?;
+        inner_buffer.loose_uniforms = self.limits.max_uniform_blocks == 0;
         inner_buffer.bind(&self.gl, Some(self.current_pipeline), false);
         self.buffer_count += 1;
         self.buffers.insert(self.buffer_count, inner_buffer);
@@ -443,8 +605,96 @@ impl DeviceBackend for GlowBackend {
         Ok(self.buffer_count)
     }
 
+    fn create_storage_buffer(&mut self, slot: u32) -> Result<u64, String> {
+        let mut inner_buffer = InnerBuffer::new(&self.gl, Kind::Storage(slot), true)?;
+        inner_buffer.bind(&self.gl, Some(self.current_pipeline), false);
+        self.buffer_count += 1;
+        self.buffers.insert(self.buffer_count, inner_buffer);
+        self.stats.buffer_creation += 1;
+        Ok(self.buffer_count)
+    }
+
+    fn read_buffer(&mut self, id: u64, bytes: &mut [u8]) -> Result<(), String> {
+        let buffer = self
+            .buffers
+            .get(&id)
+            .ok_or_else(|| format!("Invalid buffer id {id}"))?;
+        buffer.read(&self.gl, bytes)?;
+        self.stats.misc += 1;
+        Ok(())
+    }
+
+    fn create_compute_pipeline(&mut self, source: &[u8]) -> Result<u64, String> {
+        if !self.limits.compute {
+            return Err("Compute shaders are not supported by this device".to_string());
+        }
+
+        let source = std::str::from_utf8(source).map_err(|e| e.to_string())?;
+        let inner_pipeline = InnerComputePipeline::new(&self.gl, source)?;
+        self.compute_pipeline_count += 1;
+        self.compute_pipelines
+            .insert(self.compute_pipeline_count, inner_pipeline);
+        self.stats.misc += 1;
+        Ok(self.compute_pipeline_count)
+    }
+
+    fn dispatch_compute(
+        &mut self,
+        pipeline: u64,
+        buffers: &[u64],
+        groups: [u32; 3],
+    ) -> Result<(), String> {
+        let program = self
+            .compute_pipelines
+            .get(&pipeline)
+            .ok_or_else(|| format!("Invalid compute pipeline id {pipeline}"))?
+            .program;
+
+        unsafe {
+            self.gl.use_program(Some(program));
+
+            for id in buffers {
+                let buffer = self
+                    .buffers
+                    .get_mut(id)
+                    .ok_or_else(|| format!("Invalid buffer id {id}"))?;
+
+                if let Kind::Uniform(slot, name) = &buffer.kind {
+                    if let Some(index) = self.gl.get_uniform_block_index(program, name) {
+                        self.gl.uniform_block_binding(program, index, *slot as _);
+                    }
+                }
+
+                buffer.bind(&self.gl, None, false);
+            }
+
+            let [x, y, z] = groups;
+            self.gl.dispatch_compute(x, y, z);
+
+            // storage buffers can be read as vertices, indices or uniforms after the dispatch
+            self.gl.memory_barrier(
+                glow::SHADER_STORAGE_BARRIER_BIT
+                    | glow::VERTEX_ATTRIB_ARRAY_BARRIER_BIT
+                    | glow::ELEMENT_ARRAY_BARRIER_BIT
+                    | glow::UNIFORM_BARRIER_BIT
+                    | glow::BUFFER_UPDATE_BARRIER_BIT,
+            );
+        }
+
+        // the program is changed so the next render must set the pipeline again
+        self.current_pipeline = 0;
+        self.bound_pipeline = None;
+        self.stats.misc += 1;
+        Ok(())
+    }
+
     fn set_buffer_data(&mut self, id: u64, data: &[u8]) {
         if let Some(buffer) = self.buffers.get_mut(&id) {
+            if buffer.has_same_uniforms(data) {
+                self.stats.skipped_state_changes += 1;
+                return;
+            }
+
             buffer.bind(&self.gl, None, false);
             buffer.update(&self.gl, data);
             self.stats.buffer_updates += 1;
@@ -463,6 +713,11 @@ impl DeviceBackend for GlowBackend {
                     stencil,
                 } => self.begin(target, color, depth, stencil),
                 End => self.end(),
+                Clear {
+                    color,
+                    depth,
+                    stencil,
+                } => self.clear_attachments(color, depth, stencil),
                 Pipeline { id, options } => self.set_pipeline(*id, options),
                 BindBuffer { id } => self.bind_buffer(*id),
                 Draw {
@@ -490,6 +745,10 @@ impl DeviceBackend for GlowBackend {
                     width,
                     height,
                 } => self.scissors(*x, *y, *width, *height, self.dpi),
+                PushDebugGroup { name } => self.push_debug_group(name),
+                PopDebugGroup => self.pop_debug_group(),
+                BeginOcclusionQuery { id } => self.begin_occlusion_query(*id),
+                EndOcclusionQuery => self.end_occlusion_query(),
             }
         });
     }
@@ -501,6 +760,9 @@ impl DeviceBackend for GlowBackend {
             ResourceId::Buffer(id) => self.clean_buffer(*id),
             ResourceId::Texture(id) => self.clean_texture(*id),
             ResourceId::RenderTexture(id) => self.clean_render_target(*id),
+            ResourceId::PixelsRead(id) => self.clean_pixels_read(*id),
+            ResourceId::ComputePipeline(id) => self.clean_compute_pipeline(*id),
+            ResourceId::OcclusionQuery(id) => self.clean_occlusion_query(*id),
         });
     }
 
@@ -522,6 +784,7 @@ impl DeviceBackend for GlowBackend {
             TextureSourceKind::Image(buffer) => add_texture_from_image(self, buffer, info)?,
             TextureSourceKind::Bytes(bytes) => add_texture_from_bytes(self, bytes, info)?,
             TextureSourceKind::Raw(raw) => raw.create(self, info)?,
+            TextureSourceKind::CubeMap(source) => add_cubemap_texture(self, source, info)?,
         };
         self.stats.texture_creation += 1;
         Ok((id, info))
@@ -546,6 +809,22 @@ impl DeviceBackend for GlowBackend {
         Ok(self.render_target_count)
     }
 
+    fn set_texture_filter(
+        &mut self,
+        texture: u64,
+        min: TextureFilter,
+        mag: TextureFilter,
+    ) -> Result<(), String> {
+        let texture = self
+            .textures
+            .get_mut(&texture)
+            .ok_or_else(|| "Invalid texture id".to_string())?;
+
+        texture.set_filter(&self.gl, min, mag)?;
+        self.stats.misc += 1;
+        Ok(())
+    }
+
     fn update_texture(
         &mut self,
         texture: u64,
@@ -553,6 +832,9 @@ impl DeviceBackend for GlowBackend {
         opts: TextureUpdate,
     ) -> Result<(), String> {
         match self.textures.get(&texture) {
+            Some(texture) if texture.target == glow::TEXTURE_CUBE_MAP => {
+                Err("Cubemaps cannot be updated".to_string())
+            }
             Some(texture) => {
                 let use_mipmaps = texture.use_mipmaps;
 
@@ -603,52 +885,218 @@ impl DeviceBackend for GlowBackend {
     ) -> Result<(), String> {
         match self.textures.get(&texture) {
             Some(texture) => unsafe {
-                let fbo = self.gl.create_framebuffer()?;
-                self.gl.bind_framebuffer(glow::FRAMEBUFFER, Some(fbo));
-                self.gl.framebuffer_texture_2d(
-                    glow::FRAMEBUFFER,
-                    glow::COLOR_ATTACHMENT0,
-                    glow::TEXTURE_2D,
-                    Some(texture.texture),
-                    0,
-                );
-
-                let status = self.gl.check_framebuffer_status(glow::FRAMEBUFFER);
-                let can_read = status == glow::FRAMEBUFFER_COMPLETE;
-
-                let clean = || {
-                    self.gl.bind_framebuffer(glow::FRAMEBUFFER, None);
-                    self.gl.delete_framebuffer(fbo);
-                };
-
-                if can_read {
-                    self.gl.read_pixels(
-                        opts.x_offset as _,
-                        opts.y_offset as _,
-                        opts.width as _,
-                        opts.height as _,
-                        texture_format(&opts.format),
-                        texture_type(&opts.format),
-                        glow::PixelPackData::Slice(bytes),
-                    );
-
-                    clean();
-                    self.stats.read_pixels += 1;
-                    Ok(())
-                } else {
-                    clean();
-                    Err("Framebuffer incomplete...".to_string())
-                }
+                read_texture(
+                    &self.gl,
+                    texture.texture,
+                    opts,
+                    glow::PixelPackData::Slice(bytes),
+                )?;
+                self.stats.read_pixels += 1;
+                Ok(())
             },
             None => Err("Invalid texture id".to_string()),
         }
     }
 
+    fn read_screen_pixels(
+        &mut self,
+        width: u32,
+        height: u32,
+        bytes: &mut [u8],
+    ) -> Result<(), String> {
+        unsafe {
+            self.gl.bind_framebuffer(glow::FRAMEBUFFER, None);
+            self.gl.pixel_store_i32(glow::PACK_ALIGNMENT, 1);
+            self.gl.read_pixels(
+                0,
+                0,
+                width as _,
+                height as _,
+                glow::RGBA,
+                glow::UNSIGNED_BYTE,
+                glow::PixelPackData::Slice(bytes),
+            );
+            self.gl.pixel_store_i32(glow::PACK_ALIGNMENT, 4);
+        }
+
+        self.stats.read_pixels += 1;
+        Ok(())
+    }
+
+    fn blit(
+        &mut self,
+        src: u64,
+        dst: u64,
+        src_rect: [i32; 4],
+        dst_rect: [i32; 4],
+        filter: TextureFilter,
+    ) -> Result<(), String> {
+        let src_rt = self
+            .render_targets
+            .get(&src)
+            .ok_or_else(|| format!("Error blitting: render texture id '{src}' not found."))?;
+        let dst_rt = self
+            .render_targets
+            .get(&dst)
+            .ok_or_else(|| format!("Error blitting: render texture id '{dst}' not found."))?;
+
+        let [sx, sy, sw, sh] = src_rect;
+        let [dx, dy, dw, dh] = dst_rect;
+        let scaled = sw != dw || sh != dh;
+
+        unsafe {
+            self.gl
+                .bind_framebuffer(glow::READ_FRAMEBUFFER, Some(src_rt.read_fbo(scaled)));
+            self.gl
+                .bind_framebuffer(glow::DRAW_FRAMEBUFFER, Some(dst_rt.fbo));
+            self.gl.blit_framebuffer(
+                sx,
+                sy,
+                sx + sw,
+                sy + sh,
+                dx,
+                dy,
+                dx + dw,
+                dy + dh,
+                glow::COLOR_BUFFER_BIT,
+                filter.to_glow(),
+            );
+            self.gl.bind_framebuffer(glow::FRAMEBUFFER, None);
+
+            if dst_rt.use_mipmaps {
+                if let Some(texture) = self.textures.get(&dst_rt.texture_id) {
+                    self.gl
+                        .bind_texture(glow::TEXTURE_2D, Some(texture.texture));
+                    self.gl.generate_mipmap(glow::TEXTURE_2D);
+                    self.gl.bind_texture(glow::TEXTURE_2D, None);
+                }
+            }
+        }
+
+        self.stats.misc += 1;
+        Ok(())
+    }
+
+    fn read_pixels_async(&mut self, texture: u64, opts: &TextureRead) -> Result<u64, String> {
+        let texture = self
+            .textures
+            .get(&texture)
+            .ok_or_else(|| "Invalid texture id".to_string())?;
+
+        let inner = InnerPixelsRead::new(&self.gl, texture.texture, opts)?;
+        self.pixels_read_count += 1;
+        self.pixels_reads.insert(self.pixels_read_count, inner);
+        self.stats.read_pixels += 1;
+        Ok(self.pixels_read_count)
+    }
+
+    fn poll_read_pixels(&mut self, id: u64, bytes: &mut [u8]) -> Result<bool, String> {
+        let read = self
+            .pixels_reads
+            .get(&id)
+            .ok_or_else(|| "Invalid pixels read id".to_string())?;
+
+        if !read.is_ready(&self.gl) {
+            return Ok(false);
+        }
+
+        let res = read.copy_to(&self.gl, bytes);
+        self.clean_pixels_read(id);
+        res.map(|_| true)
+    }
+
+    fn begin_gpu_timer(&mut self, label: &str) -> Result<(), String> {
+        self.timers.begin(&self.gl, label)
+    }
+
+    fn end_gpu_timer(&mut self) -> Result<(), String> {
+        self.timers.end(&self.gl)
+    }
+
+    fn gpu_time(&mut self, label: &str) -> Option<Duration> {
+        self.timers.get(&self.gl, label)
+    }
+
+    fn create_occlusion_query(&mut self) -> Result<u64, String> {
+        let query = InnerOcclusionQuery::new(&self.gl)?;
+        self.occlusion_query_count += 1;
+        self.occlusion_queries
+            .insert(self.occlusion_query_count, query);
+        Ok(self.occlusion_query_count)
+    }
+
+    fn occlusion_query_result(&mut self, query: u64) -> Option<bool> {
+        self.occlusion_queries
+            .get_mut(&query)
+            .and_then(|q| q.result(&self.gl))
+    }
+
+    fn push_debug_group(&mut self, name: &str) {
+        if self.debug {
+            unsafe {
+                self.gl
+                    .push_debug_group(glow::DEBUG_SOURCE_APPLICATION, 0, name);
+            }
+        }
+    }
+
+    fn pop_debug_group(&mut self) {
+        if self.debug {
+            unsafe {
+                self.gl.pop_debug_group();
+            }
+        }
+    }
+
+    // the raw names of the objects are only available on native platforms
+    #[cfg(not(target_arch = "wasm32"))]
+    fn set_debug_label(&mut self, resource: &ResourceId, label: &str) {
+        if !self.debug {
+            return;
+        }
+
+        let object = match resource {
+            ResourceId::Buffer(id) => self.buffers.get(id).map(|b| (glow::BUFFER, b.buffer.0)),
+            ResourceId::Texture(id) => self.textures.get(id).map(|t| (glow::TEXTURE, t.texture.0)),
+            ResourceId::Pipeline(id) => {
+                self.pipelines.get(id).map(|p| (glow::PROGRAM, p.program.0))
+            }
+            ResourceId::ComputePipeline(id) => self
+                .compute_pipelines
+                .get(id)
+                .map(|p| (glow::PROGRAM, p.program.0)),
+            ResourceId::RenderTexture(id) => self
+                .render_targets
+                .get(id)
+                .map(|rt| (glow::FRAMEBUFFER, rt.fbo.0)),
+            ResourceId::OcclusionQuery(id) => self
+                .occlusion_queries
+                .get(id)
+                .map(|q| (glow::QUERY, q.raw().0)),
+            ResourceId::PixelsRead(_) => None,
+        };
+
+        if let Some((identifier, name)) = object {
+            unsafe {
+                self.gl.object_label(identifier, name.get(), Some(label));
+            }
+        }
+    }
+
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
 }
 
+/// Offsets are given in indices but OpenGL expects bytes
+#[inline]
+fn index_size(format: IndexFormat) -> i32 {
+    match format {
+        IndexFormat::Uint16 => 2,
+        IndexFormat::Uint32 => 4,
+    }
+}
+
 #[inline]
 pub(crate) fn clear(
     gl: &Context,
@@ -660,6 +1108,8 @@ pub(crate) fn clear(
     unsafe {
         if let Some(color) = color {
             mask |= glow::COLOR_BUFFER_BIT;
+            // the color mask of the last pipeline also applies to the clear
+            gl.color_mask(true, true, true, true);
             gl.clear_color(color.r, color.g, color.b, color.a);
         }
 
@@ -682,3 +1132,20 @@ pub(crate) fn clear(
         }
     }
 }
+
+fn max_anisotropy(gl: &Context) -> f32 {
+    let supported = gl.supported_extensions().iter().any(|ext| {
+        matches!(
+            ext.as_str(),
+            "EXT_texture_filter_anisotropic"
+                | "GL_EXT_texture_filter_anisotropic"
+                | "GL_ARB_texture_filter_anisotropic"
+        )
+    });
+
+    if !supported {
+        return 1.0;
+    }
+
+    unsafe { gl.get_parameter_f32(glow::MAX_TEXTURE_MAX_ANISOTROPY) }
+}
@@ -14,6 +14,7 @@ pub(crate) struct InnerPipeline {
     pub uniform_locations: Vec<UniformLocation>,
     pub attrs_bound_to: HashMap<u32, u64>,
     pub texture_locations: HashMap<u32, UniformLocation>,
+    pub reflection: ShaderReflection,
 }
 
 #[inline]
@@ -76,20 +77,59 @@ impl InnerPipeline {
     }
 
     #[inline(always)]
-    pub fn bind(&self, gl: &Context, options: &PipelineOptions) {
+    pub fn bind(&self, gl: &Context) {
         unsafe {
             gl.bind_vertex_array(Some(self.vao));
             gl.use_program(Some(self.program));
+        }
+    }
+}
 
+/// Sets the states that are different from the `last` options applied,
+/// or all of them if the current state is unknown.
+/// Returns the number of state groups skipped
+pub(crate) fn set_options(
+    gl: &Context,
+    options: &PipelineOptions,
+    last: Option<&PipelineOptions>,
+) -> usize {
+    let mut skipped = 0;
+    let mut changed = |is_same: fn(&PipelineOptions, &PipelineOptions) -> bool| {
+        let changed = last.is_none_or(|last| !is_same(last, options));
+        if !changed {
+            skipped += 1;
+        }
+        changed
+    };
+
+    unsafe {
+        if changed(|a, b| a.stencil == b.stencil && a.stencil_back == b.stencil_back) {
             set_stencil(gl, options);
+        }
+
+        if changed(|a, b| a.depth_stencil == b.depth_stencil) {
             set_depth_stencil(gl, options);
+        }
+
+        if changed(|a, b| a.color_mask == b.color_mask) {
             set_color_mask(gl, options);
+        }
+
+        if changed(|a, b| a.cull_mode == b.cull_mode) {
             set_culling(gl, options);
+        }
+
+        if changed(|a, b| a.color_blend == b.color_blend && a.alpha_blend == b.alpha_blend) {
             set_blend_mode(gl, options);
-            #[cfg(not(target_arch = "wasm32"))]
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if changed(|a, b| a.srgb_space == b.srgb_space) {
             set_srgb_space(gl, options);
         }
     }
+
+    skipped
 }
 
 #[derive(Debug, Clone)]
@@ -158,22 +198,37 @@ impl InnerAttr {
 
 #[inline(always)]
 unsafe fn set_stencil(gl: &Context, options: &PipelineOptions) {
-    if should_disable_stencil(&options.stencil) {
+    if should_disable_stencil(&options.stencil) && should_disable_stencil(&options.stencil_back) {
         gl.disable(glow::STENCIL_TEST);
-    } else if let Some(opts) = options.stencil {
-        gl.enable(glow::STENCIL_TEST);
-        gl.stencil_mask(opts.write_mask);
-        gl.stencil_op(
-            opts.stencil_fail.to_glow(),
-            opts.depth_fail.to_glow(),
-            opts.pass.to_glow(),
-        );
-        gl.stencil_func(
-            opts.compare.to_glow().unwrap_or(glow::ALWAYS),
-            opts.reference as _,
-            opts.read_mask,
-        );
+        return;
     }
+
+    gl.enable(glow::STENCIL_TEST);
+    let front = options.stencil.unwrap_or_default();
+    match options.stencil_back {
+        Some(back) => {
+            set_stencil_face(gl, glow::FRONT, &front);
+            set_stencil_face(gl, glow::BACK, &back);
+        }
+        None => set_stencil_face(gl, glow::FRONT_AND_BACK, &front),
+    }
+}
+
+#[inline(always)]
+unsafe fn set_stencil_face(gl: &Context, face: u32, opts: &StencilOptions) {
+    gl.stencil_mask_separate(face, opts.write_mask);
+    gl.stencil_op_separate(
+        face,
+        opts.stencil_fail.to_glow(),
+        opts.depth_fail.to_glow(),
+        opts.pass.to_glow(),
+    );
+    gl.stencil_func_separate(
+        face,
+        opts.compare.to_glow().unwrap_or(glow::ALWAYS),
+        opts.reference as _,
+        opts.read_mask,
+    );
 }
 
 #[inline(always)]
@@ -350,6 +405,8 @@ fn create_pipeline(
         }
     }
 
+    let reflection = reflect(gl, program);
+
     let vao = unsafe {
         let vao = gl.create_vertex_array()?;
         gl.bind_vertex_array(Some(vao));
@@ -364,11 +421,66 @@ fn create_pipeline(
         uniform_locations,
         attrs_bound_to: HashMap::default(),
         texture_locations: texture_locations_map,
+        reflection,
     })
 }
 
+fn reflect(gl: &Context, program: Program) -> ShaderReflection {
+    unsafe {
+        let mut attrs = (0..gl.get_active_attributes(program))
+            .filter_map(|index| gl.get_active_attribute(program, index))
+            .filter(|attr| !attr.name.starts_with("gl_"))
+            .filter_map(|attr| {
+                let location = gl.get_attrib_location(program, &attr.name)?;
+                Some(ShaderAttr {
+                    location,
+                    data_type: shader_data_type(attr.atype),
+                    name: attr.name,
+                })
+            })
+            .collect::<Vec<_>>();
+        attrs.sort_by_key(|attr| attr.location);
+
+        let uniforms = (0..gl.get_active_uniforms(program))
+            .filter_map(|index| gl.get_active_uniform(program, index))
+            .map(|u| ShaderUniform {
+                in_block: gl.get_uniform_location(program, &u.name).is_none(),
+                data_type: shader_data_type(u.utype),
+                len: u.size as _,
+                name: u.name,
+            })
+            .collect();
+
+        ShaderReflection { attrs, uniforms }
+    }
+}
+
+fn shader_data_type(typ: u32) -> ShaderDataType {
+    match typ {
+        glow::FLOAT => ShaderDataType::Float,
+        glow::FLOAT_VEC2 => ShaderDataType::Vec2,
+        glow::FLOAT_VEC3 => ShaderDataType::Vec3,
+        glow::FLOAT_VEC4 => ShaderDataType::Vec4,
+        glow::INT => ShaderDataType::Int,
+        glow::INT_VEC2 => ShaderDataType::IVec2,
+        glow::INT_VEC3 => ShaderDataType::IVec3,
+        glow::INT_VEC4 => ShaderDataType::IVec4,
+        glow::UNSIGNED_INT => ShaderDataType::UInt,
+        glow::UNSIGNED_INT_VEC2 => ShaderDataType::UVec2,
+        glow::UNSIGNED_INT_VEC3 => ShaderDataType::UVec3,
+        glow::UNSIGNED_INT_VEC4 => ShaderDataType::UVec4,
+        glow::BOOL => ShaderDataType::Bool,
+        glow::FLOAT_MAT2 => ShaderDataType::Mat2,
+        glow::FLOAT_MAT3 => ShaderDataType::Mat3,
+        glow::FLOAT_MAT4 => ShaderDataType::Mat4,
+        glow::SAMPLER_2D => ShaderDataType::Sampler2D,
+        glow::SAMPLER_CUBE => ShaderDataType::SamplerCube,
+        _ => ShaderDataType::Other,
+    }
+}
+
 #[inline(always)]
-fn create_shader(gl: &Context, typ: u32, source: &str) -> Result<Shader, String> {
+pub(crate) fn create_shader(gl: &Context, typ: u32, source: &str) -> Result<Shader, String> {
     unsafe {
         let shader = gl.create_shader(typ)?;
         gl.shader_source(shader, source);
@@ -385,6 +497,7 @@ fn create_shader(gl: &Context, typ: u32, source: &str) -> Result<Shader, String>
         let typ_name = match typ {
             glow::VERTEX_SHADER => "vertex".to_string(),
             glow::FRAGMENT_SHADER => "fragment".to_string(),
+            glow::COMPUTE_SHADER => "compute".to_string(),
             _ => format!("unknown type ({typ})"),
         };
 
@@ -0,0 +1,103 @@
+use crate::texture::{texture_format, texture_type, TextureKey};
+use glow::{Buffer, Context, Fence, HasContext, PixelPackData};
+use notan_graphics::TextureRead;
+
+/// Pixels read into a pixel pack buffer, they can be copied once the fence is signaled
+pub(crate) struct InnerPixelsRead {
+    buffer: Buffer,
+    fence: Fence,
+    len: usize,
+}
+
+impl InnerPixelsRead {
+    pub fn new(gl: &Context, texture: TextureKey, opts: &TextureRead) -> Result<Self, String> {
+        let len = (opts.width * opts.height) as usize * opts.format.bytes_per_pixel() as usize;
+        unsafe {
+            let buffer = gl.create_buffer()?;
+            gl.bind_buffer(glow::PIXEL_PACK_BUFFER, Some(buffer));
+            gl.buffer_data_size(glow::PIXEL_PACK_BUFFER, len as _, glow::STREAM_READ);
+
+            let res = read_texture(gl, texture, opts, PixelPackData::BufferOffset(0));
+            gl.bind_buffer(glow::PIXEL_PACK_BUFFER, None);
+            if let Err(e) = res {
+                gl.delete_buffer(buffer);
+                return Err(e);
+            }
+
+            let fence = gl.fence_sync(glow::SYNC_GPU_COMMANDS_COMPLETE, 0)?;
+            Ok(Self { buffer, fence, len })
+        }
+    }
+
+    #[inline]
+    pub fn is_ready(&self, gl: &Context) -> bool {
+        unsafe { gl.get_sync_status(self.fence) == glow::SIGNALED }
+    }
+
+    pub fn copy_to(&self, gl: &Context, bytes: &mut [u8]) -> Result<(), String> {
+        if bytes.len() < self.len {
+            return Err(format!(
+                "The provided buffer len of {} is less than the required {}",
+                bytes.len(),
+                self.len
+            ));
+        }
+
+        unsafe {
+            gl.bind_buffer(glow::PIXEL_PACK_BUFFER, Some(self.buffer));
+            gl.get_buffer_sub_data(glow::PIXEL_PACK_BUFFER, 0, &mut bytes[..self.len]);
+            gl.bind_buffer(glow::PIXEL_PACK_BUFFER, None);
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn clean(self, gl: &Context) {
+        unsafe {
+            gl.delete_sync(self.fence);
+            gl.delete_buffer(self.buffer);
+        }
+    }
+}
+
+/// Reads the texture using a temporal framebuffer
+pub(crate) unsafe fn read_texture(
+    gl: &Context,
+    texture: TextureKey,
+    opts: &TextureRead,
+    data: PixelPackData,
+) -> Result<(), String> {
+    let fbo = gl.create_framebuffer()?;
+    gl.bind_framebuffer(glow::FRAMEBUFFER, Some(fbo));
+    gl.framebuffer_texture_2d(
+        glow::FRAMEBUFFER,
+        glow::COLOR_ATTACHMENT0,
+        glow::TEXTURE_2D,
+        Some(texture),
+        0,
+    );
+
+    let status = gl.check_framebuffer_status(glow::FRAMEBUFFER);
+    let res = if status == glow::FRAMEBUFFER_COMPLETE {
+        // rows are tightly packed, formats like R8 would be padded to 4 bytes otherwise
+        gl.pixel_store_i32(glow::PACK_ALIGNMENT, 1);
+        gl.read_pixels(
+            opts.x_offset as _,
+            opts.y_offset as _,
+            opts.width as _,
+            opts.height as _,
+            texture_format(&opts.format),
+            texture_type(&opts.format),
+            data,
+        );
+        gl.pixel_store_i32(glow::PACK_ALIGNMENT, 4);
+        Ok(())
+    } else {
+        Err("Framebuffer incomplete...".to_string())
+    };
+
+    gl.bind_framebuffer(glow::FRAMEBUFFER, None);
+    gl.delete_framebuffer(fbo);
+    res
+}
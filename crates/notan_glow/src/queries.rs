@@ -0,0 +1,160 @@
+use glow::{Context, HasContext, Query};
+use hashbrown::HashMap;
+use std::time::Duration;
+
+/// Elapsed time queries, the results are read once the GPU has them available
+pub(crate) struct GpuTimers {
+    supported: bool,
+    active: Option<(String, Query)>,
+    pending: Vec<(String, Query)>,
+    free: Vec<Query>,
+    results: HashMap<String, Duration>,
+}
+
+impl GpuTimers {
+    pub fn new(gl: &Context) -> Self {
+        Self {
+            supported: is_timer_supported(gl),
+            active: None,
+            pending: vec![],
+            free: vec![],
+            results: HashMap::new(),
+        }
+    }
+
+    pub fn begin(&mut self, gl: &Context, label: &str) -> Result<(), String> {
+        if !self.supported {
+            return Err("GPU timer queries are not supported by this device".to_string());
+        }
+
+        if let Some((active, _)) = &self.active {
+            return Err(format!(
+                "Cannot begin the GPU timer '{label}' before ending '{active}'"
+            ));
+        }
+
+        let query = match self.free.pop() {
+            Some(query) => query,
+            None => unsafe { gl.create_query()? },
+        };
+
+        unsafe {
+            gl.begin_query(glow::TIME_ELAPSED, query);
+        }
+
+        self.active = Some((label.to_string(), query));
+        Ok(())
+    }
+
+    pub fn end(&mut self, gl: &Context) -> Result<(), String> {
+        let (label, query) = self
+            .active
+            .take()
+            .ok_or_else(|| "There is no GPU timer to end".to_string())?;
+
+        unsafe {
+            gl.end_query(glow::TIME_ELAPSED);
+        }
+
+        self.pending.push((label, query));
+
+        // collect finished queries to avoid growing the list if the user never reads them
+        self.poll(gl);
+        Ok(())
+    }
+
+    /// Returns the last time measured for the label
+    pub fn get(&mut self, gl: &Context, label: &str) -> Option<Duration> {
+        self.poll(gl);
+        self.results.get(label).copied()
+    }
+
+    fn poll(&mut self, gl: &Context) {
+        let mut i = 0;
+        while i < self.pending.len() {
+            let query = self.pending[i].1;
+            let available =
+                unsafe { gl.get_query_parameter_u32(query, glow::QUERY_RESULT_AVAILABLE) } != 0;
+            if !available {
+                i += 1;
+                continue;
+            }
+
+            let (label, query) = self.pending.remove(i);
+            let nanos = unsafe { gl.get_query_parameter_u32(query, glow::QUERY_RESULT) };
+            self.results.insert(label, Duration::from_nanos(nanos as _));
+            self.free.push(query);
+        }
+    }
+}
+
+fn is_timer_supported(gl: &Context) -> bool {
+    let extensions = gl.supported_extensions();
+    if cfg!(target_arch = "wasm32") {
+        return extensions.contains("EXT_disjoint_timer_query_webgl2");
+    }
+
+    let version = gl.version();
+    if version.is_embedded {
+        return extensions.contains("GL_EXT_disjoint_timer_query");
+    }
+
+    (version.major, version.minor) >= (3, 3) || extensions.contains("GL_ARB_timer_query")
+}
+
+/// Occlusion query keeping the last result available
+pub(crate) struct InnerOcclusionQuery {
+    query: Query,
+    pending: bool,
+    result: Option<bool>,
+}
+
+impl InnerOcclusionQuery {
+    pub fn new(gl: &Context) -> Result<Self, String> {
+        let query = unsafe { gl.create_query()? };
+        Ok(Self {
+            query,
+            pending: false,
+            result: None,
+        })
+    }
+
+    pub fn begin(&mut self, gl: &Context) {
+        unsafe {
+            gl.begin_query(glow::ANY_SAMPLES_PASSED, self.query);
+        }
+    }
+
+    pub fn end(&mut self, gl: &Context) {
+        unsafe {
+            gl.end_query(glow::ANY_SAMPLES_PASSED);
+        }
+        self.pending = true;
+    }
+
+    pub fn result(&mut self, gl: &Context) -> Option<bool> {
+        if self.pending {
+            let available =
+                unsafe { gl.get_query_parameter_u32(self.query, glow::QUERY_RESULT_AVAILABLE) }
+                    != 0;
+            if available {
+                let passed = unsafe { gl.get_query_parameter_u32(self.query, glow::QUERY_RESULT) };
+                self.result = Some(passed != 0);
+                self.pending = false;
+            }
+        }
+
+        self.result
+    }
+
+    #[inline(always)]
+    pub fn raw(&self) -> Query {
+        self.query
+    }
+
+    pub fn clean(&self, gl: &Context) {
+        unsafe {
+            gl.delete_query(self.query);
+        }
+    }
+}
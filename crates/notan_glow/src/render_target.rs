@@ -4,8 +4,10 @@ use glow::*;
 use notan_graphics::prelude::*;
 
 pub(crate) struct InnerRenderTexture {
-    fbo: Framebuffer,
+    pub fbo: Framebuffer,
     depth_texture: Option<TextureKey>,
+    depth_stencil: Option<Renderbuffer>,
+    msaa: Option<Multisampling>,
     pub size: (u32, u32),
     pub use_mipmaps: bool,
     pub texture_id: u64,
@@ -22,16 +24,28 @@ impl InnerRenderTexture {
         let width = info.width;
         let height = info.height;
         let depth_info = if info.depth {
-            Some(DepthInfo { width, height })
+            Some(DepthInfo {
+                width,
+                height,
+                stencil: info.stencil,
+            })
         } else {
             None
         };
 
-        let (fbo, depth_texture) = unsafe { create_fbo(gl, texture.texture, depth_info)? };
+        let (fbo, depth_texture, depth_stencil) =
+            unsafe { create_fbo(gl, texture.texture, depth_info)? };
+        let msaa = match info.samples {
+            0 => None,
+            samples => Some(unsafe { create_msaa_fbo(gl, info, samples)? }),
+        };
+
         let size = texture.size;
         Ok(Self {
             fbo,
             depth_texture,
+            depth_stencil,
+            msaa,
             size,
             use_mipmaps,
             texture_id,
@@ -45,22 +59,139 @@ impl InnerRenderTexture {
             if let Some(tex) = self.depth_texture {
                 gl.delete_texture(tex);
             }
+            if let Some(rb) = self.depth_stencil {
+                gl.delete_renderbuffer(rb);
+            }
+            if let Some(msaa) = &self.msaa {
+                gl.delete_framebuffer(msaa.fbo);
+                gl.delete_renderbuffer(msaa.color);
+                if let Some(depth) = msaa.depth {
+                    gl.delete_renderbuffer(depth);
+                }
+            }
         }
     }
 
     #[inline]
     pub fn bind(&self, gl: &Context) {
+        // draw calls go to the multisampled buffer, the texture is updated when it's resolved
+        let fbo = self.msaa.as_ref().map_or(self.fbo, |msaa| msaa.fbo);
         unsafe {
-            gl.bind_framebuffer(glow::FRAMEBUFFER, Some(self.fbo));
+            gl.bind_framebuffer(glow::FRAMEBUFFER, Some(fbo));
+        }
+    }
+
+    #[inline]
+    pub fn is_multisampled(&self) -> bool {
+        self.msaa.is_some()
+    }
+
+    /// Framebuffer to read from in a blit, scaling is not allowed from a multisampled buffer
+    /// so the resolved texture is used in that case
+    #[inline]
+    pub fn read_fbo(&self, scaled: bool) -> Framebuffer {
+        match &self.msaa {
+            Some(msaa) if !scaled => msaa.fbo,
+            _ => self.fbo,
         }
     }
+
+    /// Copies the multisampled buffer to the texture
+    pub fn resolve(&self, gl: &Context) {
+        if let Some(msaa) = &self.msaa {
+            let (width, height) = (self.size.0 as i32, self.size.1 as i32);
+            unsafe {
+                gl.bind_framebuffer(glow::READ_FRAMEBUFFER, Some(msaa.fbo));
+                gl.bind_framebuffer(glow::DRAW_FRAMEBUFFER, Some(self.fbo));
+                gl.blit_framebuffer(
+                    0,
+                    0,
+                    width,
+                    height,
+                    0,
+                    0,
+                    width,
+                    height,
+                    glow::COLOR_BUFFER_BIT,
+                    glow::NEAREST,
+                );
+                gl.bind_framebuffer(glow::FRAMEBUFFER, None);
+            }
+        }
+    }
+}
+
+struct Multisampling {
+    pub fbo: Framebuffer,
+    color: Renderbuffer,
+    depth: Option<Renderbuffer>,
+}
+
+unsafe fn create_msaa_fbo(
+    gl: &Context,
+    info: &TextureInfo,
+    samples: u8,
+) -> Result<Multisampling, String> {
+    if !samples.is_power_of_two() {
+        return Err(format!(
+            "Multisampling must be a power of two, {samples} is not valid"
+        ));
+    }
+
+    let max_samples = gl.get_parameter_i32(glow::MAX_SAMPLES);
+    let samples = (samples as i32).min(max_samples);
+    let (width, height) = (info.width as i32, info.height as i32);
+
+    let fbo = gl.create_framebuffer()?;
+    gl.bind_framebuffer(glow::FRAMEBUFFER, Some(fbo));
+
+    let color = gl.create_renderbuffer()?;
+    gl.bind_renderbuffer(glow::RENDERBUFFER, Some(color));
+    gl.renderbuffer_storage_multisample(
+        glow::RENDERBUFFER,
+        samples,
+        texture_internal_format(&info.format),
+        width,
+        height,
+    );
+    gl.framebuffer_renderbuffer(
+        glow::FRAMEBUFFER,
+        glow::COLOR_ATTACHMENT0,
+        glow::RENDERBUFFER,
+        Some(color),
+    );
+
+    let depth = if info.depth || info.stencil {
+        Some(create_depth_renderbuffer(
+            gl,
+            samples,
+            width,
+            height,
+            info.stencil,
+        )?)
+    } else {
+        None
+    };
+
+    let status = gl.check_framebuffer_status(glow::FRAMEBUFFER);
+    if status != glow::FRAMEBUFFER_COMPLETE {
+        return Err(
+            "Cannot create a multisampled render target because the framebuffer is incomplete..."
+                .to_string(),
+        );
+    }
+
+    clear(gl, &Some(Color::TRANSPARENT), &None, &None);
+
+    gl.bind_framebuffer(glow::FRAMEBUFFER, None);
+    Ok(Multisampling { fbo, color, depth })
 }
 
 unsafe fn create_fbo(
     gl: &Context,
     texture: TextureKey,
     depth_info: Option<DepthInfo>,
-) -> Result<(Framebuffer, Option<TextureKey>), String> {
+) -> Result<(Framebuffer, Option<TextureKey>, Option<Renderbuffer>), String> {
     let fbo = gl.create_framebuffer()?;
     gl.bind_framebuffer(glow::FRAMEBUFFER, Some(fbo));
     gl.framebuffer_texture_2d(
@@ -71,8 +202,20 @@ unsafe fn create_fbo(
         0,
     );
 
+    // stencil needs a packed depth-stencil buffer, which cannot be sampled as a depth texture
+    let depth_stencil = match &depth_info {
+        Some(info) if info.stencil => Some(create_depth_renderbuffer(
+            gl,
+            0,
+            info.width as _,
+            info.height as _,
+            true,
+        )?),
+        _ => None,
+    };
+
     let depth_texture = match depth_info {
-        Some(info) => Some(create_texture(
+        Some(info) if !info.stencil => Some(create_texture(
             gl,
             None,
             &TextureInfo {
@@ -98,10 +241,37 @@ unsafe fn create_fbo(
     clear(gl, &Some(Color::TRANSPARENT), &None, &None);
 
     gl.bind_framebuffer(glow::FRAMEBUFFER, None);
-    Ok((fbo, depth_texture))
+    Ok((fbo, depth_texture, depth_stencil))
+}
+
+/// Creates a depth (or depth-stencil) renderbuffer and attaches it to the bound framebuffer
+unsafe fn create_depth_renderbuffer(
+    gl: &Context,
+    samples: i32,
+    width: i32,
+    height: i32,
+    stencil: bool,
+) -> Result<Renderbuffer, String> {
+    let (format, attachment) = if stencil {
+        (glow::DEPTH24_STENCIL8, glow::DEPTH_STENCIL_ATTACHMENT)
+    } else {
+        (glow::DEPTH_COMPONENT16, glow::DEPTH_ATTACHMENT)
+    };
+
+    let rb = gl.create_renderbuffer()?;
+    gl.bind_renderbuffer(glow::RENDERBUFFER, Some(rb));
+    if samples > 0 {
+        gl.renderbuffer_storage_multisample(glow::RENDERBUFFER, samples, format, width, height);
+    } else {
+        gl.renderbuffer_storage(glow::RENDERBUFFER, format, width, height);
+    }
+    gl.framebuffer_renderbuffer(glow::FRAMEBUFFER, attachment, glow::RENDERBUFFER, Some(rb));
+    gl.bind_renderbuffer(glow::RENDERBUFFER, None);
+    Ok(rb)
 }
 
 struct DepthInfo {
     width: u32,
     height: u32,
+    stencil: bool,
 }
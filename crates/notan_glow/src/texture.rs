@@ -8,23 +8,64 @@ pub(crate) struct InnerTexture {
     pub texture: TextureKey,
     pub size: (u32, u32),
     pub use_mipmaps: bool,
+    pub target: u32,
+    info: TextureInfo,
 }
 
 impl InnerTexture {
     pub fn new(texture: TextureKey, info: &TextureInfo) -> Result<Self, String> {
         let size = (info.width, info.height);
         let use_mipmaps = info.mipmap_filter.is_some();
+        let target = if info.cubemap {
+            glow::TEXTURE_CUBE_MAP
+        } else {
+            glow::TEXTURE_2D
+        };
         Ok(Self {
             texture,
             size,
             use_mipmaps,
+            target,
+            info: info.clone(),
         })
     }
 
+    pub fn set_filter(
+        &mut self,
+        gl: &Context,
+        min: TextureFilter,
+        mag: TextureFilter,
+    ) -> Result<(), String> {
+        let info = TextureInfo {
+            min_filter: min,
+            mag_filter: mag,
+            ..self.info.clone()
+        };
+        assert_can_use_linear_filter(&info)?;
+
+        unsafe {
+            gl.bind_texture(self.target, Some(self.texture));
+            gl.tex_parameter_i32(
+                self.target,
+                glow::TEXTURE_MAG_FILTER,
+                info.mag_filter.to_glow() as _,
+            );
+            gl.tex_parameter_i32(
+                self.target,
+                glow::TEXTURE_MIN_FILTER,
+                get_min_filter(&info) as _,
+            );
+            gl.bind_texture(self.target, None);
+        }
+
+        self.info = info;
+        Ok(())
+    }
+
     pub fn bind(&self, gl: &Context, slot: u32, location: &UniformLocation) {
         unsafe {
             gl.active_texture(gl_slot(slot).unwrap());
-            gl.bind_texture(glow::TEXTURE_2D, Some(self.texture));
+            gl.bind_texture(self.target, Some(self.texture));
             gl.uniform_1_i32(Some(location), slot as _);
         }
     }
@@ -54,7 +95,8 @@ pub(crate) struct TexInfo<'a> {
     pub data: Option<&'a [u8]>,
 }
 
-const CANNOT_USE_LINEAR_FILTER: [TextureFormat; 1] = [TextureFormat::R32Float];
+const CANNOT_USE_LINEAR_FILTER: [TextureFormat; 2] =
+    [TextureFormat::R32Float, TextureFormat::Depth32Float];
 
 fn assert_can_use_linear_filter(info: &TextureInfo) -> Result<(), String> {
     let needs_check_filter = CANNOT_USE_LINEAR_FILTER.contains(&info.format);
@@ -85,34 +127,12 @@ pub(crate) unsafe fn pre_create_texture<'a>(
     gl.pixel_store_i32(glow::UNPACK_ALIGNMENT, bytes_per_pixel);
 
     gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+    set_texture_params(gl, glow::TEXTURE_2D, info);
 
-    gl.tex_parameter_i32(
-        glow::TEXTURE_2D,
-        glow::TEXTURE_WRAP_S,
-        info.wrap_x.to_glow() as _,
-    );
-
-    gl.tex_parameter_i32(
-        glow::TEXTURE_2D,
-        glow::TEXTURE_WRAP_T,
-        info.wrap_y.to_glow() as _,
-    );
-
-    gl.tex_parameter_i32(
-        glow::TEXTURE_2D,
-        glow::TEXTURE_MAG_FILTER,
-        info.mag_filter.to_glow() as _,
-    );
-
-    let min_filter = get_min_filter(info);
-
-    gl.tex_parameter_i32(glow::TEXTURE_2D, glow::TEXTURE_MIN_FILTER, min_filter as _);
-
-    let depth = matches!(info.format, TextureFormat::Depth16);
+    let depth = info.format.is_depth();
+    let format = texture_format(&info.format);
     let mut data = bytes;
-    let mut format = texture_format(&info.format);
     if depth {
-        format = glow::DEPTH_COMPONENT;
         data = None;
 
         gl.tex_parameter_i32(
@@ -142,6 +162,28 @@ pub(crate) unsafe fn pre_create_texture<'a>(
     })
 }
 
+unsafe fn set_texture_params(gl: &Context, target: u32, info: &TextureInfo) {
+    gl.tex_parameter_i32(target, glow::TEXTURE_WRAP_S, info.wrap_x.to_glow() as _);
+    gl.tex_parameter_i32(target, glow::TEXTURE_WRAP_T, info.wrap_y.to_glow() as _);
+    if target == glow::TEXTURE_CUBE_MAP {
+        gl.tex_parameter_i32(target, glow::TEXTURE_WRAP_R, info.wrap_y.to_glow() as _);
+    }
+
+    gl.tex_parameter_i32(
+        target,
+        glow::TEXTURE_MAG_FILTER,
+        info.mag_filter.to_glow() as _,
+    );
+
+    let min_filter = get_min_filter(info);
+    gl.tex_parameter_i32(target, glow::TEXTURE_MIN_FILTER, min_filter as _);
+
+    // the level is capped by the builder, it's always 1.0 without the extension
+    if info.anisotropy > 1.0 {
+        gl.tex_parameter_f32(target, glow::TEXTURE_MAX_ANISOTROPY, info.anisotropy);
+    }
+}
+
 fn get_min_filter(info: &TextureInfo) -> u32 {
     match info.mipmap_filter {
         None => info.min_filter.to_glow(),
@@ -190,6 +232,55 @@ pub(crate) unsafe fn create_texture(
     Ok(texture)
 }
 
+/// Creates a cubemap using the faces ordered as +X, -X, +Y, -Y, +Z and -Z
+pub(crate) unsafe fn create_cubemap(
+    gl: &Context,
+    faces: &[Vec<u8>],
+    info: &TextureInfo,
+) -> Result<TextureKey, String> {
+    if faces.len() != 6 {
+        return Err(format!("Cubemaps need 6 faces, {} were given", faces.len()));
+    }
+
+    if info.width != info.height {
+        return Err(format!(
+            "Cubemap faces must be squares, not {}x{}",
+            info.width, info.height
+        ));
+    }
+
+    assert_can_use_linear_filter(info)?;
+
+    let texture = gl.create_texture()?;
+
+    let bytes_per_pixel = info.bytes_per_pixel().min(8) as _;
+    gl.pixel_store_i32(glow::UNPACK_ALIGNMENT, bytes_per_pixel);
+
+    gl.bind_texture(glow::TEXTURE_CUBE_MAP, Some(texture));
+    set_texture_params(gl, glow::TEXTURE_CUBE_MAP, info);
+
+    faces.iter().enumerate().for_each(|(i, face)| {
+        gl.tex_image_2d(
+            glow::TEXTURE_CUBE_MAP_POSITIVE_X + i as u32,
+            0,
+            texture_internal_format(&info.format) as _,
+            info.width as _,
+            info.height as _,
+            0,
+            texture_format(&info.format),
+            texture_type(&info.format),
+            Some(face),
+        );
+    });
+
+    if info.mipmap_filter.is_some() {
+        gl.generate_mipmap(glow::TEXTURE_CUBE_MAP);
+    }
+
+    gl.bind_texture(glow::TEXTURE_CUBE_MAP, None);
+    Ok(texture)
+}
+
 pub(crate) fn texture_type(tf: &TextureFormat) -> u32 {
     match tf {
         TextureFormat::R32Float => glow::FLOAT,
@@ -197,6 +288,9 @@ pub(crate) fn texture_type(tf: &TextureFormat) -> u32 {
         TextureFormat::R32Uint => glow::UNSIGNED_INT,
         TextureFormat::R16Uint => glow::UNSIGNED_SHORT,
         TextureFormat::Depth16 => glow::UNSIGNED_SHORT,
+        TextureFormat::Depth24 => glow::UNSIGNED_INT,
+        TextureFormat::Depth32Float => glow::FLOAT,
+        TextureFormat::Rgba16Float => glow::HALF_FLOAT,
         _ => glow::UNSIGNED_BYTE,
     }
 }
@@ -207,11 +301,15 @@ pub(crate) fn texture_format(tf: &TextureFormat) -> u32 {
         TextureFormat::Rgba32 => glow::RGBA,
         TextureFormat::Rgba32Float => RGBA,
         TextureFormat::R8 => glow::RED,
+        TextureFormat::Rg16 => glow::RG,
+        TextureFormat::Rgba16Float => glow::RGBA,
         TextureFormat::R8Uint => glow::RED_INTEGER,
         TextureFormat::R16Uint => glow::RED_INTEGER,
         TextureFormat::R32Float => glow::RED,
         TextureFormat::R32Uint => glow::RED_INTEGER,
-        TextureFormat::Depth16 => glow::DEPTH_COMPONENT16,
+        TextureFormat::Depth16 | TextureFormat::Depth24 | TextureFormat::Depth32Float => {
+            glow::DEPTH_COMPONENT
+        }
         TextureFormat::SRgba8 => glow::RGBA,
     }
 }
@@ -219,12 +317,17 @@ pub(crate) fn texture_format(tf: &TextureFormat) -> u32 {
 pub(crate) fn texture_internal_format(tf: &TextureFormat) -> u32 {
     match tf {
         TextureFormat::R8 => glow::R8,
+        TextureFormat::Rg16 => glow::RG8,
         TextureFormat::R8Uint => glow::R8UI,
         TextureFormat::R16Uint => R16UI,
         TextureFormat::R32Float => glow::R32F,
         TextureFormat::R32Uint => glow::R32UI,
         TextureFormat::SRgba8 => glow::SRGB8_ALPHA8,
+        TextureFormat::Rgba16Float => glow::RGBA16F,
         TextureFormat::Rgba32Float => glow::RGBA32F,
+        TextureFormat::Depth16 => glow::DEPTH_COMPONENT16,
+        TextureFormat::Depth24 => glow::DEPTH_COMPONENT24,
+        TextureFormat::Depth32Float => glow::DEPTH_COMPONENT32F,
         _ => texture_format(tf),
     }
 }
@@ -1,7 +1,7 @@
-use crate::texture::{create_texture, TextureKey};
+use crate::texture::{create_cubemap, create_texture, TextureKey};
 use crate::GlowBackend;
 use notan_graphics::color::Color;
-use notan_graphics::{TextureFormat, TextureInfo};
+use notan_graphics::{CubeMapSource, TextureFormat, TextureInfo};
 
 pub(crate) fn add_empty_texture(
     backend: &mut GlowBackend,
@@ -117,6 +117,103 @@ pub(crate) fn add_texture_from_bytes(
     Ok((id, info))
 }
 
+pub(crate) fn add_cubemap_texture(
+    backend: &mut GlowBackend,
+    source: CubeMapSource,
+    mut info: TextureInfo,
+) -> Result<(u64, TextureInfo), String> {
+    let faces = match source {
+        CubeMapSource::Bytes(faces) if info.premultiplied_alpha => {
+            faces.iter().map(|f| premultiplied_alpha(f)).collect()
+        }
+        CubeMapSource::Bytes(faces) => faces,
+        CubeMapSource::Images(images) => {
+            let images = images
+                .iter()
+                .map(|bytes| image_load_from_memory(bytes))
+                .collect::<Result<Vec<_>, _>>()?;
+            cubemap_faces(&images, &mut info)?
+        }
+        CubeMapSource::Cross(bytes) => {
+            let img = image_load_from_memory(&bytes)?;
+            cubemap_faces(&split_cross(&img)?, &mut info)?
+        }
+    };
+
+    let tex = unsafe { create_cubemap(&backend.gl, &faces, &info)? };
+    let id = backend.add_inner_texture(tex, &info)?;
+    Ok((id, info))
+}
+
+fn cubemap_faces(
+    images: &[image::DynamicImage],
+    info: &mut TextureInfo,
+) -> Result<Vec<Vec<u8>>, String> {
+    if !matches!(info.format, TextureFormat::Rgba32 | TextureFormat::SRgba8) {
+        return Err(format!(
+            "Cubemaps from images cannot use the format {:?}",
+            info.format
+        ));
+    }
+
+    let (width, height) = images
+        .first()
+        .map(|img| (img.width(), img.height()))
+        .unwrap_or_default();
+    info.width = width;
+    info.height = height;
+
+    images
+        .iter()
+        .map(|img| {
+            if (img.width(), img.height()) != (width, height) {
+                return Err("All the cubemap faces must have the same size".to_string());
+            }
+
+            let mut data = img.to_rgba8();
+            if info.premultiplied_alpha {
+                data.pixels_mut().for_each(|rgba| {
+                    rgba.0 = Color::from(rgba.0).to_premultiplied_alpha().into();
+                });
+            }
+            Ok(data.into_raw())
+        })
+        .collect()
+}
+
+/// Splits the faces of an horizontal (4x3 faces) or vertical (3x4 faces) cross
+fn split_cross(img: &image::DynamicImage) -> Result<Vec<image::DynamicImage>, String> {
+    let (width, height) = (img.width(), img.height());
+    let vertical = width * 4 == height * 3;
+
+    // cells of the faces +X, -X, +Y, -Y, +Z and -Z
+    let (size, cells) = if width * 3 == height * 4 {
+        (width / 4, [(2, 1), (0, 1), (1, 0), (1, 2), (1, 1), (3, 1)])
+    } else if vertical {
+        (width / 3, [(2, 1), (0, 1), (1, 0), (1, 2), (1, 1), (1, 3)])
+    } else {
+        return Err(format!(
+            "The cubemap cross must be 4x3 or 3x4 faces, {width}x{height} is not valid"
+        ));
+    };
+
+    let faces = cells
+        .iter()
+        .enumerate()
+        .map(|(i, (x, y))| {
+            let face = img.crop_imm(x * size, y * size, size, size);
+            // the -Z face of a vertical cross is upside down
+            if vertical && i == 5 {
+                face.rotate180()
+            } else {
+                face
+            }
+        })
+        .collect();
+
+    Ok(faces)
+}
+
 fn premultiplied_alpha(pixels: &[u8]) -> Vec<u8> {
     pixels
         .chunks(4)
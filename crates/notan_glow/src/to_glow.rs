@@ -49,9 +49,10 @@ impl ToGlow for BlendFactor {
             InverseSourceAlpha => glow::ONE_MINUS_SRC_ALPHA,
             InverseSourceColor => glow::ONE_MINUS_SRC_COLOR,
             DestinationAlpha => glow::DST_ALPHA,
-            DestinationColor => glow::SRC_COLOR,
+            DestinationColor => glow::DST_COLOR,
             InverseDestinationAlpha => glow::ONE_MINUS_DST_ALPHA,
             InverseDestinationColor => glow::ONE_MINUS_DST_COLOR,
+            SourceAlphaSaturated => glow::SRC_ALPHA_SATURATE,
         }
     }
 }
@@ -98,6 +99,7 @@ impl ToGlow for BufferUsage {
             BufferUsage::Vertex => glow::ARRAY_BUFFER,
             BufferUsage::Index => glow::ELEMENT_ARRAY_BUFFER,
             BufferUsage::Uniform(_) => glow::UNIFORM_BUFFER,
+            BufferUsage::Storage(_) => glow::SHADER_STORAGE_BUFFER,
         }
     }
 }
@@ -119,6 +121,7 @@ impl ToGlow for TextureWrap {
         match self {
             Clamp => glow::CLAMP_TO_EDGE,
             Repeat => glow::REPEAT,
+            MirroredRepeat => glow::MIRRORED_REPEAT,
         }
     }
 }
@@ -0,0 +1,371 @@
+use crate::ab_glyph::{point, Font, GlyphId, GlyphImageFormat, PxScale, Rect};
+use crate::shelf::ShelfAllocator;
+use crate::FontId;
+use notan_app::{Texture, TextureFilter, TextureFormat};
+use notan_graphics::Device;
+use std::collections::HashMap;
+use ttf_parser::colr::{ClipBox, CompositeMode, Paint, Painter};
+use ttf_parser::{RgbaColor, Transform};
+
+/// Color glyphs are stored at the next power of two of the size used to draw them,
+/// between these limits, and scaled down when they're drawn
+const MIN_COLOR_GLYPH_SIZE: u16 = 16;
+const MAX_COLOR_GLYPH_SIZE: u16 = 256;
+
+/// Color glyph ready to be drawn
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorGlyph {
+    /// Area of the texture used by the glyph, in pixels
+    pub frame: notan_math::Rect,
+    /// Position of the glyph relative to its origin, using the scale requested
+    pub bounds: Rect,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct StoredGlyph {
+    frame: notan_math::Rect,
+    // bounds relative to the origin using em units
+    bounds: Rect,
+}
+
+/// Texture atlas storing the RGBA images of the glyphs defined by the color tables of
+/// the fonts, like emojis. Bitmaps from the `CBDT` and `sbix` tables are used as they're
+/// stored, the `COLR` layers are rasterized using their solid colors.
+/// The texture is created the first time that a color glyph is stored
+pub struct ColorGlyphCache {
+    texture: Option<Texture>,
+    size: (u32, u32),
+    shelf: ShelfAllocator,
+    is_color: HashMap<(FontId, GlyphId), bool>,
+    glyphs: HashMap<(FontId, GlyphId, u16), Option<StoredGlyph>>,
+}
+
+impl ColorGlyphCache {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            texture: None,
+            size: (width, height),
+            shelf: ShelfAllocator::new(width, height),
+            is_color: HashMap::new(),
+            glyphs: HashMap::new(),
+        }
+    }
+
+    pub fn texture(&self) -> Option<&Texture> {
+        self.texture.as_ref()
+    }
+
+    /// Returns true if the font defines the glyph using colors
+    pub fn is_color_glyph<F: Font>(&mut self, font_id: FontId, font: &F, id: GlyphId) -> bool {
+        *self
+            .is_color
+            .entry((font_id, id))
+            .or_insert_with(|| has_color_data(font, id))
+    }
+
+    /// Returns the color glyph, generating it if it's not on the cache yet.
+    /// Glyphs without color data return `None`
+    pub fn glyph<F: Font>(
+        &mut self,
+        device: &mut Device,
+        font_id: FontId,
+        font: &F,
+        id: GlyphId,
+        scale: PxScale,
+    ) -> Option<ColorGlyph> {
+        if !self.is_color_glyph(font_id, font, id) {
+            return None;
+        }
+
+        let px_per_em = px_per_em(font, scale);
+        let size = (px_per_em.ceil() as u16)
+            .clamp(MIN_COLOR_GLYPH_SIZE, MAX_COLOR_GLYPH_SIZE)
+            .next_power_of_two();
+
+        let stored = match self.glyphs.get(&(font_id, id, size)) {
+            Some(stored) => *stored,
+            None => {
+                let stored = self.generate(device, font, id, size);
+                self.glyphs.insert((font_id, id, size), stored);
+                stored
+            }
+        }?;
+
+        let b = stored.bounds;
+        Some(ColorGlyph {
+            frame: stored.frame,
+            bounds: Rect {
+                min: point(b.min.x * px_per_em, b.min.y * px_per_em),
+                max: point(b.max.x * px_per_em, b.max.y * px_per_em),
+            },
+        })
+    }
+
+    fn generate<F: Font>(
+        &mut self,
+        device: &mut Device,
+        font: &F,
+        id: GlyphId,
+        size: u16,
+    ) -> Option<StoredGlyph> {
+        let image =
+            rasterize_bitmap(font, id, size).or_else(|| rasterize_layers(font, id, size))?;
+
+        if self.texture.is_none() {
+            let (width, height) = self.size;
+            let texture = device
+                .create_texture()
+                .with_size(width, height)
+                .with_filter(TextureFilter::Linear, TextureFilter::Linear)
+                .with_format(TextureFormat::Rgba32)
+                .build()
+                .map_err(|e| log::error!("Cannot create the color glyph cache: {e}"))
+                .ok()?;
+            self.texture = Some(texture);
+        }
+
+        let (x, y) = self.shelf.allocate(image.width, image.height).or_else(|| {
+            log::error!("The color glyph cache is full, the glyph will not be drawn");
+            None
+        })?;
+
+        device
+            .update_texture(self.texture.as_mut()?)
+            .with_x_offset(x)
+            .with_y_offset(y)
+            .with_width(image.width)
+            .with_height(image.height)
+            .with_data(&image.pixels)
+            .update()
+            .map_err(|e| log::error!("Cannot upload the glyph to the color cache: {e}"))
+            .ok()?;
+
+        Some(StoredGlyph {
+            frame: notan_math::Rect {
+                x: x as _,
+                y: y as _,
+                width: image.width as _,
+                height: image.height as _,
+            },
+            bounds: image.bounds,
+        })
+    }
+}
+
+/// Straight alpha RGBA image of a glyph
+struct GlyphPixels {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+    bounds: Rect,
+}
+
+fn has_color_data<F: Font>(font: &F, id: GlyphId) -> bool {
+    let is_color_bitmap = font.glyph_raster_image2(id, u16::MAX).is_some_and(|img| {
+        matches!(
+            img.format,
+            GlyphImageFormat::Png | GlyphImageFormat::BitmapPremulBgra32
+        )
+    });
+
+    is_color_bitmap
+        || ttf_parser::Face::parse(font.font_data(), 0)
+            .is_ok_and(|face| face.is_color_glyph(ttf_parser::GlyphId(id.0)))
+}
+
+/// Scale is the height of the font, but the color tables use the em size
+fn px_per_em<F: Font>(font: &F, scale: PxScale) -> f32 {
+    match font.units_per_em() {
+        Some(units) => scale.y * units / font.height_unscaled(),
+        None => scale.y,
+    }
+}
+
+fn rasterize_bitmap<F: Font>(font: &F, id: GlyphId, size: u16) -> Option<GlyphPixels> {
+    let img = font.glyph_raster_image2(id, size)?;
+    let (width, height, pixels) = match img.format {
+        GlyphImageFormat::Png => {
+            let decoded = image::load_from_memory_with_format(img.data, image::ImageFormat::Png)
+                .map_err(|e| log::error!("Cannot decode the color glyph: {e}"))
+                .ok()?
+                .to_rgba8();
+            (decoded.width(), decoded.height(), decoded.into_raw())
+        }
+        GlyphImageFormat::BitmapPremulBgra32 => (
+            img.width as u32,
+            img.height as u32,
+            premultiplied_bgra_to_rgba(img.data),
+        ),
+        _ => return None,
+    };
+
+    if width == 0 || height == 0 || pixels.len() < (width * height * 4) as usize {
+        return None;
+    }
+
+    // the origin is the bottom-left of the image using y up
+    let ppem = img.pixels_per_em.max(1) as f32;
+    let (x, y) = (img.origin.x, img.origin.y);
+    let bounds = Rect {
+        min: point(x / ppem, -(y + height as f32) / ppem),
+        max: point((x + width as f32) / ppem, -y / ppem),
+    };
+
+    Some(GlyphPixels {
+        width,
+        height,
+        pixels,
+        bounds,
+    })
+}
+
+/// Converts the pixels to straight alpha RGBA, which is what the atlas uses
+fn premultiplied_bgra_to_rgba(data: &[u8]) -> Vec<u8> {
+    data.chunks_exact(4)
+        .flat_map(|bgra| {
+            let [b, g, r, a] = [bgra[0], bgra[1], bgra[2], bgra[3]];
+            let unmultiply = |c: u8| match a {
+                0 => 0,
+                _ => ((c as u32 * 255 + a as u32 / 2) / a as u32).min(255) as u8,
+            };
+            [unmultiply(r), unmultiply(g), unmultiply(b), a]
+        })
+        .collect()
+}
+
+/// Collects the layers of a `COLR` glyph, only solid colors are supported so the
+/// gradients use the foreground color, and the transforms and clips are ignored
+struct LayerCollector {
+    foreground: RgbaColor,
+    outline: Option<ttf_parser::GlyphId>,
+    layers: Vec<(GlyphId, RgbaColor)>,
+}
+
+impl<'a> Painter<'a> for LayerCollector {
+    fn outline_glyph(&mut self, glyph_id: ttf_parser::GlyphId) {
+        self.outline = Some(glyph_id);
+    }
+
+    fn paint(&mut self, paint: Paint<'a>) {
+        let color = match paint {
+            Paint::Solid(color) => color,
+            _ => self.foreground,
+        };
+
+        if let Some(id) = self.outline {
+            self.layers.push((GlyphId(id.0), color));
+        }
+    }
+
+    fn push_clip(&mut self) {}
+    fn push_clip_box(&mut self, _clipbox: ClipBox) {}
+    fn pop_clip(&mut self) {}
+    fn push_layer(&mut self, _mode: CompositeMode) {}
+    fn pop_layer(&mut self) {}
+    fn push_transform(&mut self, _transform: Transform) {}
+    fn pop_transform(&mut self) {}
+}
+
+fn rasterize_layers<F: Font>(font: &F, id: GlyphId, size: u16) -> Option<GlyphPixels> {
+    let face = ttf_parser::Face::parse(font.font_data(), 0).ok()?;
+
+    // the glyph is cached for any text color, so the foreground is always black
+    let mut collector = LayerCollector {
+        foreground: RgbaColor::new(0, 0, 0, 255),
+        outline: None,
+        layers: vec![],
+    };
+    face.paint_color_glyph(
+        ttf_parser::GlyphId(id.0),
+        0,
+        collector.foreground,
+        &mut collector,
+    )?;
+
+    let ppem = size as f32;
+    let scale = match font.units_per_em() {
+        Some(units) => PxScale::from(ppem * font.height_unscaled() / units),
+        None => PxScale::from(ppem),
+    };
+
+    let outlines = collector
+        .layers
+        .iter()
+        .filter_map(|(layer, color)| {
+            let glyph = layer.with_scale_and_position(scale, point(0.0, 0.0));
+            font.outline_glyph(glyph).map(|outline| (outline, *color))
+        })
+        .collect::<Vec<_>>();
+
+    let px_bounds = outlines
+        .iter()
+        .map(|(outline, _)| outline.px_bounds())
+        .reduce(|a, b| Rect {
+            min: point(a.min.x.min(b.min.x), a.min.y.min(b.min.y)),
+            max: point(a.max.x.max(b.max.x), a.max.y.max(b.max.y)),
+        })?;
+
+    let width = px_bounds.width() as u32;
+    let height = px_bounds.height() as u32;
+    if width == 0 || height == 0 {
+        return None;
+    }
+
+    // layers are blended in order using the "over" operator
+    let mut rgba = vec![[0.0f32; 4]; (width * height) as usize];
+    outlines.iter().for_each(|(outline, color)| {
+        let bounds = outline.px_bounds();
+        let (ox, oy) = (
+            (bounds.min.x - px_bounds.min.x) as u32,
+            (bounds.min.y - px_bounds.min.y) as u32,
+        );
+        let src = [color.red, color.green, color.blue, color.alpha].map(|c| c as f32 / 255.0);
+
+        outline.draw(|x, y, c| {
+            let (x, y) = (x + ox, y + oy);
+            if x >= width || y >= height {
+                return;
+            }
+
+            let dst = &mut rgba[(x + y * width) as usize];
+            let alpha = src[3] * c.clamp(0.0, 1.0);
+            let out_alpha = alpha + dst[3] * (1.0 - alpha);
+            if out_alpha > 0.0 {
+                (0..3).for_each(|i| {
+                    dst[i] = (src[i] * alpha + dst[i] * dst[3] * (1.0 - alpha)) / out_alpha;
+                });
+            }
+            dst[3] = out_alpha;
+        });
+    });
+
+    let pixels = rgba
+        .into_iter()
+        .flat_map(|px| px.map(|c| (c * 255.0).round() as u8))
+        .collect();
+
+    Some(GlyphPixels {
+        width,
+        height,
+        pixels,
+        bounds: Rect {
+            min: point(px_bounds.min.x / ppem, px_bounds.min.y / ppem),
+            max: point(px_bounds.max.x / ppem, px_bounds.max.y / ppem),
+        },
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bgra_is_unmultiplied() {
+        // half translucent green, opaque blue and transparent
+        let data = [0, 128, 0, 128, 255, 0, 0, 255, 0, 0, 0, 0];
+        assert_eq!(
+            premultiplied_bgra_to_rgba(&data),
+            [0, 255, 0, 128, 0, 0, 255, 255, 0, 0, 0, 0]
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use glyph_brush::ab_glyph::{Font, Rect, ScaleFont};
+use glyph_brush::{
+    BuiltInLineBreaker, GlyphPositioner, HorizontalAlign, Layout, SectionGeometry, SectionGlyph,
+    ToSectionText, VerticalAlign,
+};
+
+/// Layout that wraps the text like [Layout::Wrap] and spreads the spaces of each line
+/// to fill the width of the bounds. The last line and lines ending with a line break are
+/// aligned to the left.
+#[derive(Debug, Clone, Copy, Hash, PartialEq)]
+pub struct JustifiedLayout {
+    layout: Layout<BuiltInLineBreaker>,
+}
+
+impl JustifiedLayout {
+    pub fn new(v_align: VerticalAlign) -> Self {
+        Self {
+            layout: Layout::default_wrap()
+                .h_align(HorizontalAlign::Left)
+                .v_align(v_align),
+        }
+    }
+
+    /// Uses the line breaker and vertical alignment of the layout given
+    pub fn from_layout(layout: Layout<BuiltInLineBreaker>) -> Self {
+        let (line_breaker, v_align) = match layout {
+            Layout::SingleLine {
+                line_breaker,
+                v_align,
+                ..
+            } => (line_breaker, v_align),
+            Layout::Wrap {
+                line_breaker,
+                v_align,
+                ..
+            } => (line_breaker, v_align),
+        };
+
+        Self {
+            layout: Layout::Wrap {
+                line_breaker,
+                h_align: HorizontalAlign::Left,
+                v_align,
+            },
+        }
+    }
+}
+
+impl GlyphPositioner for JustifiedLayout {
+    fn calculate_glyphs<F, S>(
+        &self,
+        fonts: &[F],
+        geometry: &SectionGeometry,
+        sections: &[S],
+    ) -> Vec<SectionGlyph>
+    where
+        F: Font,
+        S: ToSectionText,
+    {
+        let mut glyphs = self.layout.calculate_glyphs(fonts, geometry, sections);
+
+        let width = geometry.bounds.0;
+        if !width.is_finite() {
+            return glyphs;
+        }
+
+        let right = geometry.screen_position.0 + width;
+        let texts = sections
+            .iter()
+            .map(|s| s.to_section_text().text)
+            .collect::<Vec<_>>();
+
+        let char_at = |g: &SectionGlyph| texts[g.section_index][g.byte_index..].chars().next();
+        let is_space = |g: &SectionGlyph| char_at(g).is_some_and(char::is_whitespace);
+        let ends_with_break = |g: &SectionGlyph| {
+            let text = texts[g.section_index];
+            let next = char_at(g).map(|c| g.byte_index + c.len_utf8());
+            next.and_then(|i| text[i..].chars().next())
+                .is_some_and(|c| c == '\n' || c == '\r')
+        };
+
+        // glyphs are sorted by line, and every glyph in a line shares the baseline
+        let mut lines = vec![];
+        let mut start = 0;
+        for i in 1..=glyphs.len() {
+            let new_line = i == glyphs.len()
+                || (glyphs[i].glyph.position.y - glyphs[start].glyph.position.y).abs() > 0.5;
+            if new_line {
+                lines.push(start..i);
+                start = i;
+            }
+        }
+
+        // the last line keeps the left alignment
+        lines.pop();
+
+        for line in lines {
+            if ends_with_break(&glyphs[line.end - 1]) {
+                continue;
+            }
+
+            // trailing spaces are not visible so they don't count
+            let last = match line.clone().rev().find(|i| !is_space(&glyphs[*i])) {
+                Some(last) => last,
+                None => continue,
+            };
+
+            let gaps = (line.start..last).filter(|i| is_space(&glyphs[*i])).count();
+            if gaps == 0 {
+                continue;
+            }
+
+            let g = &glyphs[last];
+            let advance = fonts[g.font_id.0]
+                .as_scaled(g.glyph.scale)
+                .h_advance(g.glyph.id);
+            let extra = right - (g.glyph.position.x + advance);
+            if extra <= 0.0 {
+                continue;
+            }
+
+            let gap_size = extra / gaps as f32;
+            let mut offset = 0.0;
+            for g in &mut glyphs[line.start..=last] {
+                g.glyph.position.x += offset;
+                if is_space(g) {
+                    offset += gap_size;
+                }
+            }
+        }
+
+        glyphs
+    }
+
+    fn bounds_rect(&self, geometry: &SectionGeometry) -> Rect {
+        self.layout.bounds_rect(geometry)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use glyph_brush::ab_glyph::FontRef;
+    use glyph_brush::SectionText;
+
+    #[test]
+    fn justify_fills_the_width() {
+        let font = FontRef::try_from_slice(include_bytes!("../../../examples/assets/Ubuntu-B.ttf"))
+            .unwrap();
+        let geometry = SectionGeometry {
+            screen_position: (0.0, 0.0),
+            bounds: (200.0, f32::INFINITY),
+        };
+        let text = "Lorem ipsum dolor sit amet, consectetur adipiscing elit.\nsed do";
+        let sections = [SectionText {
+            text,
+            scale: 20.0.into(),
+            ..Default::default()
+        }];
+
+        let glyphs = JustifiedLayout::new(VerticalAlign::Top).calculate_glyphs(
+            &[&font],
+            &geometry,
+            &sections,
+        );
+
+        let first_y = glyphs[0].glyph.position.y;
+        let last_in_first_line = glyphs
+            .iter()
+            .filter(|g| g.glyph.position.y == first_y)
+            .rev()
+            .find(|g| !text[g.byte_index..].starts_with(' '))
+            .unwrap();
+        let advance = font
+            .as_scaled(last_in_first_line.glyph.scale)
+            .h_advance(last_in_first_line.glyph.id);
+        let end = last_in_first_line.glyph.position.x + advance;
+        assert!((end - 200.0).abs() < 0.01, "{end}");
+
+        // the line before the line break is not justified
+        let before_break = glyphs
+            .iter()
+            .find(|g| text[g.byte_index..].starts_with("elit."))
+            .unwrap();
+        let unjustified = Layout::default_wrap().calculate_glyphs(&[&font], &geometry, &sections);
+        let expected = unjustified
+            .iter()
+            .find(|g| g.byte_index == before_break.byte_index)
+            .unwrap();
+        assert_eq!(before_break.glyph.position, expected.glyph.position);
+    }
+}
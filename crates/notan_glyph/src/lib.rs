@@ -1,19 +1,31 @@
 mod builder;
 mod cache;
+mod color;
 mod instance;
+mod justify;
 mod pipeline;
+mod sdf;
+#[cfg(feature = "text_shaping")]
+mod shaping;
+mod shelf;
 
 use cache::Cache;
 pub use instance::GlyphInstance;
+pub use justify::JustifiedLayout;
 
 pub use builder::GlyphBrushBuilder;
+pub use color::{ColorGlyph, ColorGlyphCache};
 pub use glyph_brush::ab_glyph;
 pub use glyph_brush::{
     BuiltInLineBreaker, Extra, FontId, GlyphCalculator, GlyphCalculatorBuilder, GlyphCruncher,
     GlyphPositioner, HorizontalAlign, Layout, LineBreak, LineBreaker, OwnedSection, OwnedText,
-    Section, SectionGeometry, SectionGlyph, SectionGlyphIter, SectionText, Text, VerticalAlign,
+    Section, SectionGeometry, SectionGlyph, SectionGlyphIter, SectionText, Text, ToSectionText,
+    VerticalAlign,
 };
 pub use pipeline::{DefaultGlyphPipeline, GlyphPipeline};
+pub use sdf::*;
+#[cfg(feature = "text_shaping")]
+pub use shaping::ShapedLayout;
 
 use ab_glyph::{Font, FontArc, Rect};
 
@@ -33,6 +45,33 @@ use notan_math::Mat4;
 pub struct GlyphBrush<F = FontArc, H = DefaultSectionHasher> {
     cache: Cache,
     glyph_brush: glyph_brush::GlyphBrush<GlyphInstance, Extra, F, H>,
+    queued: Vec<Queued>,
+}
+
+/// Copy of a queued section, used to queue it again if the glyph cache is evicted
+enum Queued {
+    Section(OwnedSection),
+    PrePositioned {
+        glyphs: Vec<SectionGlyph>,
+        extra: Vec<Extra>,
+        bounds: Rect,
+    },
+}
+
+impl Queued {
+    fn queue<F: Font, H: BuildHasher>(
+        &self,
+        glyph_brush: &mut glyph_brush::GlyphBrush<GlyphInstance, Extra, F, H>,
+    ) {
+        match self {
+            Queued::Section(section) => glyph_brush.queue(section.to_borrowed()),
+            Queued::PrePositioned {
+                glyphs,
+                extra,
+                bounds,
+            } => glyph_brush.queue_pre_positioned(glyphs.clone(), extra.clone(), *bounds),
+        }
+    }
 }
 
 impl<F: Font, H: BuildHasher> GlyphBrush<F, H> {
@@ -46,6 +85,9 @@ impl<F: Font, H: BuildHasher> GlyphBrush<F, H> {
     where
         S: Into<Cow<'a, Section<'a>>>,
     {
+        let section = section.into();
+        self.queued
+            .push(Queued::Section(Section::to_owned(&section)));
         self.glyph_brush.queue(section)
     }
 
@@ -64,6 +106,18 @@ impl<F: Font, H: BuildHasher> GlyphBrush<F, H> {
         G: GlyphPositioner,
         S: Into<Cow<'a, Section<'a>>>,
     {
+        let section = section.into();
+        // custom layouts can't be stored, so the positioned glyphs are kept instead
+        let glyphs = self
+            .glyph_brush
+            .glyphs_custom_layout(section.as_ref(), custom_layout)
+            .cloned()
+            .collect();
+        self.queued.push(Queued::PrePositioned {
+            glyphs,
+            extra: section.text.iter().map(|t| t.extra).collect(),
+            bounds: custom_layout.bounds_rect(&SectionGeometry::from(section.as_ref())),
+        });
         self.glyph_brush.queue_custom_layout(section, custom_layout)
     }
 
@@ -77,6 +131,11 @@ impl<F: Font, H: BuildHasher> GlyphBrush<F, H> {
         extra: Vec<Extra>,
         bounds: Rect,
     ) {
+        self.queued.push(Queued::PrePositioned {
+            glyphs: glyphs.clone(),
+            extra: extra.clone(),
+            bounds,
+        });
         self.glyph_brush.queue_pre_positioned(glyphs, extra, bounds)
     }
 
@@ -209,7 +268,7 @@ impl<'a, F: Font + Sync, H: BuildHasher> RenderQueueBuilder<'a, F, H> {
     }
 }
 
-impl<F: Font + Sync, H: BuildHasher> GlyphBrush<F, H> {
+impl<F: Font + Sync + Clone, H: BuildHasher + Clone> GlyphBrush<F, H> {
     pub fn render_queue<'a>(
         &'a mut self,
         device: &'a mut Device,
@@ -224,6 +283,7 @@ impl<F: Font + Sync, H: BuildHasher> GlyphBrush<F, H> {
             "You need to add at least one Font to be used as default."
         );
 
+        let queued = self.queued.len();
         let mut brush_action;
 
         loop {
@@ -241,22 +301,25 @@ impl<F: Font + Sync, H: BuildHasher> GlyphBrush<F, H> {
                 Ok(_) => break,
                 Err(BrushError::TextureTooSmall { suggested }) => {
                     let max_image_dimension = device.limits().max_texture_size;
+                    let current = self.glyph_brush.texture_dimensions();
+                    let (new_width, new_height) = (
+                        suggested.0.min(max_image_dimension),
+                        suggested.1.min(max_image_dimension),
+                    );
 
-                    let (new_width, new_height) = if (suggested.0 > max_image_dimension
-                        || suggested.1 > max_image_dimension)
-                        && (self.glyph_brush.texture_dimensions().0 < max_image_dimension
-                            || self.glyph_brush.texture_dimensions().1 < max_image_dimension)
-                    {
-                        (max_image_dimension, max_image_dimension)
-                    } else {
-                        suggested
-                    };
+                    // the cache already drops the glyphs not used by the queued sections,
+                    // if they don't fit at the max size the cache is evicted and only
+                    // the first half of the sections is queued again
+                    if (new_width, new_height) == current {
+                        self.evict(self.queued.len() / 2);
+                        continue;
+                    }
 
                     log::debug!(
                         "Increasing glyph texture size {old:?} -> {new:?}. \
                              Consider building with `.initial_cache_size({new:?})` to avoid \
                              resizing",
-                        old = self.glyph_brush.texture_dimensions(),
+                        old = current,
                         new = (new_width, new_height),
                     );
 
@@ -266,6 +329,17 @@ impl<F: Font + Sync, H: BuildHasher> GlyphBrush<F, H> {
             }
         }
 
+        if self.queued.len() < queued {
+            log::error!(
+                "The queued text needs a glyph texture bigger than {:?}, which is the maximum \
+                 size allowed by the device. Only {} of {} sections were drawn",
+                self.glyph_brush.texture_dimensions(),
+                self.queued.len(),
+                queued
+            );
+        }
+        self.queued.clear();
+
         match brush_action.unwrap() {
             BrushAction::Draw(verts) => {
                 pipeline.upload(device, &verts);
@@ -281,7 +355,38 @@ impl<F: Font, H: BuildHasher> GlyphBrush<F, H> {
         let (cache_width, cache_height) = glyph_brush.texture_dimensions();
         let cache = Cache::new(gfx, cache_width as _, cache_height as _).unwrap();
 
-        GlyphBrush { cache, glyph_brush }
+        GlyphBrush {
+            cache,
+            glyph_brush,
+            queued: vec![],
+        }
+    }
+}
+
+impl<F: Font + Clone, H: BuildHasher + Clone> GlyphBrush<F, H> {
+    /// Resizes the texture used to cache the glyphs, clamped to the max size allowed by the device.
+    /// The cached glyphs are rasterized again the next time that they're drawn
+    pub fn resize_cache(&mut self, device: &mut Device, width: u32, height: u32) {
+        let max_image_dimension = device.limits().max_texture_size;
+        let (width, height) = (
+            width.min(max_image_dimension),
+            height.min(max_image_dimension),
+        );
+        if (width, height) == self.glyph_brush.texture_dimensions() {
+            return;
+        }
+
+        self.cache = Cache::new(device, width, height).unwrap();
+        self.glyph_brush.resize_texture(width, height);
+    }
+
+    /// Starts again with an empty glyph cache and the first `keep` queued sections
+    fn evict(&mut self, keep: usize) {
+        self.queued.truncate(keep);
+        self.glyph_brush.to_builder().rebuild(&mut self.glyph_brush);
+        self.queued
+            .iter()
+            .for_each(|section| section.queue(&mut self.glyph_brush));
     }
 }
 
@@ -0,0 +1,253 @@
+use crate::ab_glyph::{point, Font, GlyphId, PxScale};
+use crate::shelf::ShelfAllocator;
+use crate::FontId;
+use notan_app::{Texture, TextureFilter, TextureFormat};
+use notan_graphics::Device;
+use notan_math::Rect;
+use std::collections::HashMap;
+
+/// Size in pixels used to rasterize the glyphs before generating the distance field
+pub const SDF_BASE_SIZE: f32 = 48.0;
+
+/// Distance in pixels, at [SDF_BASE_SIZE], stored around the glyphs.
+/// Outlines and glows can't be wider than this
+pub const SDF_SPREAD: f32 = 8.0;
+
+/// Glyph stored on the [SdfCache]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SdfGlyph {
+    /// Area of the texture used by the glyph, in pixels
+    pub frame: Rect,
+    /// Offset from the glyph's origin to the top-left of the frame at [SDF_BASE_SIZE]
+    pub offset: (f32, f32),
+}
+
+/// Texture atlas storing the signed distance field of the glyphs. Because the
+/// distance field can be scaled without losing the shape, each glyph is generated
+/// only once for any font size
+pub struct SdfCache {
+    texture: Texture,
+    glyphs: HashMap<(FontId, GlyphId), Option<SdfGlyph>>,
+    shelf: ShelfAllocator,
+}
+
+impl SdfCache {
+    pub fn new(device: &mut Device, width: u32, height: u32) -> Result<Self, String> {
+        let texture = device
+            .create_texture()
+            .with_size(width, height)
+            .with_filter(TextureFilter::Linear, TextureFilter::Linear)
+            .with_format(TextureFormat::R8)
+            .build()?;
+
+        Ok(Self {
+            texture,
+            glyphs: HashMap::new(),
+            shelf: ShelfAllocator::new(width, height),
+        })
+    }
+
+    pub fn texture(&self) -> &Texture {
+        &self.texture
+    }
+
+    /// Returns the glyph, generating it if it's not on the cache yet.
+    /// Glyphs without shape, like spaces, return `None`
+    pub fn glyph<F: Font>(
+        &mut self,
+        device: &mut Device,
+        font_id: FontId,
+        font: &F,
+        id: GlyphId,
+    ) -> Option<SdfGlyph> {
+        if let Some(glyph) = self.glyphs.get(&(font_id, id)) {
+            return *glyph;
+        }
+
+        let glyph = self.generate(device, font, id);
+        self.glyphs.insert((font_id, id), glyph);
+        glyph
+    }
+
+    fn generate<F: Font>(
+        &mut self,
+        device: &mut Device,
+        font: &F,
+        id: GlyphId,
+    ) -> Option<SdfGlyph> {
+        let outline = font.outline_glyph(
+            id.with_scale_and_position(PxScale::from(SDF_BASE_SIZE), point(0.0, 0.0)),
+        )?;
+
+        let bounds = outline.px_bounds();
+        let pad = SDF_SPREAD as u32;
+        let width = bounds.width() as u32 + pad * 2;
+        let height = bounds.height() as u32 + pad * 2;
+
+        let mut coverage = vec![0; (width * height) as usize];
+        outline.draw(|x, y, c| {
+            let index = (x + pad) + (y + pad) * width;
+            coverage[index as usize] = (c.clamp(0.0, 1.0) * 255.0) as u8;
+        });
+
+        let (x, y) = self.shelf.allocate(width, height).or_else(|| {
+            log::error!("The SDF glyph cache is full, the glyph will not be drawn");
+            None
+        })?;
+        let data = distance_field(&coverage, width, height, SDF_SPREAD);
+        device
+            .update_texture(&mut self.texture)
+            .with_x_offset(x)
+            .with_y_offset(y)
+            .with_width(width)
+            .with_height(height)
+            .with_data(&data)
+            .update()
+            .map_err(|e| log::error!("Cannot upload the glyph to the SDF cache: {e}"))
+            .ok()?;
+
+        Some(SdfGlyph {
+            frame: Rect {
+                x: x as _,
+                y: y as _,
+                width: width as _,
+                height: height as _,
+            },
+            offset: (bounds.min.x - SDF_SPREAD, bounds.min.y - SDF_SPREAD),
+        })
+    }
+}
+
+/// Converts the coverage of a glyph to a signed distance field, where 128 is the edge,
+/// bigger values are inside and smaller ones outside, up to `spread` pixels
+pub fn distance_field(coverage: &[u8], width: u32, height: u32, spread: f32) -> Vec<u8> {
+    let (w, h) = (width as usize, height as usize);
+    let inside = |i: usize| coverage[i] >= 128;
+
+    // squared distance from each pixel to the nearest pixel on the other side of the edge
+    let mut to_inside = (0..w * h)
+        .map(|i| if inside(i) { 0.0 } else { f32::INFINITY })
+        .collect::<Vec<_>>();
+    let mut to_outside = (0..w * h)
+        .map(|i| if inside(i) { f32::INFINITY } else { 0.0 })
+        .collect::<Vec<_>>();
+
+    edt_2d(&mut to_inside, w, h);
+    edt_2d(&mut to_outside, w, h);
+
+    (0..w * h)
+        .map(|i| {
+            // the edge is between the pixel centers
+            let dist = if inside(i) {
+                to_outside[i].sqrt() - 0.5
+            } else {
+                0.5 - to_inside[i].sqrt()
+            };
+
+            let value = 0.5 + dist / (2.0 * spread);
+            (value.clamp(0.0, 1.0) * 255.0).round() as u8
+        })
+        .collect()
+}
+
+// Felzenszwalb and Huttenlocher's squared euclidean distance transform
+fn edt_2d(grid: &mut [f32], w: usize, h: usize) {
+    let mut buffer = vec![0.0; w.max(h)];
+
+    for x in 0..w {
+        (0..h).for_each(|y| buffer[y] = grid[y * w + x]);
+        edt_1d(&mut buffer[..h]);
+        (0..h).for_each(|y| grid[y * w + x] = buffer[y]);
+    }
+
+    for row in grid.chunks_exact_mut(w) {
+        edt_1d(row);
+    }
+}
+
+fn edt_1d(f: &mut [f32]) {
+    let n = f.len();
+    let src = f.to_vec();
+    let mut v = vec![0usize; n];
+    let mut z = vec![0.0f32; n + 1];
+
+    // lower envelope of the parabolas, the pixels without a value don't have one
+    let mut sites = (0..n).filter(|q| src[*q].is_finite());
+    let first = match sites.next() {
+        Some(q) => q,
+        None => return,
+    };
+
+    let mut k = 0;
+    v[0] = first;
+    z[0] = f32::NEG_INFINITY;
+    z[1] = f32::INFINITY;
+
+    let intersection = |p: usize, q: usize| {
+        ((src[q] + (q * q) as f32) - (src[p] + (p * p) as f32)) / (2.0 * (q - p) as f32)
+    };
+
+    for q in sites {
+        let mut s = intersection(v[k], q);
+        while s <= z[k] {
+            k -= 1;
+            s = intersection(v[k], q);
+        }
+
+        k += 1;
+        v[k] = q;
+        z[k] = s;
+        z[k + 1] = f32::INFINITY;
+    }
+
+    k = 0;
+    for (q, value) in f.iter_mut().enumerate() {
+        while z[k + 1] < q as f32 {
+            k += 1;
+        }
+
+        let d = q as f32 - v[k] as f32;
+        *value = d * d + src[v[k]];
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn square_distance_field() {
+        // 4x4 square in the middle of a 12x12 image
+        let (w, h) = (12, 12);
+        let coverage = (0..w * h)
+            .map(|i| {
+                let (x, y) = (i % w, i / w);
+                let inside = (4..8).contains(&x) && (4..8).contains(&y);
+                if inside {
+                    255
+                } else {
+                    0
+                }
+            })
+            .collect::<Vec<u8>>();
+
+        let sdf = distance_field(&coverage, w as _, h as _, 4.0);
+        let at = |x: usize, y: usize| sdf[y * w + x];
+        let expected = |dist: f32| ((0.5 + dist / 8.0) * 255.0).round() as u8;
+
+        // borders are at half a pixel from the edge
+        assert_eq!(at(4, 5), expected(0.5));
+        assert_eq!(at(3, 5), expected(-0.5));
+
+        // inside is bigger than the border and decreases going outside
+        assert!(at(5, 5) > at(4, 5));
+        assert!(at(2, 5) < at(3, 5));
+        assert!(at(1, 5) < at(2, 5));
+
+        // the corners use the euclidean distance
+        assert_eq!(at(1, 1), expected(0.5 - 18.0f32.sqrt()));
+
+        // further than the spread is clamped
+        assert_eq!(at(0, 11), 0);
+    }
+}
@@ -0,0 +1,432 @@
+use crate::ab_glyph::{point, Font, GlyphId, PxScale, Rect, ScaleFont};
+use glyph_brush::{
+    FontId, GlyphPositioner, HorizontalAlign, Layout, LineBreaker, SectionGeometry, SectionGlyph,
+    ToSectionText, VerticalAlign,
+};
+use rustybuzz::{Direction, Face, UnicodeBuffer};
+use std::ops::Range;
+use unicode_bidi::{BidiInfo, Level};
+
+/// Layout that shapes the text with rustybuzz and reorders bidirectional text,
+/// needed by scripts like Arabic, Hebrew or Devanagari where the glyphs depend
+/// on the surrounding characters and the visual order is not the logical one.
+/// Lines are wrapped on whitespace when the bounds width is finite
+#[derive(Debug, Clone, Copy, Hash, PartialEq)]
+pub struct ShapedLayout {
+    h_align: HorizontalAlign,
+    v_align: VerticalAlign,
+}
+
+impl ShapedLayout {
+    pub fn new(h_align: HorizontalAlign, v_align: VerticalAlign) -> Self {
+        Self { h_align, v_align }
+    }
+
+    /// Uses the alignment of the layout given
+    pub fn from_layout<L: LineBreaker>(layout: Layout<L>) -> Self {
+        match layout {
+            Layout::SingleLine {
+                h_align, v_align, ..
+            } => Self::new(h_align, v_align),
+            Layout::Wrap {
+                h_align, v_align, ..
+            } => Self::new(h_align, v_align),
+        }
+    }
+}
+
+/// Run of text using the same section and direction
+struct Run {
+    section: usize,
+    range: Range<usize>,
+    glyphs: Vec<ShapedGlyph>,
+}
+
+#[derive(Clone, Copy)]
+struct ShapedGlyph {
+    id: GlyphId,
+    // byte index on the whole text of the first char of the glyph
+    cluster: usize,
+    advance: f32,
+    offset: (f32, f32),
+}
+
+#[derive(Clone, Copy, Default)]
+struct LineMetrics {
+    ascent: f32,
+    descent: f32,
+    line_gap: f32,
+}
+
+impl LineMetrics {
+    fn max(self, other: Self) -> Self {
+        Self {
+            ascent: self.ascent.max(other.ascent),
+            descent: self.descent.min(other.descent),
+            line_gap: self.line_gap.max(other.line_gap),
+        }
+    }
+
+    fn height(&self) -> f32 {
+        self.ascent - self.descent + self.line_gap
+    }
+}
+
+impl GlyphPositioner for ShapedLayout {
+    fn calculate_glyphs<F, S>(
+        &self,
+        fonts: &[F],
+        geometry: &SectionGeometry,
+        sections: &[S],
+    ) -> Vec<SectionGlyph>
+    where
+        F: Font,
+        S: ToSectionText,
+    {
+        let sections = sections
+            .iter()
+            .map(|s| s.to_section_text())
+            .collect::<Vec<_>>();
+
+        // the whole text is needed to resolve the bidi levels across sections
+        let mut text = String::new();
+        let mut starts = vec![];
+        sections.iter().for_each(|s| {
+            starts.push(text.len());
+            text.push_str(s.text);
+        });
+
+        let section_at = |index: usize| starts.partition_point(|s| *s <= index).max(1) - 1;
+        let faces = fonts
+            .iter()
+            .map(|f| Face::from_slice(f.font_data(), 0))
+            .collect::<Vec<_>>();
+
+        let metrics = |section: usize| {
+            let s = &sections[section];
+            let font = fonts[s.font_id.0].as_scaled(s.scale);
+            LineMetrics {
+                ascent: font.ascent(),
+                descent: font.descent(),
+                line_gap: font.line_gap(),
+            }
+        };
+
+        let bidi = BidiInfo::new(&text, None);
+        let mut lines = vec![];
+        for para in &bidi.paragraphs {
+            let end = text[para.range.clone()]
+                .trim_end_matches(['\n', '\r'])
+                .len()
+                + para.range.start;
+            let range = para.range.start..end;
+
+            // split the paragraph in runs of the same section and level
+            let mut runs: Vec<Run> = vec![];
+            for (i, _) in text[range.clone()].char_indices() {
+                let index = range.start + i;
+                let section = section_at(index);
+                match runs.last_mut() {
+                    Some(run)
+                        if run.section == section
+                            && bidi.levels[run.range.start] == bidi.levels[index] =>
+                    {
+                        run.range.end = index;
+                    }
+                    _ => runs.push(Run {
+                        section,
+                        range: index..index,
+                        glyphs: vec![],
+                    }),
+                }
+            }
+
+            runs.iter_mut().for_each(|run| {
+                // the end of the range is the start of the last char
+                run.range.end += text[run.range.end..]
+                    .chars()
+                    .next()
+                    .map_or(0, char::len_utf8);
+
+                let s = &sections[run.section];
+                run.glyphs = shape(
+                    fonts,
+                    &faces,
+                    s.font_id,
+                    s.scale,
+                    &text,
+                    run.range.clone(),
+                    bidi.levels[run.range.start],
+                );
+            });
+
+            let breaks = line_breaks(&text, range.clone(), &runs, geometry.bounds.0);
+            breaks.into_iter().for_each(|line| {
+                let line_metrics = runs
+                    .iter()
+                    .filter(|r| r.range.start < line.end && line.start < r.range.end)
+                    .map(|r| metrics(r.section))
+                    .reduce(LineMetrics::max)
+                    .unwrap_or_else(|| metrics(section_at(line.start.min(text.len()))));
+
+                let glyphs = visual_line(&bidi, para, line, &runs);
+                lines.push((line_metrics, glyphs));
+            });
+        }
+
+        let height = lines.iter().map(|(m, _)| m.height()).sum::<f32>();
+        let (x, y) = geometry.screen_position;
+        let mut caret_y = match self.v_align {
+            VerticalAlign::Top => y,
+            VerticalAlign::Center => y - height * 0.5,
+            VerticalAlign::Bottom => y - height,
+        };
+
+        let mut result = vec![];
+        lines.into_iter().for_each(|(line_metrics, glyphs)| {
+            let width = glyphs.iter().map(|g| g.advance).sum::<f32>();
+            let mut caret_x = match self.h_align {
+                HorizontalAlign::Left => x,
+                HorizontalAlign::Center => x - width * 0.5,
+                HorizontalAlign::Right => x - width,
+            };
+
+            let baseline = caret_y + line_metrics.ascent;
+            glyphs.into_iter().for_each(|g| {
+                let section_index = section_at(g.cluster);
+                let s = &sections[section_index];
+                result.push(SectionGlyph {
+                    section_index,
+                    byte_index: g.cluster - starts[section_index],
+                    glyph: g.id.with_scale_and_position(
+                        s.scale,
+                        point(caret_x + g.offset.0, baseline + g.offset.1),
+                    ),
+                    font_id: s.font_id,
+                });
+                caret_x += g.advance;
+            });
+
+            caret_y += line_metrics.height();
+        });
+
+        result
+    }
+
+    fn bounds_rect(&self, geometry: &SectionGeometry) -> Rect {
+        Layout::default_wrap()
+            .h_align(self.h_align)
+            .v_align(self.v_align)
+            .bounds_rect(geometry)
+    }
+}
+
+fn shape<F: Font>(
+    fonts: &[F],
+    faces: &[Option<Face>],
+    font_id: FontId,
+    scale: PxScale,
+    text: &str,
+    range: Range<usize>,
+    level: Level,
+) -> Vec<ShapedGlyph> {
+    let font = fonts[font_id.0].as_scaled(scale);
+    let face = match &faces[font_id.0] {
+        Some(face) => face,
+        // fonts that rustybuzz can't read use one glyph per char without shaping
+        None => {
+            return text[range.clone()]
+                .char_indices()
+                .map(|(i, c)| {
+                    let id = font.glyph_id(c);
+                    ShapedGlyph {
+                        id,
+                        cluster: range.start + i,
+                        advance: font.h_advance(id),
+                        offset: (0.0, 0.0),
+                    }
+                })
+                .collect();
+        }
+    };
+
+    let mut buffer = UnicodeBuffer::new();
+    buffer.push_str(&text[range.clone()]);
+    buffer.set_direction(if level.is_rtl() {
+        Direction::RightToLeft
+    } else {
+        Direction::LeftToRight
+    });
+
+    let output = rustybuzz::shape(face, &[], buffer);
+    let (sx, sy) = (font.h_scale_factor(), font.v_scale_factor());
+    output
+        .glyph_infos()
+        .iter()
+        .zip(output.glyph_positions())
+        .map(|(info, pos)| ShapedGlyph {
+            id: GlyphId(info.glyph_id as _),
+            cluster: range.start + info.cluster as usize,
+            advance: pos.x_advance as f32 * sx,
+            // font units go up, screen coordinates go down
+            offset: (pos.x_offset as f32 * sx, -pos.y_offset as f32 * sy),
+        })
+        .collect()
+}
+
+/// Breaks the paragraph in lines after the whitespace, using the logical order
+fn line_breaks(text: &str, range: Range<usize>, runs: &[Run], max_width: f32) -> Vec<Range<usize>> {
+    if !max_width.is_finite() {
+        return vec![range];
+    }
+
+    let advance_at = |index: usize| -> f32 {
+        runs.iter()
+            .filter(|r| r.range.contains(&index))
+            .flat_map(|r| r.glyphs.iter())
+            .filter(|g| g.cluster == index)
+            .map(|g| g.advance)
+            .sum()
+    };
+
+    let mut lines = vec![];
+    let mut start = range.start;
+    let mut last_break = None;
+    let mut width = 0.0;
+    for (i, c) in text[range.clone()].char_indices() {
+        let index = range.start + i;
+        width += advance_at(index);
+
+        if c.is_whitespace() {
+            last_break = Some(index + c.len_utf8());
+            continue;
+        }
+
+        if width > max_width {
+            if let Some(brk) = last_break.take() {
+                lines.push(start..brk);
+                start = brk;
+                let next = index + c.len_utf8();
+                width = text[brk..next]
+                    .char_indices()
+                    .map(|(j, _)| advance_at(brk + j))
+                    .sum();
+            }
+        }
+    }
+
+    lines.push(start..range.end);
+    lines
+}
+
+/// Returns the glyphs of the line in visual order
+fn visual_line(
+    bidi: &BidiInfo,
+    para: &unicode_bidi::ParagraphInfo,
+    line: Range<usize>,
+    runs: &[Run],
+) -> Vec<ShapedGlyph> {
+    // trailing whitespace doesn't count for the alignment
+    let end = line.start + bidi.text[line.clone()].trim_end().len();
+    let line = line.start..end;
+    if line.is_empty() {
+        return vec![];
+    }
+
+    let (levels, level_runs) = bidi.visual_runs(para, line.clone());
+    level_runs
+        .into_iter()
+        .flat_map(|level_run| {
+            let is_rtl = levels[level_run.start].is_rtl();
+            let mut overlapping = runs
+                .iter()
+                .filter(|r| r.range.start < level_run.end && level_run.start < r.range.end)
+                .collect::<Vec<_>>();
+            if is_rtl {
+                overlapping.reverse();
+            }
+
+            overlapping
+                .into_iter()
+                .flat_map(|r| r.glyphs.iter())
+                .filter(|g| level_run.contains(&g.cluster))
+                .copied()
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ab_glyph::FontRef;
+    use glyph_brush::SectionText;
+
+    fn layout(text: &str, width: f32) -> Vec<SectionGlyph> {
+        let font = FontRef::try_from_slice(include_bytes!("../../../examples/assets/Ubuntu-B.ttf"))
+            .unwrap();
+        let geometry = SectionGeometry {
+            screen_position: (0.0, 0.0),
+            bounds: (width, f32::INFINITY),
+        };
+        let sections = [SectionText {
+            text,
+            scale: 20.0.into(),
+            ..Default::default()
+        }];
+
+        ShapedLayout::new(HorizontalAlign::Left, VerticalAlign::Top).calculate_glyphs(
+            &[font],
+            &geometry,
+            &sections,
+        )
+    }
+
+    #[test]
+    fn latin_matches_default_layout() {
+        let text = "Hello world";
+        let glyphs = layout(text, f32::INFINITY);
+        assert_eq!(glyphs.len(), text.chars().count());
+
+        let xs = glyphs
+            .iter()
+            .map(|g| g.glyph.position.x)
+            .collect::<Vec<_>>();
+        assert!(xs.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn rtl_is_reversed() {
+        // hebrew letters are ordered from right to left, the latin word keeps its order
+        let text = "abc \u{05D0}\u{05D1}\u{05D2}";
+        let glyphs = layout(text, f32::INFINITY);
+        let x_of = |byte: usize| {
+            glyphs
+                .iter()
+                .find(|g| g.byte_index == byte)
+                .map(|g| g.glyph.position.x)
+                .unwrap()
+        };
+
+        assert!(x_of(0) < x_of(1));
+        let (alef, bet) = (4, 6);
+        assert!(x_of(alef) > x_of(bet));
+        assert!(x_of(bet) > x_of(2));
+    }
+
+    #[test]
+    fn wraps_on_whitespace() {
+        let glyphs = layout("aaaa bbbb cccc", 60.0);
+        let lines = glyphs
+            .iter()
+            .map(|g| g.glyph.position.y.to_bits())
+            .collect::<std::collections::HashSet<_>>();
+        assert!(lines.len() > 1);
+
+        let first_y = glyphs[0].glyph.position.y;
+        glyphs
+            .iter()
+            .filter(|g| g.glyph.position.y == first_y)
+            .for_each(|g| assert!(g.glyph.position.x < 60.0));
+    }
+}
@@ -0,0 +1,36 @@
+/// Packs rectangles on rows of a texture, each row is as tall as its tallest rectangle
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ShelfAllocator {
+    width: u32,
+    height: u32,
+    cursor: (u32, u32),
+    row_height: u32,
+}
+
+impl ShelfAllocator {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the top-left position of the rectangle or `None` if there is no space left
+    pub fn allocate(&mut self, width: u32, height: u32) -> Option<(u32, u32)> {
+        // leave one pixel between rectangles to avoid bleeding with linear filtering
+        if self.cursor.0 + width > self.width {
+            self.cursor = (0, self.cursor.1 + self.row_height + 1);
+            self.row_height = 0;
+        }
+
+        if width > self.width || self.cursor.1 + height > self.height {
+            return None;
+        }
+
+        let pos = self.cursor;
+        self.cursor.0 += width + 1;
+        self.row_height = self.row_height.max(height);
+        Some(pos)
+    }
+}
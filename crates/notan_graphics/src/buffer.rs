@@ -61,6 +61,11 @@ impl Buffer {
     pub fn is_index(&self) -> bool {
         matches!(self.usage, BufferUsage::Index)
     }
+
+    /// Returns true if it's a storage's buffer
+    pub fn is_storage(&self) -> bool {
+        matches!(self.usage, BufferUsage::Storage(_))
+    }
 }
 
 impl std::cmp::PartialEq for Buffer {
@@ -199,10 +204,61 @@ impl<'a> UniformBufferBuilder<'a> {
             loc,
         } = self;
 
+        // without uniform blocks (WebGL1) the backend uses loose uniforms instead
+        let limits = device.limits();
+        let has_blocks = limits.max_uniform_blocks > 0;
+        if has_blocks && loc >= limits.max_uniform_blocks {
+            return Err(format!(
+                "Uniform block '{name}' uses the binding {loc} but the max is {}",
+                limits.max_uniform_blocks.saturating_sub(1)
+            ));
+        }
+
+        let size = data.as_ref().map_or(0, |d| d.len());
+        if has_blocks && size > limits.max_uniform_block_size as usize {
+            return Err(format!(
+                "Uniform block '{name}' has {size} bytes but the max is {}",
+                limits.max_uniform_block_size
+            ));
+        }
+
         device.inner_create_uniform_buffer(loc, &name, data)
     }
 }
 
+pub struct StorageBufferBuilder<'a> {
+    device: &'a mut Device,
+    data: Option<Vec<u8>>,
+    slot: u32,
+}
+
+impl<'a> StorageBufferBuilder<'a> {
+    pub fn new(device: &'a mut Device, slot: u32) -> Self {
+        Self {
+            device,
+            data: None,
+            slot,
+        }
+    }
+
+    pub fn with_data<T: BufferData>(mut self, data: T) -> Self {
+        let mut buffer = vec![];
+        data.save_as_bytes(&mut buffer);
+        self.data = Some(buffer);
+        self
+    }
+
+    pub fn build(self) -> Result<Buffer, String> {
+        let Self { device, data, slot } = self;
+
+        if !device.limits().compute {
+            return Err("Storage buffers are not supported by this device".to_string());
+        }
+
+        device.inner_create_storage_buffer(slot, data)
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct VertexInfo {
     pub(crate) attrs: Vec<VertexAttr>,
@@ -230,9 +286,10 @@ pub enum BufferUsage {
     Vertex,
     Index,
     Uniform(u32),
+    Storage(u32),
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct VertexAttr {
     pub location: u32,
     pub format: VertexFormat,
@@ -254,7 +311,7 @@ pub enum VertexStepMode {
     Instance,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum VertexFormat {
     Float32,
     Float32x2,
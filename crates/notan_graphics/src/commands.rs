@@ -20,6 +20,11 @@ pub enum Commands {
         stencil: Option<i32>,
     },
     End,
+    Clear {
+        color: Option<Color>,
+        depth: Option<f32>,
+        stencil: Option<i32>,
+    },
     Pipeline {
         id: u64,
         options: PipelineOptions,
@@ -49,4 +54,12 @@ pub enum Commands {
         count: i32,
         length: i32,
     },
+    PushDebugGroup {
+        name: String,
+    },
+    PopDebugGroup,
+    BeginOcclusionQuery {
+        id: u64,
+    },
+    EndOcclusionQuery,
 }
@@ -0,0 +1,124 @@
+use crate::buffer::Buffer;
+use crate::device::{DropManager, ResourceId};
+use crate::{Device, ShaderSource};
+use std::sync::Arc;
+
+#[derive(Debug)]
+struct ComputePipelineIdRef {
+    id: u64,
+    drop_manager: Arc<DropManager>,
+}
+
+impl Drop for ComputePipelineIdRef {
+    fn drop(&mut self) {
+        self.drop_manager.push(ResourceId::ComputePipeline(self.id));
+    }
+}
+
+/// Compute shader program, it reads and writes storage buffers when is dispatched
+#[derive(Debug, Clone)]
+pub struct ComputePipeline {
+    id: u64,
+    _id_ref: Arc<ComputePipelineIdRef>,
+}
+
+impl std::cmp::PartialEq for ComputePipeline {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl ComputePipeline {
+    pub(crate) fn new(id: u64, drop_manager: Arc<DropManager>) -> Self {
+        let id_ref = Arc::new(ComputePipelineIdRef { id, drop_manager });
+
+        Self {
+            id,
+            _id_ref: id_ref,
+        }
+    }
+
+    #[inline(always)]
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+}
+
+enum ComputeShaderKind<'b> {
+    Raw(&'b [u8]),
+    Source(&'b ShaderSource<'b>),
+}
+
+/// Compute pipeline builder pattern
+pub struct ComputePipelineBuilder<'a, 'b> {
+    device: &'a mut Device,
+    shader: Option<ComputeShaderKind<'b>>,
+}
+
+impl<'a, 'b> ComputePipelineBuilder<'a, 'b> {
+    pub fn new(device: &'a mut Device) -> Self {
+        Self {
+            device,
+            shader: None,
+        }
+    }
+
+    /// Set the shader from a ShaderSource object
+    pub fn from(mut self, compute: &'b ShaderSource) -> Self {
+        self.shader = Some(ComputeShaderKind::Source(compute));
+        self
+    }
+
+    /// Set the shader from a bytes slice
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_raw(mut self, compute: &'b [u8]) -> Self {
+        self.shader = Some(ComputeShaderKind::Raw(compute));
+        self
+    }
+
+    pub fn build(self) -> Result<ComputePipeline, String> {
+        if !self.device.limits().compute {
+            return Err("Compute shaders are not supported by this device".to_string());
+        }
+
+        match self.shader {
+            Some(ComputeShaderKind::Source(source)) => {
+                self.device.inner_create_compute_pipeline(source)
+            }
+            Some(ComputeShaderKind::Raw(source)) => {
+                self.device.inner_create_compute_pipeline_from_raw(source)
+            }
+            None => Err("Compute shader should be present".to_string()),
+        }
+    }
+}
+
+/// Helper to dispatch a compute pipeline
+pub struct ComputeDispatcher<'a> {
+    device: &'a mut Device,
+    pipeline: &'a ComputePipeline,
+    buffers: Vec<u64>,
+}
+
+impl<'a> ComputeDispatcher<'a> {
+    pub fn new(device: &'a mut Device, pipeline: &'a ComputePipeline) -> Self {
+        Self {
+            device,
+            pipeline,
+            buffers: vec![],
+        }
+    }
+
+    /// Bind a storage or uniform buffer for the dispatch
+    pub fn with_buffer(mut self, buffer: &Buffer) -> Self {
+        self.buffers.push(buffer.id());
+        self
+    }
+
+    /// Runs the number of work groups given, the writes to the storage buffers
+    /// are visible to the next draw or dispatch
+    pub fn dispatch(self, x: u32, y: u32, z: u32) -> Result<(), String> {
+        self.device
+            .inner_dispatch_compute(self.pipeline, &self.buffers, [x, y, z])
+    }
+}
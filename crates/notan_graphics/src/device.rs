@@ -1,14 +1,18 @@
 use crate::buffer::*;
 use crate::commands::*;
+use crate::compute::*;
 use crate::crevice::std140::{AsStd140, Std140};
 use crate::limits::Limits;
 use crate::pipeline::*;
+use crate::query::*;
 use crate::render_texture::*;
 use crate::renderer::Renderer;
 use crate::shader::*;
 use crate::texture::*;
+use notan_math::Rect;
 use parking_lot::RwLock;
 use std::sync::Arc;
+use std::time::Duration;
 
 /// Device resource ID, used to know which resource was dropped
 #[derive(Debug)]
@@ -17,6 +21,50 @@ pub enum ResourceId {
     Texture(u64),
     Pipeline(u64),
     RenderTexture(u64),
+    PixelsRead(u64),
+    ComputePipeline(u64),
+    OcclusionQuery(u64),
+}
+
+/// GPU resources that can be named for graphics debuggers
+pub trait GpuResource {
+    fn resource_id(&self) -> ResourceId;
+}
+
+impl GpuResource for Buffer {
+    fn resource_id(&self) -> ResourceId {
+        ResourceId::Buffer(self.id())
+    }
+}
+
+impl GpuResource for Texture {
+    fn resource_id(&self) -> ResourceId {
+        ResourceId::Texture(self.id())
+    }
+}
+
+impl GpuResource for RenderTexture {
+    fn resource_id(&self) -> ResourceId {
+        ResourceId::RenderTexture(self.id())
+    }
+}
+
+impl GpuResource for Pipeline {
+    fn resource_id(&self) -> ResourceId {
+        ResourceId::Pipeline(self.id())
+    }
+}
+
+impl GpuResource for ComputePipeline {
+    fn resource_id(&self) -> ResourceId {
+        ResourceId::ComputePipeline(self.id())
+    }
+}
+
+impl GpuResource for OcclusionQuery {
+    fn resource_id(&self) -> ResourceId {
+        ResourceId::OcclusionQuery(self.id())
+    }
 }
 
 /// Represents what the GPU did in the last frame
@@ -24,6 +72,14 @@ pub enum ResourceId {
 pub struct GpuStats {
     /// Number of draw calls
     pub draw_calls: usize,
+    /// Number of vertices processed by the draw calls, counting every instance
+    pub vertices: usize,
+    /// Number of pipelines set
+    pub pipeline_binds: usize,
+    /// Number of pipelines, render states and uniform uploads skipped because they were already set
+    pub skipped_state_changes: usize,
+    /// Number of textures bound to a slot
+    pub texture_binds: usize,
     /// Number of read_pixels callas
     pub read_pixels: usize,
     /// Number of textures updated
@@ -70,6 +126,11 @@ pub trait DeviceBackend {
         options: PipelineOptions,
     ) -> Result<u64, String>;
 
+    /// Returns the inputs used by the pipeline if the backend can inspect them
+    fn pipeline_reflection(&self, _pipeline: u64) -> Option<ShaderReflection> {
+        None
+    }
+
     /// Create a new vertex buffer object and returns the id
     fn create_vertex_buffer(
         &mut self,
@@ -83,9 +144,34 @@ pub trait DeviceBackend {
     /// Create a new uniform buffer and returns the id
     fn create_uniform_buffer(&mut self, slot: u32, name: &str) -> Result<u64, String>;
 
+    /// Create a new storage buffer and returns the id
+    fn create_storage_buffer(&mut self, _slot: u32) -> Result<u64, String> {
+        Err("Storage buffers are not supported by this device".to_string())
+    }
+
     /// Upload to the GPU the buffer data slice
     fn set_buffer_data(&mut self, buffer: u64, data: &[u8]);
 
+    /// Read the buffer data from the GPU
+    fn read_buffer(&mut self, _buffer: u64, _bytes: &mut [u8]) -> Result<(), String> {
+        Err("Reading buffers is not supported by this device".to_string())
+    }
+
+    /// Create a new compute pipeline and returns the id
+    fn create_compute_pipeline(&mut self, _source: &[u8]) -> Result<u64, String> {
+        Err("Compute pipelines are not supported by this device".to_string())
+    }
+
+    /// Runs the compute pipeline with the buffers bound using the number of work groups given
+    fn dispatch_compute(
+        &mut self,
+        _pipeline: u64,
+        _buffers: &[u64],
+        _groups: [u32; 3],
+    ) -> Result<(), String> {
+        Err("Compute pipelines are not supported by this device".to_string())
+    }
+
     /// Create a new renderer using the size of the graphics
     fn render(&mut self, commands: &[Commands], target: Option<u64>);
 
@@ -109,6 +195,16 @@ pub trait DeviceBackend {
     fn create_render_texture(&mut self, texture_id: u64, info: &TextureInfo)
         -> Result<u64, String>;
 
+    /// Change the texture filters
+    fn set_texture_filter(
+        &mut self,
+        _texture: u64,
+        _min: TextureFilter,
+        _mag: TextureFilter,
+    ) -> Result<(), String> {
+        Err("Changing the texture filters is not supported by this device".to_string())
+    }
+
     /// Update texture data
     fn update_texture(
         &mut self,
@@ -125,6 +221,72 @@ pub trait DeviceBackend {
         opts: &TextureRead,
     ) -> Result<(), String>;
 
+    /// Copy a region of a render texture to another one, the rects are `[x, y, width, height]` in pixels
+    fn blit(
+        &mut self,
+        _src: u64,
+        _dst: u64,
+        _src_rect: [i32; 4],
+        _dst_rect: [i32; 4],
+        _filter: TextureFilter,
+    ) -> Result<(), String> {
+        Err("Blitting render textures is not supported by this device".to_string())
+    }
+
+    /// Read the pixels of the screen using the physical size
+    fn read_screen_pixels(
+        &mut self,
+        _width: u32,
+        _height: u32,
+        _bytes: &mut [u8],
+    ) -> Result<(), String> {
+        Err("Reading the screen pixels is not supported by this device".to_string())
+    }
+
+    /// Start to read texture pixels without waiting for the GPU, returns the id of the read
+    fn read_pixels_async(&mut self, _texture: u64, _opts: &TextureRead) -> Result<u64, String> {
+        Err("Async pixel reads are not supported by this device".to_string())
+    }
+
+    /// Copy the pixels of the read if they are available, returns false if they are not yet
+    fn poll_read_pixels(&mut self, _id: u64, _bytes: &mut [u8]) -> Result<bool, String> {
+        Err("Async pixel reads are not supported by this device".to_string())
+    }
+
+    /// Starts to measure the time that the GPU spends on the next commands
+    fn begin_gpu_timer(&mut self, _label: &str) -> Result<(), String> {
+        Err("GPU timer queries are not supported by this device".to_string())
+    }
+
+    /// Stops the current GPU timer
+    fn end_gpu_timer(&mut self) -> Result<(), String> {
+        Err("GPU timer queries are not supported by this device".to_string())
+    }
+
+    /// Returns the last time available for the label
+    fn gpu_time(&mut self, _label: &str) -> Option<Duration> {
+        None
+    }
+
+    /// Create a new occlusion query and returns the id
+    fn create_occlusion_query(&mut self) -> Result<u64, String> {
+        Err("Occlusion queries are not supported by this device".to_string())
+    }
+
+    /// Returns if any sample passed once the result is available
+    fn occlusion_query_result(&mut self, _query: u64) -> Option<bool> {
+        None
+    }
+
+    /// Opens a named group of commands for graphics debuggers, it does nothing if not supported
+    fn push_debug_group(&mut self, _name: &str) {}
+
+    /// Closes the last group opened
+    fn pop_debug_group(&mut self) {}
+
+    /// Sets a name for the resource to show it on graphics debuggers, it does nothing if not supported
+    fn set_debug_label(&mut self, _resource: &ResourceId, _label: &str) {}
+
     fn as_any_mut(&mut self) -> &mut dyn std::any::Any;
 }
 
@@ -150,6 +312,7 @@ pub struct Device {
     dpi: f64,
     backend: Box<dyn DeviceBackend>, //TODO generic?
     drop_manager: Arc<DropManager>,
+    pipeline_cache: PipelineCache,
 }
 
 impl Device {
@@ -159,6 +322,7 @@ impl Device {
             size: (1, 1),
             dpi: 1.0,
             drop_manager: Arc::new(Default::default()),
+            pipeline_cache: Default::default(),
         })
     }
 
@@ -210,6 +374,64 @@ impl Device {
         PipelineBuilder::new(self)
     }
 
+    /// Measures the time that the GPU spends on the renders until [Device::end_gpu_timer] is called.
+    /// Timers cannot be nested
+    #[inline]
+    pub fn begin_gpu_timer(&mut self, label: &str) -> Result<(), String> {
+        self.backend.begin_gpu_timer(label)
+    }
+
+    #[inline]
+    pub fn end_gpu_timer(&mut self) -> Result<(), String> {
+        self.backend.end_gpu_timer()
+    }
+
+    /// Returns the last GPU time measured for the label, the results are available
+    /// a few frames later because the GPU runs behind the CPU
+    #[inline]
+    pub fn gpu_time(&mut self, label: &str) -> Option<Duration> {
+        self.backend.gpu_time(label)
+    }
+
+    /// Creates a query to test the visibility of the draws made between
+    /// [Renderer::begin_occlusion_query] and [Renderer::end_occlusion_query]
+    #[inline]
+    pub fn create_occlusion_query(&mut self) -> Result<OcclusionQuery, String> {
+        let id = self.backend.create_occlusion_query()?;
+        Ok(OcclusionQuery::new(id, self.drop_manager.clone()))
+    }
+
+    /// Returns `Some(true)` if any sample passed the depth and stencil tests the last time
+    /// the query was used. It returns `None` while the GPU is still working on it
+    #[inline]
+    pub fn occlusion_query_result(&mut self, query: &OcclusionQuery) -> Option<bool> {
+        self.backend.occlusion_query_result(query.id())
+    }
+
+    /// Groups the next renders under a name visible on graphics debuggers like RenderDoc
+    #[inline]
+    pub fn push_debug_group(&mut self, name: &str) {
+        self.backend.push_debug_group(name);
+    }
+
+    /// Closes the last debug group opened
+    #[inline]
+    pub fn pop_debug_group(&mut self) {
+        self.backend.pop_debug_group();
+    }
+
+    /// Sets a name for the resource visible on graphics debuggers
+    #[inline]
+    pub fn set_debug_label<R: GpuResource>(&mut self, resource: &R, label: &str) {
+        self.backend.set_debug_label(&resource.resource_id(), label);
+    }
+
+    /// Drops the pipelines kept alive by [PipelineBuilder::prewarm]
+    #[inline]
+    pub fn clear_pipeline_cache(&mut self) {
+        self.pipeline_cache.clear();
+    }
+
     /// Creates a texture builder
     #[inline]
     pub fn create_texture(&mut self) -> TextureBuilder {
@@ -240,18 +462,103 @@ impl Device {
         UniformBufferBuilder::new(self, slot, name)
     }
 
+    /// Creates a storage buffer builder, check [Limits::compute] before using it
+    #[inline]
+    pub fn create_storage_buffer(&mut self, slot: u32) -> StorageBufferBuilder<'_> {
+        StorageBufferBuilder::new(self, slot)
+    }
+
+    /// Creates a compute pipeline builder, check [Limits::compute] before using it
+    #[inline]
+    pub fn create_compute_pipeline(&mut self) -> ComputePipelineBuilder<'_, '_> {
+        ComputePipelineBuilder::new(self)
+    }
+
+    /// Creates a dispatcher to run the compute pipeline
+    #[inline]
+    pub fn dispatch_compute<'a>(
+        &'a mut self,
+        pipeline: &'a ComputePipeline,
+    ) -> ComputeDispatcher<'a> {
+        ComputeDispatcher::new(self, pipeline)
+    }
+
+    /// Read the data of a buffer, usually a storage buffer written by a compute pipeline
+    #[inline]
+    pub fn read_buffer(&mut self, buffer: &Buffer, bytes: &mut [u8]) -> Result<(), String> {
+        self.backend.read_buffer(buffer.id(), bytes)
+    }
+
     /// Update the texture data
     #[inline]
     pub fn update_texture<'a>(&'a mut self, texture: &'a mut Texture) -> TextureUpdater {
         TextureUpdater::new(self, texture)
     }
 
+    /// Change the min and mag filters of the texture
+    #[inline]
+    pub fn set_texture_filter(
+        &mut self,
+        texture: &mut Texture,
+        min: TextureFilter,
+        mag: TextureFilter,
+    ) -> Result<(), String> {
+        self.backend.set_texture_filter(texture.id(), min, mag)?;
+        texture.set_filter(min, mag);
+        Ok(())
+    }
+
     /// Read pixels from a texture
     #[inline]
     pub fn read_pixels<'a>(&'a mut self, texture: &'a Texture) -> TextureReader {
         TextureReader::new(self, texture)
     }
 
+    /// Copies a region of a render texture to a region of another one without a render pass,
+    /// scaling it with the filter if the sizes are different. Multisampled textures are
+    /// resolved while they are copied if the regions have the same size
+    pub fn blit(
+        &mut self,
+        src: &RenderTexture,
+        dst: &RenderTexture,
+        src_rect: Rect,
+        dst_rect: Rect,
+        filter: TextureFilter,
+    ) -> Result<(), String> {
+        let to_pixels = |r: Rect| {
+            [
+                r.x.round() as i32,
+                r.y.round() as i32,
+                r.width.round() as i32,
+                r.height.round() as i32,
+            ]
+        };
+
+        self.backend.blit(
+            src.id(),
+            dst.id(),
+            to_pixels(src_rect),
+            to_pixels(dst_rect),
+            filter,
+        )
+    }
+
+    /// Read the pixels of the screen, it must be called after rendering and
+    /// during the same frame because the browsers can discard the buffer once presented
+    pub fn screenshot(&mut self) -> Result<Screenshot, String> {
+        let width = (self.size.0 as f64 * self.dpi).round() as u32;
+        let height = (self.size.1 as f64 * self.dpi).round() as u32;
+        let row_len = width as usize * 4;
+        let mut bytes = vec![0; row_len * height as usize];
+        self.backend.read_screen_pixels(width, height, &mut bytes)?;
+
+        Ok(Screenshot {
+            width,
+            height,
+            bytes: flip_rows(&bytes, row_len),
+        })
+    }
+
     #[inline]
     pub(crate) fn inner_create_pipeline_from_raw(
         &mut self,
@@ -261,9 +568,15 @@ impl Device {
         texture_locations: &[(u32, String)],
         options: PipelineOptions,
     ) -> Result<Pipeline, String> {
-        let stride = vertex_attrs
-            .iter()
-            .fold(0, |acc, data| acc + data.format.bytes()) as usize;
+        let key = PipelineCache::key(
+            vertex_source,
+            fragment_source,
+            vertex_attrs,
+            texture_locations,
+        );
+        if let Some(pipeline) = self.pipeline_cache.get(key, &options) {
+            return Ok(pipeline);
+        }
 
         let id = self.backend.create_pipeline(
             vertex_source,
@@ -273,12 +586,44 @@ impl Device {
             options,
         )?;
 
-        Ok(Pipeline::new(
-            id,
-            stride,
-            options,
-            self.drop_manager.clone(),
-        ))
+        // the layout is derived from the shader when the vertex info is not given
+        let reflected_info = match self.backend.pipeline_reflection(id) {
+            Some(reflection) if vertex_attrs.is_empty() => reflection.vertex_info().map(Some),
+            Some(reflection) => reflection.validate_vertex_attrs(vertex_attrs).map(|_| None),
+            None => Ok(None),
+        };
+
+        let reflected_info = match reflected_info {
+            Ok(info) => info,
+            Err(e) => {
+                self.drop_manager.push(ResourceId::Pipeline(id));
+                return Err(e);
+            }
+        };
+
+        let vertex_attrs = reflected_info
+            .as_ref()
+            .map_or(vertex_attrs, |info| info.attrs.as_slice());
+
+        let stride = vertex_attrs
+            .iter()
+            .fold(0, |acc, data| acc + data.format.bytes()) as usize;
+
+        let pipeline = Pipeline::new(id, stride, options, self.drop_manager.clone());
+        self.pipeline_cache.insert(key, &pipeline);
+        Ok(pipeline)
+    }
+
+    /// Returns the vertex attributes and uniforms used by the pipeline's shaders.
+    /// It's `None` if the backend cannot inspect the shaders
+    #[inline]
+    pub fn pipeline_reflection(&self, pipeline: &Pipeline) -> Option<ShaderReflection> {
+        self.backend.pipeline_reflection(pipeline.id())
+    }
+
+    #[inline]
+    pub(crate) fn retain_pipeline(&mut self, pipeline: &Pipeline) {
+        self.pipeline_cache.retain(pipeline.clone());
     }
 
     #[inline]
@@ -372,6 +717,59 @@ impl Device {
         Ok(buffer)
     }
 
+    #[inline]
+    pub(crate) fn inner_create_storage_buffer(
+        &mut self,
+        slot: u32,
+        data: Option<Vec<u8>>,
+    ) -> Result<Buffer, String> {
+        let id = self.backend.create_storage_buffer(slot)?;
+        let buffer = Buffer::new(
+            id,
+            BufferUsage::Storage(slot),
+            None,
+            self.drop_manager.clone(),
+        );
+
+        if let Some(d) = data {
+            self.set_buffer_data(&buffer, &d);
+        }
+
+        Ok(buffer)
+    }
+
+    #[inline]
+    pub(crate) fn inner_create_compute_pipeline_from_raw(
+        &mut self,
+        source: &[u8],
+    ) -> Result<ComputePipeline, String> {
+        let id = self.backend.create_compute_pipeline(source)?;
+        Ok(ComputePipeline::new(id, self.drop_manager.clone()))
+    }
+
+    #[inline]
+    pub(crate) fn inner_create_compute_pipeline(
+        &mut self,
+        source: &ShaderSource,
+    ) -> Result<ComputePipeline, String> {
+        let api = self.backend.api_name();
+        let compute = source
+            .get_source(api)
+            .ok_or_else(|| format!("Compute shader for api '{api}' not available."))?;
+        self.inner_create_compute_pipeline_from_raw(compute)
+    }
+
+    #[inline]
+    pub(crate) fn inner_dispatch_compute(
+        &mut self,
+        pipeline: &ComputePipeline,
+        buffers: &[u64],
+        groups: [u32; 3],
+    ) -> Result<(), String> {
+        self.backend
+            .dispatch_compute(pipeline.id(), buffers, groups)
+    }
+
     #[inline]
     pub(crate) fn inner_create_texture(
         &mut self,
@@ -441,6 +839,32 @@ impl Device {
         self.backend.read_pixels(texture.id(), bytes, opts)
     }
 
+    #[inline]
+    pub(crate) fn inner_read_pixels_async(
+        &mut self,
+        texture: &Texture,
+        opts: &TextureRead,
+    ) -> Result<PixelsRequest, String> {
+        let id = self.backend.read_pixels_async(texture.id(), opts)?;
+        let row_len = opts.width as usize * opts.format.bytes_per_pixel() as usize;
+        let len = row_len * opts.height as usize;
+        Ok(PixelsRequest::new(
+            id,
+            len,
+            row_len,
+            self.drop_manager.clone(),
+        ))
+    }
+
+    #[inline]
+    pub(crate) fn inner_poll_read_pixels(
+        &mut self,
+        id: u64,
+        bytes: &mut [u8],
+    ) -> Result<bool, String> {
+        self.backend.poll_read_pixels(id, bytes)
+    }
+
     #[inline]
     pub fn clean(&mut self) {
         self.backend.reset_stats();
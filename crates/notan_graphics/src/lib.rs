@@ -1,9 +1,11 @@
 pub mod buffer;
 pub mod color;
 pub mod commands;
+mod compute;
 pub mod device;
 mod limits;
 pub mod pipeline;
+mod query;
 mod render_texture;
 pub mod renderer;
 mod shader;
@@ -16,8 +18,10 @@ pub use crevice_notan as crevice;
 #[cfg(feature = "texture_to_file")]
 mod to_file;
 
+pub use compute::*;
 pub use device::*;
 pub use limits::*;
+pub use query::*;
 pub use render_texture::*;
 pub use renderer::*;
 pub use shader::*;
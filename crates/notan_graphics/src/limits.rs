@@ -4,14 +4,28 @@
 #[derive(Debug, Clone, Copy)]
 pub struct Limits {
     pub max_texture_size: u32,
+    /// Number of textures that a fragment shader can sample at the same time
+    pub max_texture_units: u32,
+    /// Number of binding points available for uniform buffers, 0 if they are not supported
+    /// (WebGL1), then the data is set to the uniform `vec4 {block_name}[N]` keeping the std140 layout
     pub max_uniform_blocks: u32,
+    /// Max size in bytes of a uniform buffer
+    pub max_uniform_block_size: u32,
+    /// 1.0 if anisotropic filtering is not supported
+    pub max_anisotropy: f32,
+    /// Compute shaders and storage buffers are supported
+    pub compute: bool,
 }
 
 impl Default for Limits {
     fn default() -> Self {
         Self {
             max_texture_size: 8192,
+            max_texture_units: 16,
             max_uniform_blocks: 8,
+            max_uniform_block_size: 16384,
+            max_anisotropy: 1.0,
+            compute: false,
         }
     }
 }
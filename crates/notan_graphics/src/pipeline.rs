@@ -2,7 +2,10 @@ use crate::buffer::{VertexAttr, VertexInfo};
 use crate::color::Color;
 use crate::device::{DropManager, ResourceId};
 use crate::{Device, ShaderSource};
-use std::sync::Arc;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Weak};
 
 #[derive(Debug)]
 struct PipelineIdRef {
@@ -63,6 +66,79 @@ impl Pipeline {
     }
 }
 
+/// Reference to a pipeline that doesn't keep it alive
+struct CachedPipeline {
+    id: u64,
+    stride: usize,
+    options: PipelineOptions,
+    id_ref: Weak<PipelineIdRef>,
+}
+
+impl CachedPipeline {
+    fn upgrade(&self) -> Option<Pipeline> {
+        self.id_ref.upgrade().map(|id_ref| Pipeline {
+            id: self.id,
+            _id_ref: id_ref,
+            stride: self.stride,
+            options: self.options,
+        })
+    }
+}
+
+/// Pipelines created by the device, equivalent pipelines are reused while they're alive
+/// instead of compiling the shaders again
+#[derive(Default)]
+pub(crate) struct PipelineCache {
+    pipelines: HashMap<u64, Vec<CachedPipeline>>,
+    retained: Vec<Pipeline>,
+}
+
+impl PipelineCache {
+    pub fn key(
+        vertex: &[u8],
+        fragment: &[u8],
+        attrs: &[VertexAttr],
+        texture_locations: &[(u32, String)],
+    ) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        vertex.hash(&mut hasher);
+        fragment.hash(&mut hasher);
+        attrs.hash(&mut hasher);
+        texture_locations.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    pub fn get(&mut self, key: u64, options: &PipelineOptions) -> Option<Pipeline> {
+        let cached = self.pipelines.get_mut(&key)?;
+        cached.retain(|c| c.id_ref.strong_count() > 0);
+        cached
+            .iter()
+            .find(|c| c.options == *options)
+            .and_then(|c| c.upgrade())
+    }
+
+    pub fn insert(&mut self, key: u64, pipeline: &Pipeline) {
+        self.pipelines.entry(key).or_default().push(CachedPipeline {
+            id: pipeline.id,
+            stride: pipeline.stride,
+            options: pipeline.options,
+            id_ref: Arc::downgrade(&pipeline._id_ref),
+        });
+    }
+
+    /// Keeps the pipeline alive until the cache is cleared
+    pub fn retain(&mut self, pipeline: Pipeline) {
+        if !self.retained.contains(&pipeline) {
+            self.retained.push(pipeline);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.retained.clear();
+        self.pipelines.clear();
+    }
+}
+
 enum ShaderKind<'b> {
     Raw {
         vertex: &'b [u8],
@@ -108,7 +184,8 @@ impl<'a, 'b> PipelineBuilder<'a, 'b> {
         self
     }
 
-    /// Set the vertex structure info for a vertex buffer
+    /// Set the vertex structure info for a vertex buffer.
+    /// If it's not set the layout is derived from the shader when the backend supports it
     pub fn with_vertex_info(mut self, info: &VertexInfo) -> Self {
         self.attrs.extend(&info.attrs);
         self
@@ -156,6 +233,12 @@ impl<'a, 'b> PipelineBuilder<'a, 'b> {
         self
     }
 
+    /// Set different Stencil options for the back faces, useful for techniques like shadow volumes
+    pub fn with_stencil_back(mut self, stencil: StencilOptions) -> Self {
+        self.options.stencil_back = Some(stencil);
+        self
+    }
+
     /// Enable the SRGB Color Space
     pub fn with_srgb_space(mut self, srgb: bool) -> Self {
         self.options.srgb_space = srgb;
@@ -164,25 +247,43 @@ impl<'a, 'b> PipelineBuilder<'a, 'b> {
 
     /// Build the pipeline with the data set on the builder
     pub fn build(self) -> Result<Pipeline, String> {
-        match self.shaders {
-            Some(ShaderKind::Source { vertex, fragment }) => self.device.inner_create_pipeline(
+        self.create().1
+    }
+
+    /// Creates the pipeline and keeps it alive, so the next equivalent pipelines
+    /// are returned from the cache. Use it at load time to avoid compiling shaders
+    /// the first time that something is drawn
+    pub fn prewarm(self) -> Result<Pipeline, String> {
+        let (device, pipeline) = self.create();
+        let pipeline = pipeline?;
+        device.retain_pipeline(&pipeline);
+        Ok(pipeline)
+    }
+
+    fn create(self) -> (&'a mut Device, Result<Pipeline, String>) {
+        let Self {
+            device,
+            attrs,
+            options,
+            shaders,
+            texture_locations,
+        } = self;
+
+        let pipeline = match shaders {
+            Some(ShaderKind::Source { vertex, fragment }) => {
+                device.inner_create_pipeline(vertex, fragment, &attrs, &texture_locations, options)
+            }
+            Some(ShaderKind::Raw { vertex, fragment }) => device.inner_create_pipeline_from_raw(
                 vertex,
                 fragment,
-                &self.attrs,
-                &self.texture_locations,
-                self.options,
+                &attrs,
+                &texture_locations,
+                options,
             ),
-            Some(ShaderKind::Raw { vertex, fragment }) => {
-                self.device.inner_create_pipeline_from_raw(
-                    vertex,
-                    fragment,
-                    &self.attrs,
-                    &self.texture_locations,
-                    self.options,
-                )
-            }
             _ => Err("Vertex and Fragment shaders should be present".to_string()),
-        }
+        };
+
+        (device, pipeline)
     }
 }
 
@@ -199,6 +300,8 @@ pub enum BlendFactor {
     InverseSourceAlpha,
     DestinationAlpha,
     InverseDestinationAlpha,
+    /// min(source alpha, 1 - destination alpha), only valid as source factor
+    SourceAlphaSaturated,
 }
 
 /// Blending equation used to combine source and destiny
@@ -255,12 +358,32 @@ impl BlendMode {
         dst: BlendFactor::InverseSourceAlpha,
         op: BlendOperation::Add,
     };
+    /// Subtracts the source from the destination
+    pub const SUBTRACT: BlendMode = BlendMode {
+        src: BlendFactor::One,
+        dst: BlendFactor::One,
+        op: BlendOperation::ReverseSubtract,
+    };
 
     /// Creates a new blend mode using the ADD operation
     pub fn new(source: BlendFactor, destination: BlendFactor) -> Self {
         Self::with_operation(source, destination, BlendOperation::Add)
     }
 
+    /// Creates a new blend mode with the raw factors and equation,
+    /// the same as [BlendMode::with_operation]
+    pub const fn custom(
+        source: BlendFactor,
+        destination: BlendFactor,
+        operation: BlendOperation,
+    ) -> Self {
+        Self {
+            src: source,
+            dst: destination,
+            op: operation,
+        }
+    }
+
     /// Creates a new blend mode
     pub fn with_operation(
         source: BlendFactor,
@@ -357,6 +480,8 @@ pub struct PipelineOptions {
     pub depth_stencil: DepthStencil,
     pub color_mask: ColorMask,
     pub stencil: Option<StencilOptions>,
+    /// Stencil options for back faces, `stencil` is used for both faces if it's `None`
+    pub stencil_back: Option<StencilOptions>,
     pub srgb_space: bool,
 }
 
@@ -369,6 +494,7 @@ impl Default for PipelineOptions {
             alpha_blend: None,
             color_mask: Default::default(),
             stencil: None,
+            stencil_back: None,
             srgb_space: false,
         }
     }
@@ -391,9 +517,40 @@ impl ClearOptions {
         }
     }
 
+    /// Create a new struct just with depth
+    pub fn depth(depth: f32) -> Self {
+        Self {
+            depth: Some(depth),
+            ..Default::default()
+        }
+    }
+
+    /// Create a new struct just with stencil
+    pub fn stencil(stencil: i32) -> Self {
+        Self {
+            stencil: Some(stencil),
+            ..Default::default()
+        }
+    }
+
     pub fn none() -> Self {
         Self::default()
     }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn with_depth(mut self, depth: f32) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    pub fn with_stencil(mut self, stencil: i32) -> Self {
+        self.stencil = Some(stencil);
+        self
+    }
 }
 
 /// Represents the draw usage
@@ -450,3 +607,33 @@ pub enum DrawPrimitive {
     Triangles,
     TriangleStrip,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cache_returns_alive_pipelines() {
+        let drop_manager = Arc::new(DropManager::default());
+        let mut cache = PipelineCache::default();
+        let key = PipelineCache::key(b"vert", b"frag", &[], &[]);
+        let options = PipelineOptions::default();
+
+        let pipeline = Pipeline::new(1, 8, options, drop_manager);
+        cache.insert(key, &pipeline);
+
+        let cached = cache.get(key, &options).unwrap();
+        assert_eq!(cached.id(), 1);
+        assert_eq!(cached.stride(), 8);
+
+        let blend = PipelineOptions {
+            color_blend: Some(BlendMode::NORMAL),
+            ..options
+        };
+        assert!(cache.get(key, &blend).is_none());
+
+        drop(pipeline);
+        drop(cached);
+        assert!(cache.get(key, &options).is_none());
+    }
+}
@@ -1,9 +1,11 @@
 pub use crate::buffer::*;
 pub use crate::color::*;
 pub use crate::commands::*;
+pub use crate::compute::*;
 pub use crate::device::*;
 pub use crate::limits::*;
 pub use crate::pipeline::*;
+pub use crate::query::*;
 pub use crate::render_texture::*;
 pub use crate::renderer::*;
 pub use crate::shader::*;
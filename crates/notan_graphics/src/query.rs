@@ -0,0 +1,45 @@
+use crate::device::{DropManager, ResourceId};
+use std::sync::Arc;
+
+#[derive(Debug)]
+struct OcclusionQueryIdRef {
+    id: u64,
+    drop_manager: Arc<DropManager>,
+}
+
+impl Drop for OcclusionQueryIdRef {
+    fn drop(&mut self) {
+        self.drop_manager.push(ResourceId::OcclusionQuery(self.id));
+    }
+}
+
+/// Checks if any sample of the draws between `Renderer::begin_occlusion_query`
+/// and `Renderer::end_occlusion_query` passed the depth and stencil tests.
+/// Usually the draws are cheap shapes like bounding boxes with color and depth writes disabled
+#[derive(Debug, Clone)]
+pub struct OcclusionQuery {
+    id: u64,
+    _id_ref: Arc<OcclusionQueryIdRef>,
+}
+
+impl std::cmp::PartialEq for OcclusionQuery {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl OcclusionQuery {
+    pub(crate) fn new(id: u64, drop_manager: Arc<DropManager>) -> Self {
+        let id_ref = Arc::new(OcclusionQueryIdRef { id, drop_manager });
+
+        Self {
+            id,
+            _id_ref: id_ref,
+        }
+    }
+
+    #[inline(always)]
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+}
@@ -56,6 +56,22 @@ impl RenderTexture {
         Renderer::new(self.width() as _, self.height() as _)
     }
 
+    /// Reads the pixels of the whole texture with the rows ordered from top to bottom
+    pub fn read_pixels(&self, device: &mut Device) -> Result<Vec<u8>, String> {
+        let row_len = self.width() as usize * self.format().bytes_per_pixel() as usize;
+        let mut bytes = vec![0; row_len * self.height() as usize];
+        device.read_pixels(self.texture()).read_to(&mut bytes)?;
+        Ok(flip_rows(&bytes, row_len))
+    }
+
+    /// Same as [RenderTexture::read_pixels] but without blocking until the GPU is done,
+    /// the request must be polled in later frames to get the pixels
+    pub fn read_pixels_async(&self, device: &mut Device) -> Result<PixelsRequest, String> {
+        let mut request = device.read_pixels(self.texture()).read_async()?;
+        request.flip_rows = true;
+        Ok(request)
+    }
+
     #[cfg(feature = "texture_to_file")]
     pub fn to_file<P: AsRef<std::path::Path>>(
         &self,
@@ -96,6 +112,20 @@ impl<'a> RenderTextureBuilder<'a> {
         self
     }
 
+    /// Enable stencil, a combined depth-stencil buffer is used so depth is enabled too
+    pub fn with_stencil(mut self) -> Self {
+        self.info.depth = true;
+        self.info.stencil = true;
+        self
+    }
+
+    /// Enable multisampling using the number of samples given, it must be a power of two.
+    /// The samples are resolved to the texture when the render pass ends
+    pub fn with_multisampling(mut self, samples: u8) -> Self {
+        self.info.samples = samples;
+        self
+    }
+
     /// Set the Texture format
     pub fn with_format(mut self, format: TextureFormat) -> Self {
         self.info.format = format;
@@ -132,6 +162,15 @@ impl<'a> RenderTextureBuilder<'a> {
         self
     }
 
+    /// Generates mipmaps using linear filtering for the texture and between the mipmaps,
+    /// it avoids the shimmering of textures drawn smaller than their size
+    pub fn with_trilinear_filter(mut self) -> Self {
+        self.info.min_filter = TextureFilter::Linear;
+        self.info.mag_filter = TextureFilter::Linear;
+        self.info.mipmap_filter = Some(TextureFilter::Linear);
+        self
+    }
+
     pub fn build(self) -> Result<RenderTexture, String> {
         let Self { device, info } = self;
 
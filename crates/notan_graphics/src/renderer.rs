@@ -2,6 +2,7 @@ use crate::buffer::*;
 use crate::commands::*;
 
 use crate::pipeline::*;
+use crate::query::*;
 use crate::texture::*;
 
 #[derive(Default, Clone)]
@@ -35,6 +36,16 @@ impl Renderer {
         });
     }
 
+    /// Clears the planes set on the options in the middle of the pass,
+    /// the scissors are respected so it can be used to clear only a region
+    pub fn clear_attachments(&mut self, options: ClearOptions) {
+        self.commands.push(Commands::Clear {
+            color: options.color,
+            depth: options.depth,
+            stencil: options.stencil,
+        });
+    }
+
     pub fn set_primitive(&mut self, primitive: DrawPrimitive) {
         self.primitive = primitive;
     }
@@ -125,6 +136,27 @@ impl Renderer {
         })
     }
 
+    /// Groups the next commands under a name visible on graphics debuggers like RenderDoc
+    pub fn push_debug_group(&mut self, name: &str) {
+        self.commands.push(Commands::PushDebugGroup {
+            name: name.to_string(),
+        });
+    }
+
+    pub fn pop_debug_group(&mut self) {
+        self.commands.push(Commands::PopDebugGroup);
+    }
+
+    /// Starts to count the samples of the next draws that pass the depth and stencil tests
+    pub fn begin_occlusion_query(&mut self, query: &OcclusionQuery) {
+        self.commands
+            .push(Commands::BeginOcclusionQuery { id: query.id() });
+    }
+
+    pub fn end_occlusion_query(&mut self) {
+        self.commands.push(Commands::EndOcclusionQuery);
+    }
+
     pub fn unbind_textures(&mut self) {
         self.slot_count = 0;
     }
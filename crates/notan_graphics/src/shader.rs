@@ -1,3 +1,5 @@
+use crate::buffer::{VertexAttr, VertexFormat, VertexInfo};
+
 #[derive(Debug, Clone)]
 pub struct ShaderSource<'a> {
     pub sources: &'a [(&'a str, &'a [u8])],
@@ -11,3 +13,178 @@ impl<'a> ShaderSource<'a> {
             .map(|(_, data)| *data)
     }
 }
+
+/// Data type of a shader input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShaderDataType {
+    Float,
+    Vec2,
+    Vec3,
+    Vec4,
+    Int,
+    IVec2,
+    IVec3,
+    IVec4,
+    UInt,
+    UVec2,
+    UVec3,
+    UVec4,
+    Bool,
+    Mat2,
+    Mat3,
+    Mat4,
+    Sampler2D,
+    SamplerCube,
+    /// Any other type not used by notan
+    Other,
+}
+
+impl ShaderDataType {
+    /// Number of vertex locations used by the type, matrices use one per column
+    pub fn locations(&self) -> u32 {
+        match self {
+            ShaderDataType::Mat2 => 2,
+            ShaderDataType::Mat3 => 3,
+            ShaderDataType::Mat4 => 4,
+            _ => 1,
+        }
+    }
+
+    /// The vertex format that matches the type if it can be used as a vertex attribute
+    pub fn vertex_format(&self) -> Option<VertexFormat> {
+        Some(match self {
+            ShaderDataType::Float => VertexFormat::Float32,
+            ShaderDataType::Vec2 => VertexFormat::Float32x2,
+            ShaderDataType::Vec3 => VertexFormat::Float32x3,
+            ShaderDataType::Vec4 => VertexFormat::Float32x4,
+            ShaderDataType::Mat2 => VertexFormat::Float32x2,
+            ShaderDataType::Mat3 => VertexFormat::Float32x3,
+            ShaderDataType::Mat4 => VertexFormat::Float32x4,
+            _ => return None,
+        })
+    }
+
+    pub fn is_texture(&self) -> bool {
+        matches!(
+            self,
+            ShaderDataType::Sampler2D | ShaderDataType::SamplerCube
+        )
+    }
+}
+
+/// Vertex attribute used by a shader
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShaderAttr {
+    pub name: String,
+    pub location: u32,
+    pub data_type: ShaderDataType,
+}
+
+/// Uniform used by a shader, `in_block` is true for the members of uniform blocks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShaderUniform {
+    pub name: String,
+    pub data_type: ShaderDataType,
+    pub len: u32,
+    pub in_block: bool,
+}
+
+/// Inputs used by a linked pipeline, the inputs removed by the shader compiler are not present
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShaderReflection {
+    /// Vertex attributes sorted by location
+    pub attrs: Vec<ShaderAttr>,
+    pub uniforms: Vec<ShaderUniform>,
+}
+
+impl ShaderReflection {
+    /// Texture uniforms
+    pub fn textures(&self) -> impl Iterator<Item = &ShaderUniform> {
+        self.uniforms.iter().filter(|u| u.data_type.is_texture())
+    }
+
+    /// Vertex layout with the attributes of the shader packed in order of location
+    pub fn vertex_info(&self) -> Result<VertexInfo, String> {
+        self.attrs.iter().try_fold(VertexInfo::new(), |info, attr| {
+            let format = attr.data_type.vertex_format().ok_or_else(|| {
+                format!(
+                    "Vertex attribute '{}' of type {:?} cannot be derived.",
+                    attr.name, attr.data_type
+                )
+            })?;
+
+            Ok((0..attr.data_type.locations())
+                .fold(info, |info, i| info.attr(attr.location + i, format)))
+        })
+    }
+
+    /// Checks that every attribute used by the shader has a compatible vertex attribute
+    pub fn validate_vertex_attrs(&self, vertex_attrs: &[VertexAttr]) -> Result<(), String> {
+        self.attrs.iter().try_for_each(|attr| {
+            let expected = attr.data_type.vertex_format().ok_or_else(|| {
+                format!(
+                    "Vertex attribute '{}' of type {:?} is not supported, only float types can be used.",
+                    attr.name, attr.data_type
+                )
+            })?;
+
+            (0..attr.data_type.locations()).try_for_each(|i| {
+                let location = attr.location + i;
+                let va = vertex_attrs
+                    .iter()
+                    .find(|va| va.location == location)
+                    .ok_or_else(|| {
+                        format!(
+                            "Vertex attribute '{}' at location {location} is missing in the vertex info.",
+                            attr.name
+                        )
+                    })?;
+
+                // OpenGL fills the missing components, so it's valid but usually a mistake
+                if va.format.size() != expected.size() {
+                    log::warn!(
+                        "Vertex attribute '{}' at location {location} is {:?} but the vertex info uses {:?}.",
+                        attr.name,
+                        attr.data_type,
+                        va.format
+                    );
+                }
+
+                Ok(())
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn reflection() -> ShaderReflection {
+        ShaderReflection {
+            attrs: vec![
+                ShaderAttr {
+                    name: "a_pos".to_string(),
+                    location: 0,
+                    data_type: ShaderDataType::Vec2,
+                },
+                ShaderAttr {
+                    name: "a_color".to_string(),
+                    location: 1,
+                    data_type: ShaderDataType::Vec4,
+                },
+            ],
+            uniforms: vec![],
+        }
+    }
+
+    #[test]
+    fn validate_vertex_attrs() {
+        let reflection = reflection();
+        let info = reflection.vertex_info().unwrap();
+        assert!(reflection.validate_vertex_attrs(&info.attrs).is_ok());
+
+        let missing = VertexInfo::new().attr(0, VertexFormat::Float32x2);
+        assert!(reflection.validate_vertex_attrs(&missing.attrs).is_err());
+    }
+}
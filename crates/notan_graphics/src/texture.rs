@@ -46,8 +46,21 @@ pub struct TextureInfo {
     pub premultiplied_alpha: bool,
     pub mipmap_filter: Option<TextureFilter>,
 
+    /// Anisotropic filtering level, 1.0 disables it
+    pub anisotropy: f32,
+
+    /// The texture has six faces to sample with directions
+    pub cubemap: bool,
+
     /// Used for render textures
     pub depth: bool,
+
+    /// Used for render textures, it adds a depth buffer too
+    pub stencil: bool,
+
+    /// Used for render textures, number of samples used for multisampling,
+    /// 0 disables it
+    pub samples: u8,
 }
 
 impl Default for TextureInfo {
@@ -61,8 +74,12 @@ impl Default for TextureInfo {
             width: 1,
             height: 1,
             depth: false,
+            stencil: false,
+            samples: 0,
             premultiplied_alpha: false,
             mipmap_filter: None,
+            anisotropy: 1.0,
+            cubemap: false,
         }
     }
 }
@@ -81,11 +98,20 @@ impl TextureFormat {
             R8 => 1,
             R8Uint => 1,
             R16Uint => 2,
+            Rg16 => 2,
+            Depth16 => 2,
             Rgb24 => 3,
+            Rgba16Float => 2 * 4,
             Rgba32Float => 4 * 4,
             _ => 4,
         }
     }
+
+    /// Depth textures cannot be created with pixels, they are filled drawing to a render texture
+    pub fn is_depth(&self) -> bool {
+        use TextureFormat::*;
+        matches!(self, Depth16 | Depth24 | Depth32Float)
+    }
 }
 
 struct TextureIdRef {
@@ -114,7 +140,11 @@ pub struct Texture {
     format: TextureFormat,
     min_filter: TextureFilter,
     mag_filter: TextureFilter,
+    wrap_x: TextureWrap,
+    wrap_y: TextureWrap,
     frame: Rect,
+    premultiplied_alpha: bool,
+    cubemap: bool,
     pub(crate) is_render_texture: bool,
 }
 
@@ -129,6 +159,10 @@ impl Texture {
             format,
             min_filter,
             mag_filter,
+            wrap_x,
+            wrap_y,
+            premultiplied_alpha,
+            cubemap,
             ..
         } = info;
 
@@ -148,7 +182,11 @@ impl Texture {
             format,
             min_filter,
             mag_filter,
+            wrap_x,
+            wrap_y,
             frame,
+            premultiplied_alpha,
+            cubemap,
             is_render_texture: false,
         }
     }
@@ -232,6 +270,67 @@ impl Texture {
     pub fn is_render_texture(&self) -> bool {
         self.is_render_texture
     }
+
+    #[inline(always)]
+    pub fn wrap_x(&self) -> &TextureWrap {
+        &self.wrap_x
+    }
+
+    #[inline(always)]
+    pub fn wrap_y(&self) -> &TextureWrap {
+        &self.wrap_y
+    }
+
+    /// Creates a texture from RGBA pixels, other formats can be used with
+    /// [TextureBuilder::from_bytes] and [TextureBuilder::with_format]
+    pub fn from_bytes_rgba(
+        device: &mut Device,
+        width: u32,
+        height: u32,
+        bytes: &[u8],
+    ) -> Result<Texture, String> {
+        device
+            .create_texture()
+            .from_bytes(bytes, width, height)
+            .build()
+    }
+
+    /// Uploads the bytes to the region of the texture without updating the rest,
+    /// they must use the texture's format
+    pub fn update_region(
+        &mut self,
+        device: &mut Device,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        bytes: &[u8],
+    ) -> Result<(), String> {
+        device
+            .update_texture(self)
+            .with_x_offset(x)
+            .with_y_offset(y)
+            .with_width(width)
+            .with_height(height)
+            .with_data(bytes)
+            .update()
+    }
+
+    /// Only updates this value, the filters of the GPU texture are set with [Device::set_texture_filter]
+    pub(crate) fn set_filter(&mut self, min: TextureFilter, mag: TextureFilter) {
+        self.min_filter = min;
+        self.mag_filter = mag;
+    }
+
+    /// Cubemaps are sampled in the shaders as `samplerCube`, they cannot be drawn as images
+    pub fn is_cubemap(&self) -> bool {
+        self.cubemap
+    }
+
+    /// The color channels were multiplied by the alpha when the texture was created
+    pub fn is_premultiplied_alpha(&self) -> bool {
+        self.premultiplied_alpha
+    }
 }
 
 impl std::cmp::PartialEq for Texture {
@@ -253,11 +352,17 @@ pub enum TextureFormat {
     Rgb24,
     Rgba32,
     R8,
+    /// Two channels of 8 bits
+    Rg16,
     R8Uint,
     R16Uint,
     R32Float,
     R32Uint,
     Depth16,
+    Depth24,
+    Depth32Float,
+    /// Half float channels, the bytes must be `f16` values
+    Rgba16Float,
     Rgba32Float,
 }
 
@@ -269,8 +374,11 @@ pub enum TextureFilter {
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum TextureWrap {
+    /// Clamps to the edge pixels
     Clamp,
     Repeat,
+    /// Repeats flipping the texture each time
+    MirroredRepeat,
 }
 
 enum TextureKind<'a> {
@@ -284,6 +392,17 @@ pub enum TextureSourceKind {
     Image(Vec<u8>),
     Bytes(Vec<u8>),
     Raw(Box<dyn TextureSource>),
+    CubeMap(CubeMapSource),
+}
+
+/// Faces of a cubemap, ordered as +X, -X, +Y, -Y, +Z and -Z
+pub enum CubeMapSource {
+    /// Six encoded images
+    Images(Vec<Vec<u8>>),
+    /// One encoded image with the faces placed as an horizontal (4x3) or vertical (3x4) cross
+    Cross(Vec<u8>),
+    /// Six buffers of pixels using the texture format and size
+    Bytes(Vec<Vec<u8>>),
 }
 
 pub enum TextureUpdaterSourceKind<'a> {
@@ -332,6 +451,7 @@ impl<'a, 'b> TextureBuilder<'a, 'b> {
     /// Creates a Texture from an image
     pub fn from_image(mut self, bytes: &'b [u8]) -> Self {
         self.source = None;
+        self.info.cubemap = false;
         self.kind = Some(TextureKind::Image(bytes)); // TODO remove
         self
     }
@@ -339,15 +459,48 @@ impl<'a, 'b> TextureBuilder<'a, 'b> {
     /// Creates a Texture from a buffer of pixels
     pub fn from_bytes(mut self, bytes: &'b [u8], width: u32, height: u32) -> Self {
         self.source = None;
+        self.info.cubemap = false;
         self.kind = Some(TextureKind::Bytes(bytes));
         self.info.width = width;
         self.info.height = height;
         self
     }
 
+    /// Creates a cubemap from six images, ordered as +X, -X, +Y, -Y, +Z and -Z
+    pub fn from_cubemap_images(mut self, faces: [&[u8]; 6]) -> Self {
+        let faces = faces.iter().map(|f| f.to_vec()).collect();
+        self.kind = None;
+        self.source = Some(TextureSourceKind::CubeMap(CubeMapSource::Images(faces)));
+        self.info.cubemap = true;
+        self
+    }
+
+    /// Creates a cubemap from an image with the faces placed as a cross,
+    /// horizontal (4x3 faces) or vertical (3x4 faces) depending on its size
+    pub fn from_cubemap_cross(mut self, bytes: &[u8]) -> Self {
+        self.kind = None;
+        self.source = Some(TextureSourceKind::CubeMap(CubeMapSource::Cross(
+            bytes.to_vec(),
+        )));
+        self.info.cubemap = true;
+        self
+    }
+
+    /// Creates a cubemap from six buffers of pixels, ordered as +X, -X, +Y, -Y, +Z and -Z
+    pub fn from_cubemap_bytes(mut self, faces: [&[u8]; 6], size: u32) -> Self {
+        let faces = faces.iter().map(|f| f.to_vec()).collect();
+        self.kind = None;
+        self.source = Some(TextureSourceKind::CubeMap(CubeMapSource::Bytes(faces)));
+        self.info.cubemap = true;
+        self.info.width = size;
+        self.info.height = size;
+        self
+    }
+
     /// Creates a buffer for the size passed in and creates a Texture with it
     pub fn from_empty_buffer(mut self, width: u32, height: u32) -> Self {
         self.source = None;
+        self.info.cubemap = false;
         self.kind = Some(TextureKind::EmptyBuffer);
         self.with_size(width, height)
     }
@@ -407,14 +560,40 @@ impl<'a, 'b> TextureBuilder<'a, 'b> {
         self
     }
 
+    /// Generates mipmaps using linear filtering for the texture and between the mipmaps,
+    /// it avoids the shimmering of textures drawn smaller than their size
+    pub fn with_trilinear_filter(mut self) -> Self {
+        self.info.min_filter = TextureFilter::Linear;
+        self.info.mag_filter = TextureFilter::Linear;
+        self.info.mipmap_filter = Some(TextureFilter::Linear);
+        self
+    }
+
+    /// Set the anisotropic filtering level, it improves the quality of textures
+    /// seen at steep angles. It's capped to the max level supported by the device
+    pub fn with_anisotropy(mut self, level: f32) -> Self {
+        self.info.anisotropy = level;
+        self
+    }
+
     pub fn build(self) -> Result<Texture, String> {
         let TextureBuilder {
-            info,
+            mut info,
             device,
             kind,
             mut source,
         } = self;
 
+        info.anisotropy = info.anisotropy.clamp(1.0, device.limits().max_anisotropy);
+
+        let has_pixels = matches!(kind, Some(TextureKind::Image(_) | TextureKind::Bytes(_)));
+        if info.format.is_depth() && (has_pixels || source.is_some()) {
+            return Err(format!(
+                "Textures with format {:?} cannot be created with pixels",
+                info.format
+            ));
+        }
+
         match kind {
             Some(TextureKind::Image(bytes)) => {
                 source = Some(TextureSourceKind::Image(bytes.to_vec()));
@@ -442,6 +621,17 @@ impl<'a, 'b> TextureBuilder<'a, 'b> {
             None => {}
         }
 
+        if let Some(TextureSourceKind::CubeMap(CubeMapSource::Bytes(faces))) = &source {
+            let size = (info.width * info.height * (info.bytes_per_pixel() as u32)) as usize;
+            if let Some(face) = faces.iter().find(|f| f.len() != size) {
+                return Err(format!(
+                    "Cubemap face with {} bytes, when it should be {}",
+                    face.len(),
+                    size
+                ));
+            }
+        }
+
         let s = source.unwrap_or(TextureSourceKind::Empty);
         device.inner_create_texture(s, info)
     }
@@ -521,6 +711,112 @@ impl<'a> TextureReader<'a> {
 
         device.inner_read_pixels(texture, bytes, &info)
     }
+
+    /// Starts the read without waiting for the GPU, the pixels can be polled with [PixelsRequest::poll]
+    pub fn read_async(self) -> Result<PixelsRequest, String> {
+        let Self {
+            device,
+            texture,
+            x_offset,
+            y_offset,
+            width,
+            height,
+            format,
+        } = self;
+
+        let info = TextureRead {
+            x_offset,
+            y_offset,
+            width,
+            height,
+            format,
+        };
+
+        device.inner_read_pixels_async(texture, &info)
+    }
+}
+
+/// Pending read of texture pixels, dropping it cancels the read
+#[derive(Debug)]
+pub struct PixelsRequest {
+    id: u64,
+    len: usize,
+    row_len: usize,
+    pub(crate) flip_rows: bool,
+    done: bool,
+    drop_manager: Arc<DropManager>,
+}
+
+impl PixelsRequest {
+    pub(crate) fn new(id: u64, len: usize, row_len: usize, drop_manager: Arc<DropManager>) -> Self {
+        Self {
+            id,
+            len,
+            row_len,
+            flip_rows: false,
+            done: false,
+            drop_manager,
+        }
+    }
+
+    /// Returns the pixels once the GPU is done, it returns `None` while is pending
+    /// and an error if the pixels were already taken
+    pub fn poll(&mut self, device: &mut Device) -> Result<Option<Vec<u8>>, String> {
+        if self.done {
+            return Err("The pixels of this request were already read".to_string());
+        }
+
+        let mut bytes = vec![0; self.len];
+        if !device.inner_poll_read_pixels(self.id, &mut bytes)? {
+            return Ok(None);
+        }
+
+        self.done = true;
+        if self.flip_rows {
+            bytes = flip_rows(&bytes, self.row_len);
+        }
+
+        Ok(Some(bytes))
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+}
+
+impl Drop for PixelsRequest {
+    fn drop(&mut self) {
+        if !self.done {
+            self.drop_manager.push(ResourceId::PixelsRead(self.id));
+        }
+    }
+}
+
+/// RGBA pixels of the screen, ordered from top to bottom
+#[derive(Debug, Clone)]
+pub struct Screenshot {
+    /// Width in physical pixels
+    pub width: u32,
+    /// Height in physical pixels
+    pub height: u32,
+    pub bytes: Vec<u8>,
+}
+
+impl Screenshot {
+    #[cfg(feature = "texture_to_file")]
+    pub fn to_file<P: AsRef<std::path::Path>>(&self, path: P) -> Result<(), String> {
+        crate::to_file::save_png(&self.bytes, self.width, self.height, 4, path)
+    }
+
+    /// Creates a texture with the pixels
+    pub fn to_texture(&self, device: &mut Device) -> Result<Texture, String> {
+        Texture::from_bytes_rgba(device, self.width, self.height, &self.bytes)
+    }
+}
+
+/// Reverses the order of the rows, bytes are stored bottom to top reading render textures
+pub(crate) fn flip_rows(bytes: &[u8], row_len: usize) -> Vec<u8> {
+    bytes.chunks(row_len).rev().flatten().cloned().collect()
 }
 
 pub struct TextureUpdater<'a> {
@@ -603,6 +899,26 @@ impl<'a> TextureUpdater<'a> {
         let source =
             source.ok_or_else(|| "You need to provide bytes to update a texture".to_string())?;
 
+        let fits_x = x_offset + width <= texture.width;
+        let fits_y = y_offset + height <= texture.height;
+        if !(fits_x && fits_y) {
+            return Err(format!(
+                "The region {}x{} at {},{} is out of the texture size {}x{}",
+                width, height, x_offset, y_offset, texture.width, texture.height
+            ));
+        }
+
+        if let TextureUpdaterSourceKind::Bytes(bytes) = &source {
+            let len = (width * height) as usize * format.bytes_per_pixel() as usize;
+            if bytes.len() < len {
+                return Err(format!(
+                    "The provided buffer len of {} is less than the required {} to update the texture",
+                    bytes.len(),
+                    len
+                ));
+            }
+        }
+
         let info = TextureUpdate {
             x_offset,
             y_offset,
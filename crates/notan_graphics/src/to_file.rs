@@ -1,3 +1,4 @@
+use crate::texture::flip_rows;
 use crate::Device;
 use crate::Texture;
 use image::ColorType;
@@ -9,8 +10,6 @@ pub(crate) fn save_to_png_file<P: AsRef<std::path::Path>>(
     inverse: bool,
     path: P,
 ) -> Result<(), String> {
-    use image::ImageEncoder;
-
     let bpp = texture.format().bytes_per_pixel() as usize;
     let width = texture.width() as usize;
     let height = texture.height() as usize;
@@ -20,9 +19,21 @@ pub(crate) fn save_to_png_file<P: AsRef<std::path::Path>>(
     gfx.read_pixels(texture).read_to(&mut bytes)?;
 
     if inverse {
-        bytes = bytes.chunks(width * bpp).rev().flatten().cloned().collect();
+        bytes = flip_rows(&bytes, width * bpp);
     }
 
+    save_png(&bytes, width as _, height as _, bpp, path)
+}
+
+pub(crate) fn save_png<P: AsRef<std::path::Path>>(
+    bytes: &[u8],
+    width: u32,
+    height: u32,
+    bpp: usize,
+    path: P,
+) -> Result<(), String> {
+    use image::ImageEncoder;
+
     let p = path.as_ref();
     p.with_extension(".png");
 
@@ -35,7 +46,7 @@ pub(crate) fn save_to_png_file<P: AsRef<std::path::Path>>(
     let mut data = vec![];
     let encoder = image::codecs::png::PngEncoder::new(&mut data);
     encoder
-        .write_image(&bytes, width as _, height as _, typ)
+        .write_image(bytes, width, height, typ)
         .map_err(|e| e.to_string())?;
 
     save_file(p, &data)
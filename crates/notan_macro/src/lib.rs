@@ -106,6 +106,26 @@ pub fn include_fragment_shader(input: TokenStream) -> TokenStream {
     shaders::source_from_spirv(spirv).unwrap()
 }
 
+#[cfg(shader_compilation)]
+#[proc_macro]
+pub fn compute_shader(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as LitStr);
+    let content = input.value();
+    let spirv = shaders::spirv_from(&content, shaders::ShaderType::Compute, None).unwrap();
+
+    shaders::compute_source_from_spirv(spirv).unwrap()
+}
+
+#[cfg(shader_compilation)]
+#[proc_macro]
+pub fn include_compute_shader(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as LitStr);
+    let relative_path = input.value();
+    let spirv = shaders::spirv_from_file(&relative_path, shaders::ShaderType::Compute).unwrap();
+
+    shaders::compute_source_from_spirv(spirv).unwrap()
+}
+
 #[proc_macro_attribute]
 pub fn uniform(_metadata: TokenStream, input: TokenStream) -> TokenStream {
     let derive: DeriveInput = syn::parse(input.clone()).unwrap();
@@ -11,6 +11,7 @@ use std::{io, slice};
 pub(crate) enum ShaderType {
     Vertex,
     Fragment,
+    Compute,
     //TODO more types
 }
 
@@ -20,6 +21,7 @@ impl From<ShaderType> for glsl_to_spirv::ShaderType {
         match value {
             ShaderType::Vertex => glsl_to_spirv::ShaderType::Vertex,
             ShaderType::Fragment => glsl_to_spirv::ShaderType::Fragment,
+            ShaderType::Compute => glsl_to_spirv::ShaderType::Compute,
         }
     }
 }
@@ -30,6 +32,7 @@ impl From<ShaderType> for shaderc::ShaderKind {
         match value {
             ShaderType::Vertex => shaderc::ShaderKind::Vertex,
             ShaderType::Fragment => shaderc::ShaderKind::Fragment,
+            ShaderType::Compute => shaderc::ShaderKind::Compute,
         }
     }
 }
@@ -151,7 +154,7 @@ pub(crate) fn source_from_spirv(spirv: Vec<u8>) -> Result<TokenStream, String> {
     let webgl2_bytes = spirv_to(&spirv, Output::Webgl2)?;
     // let wgpu_bytes = spirv_to(&spirv, Output::Wgpu)?;
     let opengl_3_3_bytes = spirv_to(&spirv, Output::OpenGl3_3)?;
-    // let opengl_es_bytes = spirv_to(&spirv, Output::OpenGl_ES)?;
+    let opengl_es_bytes = spirv_to(&spirv, Output::OpenGl_ES)?;
 
     Ok((quote! {
         ShaderSource {
@@ -165,8 +168,28 @@ pub(crate) fn source_from_spirv(spirv: Vec<u8>) -> Result<TokenStream, String> {
                 #[cfg(all(not(target_arch = "wasm32"), not(feature = "wgpu"), not(target_os = "ios")))]
                 ("opengl", &#opengl_3_3_bytes),
 
-                // #[cfg(any(target_os = "ios", target_os = "android"))]
-                // ("opengl_es", &#opengl_es_bytes),
+                // desktop uses it for the headless EGL contexts without desktop OpenGL
+                #[cfg(all(not(target_arch = "wasm32"), not(target_os = "macos")))]
+                ("opengl_es", &#opengl_es_bytes),
+            ]
+        }
+    })
+    .into())
+}
+
+/// Compute shaders are only available on OpenGL 4.3+ and OpenGL ES 3.1+, WebGL2 doesn't support them
+pub(crate) fn compute_source_from_spirv(spirv: Vec<u8>) -> Result<TokenStream, String> {
+    let opengl_4_3_bytes = spirv_to(&spirv, Output::OpenGl4_3)?;
+    let opengl_es_3_1_bytes = spirv_to(&spirv, Output::OpenGl_ES3_1)?;
+
+    Ok((quote! {
+        ShaderSource {
+            sources: &[
+                #[cfg(all(not(target_arch = "wasm32"), not(feature = "wgpu"), not(target_os = "ios")))]
+                ("opengl", &#opengl_4_3_bytes),
+
+                #[cfg(target_os = "android")]
+                ("opengl_es", &#opengl_es_3_1_bytes),
             ]
         }
     })
@@ -178,7 +201,9 @@ pub(crate) fn source_from_spirv(spirv: Vec<u8>) -> Result<TokenStream, String> {
 enum Output {
     Webgl2,
     OpenGl3_3,
+    OpenGl4_3,
     OpenGl_ES,
+    OpenGl_ES3_1,
     Wgpu,
 }
 
@@ -189,7 +214,9 @@ impl From<Output> for Option<glsl::Version> {
         Some(match value {
             Output::Webgl2 => V3_00Es,
             Output::OpenGl3_3 => V3_30,
+            Output::OpenGl4_3 => V4_30,
             Output::OpenGl_ES => V3_00Es,
+            Output::OpenGl_ES3_1 => V3_10Es,
             _ => return None,
         })
     }
@@ -228,9 +255,12 @@ fn compile_spirv_to_glsl(source: &[u32], api: Output) -> Result<String, String>
 
     //TODO get spirv for vulkan as input and output glsl for opengl
     //https://community.arm.com/developer/tools-software/graphics/b/blog/posts/spirv-cross-working-with-spir-v-in-your-app
-    fix_ast_for_gl(&mut ast, &res.sampled_images);
-    fix_ast_for_gl(&mut ast, &res.uniform_buffers);
-    fix_ast_for_gl(&mut ast, &res.storage_buffers);
+    // OpenGL 4.3 and OpenGL ES 3.1 support explicit bindings, they're used to bind the storage buffers
+    if !matches!(api, Output::OpenGl4_3 | Output::OpenGl_ES3_1) {
+        fix_ast_for_gl(&mut ast, &res.sampled_images);
+        fix_ast_for_gl(&mut ast, &res.uniform_buffers);
+        fix_ast_for_gl(&mut ast, &res.storage_buffers);
+    }
 
     ast.compile().map_err(error_code_to_string)
 }
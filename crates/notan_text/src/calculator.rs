@@ -1,6 +1,9 @@
 use crate::FONTS;
-use notan_glyph::{GlyphCalculator, GlyphCalculatorBuilder, GlyphCruncher, Section};
+use notan_glyph::{
+    GlyphCalculator, GlyphCalculatorBuilder, GlyphCruncher, GlyphPositioner, Section,
+};
 use notan_math::{vec2, Rect, Vec2};
+use std::hash::Hash;
 
 /// Calculate the bounds of a glyph section
 #[derive(Default, Debug)]
@@ -26,13 +29,21 @@ impl Calculator {
 
     /// Returns the bound of the section passed
     pub fn bounds(&mut self, section: &Section) -> Rect {
+        self.bounds_custom_layout(section, &section.layout)
+    }
+
+    /// Returns the bound of the section passed positioned with a custom layout
+    pub fn bounds_custom_layout<L>(&mut self, section: &Section, layout: &L) -> Rect
+    where
+        L: GlyphPositioner + Hash,
+    {
         self.create_calculator();
 
         // Glyphs is always present so it's safe to unwrap
         let glyphs = self.glyphs.as_mut().unwrap();
         let mut cache = glyphs.cache_scope();
 
-        match cache.glyph_bounds(section) {
+        match cache.glyph_bounds_custom_layout(section, layout) {
             None => Rect::default(),
             Some(bounds) => Rect {
                 x: bounds.min.x,
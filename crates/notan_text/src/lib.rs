@@ -65,7 +65,16 @@ pub struct TextExtension {
 
 impl TextExtension {
     pub fn new(gfx: &mut Graphics) -> Result<Self, String> {
-        let glyph_brush = GlyphBrushBuilder::using_fonts(vec![]).build(gfx);
+        Self::with_cache_size(gfx, 256, 256)
+    }
+
+    /// Creates the extension with a glyph cache texture of the size given.
+    /// The texture grows when the glyphs don't fit, starting with a bigger
+    /// size avoids resizing and rasterizing again every glyph when using many sizes or characters
+    pub fn with_cache_size(gfx: &mut Graphics, width: u32, height: u32) -> Result<Self, String> {
+        let glyph_brush = GlyphBrushBuilder::using_fonts(vec![])
+            .initial_cache_size((width, height))
+            .build(gfx);
         let pipelines = HashMap::default();
         let mut ext = Self {
             glyph_brush,
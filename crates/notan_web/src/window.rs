@@ -312,6 +312,11 @@ impl WindowBackend for WebWindowBackend {
             .unwrap_or(false)
     }
 
+    fn is_minimized(&self) -> bool {
+        // the tab is in the background or the browser is minimized
+        self.document.hidden()
+    }
+
     // No operation, as unsupported in browser
     fn mouse_passthrough(&mut self) -> bool {
         false
@@ -0,0 +1,98 @@
+use glutin::api::egl::context::PossiblyCurrentContext;
+use glutin::api::egl::device::Device as EglDevice;
+use glutin::api::egl::display::Display as EglDisplay;
+use glutin::config::{ConfigSurfaceTypes, ConfigTemplateBuilder};
+use glutin::context::{ContextApi, ContextAttributesBuilder, GlProfile, Version};
+use glutin::display::GlDisplay;
+use notan_app::Graphics;
+use std::ffi::CString;
+use std::ops::{Deref, DerefMut};
+
+/// Graphics without a window using a surfaceless EGL context, useful for tests
+/// on CI or to generate images on a server. There is no screen, so everything
+/// must be rendered to a `RenderTexture` and read back from it
+pub struct HeadlessGraphics {
+    // the graphics must be dropped before the context
+    gfx: Graphics,
+    _context: PossiblyCurrentContext,
+    _display: EglDisplay,
+}
+
+impl HeadlessGraphics {
+    pub fn new(width: u32, height: u32) -> Result<Self, String> {
+        let device = EglDevice::query_devices()
+            .map_err(|e| e.to_string())?
+            .next()
+            .ok_or_else(|| "There is no EGL device available.".to_string())?;
+
+        let display =
+            unsafe { EglDisplay::with_device(&device, None) }.map_err(|e| e.to_string())?;
+
+        let template = ConfigTemplateBuilder::new()
+            .with_surface_type(ConfigSurfaceTypes::empty())
+            .build();
+
+        let config = unsafe { GlDisplay::find_configs(&display, template) }
+            .map_err(|e| e.to_string())?
+            .next()
+            .ok_or_else(|| "Cannot select a valid OpenGL configuration.".to_string())?;
+
+        let context_attributes = ContextAttributesBuilder::new()
+            .with_profile(GlProfile::Core)
+            .with_context_api(ContextApi::OpenGl(Some(Version::new(3, 3))))
+            .build(None);
+
+        let fallback_context_attributes = ContextAttributesBuilder::new()
+            .with_context_api(ContextApi::Gles(Some(Version::new(3, 0))))
+            .build(None);
+
+        // the shaders for GLES are selected if the desktop OpenGL is not available
+        let (context, is_gles) = unsafe {
+            match display.create_context(&config, &context_attributes) {
+                Ok(context) => (context, false),
+                Err(_) => display
+                    .create_context(&config, &fallback_context_attributes)
+                    .map(|context| (context, true))
+                    .map_err(|e| e.to_string())?,
+            }
+        };
+
+        let context = context
+            .make_current_surfaceless()
+            .map_err(|e| e.to_string())?;
+
+        let loader = |s: &str| {
+            let symbol = CString::new(s).unwrap();
+            display.get_proc_address(symbol.as_c_str()).cast()
+        };
+
+        let backend = if is_gles {
+            notan_glow::GlowBackend::new_es(loader)?
+        } else {
+            notan_glow::GlowBackend::new(loader)?
+        };
+
+        let mut gfx = Graphics::new(Box::new(backend))?;
+        gfx.set_size(width, height);
+
+        Ok(Self {
+            gfx,
+            _context: context,
+            _display: display,
+        })
+    }
+}
+
+impl Deref for HeadlessGraphics {
+    type Target = Graphics;
+
+    fn deref(&self) -> &Self::Target {
+        &self.gfx
+    }
+}
+
+impl DerefMut for HeadlessGraphics {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.gfx
+    }
+}
@@ -6,6 +6,10 @@ mod touch;
 mod window;
 
 mod gl_manager;
+#[cfg(not(any(target_os = "macos", target_os = "ios")))]
+mod headless;
 pub mod prelude;
 
 pub use backend::*;
+#[cfg(not(any(target_os = "macos", target_os = "ios")))]
+pub use headless::*;
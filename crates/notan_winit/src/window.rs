@@ -58,6 +58,12 @@ impl WindowBackend for WinitWindowBackend {
         self.window().has_focus()
     }
 
+    fn is_minimized(&self) -> bool {
+        // some platforms don't report it but the window's size is zero
+        let size = self.window().inner_size();
+        self.window().is_minimized().unwrap_or(false) || size.width == 0 || size.height == 0
+    }
+
     fn lazy_loop(&self) -> bool {
         self.lazy
     }
@@ -350,6 +356,7 @@ impl WinitWindowBackend {
             high_dpi,
             title,
             mouse_passthrough,
+            always_on_top,
             ..
         } = config;
 
@@ -361,7 +368,7 @@ impl WinitWindowBackend {
             captured: false,
             visible,
             high_dpi,
-            is_always_on_top: false,
+            is_always_on_top: always_on_top,
             mouse_passthrough,
             title,
             use_touch_as_mouse: false,
@@ -0,0 +1,100 @@
+use notan::app::{AppFlow, EventReader};
+use notan::draw::*;
+use notan::prelude::*;
+
+// User events sent through the bus
+struct Clicked {
+    x: f32,
+    y: f32,
+}
+
+struct Milestone(u32);
+
+// Plugin listening to the clicks without accessing the state
+struct ClickCounter {
+    reader: EventReader<Clicked>,
+    count: u32,
+}
+
+impl Plugin for ClickCounter {
+    fn update(&mut self, app: &mut App, _assets: &mut Assets) -> Result<AppFlow, String> {
+        // sends a milestone each 5 clicks
+        let milestones = self.count / 5;
+        self.count += app.bus.read(&mut self.reader).count() as u32;
+        if self.count / 5 > milestones {
+            app.bus.send(Milestone(self.count / 5 * 5));
+        }
+
+        Ok(AppFlow::Next)
+    }
+}
+
+#[derive(AppState)]
+struct State {
+    font: Font,
+    reader: EventReader<Milestone>,
+    milestone: u32,
+    points: Vec<(f32, f32)>,
+}
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init_with(setup)
+        .add_config(DrawConfig)
+        .add_plugin_with(|app: &mut App| ClickCounter {
+            reader: app.bus.subscribe(),
+            count: 0,
+        })
+        .update(update)
+        .draw(draw)
+        .build()
+}
+
+fn setup(app: &mut App, gfx: &mut Graphics) -> State {
+    let font = gfx
+        .create_font(include_bytes!("assets/Ubuntu-B.ttf"))
+        .unwrap();
+
+    State {
+        font,
+        reader: app.bus.subscribe(),
+        milestone: 0,
+        points: vec![],
+    }
+}
+
+fn update(app: &mut App, state: &mut State) {
+    if app.mouse.left_was_pressed() {
+        let (x, y) = app.mouse.position();
+        state.points.push((x, y));
+        app.bus.send(Clicked { x, y });
+    }
+
+    // The milestones sent by the plugin
+    if let Some(Milestone(count)) = app.bus.read(&mut state.reader).last() {
+        state.milestone = *count;
+    }
+}
+
+fn draw(app: &mut App, gfx: &mut Graphics, state: &mut State) {
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::BLACK);
+
+    state.points.iter().for_each(|(x, y)| {
+        draw.circle(10.0).position(*x, *y).color(Color::ORANGE);
+    });
+
+    // Events sent in this frame can be read until the end of the next one
+    app.bus.iter::<Clicked>().for_each(|Clicked { x, y }| {
+        draw.circle(20.0).position(*x, *y).color(Color::WHITE);
+    });
+
+    draw.text(
+        &state.font,
+        &format!("Click to send events, last milestone: {}", state.milestone),
+    )
+    .position(10.0, 10.0)
+    .size(20.0);
+
+    gfx.render(&draw);
+}
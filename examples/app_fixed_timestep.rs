@@ -0,0 +1,71 @@
+use notan::draw::*;
+use notan::prelude::*;
+use std::time::Duration;
+
+const SPEED: f32 = 300.0;
+
+#[derive(AppState)]
+struct State {
+    font: Font,
+    x: f32,
+    prev_x: f32,
+    dir: f32,
+}
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init_with(setup)
+        .add_config(DrawConfig)
+        // A slow rate to see the difference, games usually use 1/60 seconds
+        .fixed_timestep(Duration::from_secs_f64(1.0 / 10.0))
+        .update(update)
+        .draw(draw)
+        .build()
+}
+
+fn setup(gfx: &mut Graphics) -> State {
+    let font = gfx
+        .create_font(include_bytes!("assets/Ubuntu-B.ttf"))
+        .unwrap();
+
+    State {
+        font,
+        x: 100.0,
+        prev_x: 100.0,
+        dir: 1.0,
+    }
+}
+
+// Runs 10 times per second, the delta is always the timestep
+fn update(app: &mut App, state: &mut State) {
+    state.prev_x = state.x;
+    state.x += SPEED * state.dir * app.timer.delta_f32();
+    if state.x < 100.0 || state.x > 700.0 {
+        state.dir = -state.dir;
+        state.x = state.x.clamp(100.0, 700.0);
+    }
+}
+
+fn draw(app: &mut App, gfx: &mut Graphics, state: &mut State) {
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::BLACK);
+
+    // Last position updated
+    draw.circle(40.0)
+        .position(state.x, 200.0)
+        .color(Color::ORANGE);
+
+    // Position interpolated between the last two updates
+    let alpha = app.timer.alpha();
+    let x = state.prev_x + (state.x - state.prev_x) * alpha;
+    draw.circle(40.0).position(x, 400.0).color(Color::GREEN);
+
+    draw.text(&state.font, "Without interpolation")
+        .position(10.0, 10.0)
+        .size(20.0);
+    draw.text(&state.font, "With interpolation")
+        .position(10.0, 300.0)
+        .size(20.0);
+
+    gfx.render(&draw);
+}
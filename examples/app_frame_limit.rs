@@ -0,0 +1,57 @@
+use notan::draw::*;
+use notan::prelude::*;
+
+#[derive(AppState)]
+struct State {
+    font: Font,
+}
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    let win = WindowConfig::new().set_vsync(false);
+
+    notan::init_with(setup)
+        .add_config(win)
+        .add_config(DrawConfig)
+        // Run at 30 fps, and at 5 fps if the window is unfocused or minimized
+        .frame_limit(30.0)
+        .idle_frame_limit(5.0)
+        .draw(draw)
+        .build()
+}
+
+fn setup(gfx: &mut Graphics) -> State {
+    let font = gfx
+        .create_font(include_bytes!("assets/Ubuntu-B.ttf"))
+        .unwrap();
+    State { font }
+}
+
+fn draw(app: &mut App, gfx: &mut Graphics, state: &mut State) {
+    // Press Space to toggle the limit
+    if app.keyboard.was_pressed(KeyCode::Space) {
+        let fps = match app.limiter.fps() {
+            Some(_) => None,
+            None => Some(30.0),
+        };
+        app.limiter.set_fps(fps);
+    }
+
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::BLACK);
+    draw.text(&state.font, &format!("{:.0} fps", app.timer.fps()))
+        .position(400.0, 300.0)
+        .size(40.0)
+        .h_align_center()
+        .v_align_middle();
+
+    let limit = app
+        .limiter
+        .fps()
+        .map_or_else(|| "No limit".to_string(), |fps| format!("Limit: {fps:.0}"));
+    draw.text(&state.font, &format!("{limit} (Space to toggle)"))
+        .position(10.0, 10.0)
+        .size(20.0);
+
+    gfx.render(&draw);
+}
@@ -0,0 +1,144 @@
+use notan::draw::*;
+use notan::prelude::*;
+
+// Data shared by all the scenes
+struct Shared {
+    font: Font,
+    score: u32,
+}
+
+#[derive(AppState)]
+struct State {
+    scenes: SceneStack<Shared>,
+    shared: Shared,
+}
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init_with(setup)
+        .add_config(DrawConfig)
+        .update(update)
+        .draw(draw)
+        .build()
+}
+
+fn setup(gfx: &mut Graphics) -> State {
+    let font = gfx
+        .create_font(include_bytes!("assets/Ubuntu-B.ttf"))
+        .unwrap();
+
+    let mut scenes = SceneStack::new(Menu);
+    scenes.set_effect(fade, 0.5);
+
+    State {
+        scenes,
+        shared: Shared { font, score: 0 },
+    }
+}
+
+fn update(app: &mut App, assets: &mut Assets, state: &mut State) {
+    state.scenes.update(app, assets, &mut state.shared);
+}
+
+fn draw(app: &mut App, gfx: &mut Graphics, state: &mut State) {
+    state.scenes.draw(app, gfx, &mut state.shared);
+}
+
+// Fades to black while the scene leaves and from black while the next one enters
+fn fade(_app: &mut App, gfx: &mut Graphics, progress: f32) {
+    let (width, height) = gfx.size();
+    let mut draw = gfx.create_draw();
+    draw.rect((0.0, 0.0), (width as _, height as _))
+        .color(Color::BLACK)
+        .alpha(progress);
+    gfx.render(&draw);
+}
+
+fn draw_text(gfx: &mut Graphics, shared: &Shared, color: Color, lines: &[&str]) {
+    let mut draw = gfx.create_draw();
+    draw.clear(color);
+    lines.iter().enumerate().for_each(|(i, line)| {
+        draw.text(&shared.font, line)
+            .position(400.0, 250.0 + i as f32 * 50.0)
+            .size(30.0)
+            .h_align_center()
+            .v_align_middle();
+    });
+    gfx.render(&draw);
+}
+
+struct Menu;
+
+impl Scene<Shared> for Menu {
+    fn update(&mut self, app: &mut App, _: &mut Assets, _: &mut Shared) -> SceneChange<Shared> {
+        if app.keyboard.was_pressed(KeyCode::Return) {
+            return SceneChange::replace(Game);
+        }
+
+        SceneChange::None
+    }
+
+    fn draw(&mut self, _: &mut App, gfx: &mut Graphics, shared: &mut Shared) {
+        draw_text(gfx, shared, Color::PURPLE, &["Menu", "Press Enter to play"]);
+    }
+}
+
+struct Game;
+
+impl Scene<Shared> for Game {
+    fn enter(&mut self, _: &mut App, _: &mut Assets, shared: &mut Shared) {
+        shared.score = 0;
+    }
+
+    fn update(
+        &mut self,
+        app: &mut App,
+        _: &mut Assets,
+        shared: &mut Shared,
+    ) -> SceneChange<Shared> {
+        if app.keyboard.was_pressed(KeyCode::Space) {
+            shared.score += 1;
+        }
+
+        if app.keyboard.was_pressed(KeyCode::Escape) {
+            return SceneChange::push(Pause);
+        }
+
+        SceneChange::None
+    }
+
+    fn draw(&mut self, _: &mut App, gfx: &mut Graphics, shared: &mut Shared) {
+        let score = format!("Score: {}", shared.score);
+        draw_text(gfx, shared, Color::OLIVE, &[&score, "Press Space to score"]);
+    }
+}
+
+struct Pause;
+
+impl Scene<Shared> for Pause {
+    fn update(&mut self, app: &mut App, _: &mut Assets, _: &mut Shared) -> SceneChange<Shared> {
+        if app.keyboard.was_pressed(KeyCode::Escape) {
+            return SceneChange::Pop;
+        }
+
+        SceneChange::None
+    }
+
+    fn draw(&mut self, _: &mut App, gfx: &mut Graphics, shared: &mut Shared) {
+        let mut draw = gfx.create_draw();
+        draw.rect((200.0, 150.0), (400.0, 300.0))
+            .color(Color::BLACK)
+            .alpha(0.8);
+        draw.text(&shared.font, "Paused\nPress Esc to resume")
+            .position(400.0, 300.0)
+            .size(30.0)
+            .h_align_center()
+            .v_align_middle();
+        gfx.render(&draw);
+    }
+
+    // Keeps the game visible below the pause menu
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
@@ -2,6 +2,7 @@ use notan::draw::*;
 use notan::prelude::*;
 
 // Create a new asset loaded to load .txt files as strings
+// Parsers that don't need the app or graphics can use `use_async_parser` to run on a worker thread
 fn create_text_loader() -> AssetLoader {
     AssetLoader::new().use_parser(parse_text).extension("txt")
 }
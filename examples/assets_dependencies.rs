@@ -0,0 +1,90 @@
+use notan::draw::*;
+use notan::prelude::*;
+use std::path::Path;
+
+// An atlas with its texture, both loaded as one asset
+struct Atlas {
+    data: TextureAtlas,
+    texture: Asset<Texture>,
+}
+
+// The texture is a dependency of the atlas, so it's loaded before parsing the atlas
+fn create_atlas_loader() -> AssetLoader {
+    AssetLoader::new()
+        .use_dependencies(|id, data| Ok(vec![image_path(id, &TextureAtlas::from_bytes(data)?)]))
+        .use_parser(parse_atlas)
+        .extension("json")
+}
+
+fn parse_atlas(id: &str, data: Vec<u8>, dependencies: &Dependencies) -> Result<Atlas, String> {
+    let data = TextureAtlas::from_bytes(&data)?;
+    let texture = dependencies.get::<Texture>(&image_path(id, &data))?;
+    Ok(Atlas { data, texture })
+}
+
+// The image is relative to the json file
+fn image_path(id: &str, atlas: &TextureAtlas) -> String {
+    let parent = Path::new(id).parent().unwrap_or_else(|| Path::new(""));
+    parent
+        .join(atlas.image_path())
+        .to_string_lossy()
+        .to_string()
+}
+
+#[derive(AppState)]
+struct State {
+    atlas: Asset<Atlas>,
+}
+
+impl State {
+    fn new(assets: &mut Assets) -> Self {
+        let atlas = assets.load_asset(&asset_path("sunnyland.json")).unwrap();
+        Self { atlas }
+    }
+}
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init_with(State::new)
+        .add_config(DrawConfig)
+        .add_loader(create_atlas_loader())
+        .draw(draw)
+        .build()
+}
+
+fn draw(gfx: &mut Graphics, state: &mut State) {
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::BLACK);
+
+    // The texture is already loaded when the atlas is loaded
+    if let Some(atlas) = state.atlas.lock() {
+        let texture = atlas.texture.lock().unwrap();
+        let mut names = atlas.data.names().collect::<Vec<_>>();
+        names.sort();
+
+        let (mut x, mut y) = (20.0, 20.0);
+        names.iter().for_each(|name| {
+            let region = atlas.data.region(name, &texture).unwrap();
+            if x + region.width() > 780.0 {
+                x = 20.0;
+                y += 90.0;
+            }
+
+            draw.image_region(region).position(x, y);
+            x += region.width() + 20.0;
+        });
+    }
+
+    gfx.render(&draw);
+}
+
+// The relative path for the example is different on browsers
+fn asset_path(path: &str) -> String {
+    let base = if cfg!(target_arch = "wasm32") {
+        "./assets"
+    } else {
+        "./examples/assets"
+    };
+
+    format!("{base}/{path}")
+}
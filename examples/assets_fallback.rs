@@ -0,0 +1,89 @@
+use notan::draw::*;
+use notan::prelude::*;
+
+#[derive(AppState)]
+struct State {
+    font: Font,
+    texture: Asset<Texture>,
+}
+
+impl State {
+    fn new(assets: &mut Assets, gfx: &mut Graphics) -> Self {
+        // The textures that fail to load will use this one
+        assets.set_fallback(create_checkerboard(gfx));
+
+        // This file doesn't exist
+        let texture = assets.load_asset(&asset_path("missing.png")).unwrap();
+
+        // Load a font only for debug info
+        let font = gfx
+            .create_font(include_bytes!("assets/Ubuntu-B.ttf"))
+            .unwrap();
+
+        Self { font, texture }
+    }
+}
+
+// A magenta and black checkerboard easy to spot
+fn create_checkerboard(gfx: &mut Graphics) -> Texture {
+    let bytes = (0..16)
+        .flat_map(|i| {
+            let (x, y) = (i % 4, i / 4);
+            if (x + y) % 2 == 0 {
+                [255, 0, 255, 255]
+            } else {
+                [0, 0, 0, 255]
+            }
+        })
+        .collect::<Vec<u8>>();
+
+    gfx.create_texture()
+        .from_bytes(&bytes, 4, 4)
+        .with_filter(TextureFilter::Nearest, TextureFilter::Nearest)
+        .build()
+        .unwrap()
+}
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init_with(State::new)
+        .add_config(DrawConfig)
+        .draw(draw)
+        .build()
+}
+
+fn draw(gfx: &mut Graphics, state: &mut State) {
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::BLACK);
+
+    if state.texture.is_loaded() {
+        draw.image(&state.texture.lock().unwrap())
+            .position(250.0, 150.0)
+            .size(300.0, 300.0);
+
+        if state.texture.is_fallback() {
+            let error = state.texture.error().unwrap_or_default();
+            draw.text(&state.font, &format!("Using the fallback: {error}"))
+                .max_width(780.0)
+                .position(10.0, 10.0)
+                .size(20.0);
+        }
+    } else {
+        draw.text(&state.font, "Loading...")
+            .position(10.0, 10.0)
+            .size(25.0);
+    }
+
+    gfx.render(&draw);
+}
+
+// The relative path for the example is different on browsers
+fn asset_path(path: &str) -> String {
+    let base = if cfg!(target_arch = "wasm32") {
+        "./assets"
+    } else {
+        "./examples/assets"
+    };
+
+    format!("{base}/{path}")
+}
@@ -0,0 +1,38 @@
+use notan::draw::*;
+use notan::prelude::*;
+
+#[derive(AppState)]
+struct State {
+    texture: Asset<Texture>,
+}
+
+impl State {
+    fn new(assets: &mut Assets) -> Self {
+        let texture = assets
+            .load_asset("./examples/assets/rust-logo-512x512.png")
+            .unwrap();
+
+        Self { texture }
+    }
+}
+
+// Edit and save the image while the app is running to see the changes
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init_with(State::new)
+        .add_config(DrawConfig)
+        .draw(draw)
+        .build()
+}
+
+fn draw(gfx: &mut Graphics, state: &mut State) {
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::BLACK);
+
+    // the handle is the same, but the texture inside is replaced when the file changes
+    if let Some(texture) = state.texture.lock() {
+        draw.image(&texture).position(150.0, 50.0);
+    }
+
+    gfx.render(&draw);
+}
@@ -55,6 +55,7 @@ fn update(state: &mut State) {
         // Remove the asset from the list taking the ownership
         match state.list.take::<Texture>(&logo_path) {
             Ok(asset) => {
+                // Without a cache budget the assets cache doesn't keep a reference to it
                 let logo = asset.try_unwrap().unwrap();
                 state.logo = Some(logo);
             }
@@ -84,9 +85,18 @@ fn draw(gfx: &mut Graphics, state: &mut State) {
     .position(10.0, 10.0)
     .size(25.0);
 
+    // The bytes are known only when the source can read the size of the files
+    let bytes = match state.list.bytes_total() {
+        Some(total) => format!("{}/{} bytes", state.list.bytes_loaded(), total),
+        None => format!("{} bytes", state.list.bytes_loaded()),
+    };
+    draw.text(&state.font, &bytes)
+        .position(10.0, 40.0)
+        .size(20.0);
+
     // If the list is loaded we draw the rust logo
     if let Some(tex) = &state.logo {
-        draw.image(tex).position(150.0, 50.0);
+        draw.image(tex).position(150.0, 80.0);
     }
 
     gfx.render(&draw);
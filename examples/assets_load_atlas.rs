@@ -0,0 +1,62 @@
+use notan::draw::*;
+use notan::prelude::*;
+
+#[derive(AppState)]
+struct State {
+    atlas: Asset<TextureAtlas>,
+}
+
+impl State {
+    fn new(assets: &mut Assets) -> Self {
+        let atlas = assets.load_asset(&asset_path("sunnyland.json")).unwrap();
+        Self { atlas }
+    }
+}
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init_with(State::new)
+        .add_config(DrawConfig)
+        // The json parser is not added by default
+        .add_loader(create_atlas_parser())
+        .draw(draw)
+        .build()
+}
+
+fn draw(gfx: &mut Graphics, state: &mut State) {
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::BLACK);
+
+    // The image of the atlas is loaded before the atlas
+    if let Some(atlas) = state.atlas.lock() {
+        let texture = atlas.base_texture().unwrap().lock().unwrap();
+        let mut names = atlas.names().collect::<Vec<_>>();
+        names.sort();
+
+        let (mut x, mut y) = (20.0, 20.0);
+        names.iter().for_each(|name| {
+            // regions use the atlas texture without cloning it
+            let region = atlas.region(name, &texture).unwrap();
+            if x + region.width() > 780.0 {
+                x = 20.0;
+                y += 90.0;
+            }
+
+            draw.image_region(region).position(x, y);
+            x += region.width() + 20.0;
+        });
+    }
+
+    gfx.render(&draw);
+}
+
+// The relative path for the example is different on browsers
+fn asset_path(path: &str) -> String {
+    let base = if cfg!(target_arch = "wasm32") {
+        "./assets"
+    } else {
+        "./examples/assets"
+    };
+
+    format!("{base}/{path}")
+}
@@ -0,0 +1,89 @@
+use notan::app::assets::LoadBudget;
+use notan::draw::*;
+use notan::prelude::*;
+
+// Big files like music or videos can be parsed while they are read
+#[derive(Default)]
+struct Track {
+    chunks: usize,
+    bytes: usize,
+}
+
+// The parser receives the chunks of the file, the asset can be used once it's created
+fn create_track_loader() -> AssetLoader {
+    AssetLoader::new()
+        .use_stream_parser(parse_track)
+        .extension("ogg")
+}
+
+fn parse_track(_id: &str, chunk: StreamChunk, track: &mut Option<Track>) -> Result<(), String> {
+    let track = track.get_or_insert_with(Track::default);
+    if let StreamChunk::Data(data) = chunk {
+        track.chunks += 1;
+        track.bytes += data.len();
+    }
+
+    Ok(())
+}
+
+#[derive(AppState)]
+struct State {
+    font: Font,
+    track: Asset<Track>,
+}
+
+impl State {
+    fn new(assets: &mut Assets, gfx: &mut Graphics) -> Self {
+        // One chunk each frame, so it's easier to see
+        assets.set_load_budget(LoadBudget::default().with_bytes(1));
+        let track = assets.load_asset(&asset_path("jingles_NES00.ogg")).unwrap();
+
+        let font = gfx
+            .create_font(include_bytes!("assets/Ubuntu-B.ttf"))
+            .unwrap();
+
+        Self { font, track }
+    }
+}
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init_with(State::new)
+        .add_config(DrawConfig)
+        .add_loader(create_track_loader())
+        .draw(draw)
+        .build()
+}
+
+fn draw(gfx: &mut Graphics, state: &mut State) {
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::BLACK);
+
+    let status = if state.track.is_loaded() {
+        "Loaded"
+    } else {
+        "Loading..."
+    };
+
+    let text = match state.track.lock() {
+        Some(track) => format!("{status}\nChunks: {}\nBytes: {}", track.chunks, track.bytes),
+        None => status.to_string(),
+    };
+
+    draw.text(&state.font, &text)
+        .position(10.0, 10.0)
+        .size(25.0);
+
+    gfx.render(&draw);
+}
+
+// The relative path for the example is different on browsers
+fn asset_path(path: &str) -> String {
+    let base = if cfg!(target_arch = "wasm32") {
+        "./assets"
+    } else {
+        "./examples/assets"
+    };
+
+    format!("{base}/{path}")
+}
@@ -52,6 +52,7 @@ fn update(state: &mut State) {
         let asset = state.loading.take().unwrap();
 
         // Unwrap the asset reference to get the inner
+        // Without a cache budget the assets cache doesn't keep a reference to it
         let texture = asset.try_unwrap().unwrap();
         state.loaded = Some(texture);
     }
@@ -0,0 +1,63 @@
+use notan::draw::*;
+use notan::prelude::*;
+
+#[derive(AppState)]
+struct State {
+    walk: Animation,
+    jump: Animation,
+}
+
+impl State {
+    fn new(gfx: &mut Graphics) -> Self {
+        let texture = gfx
+            .create_texture()
+            .from_image(include_bytes!("assets/golem-walk.png"))
+            .build()
+            .unwrap();
+
+        // every frame of the grid is displayed for 0.1 seconds
+        let walk = Animation::from_grid(&texture, 7, 4, 0.1, AnimationMode::Loop);
+
+        // frames can have their own duration too
+        let frame = texture.frame();
+        let (width, height) = (frame.width / 7.0, frame.height / 4.0);
+        let mut jump = Animation::new(AnimationMode::PingPong);
+        (0..7).for_each(|i| {
+            let duration = if i == 3 { 0.5 } else { 0.08 };
+            jump.add_frame(
+                texture.with_frame(i as f32 * width, height * 2.0, width, height),
+                duration,
+            );
+        });
+
+        Self { walk, jump }
+    }
+}
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init_with(State::new)
+        .add_config(DrawConfig)
+        .draw(draw)
+        .build()
+}
+
+fn draw(app: &mut App, gfx: &mut Graphics, state: &mut State) {
+    let delta = app.timer.delta_f32();
+    state.walk.update(delta);
+    state.jump.update(delta);
+
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::BLACK);
+
+    // nothing is drawn if the animations don't have frames
+    if let Some(mut walk) = draw.animation(&state.walk) {
+        walk.position(150.0, 200.0);
+    }
+
+    if let Some(mut jump) = draw.animation(&state.jump) {
+        jump.position(450.0, 200.0).flip_x(true);
+    }
+
+    gfx.render(&draw);
+}
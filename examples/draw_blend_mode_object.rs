@@ -61,6 +61,8 @@ fn draw(app: &mut App, gfx: &mut Graphics, state: &mut State) {
         ("Erase", BlendMode::ERASE),
         ("Screen", BlendMode::SCREEN),
         ("Multiply", BlendMode::MULTIPLY),
+        ("Subtract", BlendMode::SUBTRACT),
+        ("Darken", BlendMode::custom(BlendFactor::One, BlendFactor::One, BlendOperation::Min)),
     ];
 
     modes.iter().enumerate().for_each(|(i, (name, mode))| {
@@ -13,6 +13,7 @@ struct State {
     texture: Texture,
     rng: Random,
     bunnies: Vec<Bunny>,
+    sprites: Vec<SpriteInstance>,
 }
 
 impl State {
@@ -32,6 +33,7 @@ impl State {
             texture,
             rng: Random::default(),
             bunnies: vec![],
+            sprites: vec![],
         }
     }
 
@@ -85,9 +87,16 @@ fn update(app: &mut App, state: &mut State) {
 fn draw(app: &mut App, gfx: &mut Graphics, state: &mut State) {
     let mut draw = gfx.create_draw();
     draw.clear([0.0, 0.0, 0.0, 1.0].into());
-    state.bunnies.iter().for_each(|b| {
-        draw.image(&state.texture).position(b.pos.x, b.pos.y);
-    });
+
+    // all the bunnies are sent in just one call
+    state.sprites.clear();
+    state.sprites.extend(
+        state
+            .bunnies
+            .iter()
+            .map(|b| SpriteInstance::new(b.pos.x, b.pos.y)),
+    );
+    draw.images(&state.texture, &state.sprites);
 
     draw.text(
         &state.font,
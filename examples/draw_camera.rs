@@ -0,0 +1,79 @@
+use notan::draw::*;
+use notan::prelude::*;
+
+#[derive(AppState)]
+struct State {
+    camera: Camera2D,
+    player: (f32, f32),
+}
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init_with(|| State {
+        camera: Camera2D::new(800.0, 600.0),
+        player: (400.0, 300.0),
+    })
+    .add_config(DrawConfig)
+    .update(update)
+    .draw(draw)
+    .build()
+}
+
+fn update(app: &mut App, state: &mut State) {
+    let speed = 300.0 * app.timer.delta_f32();
+    if app.keyboard.is_down(KeyCode::W) {
+        state.player.1 -= speed;
+    }
+    if app.keyboard.is_down(KeyCode::S) {
+        state.player.1 += speed;
+    }
+    if app.keyboard.is_down(KeyCode::A) {
+        state.player.0 -= speed;
+    }
+    if app.keyboard.is_down(KeyCode::D) {
+        state.player.0 += speed;
+    }
+
+    if app.mouse.is_scrolling() {
+        let zoom = state.camera.zoom() + app.mouse.wheel_delta.y * 0.001;
+        state.camera.set_zoom(zoom.clamp(0.25, 4.0));
+    }
+
+    // smoothly follow the player
+    let (x, y) = state.player;
+    state.camera.follow_smooth(x, y, 0.1);
+}
+
+fn draw(app: &mut App, gfx: &mut Graphics, state: &mut State) {
+    let (width, height) = gfx.size();
+    state.camera.set_size(width as _, height as _);
+
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::BLACK);
+    state.camera.apply(&mut draw);
+
+    // world grid
+    for x in -10..10i32 {
+        for y in -10..10 {
+            let color = if (x + y).rem_euclid(2) == 0 {
+                Color::GRAY
+            } else {
+                Color::SILVER
+            };
+
+            draw.rect((x as f32 * 100.0, y as f32 * 100.0), (100.0, 100.0))
+                .color(color);
+        }
+    }
+
+    let (x, y) = state.player;
+    draw.circle(20.0).position(x, y).color(Color::RED);
+
+    // mouse position converted to the world space used by the camera
+    let mouse = draw.screen_to_world_position(app.mouse.x, app.mouse.y);
+    draw.circle(5.0)
+        .position(mouse.x, mouse.y)
+        .color(Color::YELLOW);
+
+    gfx.render(&draw);
+}
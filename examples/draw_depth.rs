@@ -0,0 +1,27 @@
+use notan::draw::*;
+use notan::prelude::*;
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init().add_config(DrawConfig).draw(draw).build()
+}
+
+fn draw(gfx: &mut Graphics) {
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::BLACK);
+
+    // the elements are sorted by depth, not by the order they are drawn
+    draw.set_depth_test(true);
+
+    draw.set_depth(0.5);
+    draw.rect((300.0, 200.0), (200.0, 200.0)).color(Color::RED);
+
+    draw.set_depth(-0.5);
+    draw.circle(120.0).position(400.0, 300.0).color(Color::BLUE);
+
+    draw.set_depth(0.8);
+    draw.triangle((400.0, 250.0), (450.0, 350.0), (350.0, 350.0))
+        .color(Color::YELLOW);
+
+    gfx.render(&draw);
+}
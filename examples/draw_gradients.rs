@@ -0,0 +1,60 @@
+use notan::draw::*;
+use notan::prelude::*;
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init().add_config(DrawConfig).draw(draw).build()
+}
+
+fn draw(gfx: &mut Graphics) {
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::BLACK);
+
+    // gradient using two points
+    draw.rect((40.0, 40.0), (340.0, 200.0)).fill_gradient(
+        Gradient::linear((40.0, 40.0), (380.0, 40.0))
+            .color_stop(0.0, Color::RED)
+            .color_stop(1.0, Color::BLUE),
+    );
+
+    // gradient using an angle and more than two colors
+    draw.rect((420.0, 40.0), (340.0, 200.0))
+        .corner_radius(20.0)
+        .fill_gradient(
+            Gradient::linear_with_angle((420.0, 40.0), (340.0, 200.0), 45f32.to_radians())
+                .color_stop(0.0, Color::YELLOW)
+                .color_stop(0.5, Color::ORANGE)
+                .color_stop(1.0, Color::PURPLE),
+        );
+
+    draw.circle(100.0)
+        .position(210.0, 420.0)
+        .fill_gradient(
+            Gradient::linear((210.0, 320.0), (210.0, 520.0))
+                .color_stop(0.0, Color::WHITE)
+                .color_stop(1.0, Color::GREEN),
+        )
+        .fill()
+        .stroke(4.0);
+
+    draw.star(6, 110.0, 60.0)
+        .position(590.0, 420.0)
+        .fill_gradient(
+            Gradient::linear((480.0, 420.0), (700.0, 420.0))
+                .color_stop(0.0, Color::AQUA)
+                .color_stop(1.0, Color::MAGENTA),
+        );
+
+    // radial gradients
+    draw.circle(60.0)
+        .position(400.0, 420.0)
+        .fill_radial_gradient(Color::WHITE, Color::new(1.0, 1.0, 0.0, 0.0));
+
+    draw.rect((330.0, 520.0), (140.0, 60.0)).fill_gradient(
+        Gradient::radial((400.0, 550.0), 70.0)
+            .color_stop(0.0, Color::TRANSPARENT)
+            .color_stop(1.0, Color::GRAY),
+    );
+
+    gfx.render(&draw);
+}
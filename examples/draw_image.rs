@@ -26,6 +26,16 @@ fn init(gfx: &mut Graphics) -> State {
 fn draw(gfx: &mut Graphics, state: &mut State) {
     let mut draw = gfx.create_draw();
     draw.clear(Color::BLACK);
-    draw.image(&state.img).position(250.0, 200.0);
+    draw.image(&state.img).position(250.0, 100.0);
+
+    // fade out to the bottom using a color per corner
+    draw.image(&state.img)
+        .position(250.0, 300.0)
+        .corner_colors([
+            Color::WHITE,
+            Color::WHITE,
+            Color::WHITE.with_alpha(0.0),
+            Color::WHITE.with_alpha(0.0),
+        ]);
     gfx.render(&draw);
 }
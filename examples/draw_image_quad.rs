@@ -0,0 +1,53 @@
+use notan::draw::*;
+use notan::prelude::*;
+
+#[derive(AppState)]
+struct State {
+    img: Texture,
+}
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init_with(init)
+        .add_config(DrawConfig)
+        .draw(draw)
+        .build()
+}
+
+fn init(gfx: &mut Graphics) -> State {
+    let texture = gfx
+        .create_texture()
+        .from_image(include_bytes!("assets/pattern.png"))
+        // uvs bigger than 1.0 will repeat the texture
+        .with_wrap(TextureWrap::Repeat, TextureWrap::Repeat)
+        .build()
+        .unwrap();
+    State { img: texture }
+}
+
+fn draw(app: &mut App, gfx: &mut Graphics, state: &mut State) {
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::BLACK);
+
+    // floor in perspective, the subdivisions reduce the distortion on the diagonal
+    draw.image_quad(
+        &state.img,
+        [(300.0, 320.0), (500.0, 320.0), (0.0, 600.0), (800.0, 600.0)],
+    )
+    .uvs([(0.0, 0.0), (4.0, 0.0), (0.0, 4.0), (4.0, 4.0)])
+    .subdivisions(16);
+
+    // sprite sheared by the wind
+    let shear = (app.timer.elapsed_f32() * 2.0).sin() * 40.0;
+    draw.image_quad(
+        &state.img,
+        [
+            (340.0 + shear, 60.0),
+            (460.0 + shear, 60.0),
+            (340.0, 180.0),
+            (460.0, 180.0),
+        ],
+    );
+
+    gfx.render(&draw);
+}
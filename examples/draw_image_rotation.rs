@@ -0,0 +1,42 @@
+use notan::draw::*;
+use notan::prelude::*;
+
+#[derive(AppState)]
+struct State {
+    img: Texture,
+}
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init_with(init)
+        .add_config(DrawConfig)
+        .draw(draw)
+        .build()
+}
+
+fn init(gfx: &mut Graphics) -> State {
+    let texture = gfx
+        .create_texture()
+        .from_image(include_bytes!("assets/ferris.png"))
+        .build()
+        .unwrap();
+    State { img: texture }
+}
+
+fn draw(app: &mut App, gfx: &mut Graphics, state: &mut State) {
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::BLACK);
+
+    let angle = app.timer.elapsed_f32();
+
+    // rotates around the center by default
+    draw.image(&state.img).position(50.0, 200.0).rotation(angle);
+
+    // rotates around the top-left corner
+    draw.image(&state.img)
+        .position(450.0, 200.0)
+        .origin(0.0, 0.0)
+        .rotation(-angle);
+
+    gfx.render(&draw);
+}
@@ -0,0 +1,55 @@
+use notan::draw::*;
+use notan::prelude::*;
+
+#[derive(AppState)]
+struct State {
+    vertices: Vec<MeshVertex>,
+    indices: Vec<u32>,
+}
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init_with(setup)
+        .add_config(DrawConfig)
+        .draw(draw)
+        .build()
+}
+
+fn setup() -> State {
+    // hexagon with a white center and a different color on each corner
+    let colors = [
+        Color::RED,
+        Color::YELLOW,
+        Color::GREEN,
+        Color::AQUA,
+        Color::BLUE,
+        Color::MAGENTA,
+    ];
+
+    let mut vertices = vec![MeshVertex::new(0.0, 0.0, Color::WHITE)];
+    let mut indices = vec![];
+    colors.iter().enumerate().for_each(|(i, color)| {
+        let angle = (i as f32 * 60.0).to_radians();
+        vertices.push(MeshVertex::new(
+            angle.cos() * 200.0,
+            angle.sin() * 200.0,
+            *color,
+        ));
+
+        let next = (i as u32 + 1) % colors.len() as u32;
+        indices.extend_from_slice(&[0, i as u32 + 1, next + 1]);
+    });
+
+    State { vertices, indices }
+}
+
+fn draw(app: &mut App, gfx: &mut Graphics, state: &mut State) {
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::BLACK);
+
+    draw.mesh(&state.vertices, &state.indices)
+        .rotate(app.timer.elapsed_f32() * 0.5)
+        .translate(400.0, 300.0);
+
+    gfx.render(&draw);
+}
@@ -66,5 +66,14 @@ fn draw(gfx: &mut Graphics, state: &mut State) {
         state.img2.height() + state.count,
     );
 
+    // the edges and the center are repeated instead of stretched
+    draw.nine_slice(&state.img2)
+        .position(450.0, 320.0)
+        .size(
+            state.img2.width() + state.count,
+            state.img2.height() + state.count,
+        )
+        .tiled(true);
+
     gfx.render(&draw);
 }
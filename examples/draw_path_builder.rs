@@ -0,0 +1,56 @@
+use notan::draw::*;
+use notan::prelude::*;
+
+#[derive(AppState)]
+struct State {
+    heart: TessellatedPath,
+    outline: TessellatedPath,
+}
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init_with(setup)
+        .add_config(DrawConfig)
+        .draw(draw)
+        .build()
+}
+
+fn setup() -> State {
+    // the path is tessellated just once and reused every frame
+    let mut builder = PathBuilder::new();
+    builder
+        .move_to(0.0, -20.0)
+        .cubic_bezier_to((30.0, -60.0), (80.0, -10.0), (0.0, 50.0))
+        .cubic_bezier_to((-80.0, -10.0), (-30.0, -60.0), (0.0, -20.0))
+        .close();
+
+    State {
+        heart: builder.fill(),
+        outline: builder.stroke(4.0),
+    }
+}
+
+fn draw(app: &mut App, gfx: &mut Graphics, state: &mut State) {
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::BLACK);
+
+    let time = app.timer.elapsed_f32();
+    for y in 0..4 {
+        for x in 0..6 {
+            let (xx, yy) = (80.0 + x as f32 * 128.0, 90.0 + y as f32 * 140.0);
+            let scale = 0.9 + ((time * 4.0) + (x + y) as f32).sin() * 0.1;
+
+            draw.tessellated(&state.heart)
+                .color(Color::RED)
+                .scale(scale, scale)
+                .translate(xx, yy);
+
+            draw.tessellated(&state.outline)
+                .color(Color::WHITE)
+                .scale(scale, scale)
+                .translate(xx, yy);
+        }
+    }
+
+    gfx.render(&draw);
+}
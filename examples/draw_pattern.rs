@@ -31,6 +31,8 @@ fn init(gfx: &mut Graphics) -> State {
     let texture = gfx
         .create_texture()
         .from_image(include_bytes!("assets/pattern.png"))
+        // the pattern is tiled by the gpu when the texture repeats
+        .with_wrap(TextureWrap::Repeat, TextureWrap::Repeat)
         .build()
         .unwrap();
 
@@ -0,0 +1,46 @@
+use notan::draw::*;
+use notan::prelude::*;
+
+#[derive(AppState)]
+struct State {
+    img: Texture,
+}
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init_with(init)
+        .add_config(DrawConfig)
+        .draw(draw)
+        .build()
+}
+
+fn init(gfx: &mut Graphics) -> State {
+    let texture = gfx
+        .create_texture()
+        .from_image(include_bytes!("assets/pattern.png"))
+        .build()
+        .unwrap();
+    State { img: texture }
+}
+
+fn draw(app: &mut App, gfx: &mut Graphics, state: &mut State) {
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::BLACK);
+
+    let time = app.timer.elapsed_f32();
+
+    // angled background scrolling to the right
+    draw.pattern(&state.img)
+        .size(800.0, 300.0)
+        .image_rotation_degrees(30.0)
+        .image_offset(-time * 40.0, 0.0);
+
+    // the image rotates around the center of the quad
+    draw.pattern(&state.img)
+        .position(0.0, 300.0)
+        .size(800.0, 300.0)
+        .image_anchor(0.5, 0.5)
+        .image_rotation(time * 0.5);
+
+    gfx.render(&draw);
+}
@@ -0,0 +1,50 @@
+use notan::draw::*;
+use notan::prelude::*;
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init().add_config(DrawConfig).draw(draw).build()
+}
+
+fn draw(gfx: &mut Graphics) {
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::BLACK);
+
+    // concave shape
+    draw.complex_polygon(&[
+        (60.0, 80.0),
+        (320.0, 80.0),
+        (200.0, 200.0),
+        (320.0, 320.0),
+        (60.0, 320.0),
+        (140.0, 200.0),
+    ])
+    .fill_color(Color::ORANGE)
+    .fill()
+    .stroke_color(Color::WHITE)
+    .stroke(4.0);
+
+    // island with two lakes
+    draw.polygon_with_holes(
+        &[
+            (420.0, 120.0),
+            (600.0, 60.0),
+            (760.0, 180.0),
+            (700.0, 420.0),
+            (520.0, 540.0),
+            (400.0, 380.0),
+        ],
+        &[
+            &[(480.0, 180.0), (580.0, 160.0), (560.0, 260.0)],
+            &[
+                (580.0, 340.0),
+                (680.0, 300.0),
+                (640.0, 420.0),
+                (560.0, 440.0),
+            ],
+        ],
+    )
+    .color(Color::GREEN);
+
+    gfx.render(&draw);
+}
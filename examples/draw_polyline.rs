@@ -0,0 +1,48 @@
+use notan::draw::*;
+use notan::prelude::*;
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init().add_config(DrawConfig).draw(draw).build()
+}
+
+fn draw(gfx: &mut Graphics) {
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::BLACK);
+
+    let points = [(0.0, 0.0), (60.0, 120.0), (120.0, 0.0), (180.0, 120.0)];
+    let offset = |x: f32, y: f32| {
+        points
+            .iter()
+            .map(|(px, py)| (px + x, py + y))
+            .collect::<Vec<_>>()
+    };
+
+    draw.polyline(&offset(60.0, 60.0))
+        .color(Color::ORANGE)
+        .width(20.0);
+
+    draw.polyline(&offset(300.0, 60.0))
+        .color(Color::AQUA)
+        .width(20.0)
+        .bevel_join()
+        .square_cap();
+
+    draw.polyline(&offset(540.0, 60.0))
+        .color(Color::GREEN)
+        .width(20.0)
+        .round_join()
+        .round_cap();
+
+    draw.polyline(&[
+        (200.0, 350.0),
+        (400.0, 300.0),
+        (600.0, 350.0),
+        (400.0, 550.0),
+    ])
+    .color(Color::WHITE)
+    .width(10.0)
+    .close();
+
+    gfx.render(&draw);
+}
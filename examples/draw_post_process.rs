@@ -0,0 +1,116 @@
+use notan::draw::*;
+use notan::extra::{PostPass, PostProcess};
+use notan::prelude::*;
+
+//language=glsl
+const PIXELATE_FRAGMENT: ShaderSource = notan::fragment_shader! {
+    r#"
+    #version 450
+    precision mediump float;
+
+    layout(location = 0) in vec2 v_uvs;
+    layout(location = 0) out vec4 color;
+
+    layout(binding = 0) uniform sampler2D u_texture;
+    layout(set = 0, binding = 0) uniform Locals {
+        vec2 u_tex_size;
+        float u_size;
+    };
+
+    void main() {
+        vec2 size = vec2(u_size);
+        vec2 coord = floor(v_uvs * u_tex_size / size) * size + size * 0.5;
+        color = texture(u_texture, coord / u_tex_size);
+    }
+    "#
+};
+
+//language=glsl
+const VIGNETTE_FRAGMENT: ShaderSource = notan::fragment_shader! {
+    r#"
+    #version 450
+    precision mediump float;
+
+    layout(location = 0) in vec2 v_uvs;
+    layout(location = 0) out vec4 color;
+
+    layout(binding = 0) uniform sampler2D u_texture;
+
+    void main() {
+        float dist = distance(v_uvs, vec2(0.5));
+        float vignette = smoothstep(0.75, 0.35, dist);
+        vec4 tex_color = texture(u_texture, v_uvs);
+        color = vec4(tex_color.rgb * vignette, tex_color.a);
+    }
+    "#
+};
+
+#[derive(AppState)]
+struct State {
+    post_process: PostProcess,
+    pixelate_ubo: Buffer,
+    time: f32,
+}
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init_with(setup)
+        .add_config(DrawConfig)
+        .update(update)
+        .draw(draw)
+        .build()
+}
+
+fn setup(gfx: &mut Graphics) -> State {
+    let pixelate_ubo = gfx
+        .create_uniform_buffer(0, "Locals")
+        .with_data(&[800.0, 600.0, 1.0])
+        .build()
+        .unwrap();
+
+    let mut pixelate = PostPass::new(gfx, &PIXELATE_FRAGMENT).unwrap();
+    pixelate.add_uniforms(&pixelate_ubo);
+    let vignette = PostPass::new(gfx, &VIGNETTE_FRAGMENT).unwrap();
+
+    let mut post_process = PostProcess::new(gfx).unwrap();
+    post_process.add_pass(pixelate).add_pass(vignette);
+
+    State {
+        post_process,
+        pixelate_ubo,
+        time: 0.0,
+    }
+}
+
+fn update(app: &mut App, state: &mut State) {
+    state.time += app.timer.delta_f32();
+
+    // toggles the vignette
+    if app.keyboard.was_pressed(KeyCode::Space) {
+        let vignette = &mut state.post_process.passes_mut()[1];
+        let enabled = vignette.is_enabled();
+        vignette.set_enabled(!enabled);
+    }
+}
+
+fn draw(gfx: &mut Graphics, state: &mut State) {
+    let (width, height) = gfx.size();
+    let pixel_size = 1.0 + (state.time.sin() * 0.5 + 0.5) * 9.0;
+    gfx.set_buffer_data(
+        &state.pixelate_ubo,
+        &[width as f32, height as f32, pixel_size.floor()],
+    );
+
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::WHITE);
+    draw.circle(150.0)
+        .position(400.0, 300.0)
+        .color(Color::ORANGE);
+    draw.rect((100.0, 100.0), (200.0, 120.0))
+        .color(Color::BLUE)
+        .rotate_from((200.0, 160.0), state.time);
+    draw.triangle((600.0, 100.0), (700.0, 250.0), (500.0, 250.0))
+        .color(Color::GREEN);
+
+    state.post_process.render(gfx, &draw).unwrap();
+}
@@ -0,0 +1,66 @@
+use notan::draw::*;
+use notan::math::{vec2, Mat3};
+use notan::prelude::*;
+
+#[derive(AppState)]
+struct State {
+    minimap: RenderTexture,
+    angle: f32,
+}
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init_with(setup)
+        .add_config(DrawConfig)
+        .update(update)
+        .draw(draw)
+        .build()
+}
+
+fn setup(gfx: &mut Graphics) -> State {
+    // the shapes are tessellated, multisampling smooths their edges
+    let minimap = gfx
+        .create_render_texture(200, 150)
+        .with_multisampling(4)
+        .build()
+        .unwrap();
+    State {
+        minimap,
+        angle: 0.0,
+    }
+}
+
+fn update(app: &mut App, state: &mut State) {
+    state.angle += app.timer.delta_f32();
+}
+
+fn draw_scene(draw: &mut Draw, angle: f32) {
+    draw.rect((100.0, 100.0), (200.0, 150.0))
+        .color(Color::ORANGE)
+        .rotate_from((200.0, 175.0), angle);
+    draw.circle(80.0).position(550.0, 400.0).color(Color::AQUA);
+    draw.triangle((400.0, 100.0), (500.0, 250.0), (300.0, 250.0))
+        .color(Color::MAGENTA);
+}
+
+fn draw(gfx: &mut Graphics, state: &mut State) {
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::BLACK);
+
+    // the minimap is drawn before the main draw, so it can be used as a texture on it
+    draw.render_to(&state.minimap, |draw| {
+        draw.clear(Color::GRAY);
+        draw.transform().push(Mat3::from_scale(vec2(0.25, 0.25)));
+        draw_scene(draw, state.angle);
+        draw.transform().pop();
+    });
+
+    draw_scene(&mut draw, state.angle);
+
+    draw.image(&state.minimap).position(590.0, 440.0);
+    draw.rect((590.0, 440.0), (200.0, 150.0))
+        .stroke(2.0)
+        .color(Color::WHITE);
+
+    gfx.render(&draw);
+}
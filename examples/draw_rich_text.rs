@@ -0,0 +1,75 @@
+use notan::draw::*;
+use notan::prelude::*;
+
+#[derive(AppState)]
+struct State {
+    font: Font,
+    pixel_font: Font,
+    damage: u32,
+}
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init_with(setup)
+        .add_config(DrawConfig)
+        .update(update)
+        .draw(draw)
+        .build()
+}
+
+fn setup(gfx: &mut Graphics) -> State {
+    let font = gfx
+        .create_font(include_bytes!("assets/Ubuntu-B.ttf"))
+        .unwrap();
+    let pixel_font = gfx
+        .create_font(include_bytes!("assets/kenney_pixel-webfont.ttf"))
+        .unwrap();
+
+    State {
+        font,
+        pixel_font,
+        damage: 32,
+    }
+}
+
+fn update(app: &mut App, state: &mut State) {
+    if app.keyboard.was_pressed(KeyCode::Space) {
+        state.damage = (state.damage * 7 + 13) % 100;
+    }
+}
+
+fn draw(gfx: &mut Graphics, state: &mut State) {
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::BLACK);
+
+    let damage = state.damage.to_string();
+    let hp = [
+        Span::colored("HP: ", Color::WHITE),
+        Span::colored(&damage, Color::RED).with_size(60.0),
+        Span::new(" / 100").with_font(&state.pixel_font),
+    ];
+
+    draw.rich_text(&state.font, &hp)
+        .position(400.0, 200.0)
+        .size(30.0)
+        .color(Color::GRAY)
+        .h_align_center()
+        .v_align_middle();
+
+    let tip = [
+        Span::new("Press "),
+        Span::colored("SPACE", Color::YELLOW).with_size(26.0),
+        Span::new(" to deal a different amount of "),
+        Span::colored("damage", Color::ORANGE),
+        Span::new(". Highlighted keywords keep the same baseline as the rest of the sentence."),
+    ];
+
+    draw.rich_text(&state.font, &tip)
+        .position(400.0, 400.0)
+        .size(20.0)
+        .max_width(500.0)
+        .h_align_center()
+        .v_align_middle();
+
+    gfx.render(&draw);
+}
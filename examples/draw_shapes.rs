@@ -12,6 +12,15 @@ fn draw(gfx: &mut Graphics) {
 
     draw.line((20.0, 30.0), (780.0, 30.0)).width(4.0);
 
+    draw.cubic_bezier(
+        (450.0, 560.0),
+        (520.0, 480.0),
+        (660.0, 620.0),
+        (780.0, 540.0),
+    )
+    .color(Color::SILVER)
+    .width(4.0);
+
     draw.triangle((100.0, 100.0), (150.0, 200.0), (200.0, 100.0))
         .color(Color::YELLOW);
 
@@ -25,6 +34,11 @@ fn draw(gfx: &mut Graphics) {
         .color(Color::RED)
         .rotate_degrees(-45.0);
 
+    draw.ellipse((560.0, 320.0), (70.0, 30.0))
+        .color(Color::AQUA)
+        .rotate_degrees(20.0)
+        .stroke(4.0);
+
     draw.circle(40.0)
         .position(600.0, 450.0)
         .fill_color(Color::BLUE)
@@ -46,6 +60,7 @@ fn draw(gfx: &mut Graphics) {
 
     draw.polygon(5, 50.0)
         .position(350.0, 150.0)
+        .rotate_degrees(36.0)
         .color(Color::WHITE)
         .stroke(8.0);
 
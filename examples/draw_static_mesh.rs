@@ -0,0 +1,55 @@
+use notan::draw::*;
+use notan::prelude::*;
+
+#[derive(AppState)]
+struct State {
+    mesh: StaticMesh,
+    rotation: f32,
+}
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init_with(setup)
+        .add_config(DrawConfig)
+        .draw(draw)
+        .build()
+}
+
+fn setup(gfx: &mut Graphics) -> State {
+    // the circles are tessellated and uploaded only once
+    let mut draw = gfx.create_draw();
+    for i in 0..2000 {
+        let angle = i as f32 * 0.1;
+        let distance = i as f32 * 0.15;
+        let color = Color::from_hex(0xff6600ff).with_alpha(0.3 + (i % 7) as f32 * 0.1);
+        draw.circle(4.0)
+            .position(angle.cos() * distance, angle.sin() * distance)
+            .color(color);
+    }
+
+    let mesh = gfx.create_static_mesh(&draw).unwrap();
+    State {
+        mesh,
+        rotation: 0.0,
+    }
+}
+
+fn draw(app: &mut App, gfx: &mut Graphics, state: &mut State) {
+    state.rotation += app.timer.delta_f32();
+
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::BLACK);
+
+    draw.static_mesh(&state.mesh)
+        .rotate(state.rotation)
+        .translate(400.0, 300.0);
+
+    draw.static_mesh(&state.mesh)
+        .color(Color::AQUA)
+        .alpha(0.5)
+        .scale(0.5, 0.5)
+        .rotate(-state.rotation)
+        .translate(400.0, 300.0);
+
+    gfx.render(&draw);
+}
@@ -0,0 +1,83 @@
+use notan::draw::*;
+use notan::prelude::*;
+
+#[derive(AppState)]
+struct State {
+    font: Font,
+    bunny: Texture,
+    ferris: Texture,
+    gpu_stats: GpuStats,
+}
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init_with(setup)
+        .add_config(DrawConfig)
+        .draw(draw)
+        .build()
+}
+
+fn setup(gfx: &mut Graphics) -> State {
+    let font = gfx
+        .create_font(include_bytes!("assets/Ubuntu-B.ttf"))
+        .unwrap();
+    let bunny = gfx
+        .create_texture()
+        .from_image(include_bytes!("assets/bunny.png"))
+        .build()
+        .unwrap();
+    let ferris = gfx
+        .create_texture()
+        .from_image(include_bytes!("assets/ferris.png"))
+        .build()
+        .unwrap();
+
+    State {
+        font,
+        bunny,
+        ferris,
+        gpu_stats: GpuStats::default(),
+    }
+}
+
+fn draw(gfx: &mut Graphics, state: &mut State) {
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::BLACK);
+    draw.set_culling(true);
+
+    // shapes and images of different textures share the batches
+    for i in 0..60 {
+        let x = (i % 10) as f32 * 90.0;
+        let y = (i / 10) as f32 * 110.0 + 120.0;
+        draw.circle(20.0).position(x + 20.0, y).color(Color::ORANGE);
+        let texture = if i % 2 == 0 {
+            &state.bunny
+        } else {
+            &state.ferris
+        };
+        draw.image(texture).position(x, y).size(40.0, 30.0);
+    }
+
+    // the text starts a new batch, and the elements outside of the screen are culled
+    let stats = draw.stats();
+    let text = format!(
+        "Draw: {} elements, {} culled, {} batches ({} breaks), {} vertices\nGPU (last frame): {} draw calls, {} texture binds, {} buffer updates, {} skipped state changes",
+        stats.elements,
+        stats.culled,
+        stats.batches,
+        stats.breaks.total(),
+        stats.vertices,
+        state.gpu_stats.draw_calls,
+        state.gpu_stats.texture_binds,
+        state.gpu_stats.buffer_updates,
+        state.gpu_stats.skipped_state_changes,
+    );
+    draw.text(&state.font, &text)
+        .position(10.0, 10.0)
+        .size(18.0);
+
+    gfx.render(&draw);
+
+    // the gpu stats are reset at the end of the frame, so they are read after rendering
+    state.gpu_stats = gfx.stats();
+}
@@ -0,0 +1,59 @@
+use notan::draw::*;
+use notan::prelude::*;
+
+#[derive(AppState)]
+struct State {
+    font: BitmapFont,
+}
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init_with(setup)
+        .add_config(DrawConfig)
+        .draw(draw)
+        .build()
+}
+
+fn setup(gfx: &mut Graphics) -> State {
+    let mut font = BitmapFont::from_bytes(include_bytes!("assets/pixel_font.fnt")).unwrap();
+
+    // pixel fonts look better without filtering when they are scaled
+    let page = gfx
+        .create_texture()
+        .from_image(include_bytes!("assets/pixel_font.png"))
+        .with_filter(TextureFilter::Nearest, TextureFilter::Nearest)
+        .build()
+        .unwrap();
+    font.set_page(0, page);
+
+    State { font }
+}
+
+fn draw(gfx: &mut Graphics, state: &mut State) {
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::new(0.1, 0.1, 0.2, 1.0));
+
+    // by default the text uses the size of the font
+    draw.text(&state.font, "Bitmap fonts don't need to rasterize glyphs")
+        .position(20.0, 20.0);
+
+    draw.text(&state.font, "GAME OVER")
+        .position(400.0, 200.0)
+        .size(96.0)
+        .color(Color::RED)
+        .h_align_center()
+        .v_align_middle();
+
+    draw.text(
+        &state.font,
+        "Pixel-art games usually ship pre-baked fonts made with tools like BMFont or Hiero.\nThe same alignment and wrapping options used for TTF fonts work here too.",
+    )
+    .position(400.0, 420.0)
+    .size(32.0)
+    .color(Color::YELLOW)
+    .max_width(600.0)
+    .h_align_center()
+    .v_align_middle();
+
+    gfx.render(&draw);
+}
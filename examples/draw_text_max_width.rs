@@ -40,11 +40,11 @@ fn draw(gfx: &mut Graphics, state: &mut State) {
         .v_align_middle();
 
     draw.text(&state.font, LOREM_IPSUM)
-        .position(600.0, 300.0)
+        .position(450.0, 300.0)
         .size(14.0)
         .color(Color::MAGENTA)
         .max_width(300.0)
-        .h_align_center()
+        .h_align_justify()
         .v_align_middle();
 
     gfx.render(&draw);
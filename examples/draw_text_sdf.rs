@@ -0,0 +1,67 @@
+use notan::draw::*;
+use notan::prelude::*;
+
+#[derive(AppState)]
+struct State {
+    font: Font,
+}
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init_with(setup)
+        .add_config(DrawConfig)
+        .draw(draw)
+        .build()
+}
+
+fn setup(gfx: &mut Graphics) -> State {
+    let font = gfx
+        .create_font(include_bytes!("assets/Ubuntu-B.ttf"))
+        .unwrap();
+    State { font }
+}
+
+fn draw(app: &mut App, gfx: &mut Graphics, state: &mut State) {
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::new(0.1, 0.1, 0.2, 1.0));
+
+    let t = app.timer.elapsed_f32();
+    let scale = 1.0 + (t.sin() * 0.5 + 0.5) * 3.0;
+
+    // raster text gets blurry when it's scaled
+    draw.text(&state.font, "Raster")
+        .position(200.0, 150.0)
+        .size(20.0)
+        .h_align_center()
+        .v_align_middle()
+        .scale_from((200.0, 150.0), (scale, scale));
+
+    // sdf text keeps the edges sharp
+    draw.text(&state.font, "SDF")
+        .position(600.0, 150.0)
+        .size(20.0)
+        .sdf(true)
+        .h_align_center()
+        .v_align_middle()
+        .scale_from((600.0, 150.0), (scale, scale));
+
+    draw.text(&state.font, "Outline")
+        .position(200.0, 420.0)
+        .size(60.0)
+        .color(Color::YELLOW)
+        .outline(3.0, Color::BLACK)
+        .h_align_center()
+        .v_align_middle()
+        .rotate_degrees_from((200.0, 420.0), t.sin() * 20.0);
+
+    draw.text(&state.font, "Glow")
+        .position(600.0, 420.0)
+        .size(60.0)
+        .color(Color::WHITE)
+        .outline(2.0, Color::ORANGE)
+        .glow(6.0, Color::RED)
+        .h_align_center()
+        .v_align_middle();
+
+    gfx.render(&draw);
+}
@@ -31,8 +31,7 @@ fn draw(app: &mut App, gfx: &mut Graphics, state: &mut State) {
     draw.clear(Color::BLACK);
 
     // Push to the transformation stack a translation matrix
-    draw.transform()
-        .push(Mat3::from_translation(Vec2::new(350.0, 250.0)));
+    draw.transform().push_translate(350.0, 250.0);
 
     // Calculate the matrix that we use for each object
     let translation = Mat3::from_translation(Vec2::new(30.0, 20.0));
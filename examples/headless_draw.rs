@@ -0,0 +1,37 @@
+// Draws to a render texture without opening a window and reads back the pixels.
+// The headless graphics use EGL, which is not available on MacOS, iOS and the web
+#[cfg(not(any(target_arch = "wasm32", target_os = "macos", target_os = "ios")))]
+fn main() -> Result<(), String> {
+    use notan::backend::HeadlessGraphics;
+    use notan::draw::*;
+    use notan::prelude::*;
+
+    const WIDTH: u32 = 400;
+    const HEIGHT: u32 = 300;
+
+    let mut gfx = HeadlessGraphics::new(WIDTH, HEIGHT)?;
+    let draw_ext = DrawExtension::new(&mut gfx)?;
+    gfx.add_extension(draw_ext);
+
+    let rt = gfx.create_render_texture(WIDTH, HEIGHT).build()?;
+
+    let mut draw = rt.create_draw();
+    draw.clear(Color::BLACK);
+    draw.circle(100.0)
+        .position(WIDTH as f32 * 0.5, HEIGHT as f32 * 0.5)
+        .color(Color::ORANGE);
+    gfx.render_to(&rt, &draw);
+
+    let mut bytes = vec![0; (WIDTH * HEIGHT * 4) as usize];
+    gfx.read_pixels(&rt).read_to(&mut bytes)?;
+
+    let center = ((HEIGHT / 2 * WIDTH + WIDTH / 2) * 4) as usize;
+    println!("Center pixel: {:?}", &bytes[center..center + 4]);
+
+    Ok(())
+}
+
+#[cfg(any(target_arch = "wasm32", target_os = "macos", target_os = "ios"))]
+fn main() {
+    println!("Headless graphics are not available on this platform");
+}
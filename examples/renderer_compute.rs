@@ -0,0 +1,111 @@
+use notan::draw::*;
+use notan::prelude::*;
+
+// Number of particles moved by the compute shader
+const PARTICLES: usize = 1024;
+
+//language=glsl
+const COMPUTE: ShaderSource = notan::compute_shader! {
+    r#"
+    #version 450
+    layout(local_size_x = 64) in;
+
+    layout(std430, set = 0, binding = 0) buffer Particles {
+        vec2 positions[];
+    };
+
+    layout(set = 0, binding = 1) uniform Locals {
+        float time;
+    };
+
+    void main() {
+        uint i = gl_GlobalInvocationID.x;
+        float n = float(i) * 0.01;
+        float radius = 50.0 + fract(n * 7.0) * 220.0;
+        float angle = time * (0.2 + fract(n * 3.0)) + n * 6.2831;
+        positions[i] = vec2(400.0, 300.0) + vec2(cos(angle), sin(angle)) * radius;
+    }
+    "#
+};
+
+struct Compute {
+    pipeline: ComputePipeline,
+    particles: Buffer,
+    ubo: Buffer,
+}
+
+#[derive(AppState)]
+struct State {
+    compute: Option<Compute>,
+    bytes: Vec<u8>,
+}
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init_with(setup)
+        .add_config(DrawConfig)
+        .draw(draw)
+        .build()
+}
+
+fn setup(gfx: &mut Graphics) -> State {
+    // Compute shaders are not available everywhere (like WebGL2)
+    let compute = if gfx.limits().compute {
+        let pipeline = gfx
+            .create_compute_pipeline()
+            .from(&COMPUTE)
+            .build()
+            .unwrap();
+
+        let particles = gfx
+            .create_storage_buffer(0)
+            .with_data(&vec![0.0f32; PARTICLES * 2])
+            .build()
+            .unwrap();
+
+        let ubo = gfx
+            .create_uniform_buffer(1, "Locals")
+            .with_data(&[0.0f32])
+            .build()
+            .unwrap();
+
+        Some(Compute {
+            pipeline,
+            particles,
+            ubo,
+        })
+    } else {
+        notan::log::warn!("Compute shaders are not supported on this device");
+        None
+    };
+
+    State {
+        compute,
+        bytes: vec![0; PARTICLES * 2 * 4],
+    }
+}
+
+fn draw(app: &mut App, gfx: &mut Graphics, state: &mut State) {
+    let mut draw = gfx.create_draw();
+    draw.clear(Color::BLACK);
+
+    if let Some(compute) = &state.compute {
+        gfx.set_buffer_data(&compute.ubo, &[app.timer.elapsed_f32()]);
+        gfx.dispatch_compute(&compute.pipeline)
+            .with_buffer(&compute.particles)
+            .with_buffer(&compute.ubo)
+            .dispatch((PARTICLES / 64) as _, 1, 1)
+            .unwrap();
+
+        gfx.read_buffer(&compute.particles, &mut state.bytes)
+            .unwrap();
+
+        state.bytes.chunks_exact(8).for_each(|pos| {
+            let x = f32::from_ne_bytes([pos[0], pos[1], pos[2], pos[3]]);
+            let y = f32::from_ne_bytes([pos[4], pos[5], pos[6], pos[7]]);
+            draw.circle(2.0).position(x, y).color(Color::ORANGE);
+        });
+    }
+
+    gfx.render(&draw);
+}
@@ -0,0 +1,189 @@
+use notan::math::{Mat4, Vec3};
+use notan::prelude::*;
+
+//language=glsl
+const VERT: ShaderSource = notan::vertex_shader! {
+    r#"
+    #version 450
+    layout(location = 0) in vec3 a_position;
+
+    layout(location = 0) out vec3 v_direction;
+
+    layout(set = 0, binding = 0) uniform Locals {
+        mat4 u_matrix;
+    };
+
+    void main() {
+        v_direction = a_position;
+        gl_Position = u_matrix * vec4(a_position, 1.0);
+    }
+    "#
+};
+
+//language=glsl
+const FRAG: ShaderSource = notan::fragment_shader! {
+    r#"
+    #version 450
+    precision mediump float;
+
+    layout(location = 0) in vec3 v_direction;
+    layout(location = 0) out vec4 color;
+
+    layout(binding = 0) uniform samplerCube u_cubemap;
+
+    void main() {
+        color = texture(u_cubemap, normalize(v_direction));
+    }
+    "#
+};
+
+const FACE_SIZE: u32 = 64;
+
+#[derive(AppState)]
+struct State {
+    clear_options: ClearOptions,
+    pipeline: Pipeline,
+    vbo: Buffer,
+    ebo: Buffer,
+    ubo: Buffer,
+    cubemap: Texture,
+    mvp: Mat4,
+    angle: f32,
+}
+
+#[notan_main]
+fn main() -> Result<(), String> {
+    notan::init_with(setup).draw(draw).build()
+}
+
+// checkerboard with a different color for each face
+fn face(color: Color) -> Vec<u8> {
+    let [r, g, b, a] = color.rgba_u8();
+    (0..FACE_SIZE * FACE_SIZE)
+        .flat_map(|i| {
+            let (x, y) = (i % FACE_SIZE / 8, i / FACE_SIZE / 8);
+            if (x + y) % 2 == 0 {
+                [r, g, b, a]
+            } else {
+                [r / 2, g / 2, b / 2, a]
+            }
+        })
+        .collect()
+}
+
+fn setup(gfx: &mut Graphics) -> State {
+    let clear_options = ClearOptions::color(Color::new(0.1, 0.2, 0.3, 1.0)).with_depth(1.0);
+
+    let vertex_info = VertexInfo::new().attr(0, VertexFormat::Float32x3);
+
+    let pipeline = gfx
+        .create_pipeline()
+        .from(&VERT, &FRAG)
+        .with_vertex_info(&vertex_info)
+        .with_texture_location(0, "u_cubemap")
+        .with_depth_stencil(DepthStencil {
+            write: true,
+            compare: CompareMode::Less,
+        })
+        .build()
+        .unwrap();
+
+    // faces ordered as +X, -X, +Y, -Y, +Z, -Z
+    let faces = [
+        face(Color::RED),
+        face(Color::GREEN),
+        face(Color::BLUE),
+        face(Color::YELLOW),
+        face(Color::MAGENTA),
+        face(Color::AQUA),
+    ];
+
+    let cubemap = gfx
+        .create_texture()
+        .from_cubemap_bytes(
+            [
+                &faces[0], &faces[1], &faces[2], &faces[3], &faces[4], &faces[5],
+            ],
+            FACE_SIZE,
+        )
+        .build()
+        .unwrap();
+
+    #[rustfmt::skip]
+    let vertices = [
+        -1.0, -1.0, -1.0,
+        1.0, -1.0, -1.0,
+        1.0, 1.0, -1.0,
+        -1.0, 1.0, -1.0,
+        -1.0, -1.0, 1.0,
+        1.0, -1.0, 1.0,
+        1.0, 1.0, 1.0,
+        -1.0, 1.0, 1.0,
+    ];
+
+    #[rustfmt::skip]
+    let indices = [
+        0, 2, 1, 0, 3, 2,
+        4, 5, 6, 4, 6, 7,
+        0, 4, 7, 0, 7, 3,
+        1, 2, 6, 1, 6, 5,
+        3, 7, 6, 3, 6, 2,
+        0, 1, 5, 0, 5, 4,
+    ];
+
+    let projection = Mat4::perspective_rh_gl(45.0, 4.0 / 3.0, 0.1, 100.0);
+    let view = Mat4::look_at_rh(
+        Vec3::new(4.0, 3.0, 3.0),
+        Vec3::new(0.0, 0.0, 0.0),
+        Vec3::new(0.0, 1.0, 0.0),
+    );
+    let mvp = Mat4::IDENTITY * projection * view;
+
+    let vbo = gfx
+        .create_vertex_buffer()
+        .with_info(&vertex_info)
+        .with_data(&vertices)
+        .build()
+        .unwrap();
+
+    let ebo = gfx
+        .create_index_buffer()
+        .with_data(&indices)
+        .build()
+        .unwrap();
+
+    let ubo = gfx
+        .create_uniform_buffer(0, "Locals")
+        .with_data(&mvp)
+        .build()
+        .unwrap();
+
+    State {
+        clear_options,
+        pipeline,
+        vbo,
+        ebo,
+        ubo,
+        cubemap,
+        mvp,
+        angle: 0.0,
+    }
+}
+
+fn draw(app: &mut App, gfx: &mut Graphics, state: &mut State) {
+    let rotation = Mat4::from_rotation_x(state.angle) * Mat4::from_rotation_y(state.angle);
+    gfx.set_buffer_data(&state.ubo, &(state.mvp * rotation));
+
+    let mut renderer = gfx.create_renderer();
+
+    renderer.begin(Some(state.clear_options));
+    renderer.set_pipeline(&state.pipeline);
+    renderer.bind_buffers(&[&state.vbo, &state.ebo, &state.ubo]);
+    renderer.bind_texture(0, &state.cubemap);
+    renderer.draw(0, 36);
+    renderer.end();
+
+    gfx.render(&renderer);
+
+    state.angle += 0.6 * app.timer.delta_f32();
+}
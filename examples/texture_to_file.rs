@@ -140,6 +140,12 @@ fn draw(app: &mut App, gfx: &mut Graphics, state: &mut State) {
         state.rt.to_file(gfx, "draw.png").unwrap();
         notan::log::info!("Saved file as 'draw.png'");
     }
+
+    if app.keyboard.was_pressed(KeyCode::F12) {
+        // Save what is on the screen, after rendering the frame
+        gfx.screenshot().unwrap().to_file("screenshot.png").unwrap();
+        notan::log::info!("Saved file as 'screenshot.png'");
+    }
 }
 
 fn draw_board(mouse: &Mouse, gfx: &mut Graphics, state: &mut State) {
@@ -207,7 +213,7 @@ fn draw_ui(gfx: &mut Graphics, state: &mut State) {
 
     state.colors.iter().for_each(|btn| btn.draw(&mut draw));
 
-    draw.text(&state.font, "Press SPACE to save")
+    draw.text(&state.font, "Press SPACE to save, F12 for a screenshot")
         .h_align_center()
         .v_align_top()
         .position(400.0, 560.0)
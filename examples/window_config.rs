@@ -11,6 +11,8 @@ fn main() -> Result<(), String> {
         .set_vsync(true) // enable vsync
         .set_resizable(true) // window can be resized
         .set_min_size(600, 400) // Set a minimum window size
+        .set_max_size(1920, 1080) // Set a maximum window size
+        .set_position(100, 100) // window's initial position
         .set_window_icon(Some(PathBuf::from("./examples/assets/rust.ico")))
         .set_taskbar_icon(Some(PathBuf::from("./examples/assets/rust.ico")));
 